@@ -9,25 +9,50 @@ mod icon;
 mod screenshot;
 
 use eframe::{App, Frame, egui};
-use egui::{CentralPanel, Color32};
-use egui_shadcn::{AvatarProps, AvatarSize, AvatarVariant, Theme, avatar};
+use egui::load::SizedTexture;
+use egui::{CentralPanel, Color32, ColorImage, TextureHandle, TextureOptions};
+use egui_shadcn::{
+    AvatarGroupProps, AvatarProps, AvatarSize, AvatarVariant, Theme, avatar, avatar_group,
+};
 
 struct AvatarExample {
     theme: Theme,
+    portrait: Option<TextureHandle>,
 }
 
 impl AvatarExample {
     fn new() -> Self {
         Self {
             theme: Theme::default(),
+            portrait: None,
         }
     }
+
+    fn portrait(&mut self, ctx: &egui::Context) -> SizedTexture {
+        let texture = self.portrait.get_or_insert_with(|| {
+            let size = 64;
+            let pixels = (0..size * size)
+                .map(|index| {
+                    let (x, y) = (index % size, index / size);
+                    Color32::from_rgb((x * 4) as u8, 120, (y * 4) as u8)
+                })
+                .collect();
+            ctx.load_texture(
+                "avatar-portrait",
+                ColorImage::new([size, size], pixels),
+                TextureOptions::LINEAR,
+            )
+        });
+        SizedTexture::from_handle(texture)
+    }
 }
 
 impl App for AvatarExample {
     fn update(&mut self, ctx: &egui::Context, _frame: &mut Frame) {
         screenshot::apply_screenshot_scale(ctx);
 
+        let portrait = self.portrait(ctx);
+
         CentralPanel::default().show(ctx, |ui| {
             ui.heading("Avatar Component");
             ui.add_space(16.0);
@@ -147,6 +172,46 @@ impl App for AvatarExample {
                         .color(Color32::from_rgb(59, 130, 246)),
                 );
             });
+
+            ui.add_space(16.0);
+            ui.label("Image (fallback shown only after 0.6s of loading or on error):");
+            ui.horizontal(|ui| {
+                avatar(
+                    ui,
+                    &self.theme,
+                    AvatarProps::new("IM")
+                        .size(AvatarSize::Size5)
+                        .image(portrait),
+                );
+                avatar(
+                    ui,
+                    &self.theme,
+                    AvatarProps::new("ER")
+                        .size(AvatarSize::Size5)
+                        .image("file://missing-avatar.png")
+                        .fallback_delay(0.6),
+                );
+            });
+
+            ui.add_space(16.0);
+            ui.label("Group:");
+            let initials = ["JD", "AB", "CD", "EF", "GH", "IJ"];
+            let avatars = initials
+                .iter()
+                .enumerate()
+                .map(|(index, initials)| {
+                    let props = AvatarProps::new(initials).variant(AvatarVariant::Solid);
+                    if index == 0 {
+                        props.image(portrait)
+                    } else {
+                        props
+                    }
+                })
+                .collect();
+            let group = avatar_group(ui, &self.theme, AvatarGroupProps::new(avatars).max(4));
+            group
+                .response
+                .on_hover_text(format!("{} more", group.overflow));
         });
     }
 }
@@ -11,20 +11,31 @@ mod screenshot;
 use eframe::{App, Frame, egui};
 use egui::{CentralPanel, Id, RichText};
 use egui_shadcn::{
-    BreadcrumbProps, DropdownMenuItemProps, DropdownMenuProps, DropdownMenuTriggerProps, Theme,
-    breadcrumb, breadcrumb_ellipsis, breadcrumb_item, breadcrumb_link, breadcrumb_list,
-    breadcrumb_page, breadcrumb_separator, dropdown_menu, dropdown_menu_item,
-    dropdown_menu_trigger,
+    BreadcrumbPathProps, BreadcrumbProps, DropdownMenuItemProps, DropdownMenuProps,
+    DropdownMenuTriggerProps, Theme, breadcrumb, breadcrumb_ellipsis, breadcrumb_item,
+    breadcrumb_link, breadcrumb_list, breadcrumb_page, breadcrumb_path, breadcrumb_separator,
+    dropdown_menu, dropdown_menu_item, dropdown_menu_trigger,
 };
 
+const PATH: [&str; 6] = [
+    "Home",
+    "Documentation",
+    "Building Your Application",
+    "Routing",
+    "Dynamic Routes",
+    "Catch-all Segments",
+];
+
 struct BreadcrumbExample {
     theme: Theme,
+    path_len: usize,
 }
 
 impl BreadcrumbExample {
     fn new() -> Self {
         Self {
             theme: Theme::default(),
+            path_len: PATH.len(),
         }
     }
 }
@@ -66,6 +77,25 @@ impl App for BreadcrumbExample {
                 "Collapse on narrow widths.",
             );
             render_breadcrumb_responsive(ui, &self.theme);
+
+            render_section(
+                ui,
+                &self.theme,
+                "Breadcrumb path",
+                "Built from segments; resize the window to collapse the middle ones.",
+            );
+            let response = breadcrumb_path(
+                ui,
+                &self.theme,
+                BreadcrumbProps::new(),
+                BreadcrumbPathProps::new(Id::new("breadcrumb-path"), &PATH[..self.path_len]),
+            );
+            if let Some(index) = response.clicked {
+                self.path_len = index + 1;
+            }
+            if self.path_len < PATH.len() && ui.button("Reset path").clicked() {
+                self.path_len = PATH.len();
+            }
         });
     }
 }
@@ -12,9 +12,10 @@ use eframe::{App, Frame, egui};
 use egui::scroll_area::ScrollSource;
 use egui::{Align, CentralPanel, CornerRadius, Layout, RichText, Stroke, StrokeKind, vec2};
 use egui_shadcn::{
-    CarouselContentProps, CarouselItemProps, CarouselOptions, CarouselOrientation, CarouselProps,
-    ControlSize, ControlVariant, Theme, button, carousel, carousel_content, carousel_item,
-    carousel_next, carousel_previous,
+    CarouselAlign, CarouselContentProps, CarouselItemProps, CarouselOptions, CarouselOrientation,
+    CarouselProps, CarouselThumbnailsProps, ControlSize, ControlVariant, Theme, button, carousel,
+    carousel_content, carousel_dots, carousel_item, carousel_next, carousel_previous,
+    carousel_thumbnails,
 };
 
 struct CarouselExample {
@@ -96,6 +97,30 @@ impl App for CarouselExample {
                         "Autoplay with looping enabled.",
                     );
                     render_carousel_autoplay(ui, &self.theme);
+
+                    render_section(
+                        ui,
+                        &self.theme,
+                        "Carousel dots",
+                        "Two slides per step, centered, with dot indicators.",
+                    );
+                    render_carousel_dots(ui, &self.theme);
+
+                    render_section(
+                        ui,
+                        &self.theme,
+                        "Carousel drag free",
+                        "Flick or scroll with a trackpad; slides glide and stop anywhere.",
+                    );
+                    render_carousel_drag_free(ui, &self.theme);
+
+                    render_section(
+                        ui,
+                        &self.theme,
+                        "Carousel thumbnails",
+                        "Thumbnail strip synchronized with the slides.",
+                    );
+                    render_carousel_thumbnails(ui, &self.theme);
                 });
         });
     }
@@ -241,6 +266,79 @@ fn render_carousel_autoplay(ui: &mut egui::Ui, theme: &Theme) {
     );
 }
 
+fn render_carousel_dots(ui: &mut egui::Ui, theme: &Theme) {
+    let slides = ["One", "Two", "Three", "Four", "Five", "Six"];
+    let opts = CarouselOptions::default()
+        .slides_to_scroll(2)
+        .align(CarouselAlign::Center);
+    carousel(
+        ui,
+        theme,
+        CarouselProps::new(egui::Id::new("carousel-dots")).opts(opts),
+        |ui, ctx| {
+            ui.with_layout(Layout::top_down(Align::Center), |col| {
+                col.spacing_mut().item_spacing.y = 12.0;
+                let _ = carousel_content(
+                    col,
+                    theme,
+                    ctx,
+                    CarouselContentProps::new()
+                        .size(vec2(360.0, 140.0))
+                        .item_basis(0.45)
+                        .spacing(12.0),
+                    |content_ui, ctx| render_slides(content_ui, theme, ctx, &slides),
+                );
+                carousel_dots(col, theme, ctx);
+            });
+        },
+    );
+}
+
+fn render_carousel_drag_free(ui: &mut egui::Ui, theme: &Theme) {
+    let slides = ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"];
+    let _ = render_carousel(
+        ui,
+        theme,
+        "carousel-drag-free",
+        CarouselOrientation::Horizontal,
+        CarouselOptions::default().drag_free(true),
+        CarouselContentProps::new()
+            .size(vec2(360.0, 120.0))
+            .item_basis(0.3)
+            .spacing(8.0),
+        &slides,
+    );
+}
+
+fn render_carousel_thumbnails(ui: &mut egui::Ui, theme: &Theme) {
+    let slides = ["Mountains", "Lake", "Forest", "Desert", "Coast", "City"];
+    carousel(
+        ui,
+        theme,
+        CarouselProps::new(egui::Id::new("carousel-thumbnails")),
+        |ui, ctx| {
+            ui.vertical(|col| {
+                col.spacing_mut().item_spacing.y = 12.0;
+                let _ = carousel_content(
+                    col,
+                    theme,
+                    ctx,
+                    CarouselContentProps::new().size(vec2(360.0, 160.0)),
+                    |content_ui, ctx| render_slides(content_ui, theme, ctx, &slides),
+                );
+                col.set_max_width(360.0);
+                carousel_thumbnails(
+                    col,
+                    theme,
+                    ctx,
+                    CarouselThumbnailsProps::new(),
+                    |thumb_ui, index| render_slide(thumb_ui, theme, index, ""),
+                );
+            });
+        },
+    );
+}
+
 fn render_carousel(
     ui: &mut egui::Ui,
     theme: &Theme,
@@ -23,20 +23,34 @@ mod app {
     use egui_plot::Bar;
     use egui_shadcn::chart::AxisFormatter;
     use egui_shadcn::{
-        BarChart, CardProps, CardVariant, ChartIndicator, ChartLegend, ChartLegendItem, ChartProps,
-        ChartTooltip, ChartTooltipItem, LineChart, ShadcnChart, Theme, card,
+        AreaChart, BarChart, CardProps, CardVariant, ChartConfig, ChartConfigItem, ChartIndicator,
+        ChartLegend, ChartLegendItem, ChartProps, ChartSeries, ChartSlice, ChartStacking,
+        ChartTooltip, ChartTooltipItem, LineChart, MultiBarChart, PieChart, RadarChart,
+        RadialBarChart, RingSeries, ShadcnChart, Theme, TimeAxis, card, time_x,
     };
     use lucide_icons::{Icon, LUCIDE_FONT_BYTES};
     use std::sync::Arc;
 
     pub struct ChartExample {
         theme: Theme,
+        config: ChartConfig,
+        live: RingSeries,
     }
 
     impl ChartExample {
         pub fn new() -> Self {
             Self {
                 theme: Theme::default(),
+                config: ChartConfig::new()
+                    .item(
+                        "desktop",
+                        ChartConfigItem::new("Desktop").icon(Icon::Monitor),
+                    )
+                    .item(
+                        "mobile",
+                        ChartConfigItem::new("Mobile").icon(Icon::Smartphone),
+                    ),
+                live: RingSeries::new(2_000),
             }
         }
     }
@@ -63,6 +77,13 @@ mod app {
                         render_bar_demo(ui, &self.theme);
                         render_tooltip_demo(ui, &self.theme);
                         render_legend_demo(ui, &self.theme);
+                        render_live_demo(ui, &self.theme, &mut self.live);
+                        render_config_demo(ui, &self.theme, &mut self.config);
+                        render_stacked_area_demo(ui, &self.theme);
+                        render_multi_bar_demo(ui, &self.theme);
+                        render_donut_demo(ui, &self.theme);
+                        render_radar_demo(ui, &self.theme);
+                        render_radial_demo(ui, &self.theme);
                     });
             });
         }
@@ -422,6 +443,307 @@ mod app {
         );
     }
 
+    fn chart_card(
+        ui: &mut egui::Ui,
+        theme: &Theme,
+        title: &str,
+        description: &str,
+        add_contents: impl FnOnce(&mut egui::Ui),
+    ) {
+        card(
+            ui,
+            theme,
+            CardProps::default()
+                .with_variant(CardVariant::Outline)
+                .with_shadow(false)
+                .with_padding(vec2(24.0, 24.0)),
+            |card_ui| {
+                card_ui.spacing_mut().item_spacing.y = 12.0;
+                render_card_header(card_ui, theme, title, description);
+                card_ui.vertical(add_contents);
+            },
+        );
+    }
+
+    fn render_live_demo(ui: &mut egui::Ui, theme: &Theme, live: &mut RingSeries) {
+        // A noisy signal sampled every frame; the ring keeps the newest 2000 samples.
+        let now = chrono::Utc::now();
+        let t = time_x(&now);
+        live.push_time(&now, 50.0 + 30.0 * (t * 0.5).sin() + 10.0 * (t * 3.1).sin());
+        ui.ctx().request_repaint();
+
+        chart_card(
+            ui,
+            theme,
+            "Line Chart - Live",
+            "Follows the newest 30 seconds; drag to pause, double-click to resume",
+            |content| {
+                let line = live
+                    .line(300)
+                    .label("Signal")
+                    .color(theme.palette.chart_1)
+                    .fill_alpha(0.2);
+                let _ = ShadcnChart::new(
+                    ChartProps::new(egui::Id::new("chart-live"))
+                        .height(chart_height(content))
+                        .margin(vec2(12.0, 12.0))
+                        .show_legend(false)
+                        .show_y(true)
+                        .time_x_axis(TimeAxis::Local),
+                )
+                .show(content, theme, |plot_ui| {
+                    line.show(plot_ui);
+                    live.follow_latest(plot_ui, 30.0);
+                });
+            },
+        );
+    }
+
+    fn render_config_demo(ui: &mut egui::Ui, theme: &Theme, config: &mut ChartConfig) {
+        chart_card(
+            ui,
+            theme,
+            "Area Chart - Interactive",
+            "Click a legend item to hide or show its series",
+            |content| {
+                let data = [("desktop", &LINE_VALUES), ("mobile", &MOBILE_VALUES)];
+                let area = AreaChart::new(config.visible_series(theme, data));
+                let response = ShadcnChart::new(
+                    ChartProps::new(egui::Id::new("chart-config-demo"))
+                        .height(chart_height(content))
+                        .margin(vec2(12.0, 12.0))
+                        .show_legend(false)
+                        .crosshair(true)
+                        .x_axis_formatter(axis_formatter(&MONTH_LABELS)),
+                )
+                .show(content, theme, |plot_ui| area.show(plot_ui, theme));
+
+                if let (Some(index), Some(pos)) = (
+                    response.hovered_index(MONTH_LABELS.len()),
+                    response.pointer_pos,
+                ) {
+                    let items = config.tooltip_items(theme, index, data);
+                    ChartTooltip::new(&items)
+                        .title(MONTH_LABELS[index])
+                        .indicator(ChartIndicator::Dashed)
+                        .show(
+                            content.ctx(),
+                            theme,
+                            egui::Id::new("chart-config-tooltip"),
+                            pos,
+                        );
+                }
+                config.show_legend(content, theme);
+            },
+        );
+    }
+
+    fn visitor_series() -> Vec<ChartSeries> {
+        vec![
+            ChartSeries::new("Desktop", LINE_VALUES.to_vec()),
+            ChartSeries::new("Mobile", MOBILE_VALUES.to_vec()),
+        ]
+    }
+
+    fn render_stacked_area_demo(ui: &mut egui::Ui, theme: &Theme) {
+        chart_card(
+            ui,
+            theme,
+            "Area Chart - Stacked Expanded",
+            "Share of visitors per device for the last 6 months",
+            |content| {
+                let height = chart_height(content);
+                let area = AreaChart::new(visitor_series()).stacking(ChartStacking::Percent);
+                let chart = ShadcnChart::new(
+                    ChartProps::new(egui::Id::new("chart-area-percent"))
+                        .height(height)
+                        .margin(vec2(12.0, 12.0))
+                        .show_legend(false)
+                        .x_axis_formatter(axis_formatter(&MONTH_LABELS)),
+                );
+                let response = chart.show(content, theme, |plot_ui| area.show(plot_ui, theme));
+
+                if let (Some(point), Some(pos)) = (response.hovered_point, response.pointer_pos)
+                    && let Some((index, label, _)) =
+                        lookup_value(&MONTH_LABELS, &LINE_VALUES, point.x)
+                {
+                    let items = area.tooltip_items(theme, index);
+                    ChartTooltip::new(&items)
+                        .title(label)
+                        .indicator(ChartIndicator::Line)
+                        .show(
+                            content.ctx(),
+                            theme,
+                            egui::Id::new("chart-area-percent-tooltip"),
+                            pos,
+                        );
+                }
+                let _ = ChartLegend::new(&area.legend_items(theme)).show(content, theme);
+            },
+        );
+    }
+
+    fn render_multi_bar_demo(ui: &mut egui::Ui, theme: &Theme) {
+        chart_card(
+            ui,
+            theme,
+            "Bar Chart - Multiple",
+            "Grouped, stacked and horizontal bars",
+            |content| {
+                let height = chart_height(content);
+                let layouts = [
+                    ("grouped", ChartStacking::None, false),
+                    ("stacked", ChartStacking::Stacked, false),
+                    ("horizontal", ChartStacking::Stacked, true),
+                ];
+                for (name, stacking, horizontal) in layouts {
+                    let bars = MultiBarChart::new(visitor_series())
+                        .stacking(stacking)
+                        .horizontal(horizontal);
+                    let mut props = ChartProps::new(egui::Id::new(("chart-multi-bar", name)))
+                        .height(height * 0.75)
+                        .margin(vec2(12.0, 12.0))
+                        .show_legend(false);
+                    props = if horizontal {
+                        props
+                            .show_y(true)
+                            .show_grid(egui::Vec2b::new(true, false))
+                            .y_axis_formatter(axis_formatter(&MONTH_LABELS))
+                    } else {
+                        props.x_axis_formatter(axis_formatter(&MONTH_LABELS))
+                    };
+                    let response = ShadcnChart::new(props)
+                        .show(content, theme, |plot_ui| bars.show(plot_ui, theme));
+
+                    let category = response
+                        .hovered_point
+                        .map(|point| if horizontal { point.y } else { point.x });
+                    if let (Some(category), Some(pos)) = (category, response.pointer_pos)
+                        && let Some((index, label, _)) =
+                            lookup_value(&MONTH_LABELS, &LINE_VALUES, category)
+                    {
+                        let items = bars.tooltip_items(theme, index);
+                        ChartTooltip::new(&items).title(label).show(
+                            content.ctx(),
+                            theme,
+                            egui::Id::new(("chart-multi-bar-tooltip", name)),
+                            pos,
+                        );
+                    }
+                }
+            },
+        );
+    }
+
+    fn browser_slices() -> Vec<ChartSlice> {
+        vec![
+            ChartSlice::new("Chrome", 275.0),
+            ChartSlice::new("Safari", 200.0),
+            ChartSlice::new("Firefox", 187.0),
+            ChartSlice::new("Edge", 173.0),
+            ChartSlice::new("Other", 90.0),
+        ]
+    }
+
+    fn render_donut_demo(ui: &mut egui::Ui, theme: &Theme) {
+        chart_card(
+            ui,
+            theme,
+            "Pie Chart - Donut with Text",
+            "January - June 2024",
+            |content| {
+                let slices = browser_slices();
+                let total: f64 = slices.iter().map(|slice| slice.value).sum();
+                let pie = PieChart::new(slices)
+                    .donut(0.6)
+                    .center_label(format!("{total:.0}"), "Visitors");
+                let response = ShadcnChart::new(
+                    ChartProps::new(egui::Id::new("chart-pie-donut"))
+                        .polar()
+                        .height(chart_height(content)),
+                )
+                .show(content, theme, |plot_ui| pie.show(plot_ui, theme));
+
+                if let (Some(point), Some(pos)) = (response.hovered_point, response.pointer_pos)
+                    && let Some(item) = pie
+                        .slice_at(point)
+                        .and_then(|index| pie.tooltip_item(theme, index))
+                {
+                    ChartTooltip::new(&[item]).show(
+                        content.ctx(),
+                        theme,
+                        egui::Id::new("chart-pie-donut-tooltip"),
+                        pos,
+                    );
+                }
+                let _ = ChartLegend::new(&pie.legend_items(theme)).show(content, theme);
+            },
+        );
+    }
+
+    fn render_radar_demo(ui: &mut egui::Ui, theme: &Theme) {
+        chart_card(
+            ui,
+            theme,
+            "Radar Chart - Multiple",
+            "Showing total visitors for the last 6 months",
+            |content| {
+                let axes = MONTH_LABELS.map(String::from).to_vec();
+                let radar = RadarChart::new(axes, visitor_series());
+                let response = ShadcnChart::new(
+                    ChartProps::new(egui::Id::new("chart-radar"))
+                        .polar()
+                        .height(chart_height(content)),
+                )
+                .show(content, theme, |plot_ui| radar.show(plot_ui, theme));
+
+                if let (Some(point), Some(pos)) = (response.hovered_point, response.pointer_pos)
+                    && let Some(axis) = radar.axis_at(point)
+                {
+                    let items = radar.tooltip_items(theme, axis);
+                    ChartTooltip::new(&items).title(MONTH_LABELS[axis]).show(
+                        content.ctx(),
+                        theme,
+                        egui::Id::new("chart-radar-tooltip"),
+                        pos,
+                    );
+                }
+                let _ = ChartLegend::new(&radar.legend_items(theme)).show(content, theme);
+            },
+        );
+    }
+
+    fn render_radial_demo(ui: &mut egui::Ui, theme: &Theme) {
+        chart_card(
+            ui,
+            theme,
+            "Radial Chart",
+            "January - June 2024",
+            |content| {
+                let radial = RadialBarChart::new(browser_slices());
+                let response = ShadcnChart::new(
+                    ChartProps::new(egui::Id::new("chart-radial"))
+                        .polar()
+                        .height(chart_height(content)),
+                )
+                .show(content, theme, |plot_ui| radial.show(plot_ui, theme));
+
+                if let (Some(point), Some(pos)) = (response.hovered_point, response.pointer_pos)
+                    && let Some(item) = radial
+                        .slice_at(point)
+                        .and_then(|index| radial.tooltip_item(theme, index))
+                {
+                    ChartTooltip::new(&[item]).show(
+                        content.ctx(),
+                        theme,
+                        egui::Id::new("chart-radial-tooltip"),
+                        pos,
+                    );
+                }
+            },
+        );
+    }
+
     fn series_points(values: &[f64]) -> Vec<[f64; 2]> {
         values
             .iter()
@@ -10,7 +10,11 @@ mod screenshot;
 
 use eframe::{App, Frame, egui};
 use egui::RichText;
-use egui_shadcn::{ControlSize, ControlVariant, Input, InputSize, InputType, Label, Theme, button};
+use egui_shadcn::form::max_length;
+use egui_shadcn::{
+    ControlSize, ControlVariant, Input, InputMask, InputSize, InputType, Label, NumberFormat,
+    NumberInputProps, SelectItem, TagsInputProps, Theme, button, number_input, tags_input,
+};
 use rfd::FileDialog;
 
 struct InputDemo {
@@ -22,6 +26,14 @@ struct InputDemo {
     labeled_email: String,
     text_email: String,
     username: String,
+    price: f64,
+    quantity: u32,
+    phone: String,
+    card: String,
+    phone_mask: InputMask,
+    card_mask: InputMask,
+    tags: Vec<String>,
+    tag_suggestions: Vec<SelectItem>,
 }
 
 impl InputDemo {
@@ -35,6 +47,17 @@ impl InputDemo {
             labeled_email: String::new(),
             text_email: String::new(),
             username: String::new(),
+            price: 1249.5,
+            quantity: 3,
+            phone: String::new(),
+            card: String::new(),
+            phone_mask: InputMask::phone_us(),
+            card_mask: InputMask::card_number(),
+            tags: vec!["egui".to_string(), "rust".to_string()],
+            tag_suggestions: ["egui", "rust", "shadcn", "wasm", "ui", "design"]
+                .into_iter()
+                .map(|tag| SelectItem::option(tag, tag))
+                .collect(),
         }
     }
 }
@@ -201,6 +224,72 @@ impl App for InputDemo {
                             true,
                         );
                     });
+                    grid.end_row();
+
+                    example_card(grid, "Number", |ui| {
+                        ui.set_min_width(card_width);
+                        ui.spacing_mut().item_spacing.y = 10.0;
+                        number_input(
+                            ui,
+                            &self.theme,
+                            NumberInputProps::new("price", &mut self.price)
+                                .range(0.0, 1_000_000.0)
+                                .step(0.5)
+                                .format(NumberFormat::locale("en-US").decimals(2).currency("$"))
+                                .width(card_width),
+                        );
+                        number_input(
+                            ui,
+                            &self.theme,
+                            NumberInputProps::new("quantity", &mut self.quantity)
+                                .range(1.0, 99.0)
+                                .width(120.0),
+                        );
+                    });
+
+                    example_card(grid, "Masked", |ui| {
+                        ui.set_min_width(card_width);
+                        ui.spacing_mut().item_spacing.y = 10.0;
+                        Input::new("phone")
+                            .placeholder("(555) 123-4567")
+                            .input_type(InputType::Tel)
+                            .mask(&self.phone_mask)
+                            .width(card_width)
+                            .show(ui, &self.theme, &mut self.phone);
+                        Input::new("card")
+                            .placeholder("1234 5678 9012 3456")
+                            .mask(&self.card_mask)
+                            .width(card_width)
+                            .show(ui, &self.theme, &mut self.card);
+                        ui.label(
+                            RichText::new(format!("Raw: {}", self.card_mask.unmask(&self.card)))
+                                .color(self.theme.palette.muted_foreground)
+                                .size(12.0),
+                        );
+                    });
+
+                    example_card(grid, "Tags", |ui| {
+                        ui.set_min_width(card_width);
+                        ui.spacing_mut().item_spacing.y = 6.0;
+                        let response = tags_input(
+                            ui,
+                            &self.theme,
+                            TagsInputProps::new("tags", &mut self.tags)
+                                .placeholder("Add a tag...")
+                                .width(card_width)
+                                .max_tags(5)
+                                .validator(max_length(12, "Tags are at most 12 characters."))
+                                .suggestions(&self.tag_suggestions),
+                        );
+                        let (text, color) = match response.error {
+                            Some(error) => (error, self.theme.palette.destructive),
+                            None => (
+                                "Enter or comma to add, Backspace to remove.".to_string(),
+                                self.theme.palette.muted_foreground,
+                            ),
+                        };
+                        ui.label(RichText::new(text).color(color).size(12.0));
+                    });
                 });
         });
     }
@@ -0,0 +1,174 @@
+#![cfg_attr(
+    all(target_os = "windows", not(debug_assertions)),
+    windows_subsystem = "windows"
+)]
+
+#[path = "../_shared/icon.rs"]
+mod icon;
+#[path = "../_shared/screenshot.rs"]
+mod screenshot;
+
+use eframe::{App, Frame, egui};
+use egui::{CentralPanel, Id, RichText, vec2};
+use egui_shadcn::{
+    CardProps, CardVariant, MiniChartProps, Theme, card, chart_color, mini_area, mini_bar,
+    sparkline,
+};
+
+const MONTHS: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+const REVENUE: [f64; 12] = [
+    12.0, 18.0, 15.0, 22.0, 19.0, 27.0, 24.0, 31.0, 28.0, 35.0, 33.0, 41.0,
+];
+const SUBSCRIPTIONS: [f64; 12] = [
+    40.0, 52.0, 61.0, 48.0, 70.0, 66.0, 82.0, 75.0, 90.0, 86.0, 97.0, 110.0,
+];
+const SALES: [f64; 12] = [
+    8.0, 11.0, 9.0, 14.0, 12.0, 10.0, 16.0, 13.0, 18.0, 15.0, 20.0, 17.0,
+];
+
+struct MiniChartExample {
+    theme: Theme,
+}
+
+impl MiniChartExample {
+    fn new() -> Self {
+        Self {
+            theme: Theme::default(),
+        }
+    }
+}
+
+impl App for MiniChartExample {
+    fn update(&mut self, ctx: &egui::Context, _frame: &mut Frame) {
+        screenshot::apply_screenshot_scale(ctx);
+        let theme = &self.theme;
+
+        CentralPanel::default().show(ctx, |ui| {
+            ui.heading("Mini charts");
+            ui.label("Painter-based charts that need no plotting backend.");
+            ui.add_space(16.0);
+
+            ui.horizontal_wrapped(|row| {
+                row.spacing_mut().item_spacing = vec2(16.0, 16.0);
+                stat_card(row, theme, "Total Revenue", "$45,231.89", |ui| {
+                    sparkline(
+                        ui,
+                        theme,
+                        MiniChartProps::new(Id::new("revenue"), &REVENUE)
+                            .size(vec2(200.0, 48.0))
+                            .label("Revenue")
+                            .categories(&MONTHS),
+                    );
+                });
+                stat_card(row, theme, "Subscriptions", "+2,350", |ui| {
+                    mini_area(
+                        ui,
+                        theme,
+                        MiniChartProps::new(Id::new("subscriptions"), &SUBSCRIPTIONS)
+                            .size(vec2(200.0, 48.0))
+                            .color(chart_color(theme, 1))
+                            .label("Subscriptions")
+                            .categories(&MONTHS),
+                    );
+                });
+                stat_card(row, theme, "Sales", "+12,234", |ui| {
+                    mini_bar(
+                        ui,
+                        theme,
+                        MiniChartProps::new(Id::new("sales"), &SALES)
+                            .size(vec2(200.0, 48.0))
+                            .color(chart_color(theme, 2))
+                            .label("Sales")
+                            .categories(&MONTHS),
+                    );
+                });
+            });
+
+            ui.add_space(24.0);
+            ui.label("In a table:");
+            ui.add_space(8.0);
+            egui::Grid::new("mini-chart-table")
+                .num_columns(3)
+                .spacing(vec2(24.0, 12.0))
+                .show(ui, |grid| {
+                    for (index, (name, values)) in [
+                        ("Revenue", &REVENUE),
+                        ("Subscriptions", &SUBSCRIPTIONS),
+                        ("Sales", &SALES),
+                    ]
+                    .into_iter()
+                    .enumerate()
+                    {
+                        grid.label(name);
+                        sparkline(
+                            grid,
+                            theme,
+                            MiniChartProps::new(Id::new(("table-trend", index)), values)
+                                .size(vec2(96.0, 20.0))
+                                .color(chart_color(theme, index))
+                                .label(name)
+                                .categories(&MONTHS),
+                        );
+                        mini_bar(
+                            grid,
+                            theme,
+                            MiniChartProps::new(Id::new(("table-bars", index)), &values[6..])
+                                .size(vec2(64.0, 20.0))
+                                .color(chart_color(theme, index))
+                                .label(name)
+                                .categories(&MONTHS[6..]),
+                        );
+                        grid.end_row();
+                    }
+                });
+        });
+    }
+}
+
+fn stat_card(
+    ui: &mut egui::Ui,
+    theme: &Theme,
+    title: &str,
+    value: &str,
+    add_chart: impl FnOnce(&mut egui::Ui),
+) {
+    ui.vertical(|tile| {
+        tile.set_width(248.0);
+        card(
+            tile,
+            theme,
+            CardProps::default()
+                .with_variant(CardVariant::Outline)
+                .with_shadow(false)
+                .with_padding(vec2(24.0, 20.0)),
+            |content| {
+                content.spacing_mut().item_spacing.y = 6.0;
+                content.label(
+                    RichText::new(title)
+                        .size(12.0)
+                        .color(theme.palette.muted_foreground),
+                );
+                content.label(
+                    RichText::new(value)
+                        .size(22.0)
+                        .strong()
+                        .color(theme.palette.foreground),
+                );
+                content.add_space(6.0);
+                add_chart(content);
+            },
+        );
+    });
+}
+
+fn main() -> eframe::Result<()> {
+    env_logger::init();
+    let options = icon::native_options();
+    eframe::run_native(
+        "Mini chart example",
+        options,
+        Box::new(|_cc| Ok(Box::new(MiniChartExample::new()))),
+    )
+}
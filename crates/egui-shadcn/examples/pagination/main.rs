@@ -9,15 +9,20 @@ mod icon;
 mod screenshot;
 
 use eframe::{App, Frame, egui};
-use egui::CentralPanel;
+use egui::{CentralPanel, Id};
 use egui_shadcn::{
-    PaginationLinkProps, PaginationProps, Theme, pagination, pagination_content,
-    pagination_ellipsis, pagination_item, pagination_link, pagination_next, pagination_previous,
+    PaginationControlsProps, PaginationLinkProps, PaginationProps, Theme, pagination,
+    pagination_content, pagination_controls, pagination_ellipsis, pagination_item, pagination_link,
+    pagination_next, pagination_previous,
 };
 
+const TOTAL_ROWS: usize = 487;
+
 struct PaginationExample {
     theme: Theme,
     current_page: usize,
+    controls_page: usize,
+    page_size: usize,
 }
 
 impl PaginationExample {
@@ -25,6 +30,8 @@ impl PaginationExample {
         Self {
             theme: Theme::default(),
             current_page: 2,
+            controls_page: 1,
+            page_size: 10,
         }
     }
 }
@@ -78,6 +85,29 @@ impl App for PaginationExample {
                     });
                 },
             );
+
+            ui.add_space(32.0);
+            ui.heading("Pagination controls");
+            ui.add_space(16.0);
+
+            let total_pages = TOTAL_ROWS.div_ceil(self.page_size);
+            pagination_controls(
+                ui,
+                &self.theme,
+                PaginationControlsProps::new(
+                    Id::new("pagination-controls"),
+                    total_pages,
+                    &mut self.controls_page,
+                )
+                .siblings(1)
+                .boundaries(2)
+                .show_jump(true)
+                .page_size(&mut self.page_size, &[10, 20, 50, 100]),
+            );
+            ui.add_space(8.0);
+            let first = (self.controls_page - 1) * self.page_size + 1;
+            let last = (self.controls_page * self.page_size).min(TOTAL_ROWS);
+            ui.label(format!("Rows {first}-{last} of {TOTAL_ROWS}"));
         });
     }
 }
@@ -51,13 +51,16 @@ impl App for ResizableDemo {
                 resizable_panel_group(
                     ui,
                     &theme,
-                    ResizablePanelGroupProps::new("horizontal-demo"),
+                    ResizablePanelGroupProps::new("horizontal-demo")
+                        .auto_save_id("resizable-demo-horizontal"),
                     &mut self.horizontal_sizes,
                     |ui, ctx| {
                         resizable_panel(
                             ui,
                             ctx,
-                            ResizablePanelProps::new(50.0).min_size(20.0),
+                            ResizablePanelProps::new(50.0)
+                                .min_size(20.0)
+                                .collapsible(true),
                             0,
                             |ui| {
                                 panel_content(ui, "Left Panel", Color32::from_rgb(59, 130, 246));
@@ -0,0 +1,183 @@
+#![cfg_attr(
+    all(target_os = "windows", not(debug_assertions)),
+    windows_subsystem = "windows"
+)]
+
+#[path = "../_shared/icon.rs"]
+mod icon;
+#[path = "../_shared/screenshot.rs"]
+mod screenshot;
+
+use eframe::{App, Frame, egui};
+use egui::{Id, RichText};
+use egui_shadcn::{
+    BreadcrumbProps, Button, ButtonSize, ButtonVariant, Route, Router, SidebarMenuButtonProps,
+    SidebarProps, SidebarProviderProps, Theme, router_breadcrumb, router_sidebar_menu_button,
+    router_tabs, sidebar, sidebar_content, sidebar_menu, sidebar_menu_item, sidebar_provider,
+};
+
+#[derive(Clone, Debug, PartialEq)]
+enum Screen {
+    Dashboard,
+    Projects,
+    Project { id: u32 },
+    ProjectSettings { id: u32 },
+    Settings,
+}
+
+impl Route for Screen {
+    fn title(&self) -> String {
+        match self {
+            Screen::Dashboard => "Dashboard".into(),
+            Screen::Projects => "Projects".into(),
+            Screen::Project { id } => format!("Project {id}"),
+            Screen::ProjectSettings { .. } => "Settings".into(),
+            Screen::Settings => "Settings".into(),
+        }
+    }
+
+    fn parent(&self) -> Option<Self> {
+        match self {
+            Screen::Dashboard => None,
+            Screen::Projects | Screen::Settings => Some(Screen::Dashboard),
+            Screen::Project { .. } => Some(Screen::Projects),
+            Screen::ProjectSettings { id } => Some(Screen::Project { id: *id }),
+        }
+    }
+}
+
+struct RouterDemo {
+    theme: Theme,
+    router: Router<Screen>,
+    sidebar_open: bool,
+}
+
+impl RouterDemo {
+    fn new() -> Self {
+        Self {
+            theme: Theme::default(),
+            router: Router::new(Screen::Dashboard),
+            sidebar_open: true,
+        }
+    }
+}
+
+impl App for RouterDemo {
+    fn update(&mut self, ctx: &egui::Context, _frame: &mut Frame) {
+        screenshot::apply_screenshot_scale(ctx);
+        self.router.handle_input(ctx);
+
+        egui::CentralPanel::default().show(ctx, |ui| {
+            let theme = &self.theme;
+            let router = &mut self.router;
+            sidebar_provider(
+                ui,
+                SidebarProviderProps::new(Id::new("router-sidebar"), &mut self.sidebar_open),
+                |ui, sidebar_ctx| {
+                    ui.horizontal(|ui| {
+                        sidebar(ui, theme, sidebar_ctx, SidebarProps::new(), |ui, ctx| {
+                            sidebar_content(ui, ctx, |ui, ctx| {
+                                sidebar_menu(ui, |ui| {
+                                    for route in
+                                        [Screen::Dashboard, Screen::Projects, Screen::Settings]
+                                    {
+                                        sidebar_menu_item(ui, |ui| {
+                                            router_sidebar_menu_button(
+                                                ui,
+                                                theme,
+                                                ctx,
+                                                SidebarMenuButtonProps::new(route.title()),
+                                                router,
+                                                route,
+                                            );
+                                        });
+                                    }
+                                });
+                            });
+                        });
+                        ui.add_space(16.0);
+                        ui.vertical(|ui| render_main(ui, theme, router));
+                    });
+                },
+            );
+        });
+    }
+}
+
+fn render_main(ui: &mut egui::Ui, theme: &Theme, router: &mut Router<Screen>) {
+    ui.horizontal(|ui| {
+        let back = Button::new("Back")
+            .variant(ButtonVariant::Outline)
+            .size(ButtonSize::Sm)
+            .enabled(router.can_go_back())
+            .show(ui, theme);
+        if back.clicked() {
+            router.back();
+        }
+        let forward = Button::new("Forward")
+            .variant(ButtonVariant::Outline)
+            .size(ButtonSize::Sm)
+            .enabled(router.can_go_forward())
+            .show(ui, theme);
+        if forward.clicked() {
+            router.forward();
+        }
+    });
+    ui.add_space(12.0);
+    router_breadcrumb(
+        ui,
+        theme,
+        BreadcrumbProps::new(),
+        Id::new("router-breadcrumb"),
+        router,
+    );
+    ui.add_space(12.0);
+
+    match router.current().clone() {
+        Screen::Dashboard => {
+            ui.heading("Dashboard");
+            ui.label(
+                "Use the mouse back/forward buttons or Alt+Left/Right to move through history.",
+            );
+        }
+        Screen::Projects => {
+            ui.heading("Projects");
+            for id in 1..=3 {
+                if ui.link(format!("Project {id}")).clicked() {
+                    router.navigate(Screen::Project { id });
+                }
+            }
+        }
+        Screen::Project { id } | Screen::ProjectSettings { id } => {
+            ui.heading(format!("Project {id}"));
+            let routes = [Screen::Project { id }, Screen::ProjectSettings { id }];
+            router_tabs(
+                ui,
+                theme,
+                Id::new("router-project-tabs"),
+                router,
+                &routes,
+                |ui, route| {
+                    let text = match route {
+                        Screen::ProjectSettings { .. } => "Project settings live here.",
+                        _ => "Project overview.",
+                    };
+                    ui.label(RichText::new(text).color(theme.palette.muted_foreground));
+                },
+            );
+        }
+        Screen::Settings => {
+            ui.heading("Settings");
+        }
+    }
+}
+
+fn main() -> eframe::Result<()> {
+    env_logger::init();
+    let options = icon::native_options();
+    eframe::run_native(
+        "Router example",
+        options,
+        Box::new(|_cc| Ok(Box::new(RouterDemo::new()))),
+    )
+}
@@ -25,6 +25,7 @@ struct SheetExample {
     side_open: [bool; 4],
     side_names: [String; 4],
     side_usernames: [String; 4],
+    drawer_open: bool,
 }
 
 impl SheetExample {
@@ -37,6 +38,7 @@ impl SheetExample {
             side_open: [false; 4],
             side_names: std::array::from_fn(|_| "Pedro Duarte".to_string()),
             side_usernames: std::array::from_fn(|_| "@peduarte".to_string()),
+            drawer_open: false,
         }
     }
 }
@@ -74,10 +76,46 @@ impl App for SheetExample {
                 &mut self.side_names,
                 &mut self.side_usernames,
             );
+
+            render_section(
+                ui,
+                &self.theme,
+                "Drawer",
+                "Bottom sheet with snap points; drag the handle down to dismiss.",
+            );
+            render_drawer(ui, &self.theme, &mut self.drawer_open);
         });
     }
 }
 
+fn render_drawer(ui: &mut egui::Ui, theme: &Theme, open: &mut bool) {
+    sheet(
+        ui,
+        SheetProps::new(ui.make_persistent_id("sheet-drawer"), open)
+            .side(SheetSide::Bottom)
+            .snap_points([0.25, 0.5, 1.0])
+            .drawer(),
+        |ui, ctx| {
+            let _ = sheet_trigger(ui, ctx, |ui| {
+                button(
+                    ui,
+                    theme,
+                    "Open Drawer",
+                    ControlVariant::Outline,
+                    ControlSize::Md,
+                    true,
+                )
+            });
+            let _ = sheet_content(ui, theme, ctx, |content_ui| {
+                sheet_header(content_ui, |header_ui| {
+                    sheet_title(header_ui, theme, "Move Goal");
+                    sheet_description(header_ui, theme, "Set your daily activity goal.");
+                });
+            });
+        },
+    );
+}
+
 fn render_section(ui: &mut egui::Ui, theme: &Theme, title: &str, description: &str) {
     ui.vertical(|ui| {
         ui.label(RichText::new(title).strong());
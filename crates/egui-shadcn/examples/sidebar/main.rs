@@ -9,17 +9,23 @@ mod icon;
 mod screenshot;
 
 use eframe::{App, Frame, egui};
-use egui::{Align, Layout, RichText};
+use egui::{Align, FontData, FontDefinitions, FontFamily, Id, Layout, RichText, Ui};
 use egui_shadcn::{
-    SidebarGroupLabelProps, SidebarGroupProps, SidebarMenuButtonProps, SidebarMenuButtonSize,
-    SidebarProps, SidebarProviderProps, Theme, sidebar, sidebar_content, sidebar_footer,
-    sidebar_group, sidebar_group_content, sidebar_group_label, sidebar_header, sidebar_menu,
-    sidebar_menu_button, sidebar_menu_item, sidebar_provider, sidebar_trigger,
+    ControlSize, ControlVariant, SidebarCollapsibleProps, SidebarContext, SidebarGroupLabelProps,
+    SidebarGroupProps, SidebarMenuActionProps, SidebarMenuButtonProps, SidebarMenuButtonSize,
+    SidebarProps, SidebarProviderProps, SidebarVariant, Theme, button, sidebar, sidebar_content,
+    sidebar_footer, sidebar_group, sidebar_group_collapsible, sidebar_group_content,
+    sidebar_group_label, sidebar_header, sidebar_inset, sidebar_menu, sidebar_menu_action,
+    sidebar_menu_badge, sidebar_menu_button, sidebar_menu_collapsible, sidebar_menu_item,
+    sidebar_provider, sidebar_rail, sidebar_trigger,
 };
+use lucide_icons::{Icon, LUCIDE_FONT_BYTES};
 
 struct SidebarDemo {
     theme: Theme,
     sidebar_open: bool,
+    variant: SidebarVariant,
+    active: &'static str,
 }
 
 impl SidebarDemo {
@@ -27,6 +33,8 @@ impl SidebarDemo {
         Self {
             theme: Theme::default(),
             sidebar_open: true,
+            variant: SidebarVariant::Sidebar,
+            active: "Overview",
         }
     }
 }
@@ -34,161 +42,263 @@ impl SidebarDemo {
 impl App for SidebarDemo {
     fn update(&mut self, ctx: &egui::Context, _frame: &mut Frame) {
         screenshot::apply_screenshot_scale(ctx);
-        egui::CentralPanel::default().show(ctx, |ui| {
-            sidebar_provider(
-                ui,
-                SidebarProviderProps::new(
-                    ui.make_persistent_id("sidebar-demo"),
-                    &mut self.sidebar_open,
-                )
-                .expanded_width(240.0)
-                .collapsed_width(64.0),
-                |ui, sidebar_ctx| {
-                    ui.horizontal(|layout| {
-                        let _ = sidebar(
-                            layout,
-                            &self.theme,
-                            sidebar_ctx,
-                            SidebarProps::new(),
-                            |sidebar_ui, sidebar_ctx| {
-                                sidebar_header(sidebar_ui, sidebar_ctx, |header_ui, _ctx| {
-                                    header_ui.horizontal(|row| {
-                                        row.label(RichText::new("Acme Inc").strong().size(14.0));
-                                    });
-                                });
+        ensure_lucide_font(ctx);
 
-                                sidebar_content(sidebar_ui, sidebar_ctx, |content_ui, ctx| {
-                                    content_ui.spacing_mut().item_spacing.y = 12.0;
+        let panel_fill = if self.variant == SidebarVariant::Inset {
+            self.theme.palette.sidebar
+        } else {
+            self.theme.palette.background
+        };
+        egui::CentralPanel::default()
+            .frame(egui::Frame::central_panel(&ctx.style()).fill(panel_fill))
+            .show(ctx, |ui| {
+                let theme = self.theme.clone();
+                let variant = self.variant;
+                let active = &mut self.active;
+                let mut next_variant = variant;
+                sidebar_provider(
+                    ui,
+                    SidebarProviderProps::new(
+                        ui.make_persistent_id("sidebar-demo"),
+                        &mut self.sidebar_open,
+                    )
+                    .expanded_width(240.0)
+                    .collapsed_width(64.0),
+                    |ui, sidebar_ctx| {
+                        ui.horizontal(|layout| {
+                            let _ = sidebar(
+                                layout,
+                                &theme,
+                                sidebar_ctx,
+                                SidebarProps::new().variant(variant),
+                                |sidebar_ui, sidebar_ctx| {
+                                    render_sidebar(sidebar_ui, &theme, sidebar_ctx, active)
+                                },
+                            );
+                            sidebar_rail(layout, &theme, sidebar_ctx);
 
-                                    sidebar_group(
+                            layout.add_space(16.0);
+                            if variant == SidebarVariant::Inset {
+                                sidebar_inset(layout, &theme, |content_ui| {
+                                    render_main(
+                                        content_ui,
+                                        &theme,
+                                        sidebar_ctx,
+                                        active,
+                                        &mut next_variant,
+                                    );
+                                });
+                            } else {
+                                layout.vertical(|content_ui| {
+                                    render_main(
                                         content_ui,
+                                        &theme,
+                                        sidebar_ctx,
+                                        active,
+                                        &mut next_variant,
+                                    );
+                                });
+                            }
+                        });
+                    },
+                );
+                self.variant = next_variant;
+            });
+    }
+}
+
+fn render_sidebar(
+    sidebar_ui: &mut Ui,
+    theme: &Theme,
+    sidebar_ctx: &mut SidebarContext,
+    active: &mut &'static str,
+) {
+    sidebar_header(sidebar_ui, sidebar_ctx, |header_ui, ctx| {
+        header_ui.horizontal(|row| {
+            let title = if ctx.is_collapsed() { "A" } else { "Acme Inc" };
+            row.label(RichText::new(title).strong().size(14.0));
+        });
+    });
+
+    sidebar_content(sidebar_ui, sidebar_ctx, |content_ui, ctx| {
+        content_ui.spacing_mut().item_spacing.y = 12.0;
+
+        sidebar_group(content_ui, ctx, SidebarGroupProps::new(), |group_ui| {
+            sidebar_group_label(
+                group_ui,
+                theme,
+                ctx,
+                SidebarGroupLabelProps::new("Navigation"),
+            );
+            sidebar_group_content(group_ui, ctx, |group_ui| {
+                sidebar_menu(group_ui, |menu_ui| {
+                    let items = [
+                        ("Overview", Icon::House),
+                        ("Projects", Icon::Folder),
+                        ("Tasks", Icon::SquareCheck),
+                        ("Calendar", Icon::Calendar),
+                    ];
+                    for (label, icon) in items {
+                        sidebar_menu_item(menu_ui, |item_ui| {
+                            let response = sidebar_menu_button(
+                                item_ui,
+                                theme,
+                                ctx,
+                                SidebarMenuButtonProps::new(label)
+                                    .icon(icon)
+                                    .active(*active == label),
+                            );
+                            if response.clicked() {
+                                *active = label;
+                            }
+                            match label {
+                                "Tasks" => sidebar_menu_badge(item_ui, theme, ctx, &response, "12"),
+                                "Projects" => {
+                                    let _ = sidebar_menu_action(
+                                        item_ui,
+                                        theme,
                                         ctx,
-                                        SidebarGroupProps::new(),
-                                        |group_ui| {
-                                            sidebar_group_label(
-                                                group_ui,
-                                                &self.theme,
-                                                ctx,
-                                                SidebarGroupLabelProps::new("Navigation"),
-                                            );
-                                            sidebar_group_content(group_ui, ctx, |group_ui| {
-                                                sidebar_menu(group_ui, |menu_ui| {
-                                                    sidebar_menu_item(menu_ui, |item_ui| {
-                                                        let _ = sidebar_menu_button(
-                                                            item_ui,
-                                                            &self.theme,
-                                                            ctx,
-                                                            SidebarMenuButtonProps::new("Overview")
-                                                                .active(true),
-                                                        );
-                                                    });
-                                                    sidebar_menu_item(menu_ui, |item_ui| {
-                                                        let _ = sidebar_menu_button(
-                                                            item_ui,
-                                                            &self.theme,
-                                                            ctx,
-                                                            SidebarMenuButtonProps::new("Projects"),
-                                                        );
-                                                    });
-                                                    sidebar_menu_item(menu_ui, |item_ui| {
-                                                        let _ = sidebar_menu_button(
-                                                            item_ui,
-                                                            &self.theme,
-                                                            ctx,
-                                                            SidebarMenuButtonProps::new("Tasks"),
-                                                        );
-                                                    });
-                                                    sidebar_menu_item(menu_ui, |item_ui| {
-                                                        let _ = sidebar_menu_button(
-                                                            item_ui,
-                                                            &self.theme,
-                                                            ctx,
-                                                            SidebarMenuButtonProps::new("Calendar"),
-                                                        );
-                                                    });
-                                                });
-                                            });
-                                        },
+                                        &response,
+                                        SidebarMenuActionProps::new(Icon::Plus).show_on_hover(true),
                                     );
+                                }
+                                _ => {}
+                            }
+                        });
+                    }
 
-                                    sidebar_group(
-                                        content_ui,
+                    let _ = sidebar_menu_collapsible(
+                        menu_ui,
+                        theme,
+                        ctx,
+                        SidebarCollapsibleProps::new(Id::new("sidebar-docs")).default_open(false),
+                        SidebarMenuButtonProps::new("Documentation").icon(Icon::BookOpen),
+                        |sub_ui| {
+                            for label in ["Introduction", "Get started", "Changelog"] {
+                                sidebar_menu_item(sub_ui, |item_ui| {
+                                    let response = sidebar_menu_button(
+                                        item_ui,
+                                        theme,
                                         ctx,
-                                        SidebarGroupProps::new(),
-                                        |group_ui| {
-                                            sidebar_group_label(
-                                                group_ui,
-                                                &self.theme,
-                                                ctx,
-                                                SidebarGroupLabelProps::new("Settings"),
-                                            );
-                                            sidebar_group_content(group_ui, ctx, |group_ui| {
-                                                sidebar_menu(group_ui, |menu_ui| {
-                                                    sidebar_menu_item(menu_ui, |item_ui| {
-                                                        let _ = sidebar_menu_button(
-                                                            item_ui,
-                                                            &self.theme,
-                                                            ctx,
-                                                            SidebarMenuButtonProps::new("Profile"),
-                                                        );
-                                                    });
-                                                    sidebar_menu_item(menu_ui, |item_ui| {
-                                                        let _ = sidebar_menu_button(
-                                                            item_ui,
-                                                            &self.theme,
-                                                            ctx,
-                                                            SidebarMenuButtonProps::new(
-                                                                "Preferences",
-                                                            ),
-                                                        );
-                                                    });
-                                                });
-                                            });
-                                        },
+                                        SidebarMenuButtonProps::new(label)
+                                            .size(SidebarMenuButtonSize::Sm)
+                                            .active(*active == label),
                                     );
+                                    if response.clicked() {
+                                        *active = label;
+                                    }
                                 });
+                            }
+                        },
+                    );
+                });
+            });
+        });
 
-                                sidebar_footer(sidebar_ui, sidebar_ctx, |footer_ui, ctx| {
-                                    sidebar_menu(footer_ui, |menu_ui| {
-                                        sidebar_menu_item(menu_ui, |item_ui| {
-                                            let _ = sidebar_menu_button(
-                                                item_ui,
-                                                &self.theme,
-                                                ctx,
-                                                SidebarMenuButtonProps::new("Log out")
-                                                    .size(SidebarMenuButtonSize::Sm),
-                                            );
-                                        });
-                                    });
-                                });
-                            },
-                        );
-
-                        layout.add_space(16.0);
-                        layout.vertical(|content_ui| {
-                            content_ui.with_layout(Layout::top_down(Align::Min), |content_ui| {
-                                sidebar_trigger(
-                                    content_ui,
-                                    &self.theme,
-                                    sidebar_ctx,
-                                    "Toggle sidebar",
-                                );
-                                content_ui.add_space(12.0);
-                                content_ui.heading("Main content");
-                                content_ui.add_space(8.0);
-                                content_ui.label(
-                                    RichText::new(
-                                        "Use the toggle button to collapse or expand the sidebar.",
-                                    )
-                                    .size(13.0),
-                                );
-                            });
+        let _ = sidebar_group_collapsible(
+            content_ui,
+            theme,
+            ctx,
+            SidebarCollapsibleProps::new(Id::new("sidebar-settings")),
+            SidebarGroupLabelProps::new("Settings"),
+            |group_ui| {
+                sidebar_menu(group_ui, |menu_ui| {
+                    for (label, icon) in [("Profile", Icon::User), ("Preferences", Icon::Settings)]
+                    {
+                        sidebar_menu_item(menu_ui, |item_ui| {
+                            let response = sidebar_menu_button(
+                                item_ui,
+                                theme,
+                                ctx,
+                                SidebarMenuButtonProps::new(label)
+                                    .icon(icon)
+                                    .active(*active == label),
+                            );
+                            if response.clicked() {
+                                *active = label;
+                            }
                         });
-                    });
-                },
-            );
+                    }
+                });
+            },
+        );
+    });
+
+    sidebar_footer(sidebar_ui, sidebar_ctx, |footer_ui, ctx| {
+        sidebar_menu(footer_ui, |menu_ui| {
+            sidebar_menu_item(menu_ui, |item_ui| {
+                let _ = sidebar_menu_button(
+                    item_ui,
+                    theme,
+                    ctx,
+                    SidebarMenuButtonProps::new("Log out")
+                        .icon(Icon::LogOut)
+                        .size(SidebarMenuButtonSize::Sm),
+                );
+            });
         });
+    });
+}
+
+fn render_main(
+    content_ui: &mut Ui,
+    theme: &Theme,
+    sidebar_ctx: &mut SidebarContext,
+    active: &str,
+    variant: &mut SidebarVariant,
+) {
+    content_ui.with_layout(Layout::top_down(Align::Min), |content_ui| {
+        sidebar_trigger(content_ui, theme, sidebar_ctx, "Toggle sidebar");
+        content_ui.add_space(12.0);
+        content_ui.heading(active);
+        content_ui.add_space(8.0);
+        content_ui.label(
+            RichText::new(
+                "Use the toggle button or click and drag the sidebar edge to collapse it. \
+                 Collapsed items show their icons, with labels as tooltips. In a narrow \
+                 window the sidebar becomes a sheet opened by the same toggle.",
+            )
+            .size(13.0),
+        );
+        content_ui.add_space(12.0);
+        content_ui.horizontal(|row| {
+            for (label, option) in [
+                ("Sidebar", SidebarVariant::Sidebar),
+                ("Floating", SidebarVariant::Floating),
+                ("Inset", SidebarVariant::Inset),
+            ] {
+                let control = if *variant == option {
+                    ControlVariant::Primary
+                } else {
+                    ControlVariant::Secondary
+                };
+                if button(row, theme, label, control, ControlSize::Sm, true).clicked() {
+                    *variant = option;
+                }
+            }
+        });
+    });
+}
+
+fn ensure_lucide_font(ctx: &egui::Context) {
+    let font_loaded_id = egui::Id::new("lucide_font_loaded");
+    let already_set = ctx.data(|d| d.get_temp::<bool>(font_loaded_id).unwrap_or(false));
+    if already_set {
+        return;
     }
+
+    let mut fonts = FontDefinitions::default();
+    fonts.font_data.insert(
+        "lucide".into(),
+        FontData::from_static(LUCIDE_FONT_BYTES).into(),
+    );
+    fonts
+        .families
+        .entry(FontFamily::Proportional)
+        .or_default()
+        .push("lucide".into());
+    ctx.set_fonts(fonts);
+    ctx.data_mut(|d| d.insert_temp(font_loaded_id, true));
 }
 
 fn main() -> eframe::Result<()> {
@@ -0,0 +1,186 @@
+#![cfg_attr(
+    all(target_os = "windows", not(debug_assertions)),
+    windows_subsystem = "windows"
+)]
+
+#[path = "../_shared/icon.rs"]
+mod icon;
+#[path = "../_shared/screenshot.rs"]
+mod screenshot;
+
+use eframe::{App, Frame, egui};
+use egui::{FontData, FontDefinitions, FontFamily, Id, RichText, vec2};
+use egui_shadcn::{
+    SortableAxis, SortableProps, TabItem, TabsProps, Theme, apply_sortable_move, reorder_items,
+    sortable, tabs,
+};
+use lucide_icons::{Icon, LUCIDE_FONT_BYTES};
+
+const COLUMNS: [&str; 3] = ["Todo", "In progress", "Done"];
+
+struct SortableDemo {
+    theme: Theme,
+    tasks: Vec<String>,
+    board: Vec<Vec<String>>,
+    chips: Vec<String>,
+    tabs: Vec<TabItem>,
+    active_tab: String,
+}
+
+impl SortableDemo {
+    fn new() -> Self {
+        let strings = |items: &[&str]| items.iter().map(|item| item.to_string()).collect();
+        Self {
+            theme: Theme::default(),
+            tasks: strings(&[
+                "Review pull requests",
+                "Update dependencies",
+                "Write release notes",
+                "Tag the release",
+            ]),
+            board: vec![
+                strings(&["Design tokens", "Focus rings", "Dark mode"]),
+                strings(&["Tree view"]),
+                strings(&["Data table", "Tabs"]),
+            ],
+            chips: strings(&["Rust", "egui", "shadcn", "wgpu"]),
+            tabs: vec![
+                TabItem::new("overview", "Overview"),
+                TabItem::new("activity", "Activity"),
+                TabItem::new("settings", "Settings"),
+            ],
+            active_tab: "overview".into(),
+        }
+    }
+}
+
+impl App for SortableDemo {
+    fn update(&mut self, ctx: &egui::Context, _frame: &mut Frame) {
+        screenshot::apply_screenshot_scale(ctx);
+        ensure_lucide_font(ctx);
+
+        egui::CentralPanel::default().show(ctx, |ui| {
+            let theme = &self.theme;
+            let hint = |ui: &mut egui::Ui, text: &str| {
+                ui.label(
+                    RichText::new(text)
+                        .size(12.0)
+                        .color(theme.palette.muted_foreground),
+                );
+                ui.add_space(8.0);
+            };
+
+            ui.heading("Sortable list");
+            hint(
+                ui,
+                "Drag items, or focus one and press Space, then the arrow keys.",
+            );
+            ui.scope(|ui| {
+                ui.set_max_width(360.0);
+                let tasks = &self.tasks;
+                let response = sortable(
+                    ui,
+                    theme,
+                    SortableProps::new(Id::new("tasks"), tasks.len()),
+                    |ui, item| {
+                        ui.horizontal(|ui| {
+                            ui.label(
+                                RichText::new(Icon::GripVertical.unicode().to_string())
+                                    .color(theme.palette.muted_foreground),
+                            );
+                            ui.label(&tasks[item.index]);
+                        });
+                    },
+                );
+                if let Some(moved) = response.moved {
+                    reorder_items(&mut self.tasks, &moved);
+                }
+            });
+
+            ui.add_space(24.0);
+            ui.heading("Kanban");
+            hint(
+                ui,
+                "Cards move between columns; Left and Right do the same.",
+            );
+            let containers: Vec<Id> = COLUMNS.iter().map(Id::new).collect();
+            let mut moved = None;
+            ui.columns(COLUMNS.len(), |columns| {
+                for (index, column) in columns.iter_mut().enumerate() {
+                    let cards = &self.board[index];
+                    column.label(RichText::new(COLUMNS[index]).strong());
+                    column.add_space(4.0);
+                    let props = SortableProps::new(containers[index], cards.len())
+                        .group(Id::new("board"))
+                        .min_size(vec2(0.0, 120.0));
+                    let response = sortable(column, theme, props, |ui, item| {
+                        ui.label(&cards[item.index]);
+                    });
+                    moved = moved.or(response.moved);
+                }
+            });
+            if let Some(moved) = moved {
+                apply_sortable_move(&mut self.board, &containers, &moved);
+            }
+
+            ui.add_space(24.0);
+            ui.heading("Reorderable tabs");
+            hint(ui, "Tabs and data tables use the same primitive.");
+            let props =
+                TabsProps::new(Id::new("tabs"), &self.tabs, &mut self.active_tab).reorderable(true);
+            let result = tabs(ui, theme, props, |ui, tab| {
+                ui.label(format!("{} panel", tab.id));
+            });
+            if let Some(moved) = result.moved {
+                reorder_items(&mut self.tabs, &moved);
+            }
+
+            ui.add_space(24.0);
+            ui.heading("Horizontal");
+            ui.add_space(8.0);
+            let chips = &self.chips;
+            let response = sortable(
+                ui,
+                theme,
+                SortableProps::new(Id::new("chips"), chips.len()).axis(SortableAxis::Horizontal),
+                |ui, item| {
+                    ui.label(&chips[item.index]);
+                },
+            );
+            if let Some(moved) = response.moved {
+                reorder_items(&mut self.chips, &moved);
+            }
+        });
+    }
+}
+
+fn ensure_lucide_font(ctx: &egui::Context) {
+    let font_loaded_id = egui::Id::new("lucide_font_loaded");
+    let already_set = ctx.data(|d| d.get_temp::<bool>(font_loaded_id).unwrap_or(false));
+    if already_set {
+        return;
+    }
+
+    let mut fonts = FontDefinitions::default();
+    fonts.font_data.insert(
+        "lucide".into(),
+        FontData::from_static(LUCIDE_FONT_BYTES).into(),
+    );
+    fonts
+        .families
+        .entry(FontFamily::Proportional)
+        .or_default()
+        .push("lucide".into());
+    ctx.set_fonts(fonts);
+    ctx.data_mut(|d| d.insert_temp(font_loaded_id, true));
+}
+
+fn main() -> eframe::Result<()> {
+    env_logger::init();
+    let options = icon::native_options();
+    eframe::run_native(
+        "Sortable example",
+        options,
+        Box::new(|_cc| Ok(Box::new(SortableDemo::new()))),
+    )
+}
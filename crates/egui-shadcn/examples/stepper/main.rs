@@ -0,0 +1,207 @@
+#![cfg_attr(
+    all(target_os = "windows", not(debug_assertions)),
+    windows_subsystem = "windows"
+)]
+
+#[path = "../_shared/icon.rs"]
+mod icon;
+#[path = "../_shared/screenshot.rs"]
+mod screenshot;
+
+use eframe::{App, Frame, egui};
+use egui::{FontData, FontDefinitions, FontFamily, Id, RichText};
+use egui_shadcn::{
+    Button, ButtonSize, ButtonVariant, FormControl, FormItem, FormLabel, FormMessage, FormState,
+    Input, StepItem, StepperOrientation, StepperProps, StepperState, Theme, ValidationMode,
+    compose, email, min_length, required, stepper,
+};
+use lucide_icons::{Icon, LUCIDE_FONT_BYTES};
+
+struct Account {
+    email: String,
+    password: String,
+    name: String,
+    company: String,
+}
+
+struct StepperDemo {
+    theme: Theme,
+    steps: Vec<StepItem>,
+    state: StepperState,
+    form: FormState,
+    account: Account,
+    orientation: StepperOrientation,
+}
+
+impl StepperDemo {
+    fn new() -> Self {
+        let mut form = FormState::new(ValidationMode::OnSubmit);
+        form.field(
+            "email",
+            compose(vec![
+                required("Email is required."),
+                email("Please enter a valid email address."),
+            ]),
+        );
+        form.field("password", min_length(8, "Use at least 8 characters."));
+        form.field("name", required("Tell us your name."));
+        Self {
+            theme: Theme::default(),
+            steps: vec![
+                StepItem::new("Account")
+                    .description("Sign-in details")
+                    .icon(Icon::Mail)
+                    .fields(["email", "password"]),
+                StepItem::new("Profile")
+                    .description("How others see you")
+                    .icon(Icon::User)
+                    .fields(["name"])
+                    .optional(true),
+                StepItem::new("Confirm").icon(Icon::Check),
+            ],
+            state: StepperState::new(),
+            form,
+            account: Account {
+                email: String::new(),
+                password: String::new(),
+                name: String::new(),
+                company: String::new(),
+            },
+            orientation: StepperOrientation::Horizontal,
+        }
+    }
+}
+
+impl App for StepperDemo {
+    fn update(&mut self, ctx: &egui::Context, _frame: &mut Frame) {
+        screenshot::apply_screenshot_scale(ctx);
+        ensure_lucide_font(ctx);
+
+        egui::CentralPanel::default().show(ctx, |ui| {
+            let theme = &self.theme;
+            ui.set_max_width(640.0);
+            ui.heading("Onboarding");
+            ui.label(
+                RichText::new("Each step validates its own fields before moving on.")
+                    .size(12.0)
+                    .color(theme.palette.muted_foreground),
+            );
+            ui.add_space(8.0);
+            let label = match self.orientation {
+                StepperOrientation::Horizontal => "Vertical layout",
+                StepperOrientation::Vertical => "Horizontal layout",
+            };
+            let toggle = Button::new(label)
+                .variant(ButtonVariant::Outline)
+                .size(ButtonSize::Sm)
+                .show(ui, theme);
+            if toggle.clicked() {
+                self.orientation = match self.orientation {
+                    StepperOrientation::Horizontal => StepperOrientation::Vertical,
+                    StepperOrientation::Vertical => StepperOrientation::Horizontal,
+                };
+            }
+            ui.add_space(16.0);
+
+            // Report every field, including those of steps not on screen.
+            let account = &mut self.account;
+            self.form.set_text("email", account.email.clone());
+            self.form.set_text("password", account.password.clone());
+            self.form.set_text("name", account.name.clone());
+
+            let props = StepperProps::new(
+                Id::new("onboarding"),
+                &self.steps,
+                &mut self.state,
+                &mut self.form,
+            )
+            .orientation(self.orientation);
+            let response = stepper(ui, theme, props, |ui, step, form| match step {
+                0 => {
+                    text_field(ui, theme, form, "email", "Email", &mut account.email);
+                    text_field(
+                        ui,
+                        theme,
+                        form,
+                        "password",
+                        "Password",
+                        &mut account.password,
+                    );
+                }
+                1 => {
+                    text_field(ui, theme, form, "name", "Name", &mut account.name);
+                    text_field(ui, theme, form, "company", "Company", &mut account.company);
+                }
+                _ => {
+                    ui.label(format!("Email: {}", account.email));
+                    let name = if account.name.is_empty() {
+                        "(skipped)"
+                    } else {
+                        account.name.as_str()
+                    };
+                    ui.label(format!("Name: {name}"));
+                }
+            });
+            if response.finished {
+                ui.add_space(12.0);
+                ui.label(RichText::new("All set. Welcome aboard!").strong());
+            }
+        });
+    }
+}
+
+fn text_field(
+    ui: &mut egui::Ui,
+    theme: &Theme,
+    form: &mut FormState,
+    name: &str,
+    label: &str,
+    value: &mut String,
+) {
+    let error = form.error(name).map(str::to_string);
+    FormItem::new(ui.make_persistent_id(name)).show(ui, |ui, ctx| {
+        FormLabel::new(label)
+            .error(error.is_some())
+            .show(ui, theme, ctx);
+        FormControl::new().show(ui, ctx, |ui, id| {
+            Input::new(id)
+                .invalid(error.is_some())
+                .width(320.0)
+                .show(ui, theme, value)
+        });
+        form.set_text(name, value.clone());
+        FormMessage::from_error(error.as_deref()).show(ui, theme);
+    });
+    ui.add_space(8.0);
+}
+
+fn ensure_lucide_font(ctx: &egui::Context) {
+    let font_loaded_id = egui::Id::new("lucide_font_loaded");
+    let already_set = ctx.data(|d| d.get_temp::<bool>(font_loaded_id).unwrap_or(false));
+    if already_set {
+        return;
+    }
+
+    let mut fonts = FontDefinitions::default();
+    fonts.font_data.insert(
+        "lucide".into(),
+        FontData::from_static(LUCIDE_FONT_BYTES).into(),
+    );
+    fonts
+        .families
+        .entry(FontFamily::Proportional)
+        .or_default()
+        .push("lucide".into());
+    ctx.set_fonts(fonts);
+    ctx.data_mut(|d| d.insert_temp(font_loaded_id, true));
+}
+
+fn main() -> eframe::Result<()> {
+    env_logger::init();
+    let options = icon::native_options();
+    eframe::run_native(
+        "Stepper example",
+        options,
+        Box::new(|_cc| Ok(Box::new(StepperDemo::new()))),
+    )
+}
@@ -10,8 +10,10 @@ mod screenshot;
 
 use eframe::{App, Frame, egui};
 use egui_shadcn::{
-    ControlSize, ControlVariant, Label, Textarea, TextareaResize, TextareaSize, Theme, button,
+    ControlSize, ControlVariant, JsonHighlighter, KeywordHighlighter, Label, Mention, MentionItem,
+    MentionTrigger, Textarea, TextareaMentions, TextareaResize, TextareaSize, Theme, button,
 };
+use lucide_icons::Icon;
 
 struct TextareaDemo {
     theme: Theme,
@@ -21,6 +23,13 @@ struct TextareaDemo {
     message_with_text: String,
     message_with_button: String,
     bio_text: String,
+    autosize_text: String,
+    json_text: String,
+    sql_text: String,
+    chat_text: String,
+    chat_mentions: Vec<Mention>,
+    people: Vec<MentionItem>,
+    commands: Vec<MentionItem>,
 }
 
 impl TextareaDemo {
@@ -33,6 +42,24 @@ impl TextareaDemo {
             message_with_text: String::new(),
             message_with_button: String::new(),
             bio_text: String::new(),
+            autosize_text: String::new(),
+            json_text: "{\n  \"name\": \"egui-shadcn\",\n  \"version\": 1,\n  \"stable\": false\n}"
+                .to_string(),
+            sql_text:
+                "-- Active users\nSELECT id, name\nFROM users\nWHERE active = 'yes'\nLIMIT 10;"
+                    .to_string(),
+            chat_text: String::new(),
+            chat_mentions: Vec::new(),
+            people: vec![
+                MentionItem::new("u1", "olivia").keywords(&["Olivia Martin"]),
+                MentionItem::new("u2", "jackson").keywords(&["Jackson Lee"]),
+                MentionItem::new("u3", "isabella").keywords(&["Isabella Nguyen"]),
+            ],
+            commands: vec![
+                MentionItem::new("giphy", "giphy").icon(Icon::Image),
+                MentionItem::new("remind", "remind").icon(Icon::Clock),
+                MentionItem::new("poll", "poll").icon(Icon::ChartBar),
+            ],
         }
     }
 }
@@ -177,6 +204,74 @@ impl App for TextareaDemo {
                         );
                     });
                     grid.end_row();
+
+                    example_card(grid, "Autosize", |ui| {
+                        ui.set_min_width(area_width);
+                        ui.set_max_width(area_width);
+                        Textarea::new("textarea-autosize")
+                            .placeholder("Grows from 2 to 6 rows.")
+                            .width(area_width)
+                            .resize(TextareaResize::None)
+                            .autosize(2, 6)
+                            .show(ui, &self.theme, &mut self.autosize_text);
+                    });
+
+                    example_card(grid, "JSON", |ui| {
+                        ui.set_min_width(area_width);
+                        ui.set_max_width(area_width);
+                        Textarea::new("textarea-json")
+                            .size(TextareaSize::Size1)
+                            .width(area_width)
+                            .resize(TextareaResize::Horizontal)
+                            .autosize(4, 10)
+                            .line_numbers(true)
+                            .highlighter(JsonHighlighter)
+                            .show(ui, &self.theme, &mut self.json_text);
+                    });
+
+                    example_card(grid, "SQL", |ui| {
+                        ui.set_min_width(area_width);
+                        ui.set_max_width(area_width);
+                        Textarea::new("textarea-sql")
+                            .size(TextareaSize::Size1)
+                            .width(area_width)
+                            .resize(TextareaResize::None)
+                            .autosize(4, 10)
+                            .line_numbers(true)
+                            .highlighter(KeywordHighlighter::sql())
+                            .show(ui, &self.theme, &mut self.sql_text);
+                    });
+
+                    example_card(grid, "Mentions", |ui| {
+                        ui.spacing_mut().item_spacing.y = 8.0;
+                        ui.set_min_width(area_width);
+                        ui.set_max_width(area_width);
+                        Textarea::new("textarea-mentions")
+                            .placeholder("Type @ to mention someone or / for commands.")
+                            .width(area_width)
+                            .resize(TextareaResize::None)
+                            .autosize(3, 8)
+                            .mentions(
+                                TextareaMentions::new(&mut self.chat_mentions)
+                                    .trigger(
+                                        MentionTrigger::new('@', &self.people).heading("People"),
+                                    )
+                                    .trigger(
+                                        MentionTrigger::new('/', &self.commands)
+                                            .heading("Commands")
+                                            .line_start(true),
+                                    ),
+                            )
+                            .show(ui, &self.theme, &mut self.chat_text);
+                        let ids: Vec<&str> =
+                            self.chat_mentions.iter().map(|m| m.id.as_str()).collect();
+                        ui.label(
+                            egui::RichText::new(format!("Mentions: {}", ids.join(", ")))
+                                .color(self.theme.palette.muted_foreground)
+                                .size(12.0),
+                        );
+                    });
+                    grid.end_row();
                 });
         });
     }
@@ -0,0 +1,174 @@
+#![cfg_attr(
+    all(target_os = "windows", not(debug_assertions)),
+    windows_subsystem = "windows"
+)]
+
+#[path = "../_shared/icon.rs"]
+mod icon;
+#[path = "../_shared/screenshot.rs"]
+mod screenshot;
+
+use eframe::{App, Frame, egui};
+use egui::{FontData, FontDefinitions, FontFamily, Id, RichText};
+use egui_shadcn::{
+    Theme, TreeNode, TreeSelectionMode, TreeViewProps, TreeViewState, apply_tree_move, tree_view,
+};
+use lucide_icons::{Icon, LUCIDE_FONT_BYTES};
+
+fn file(path: &str) -> TreeNode<String> {
+    let name = path.rsplit('/').next().unwrap_or(path);
+    TreeNode::new(path.to_string(), name).icon(Icon::File)
+}
+
+fn folder(path: &str, children: Vec<TreeNode<String>>) -> TreeNode<String> {
+    let name = path.rsplit('/').next().unwrap_or(path);
+    TreeNode::new(path.to_string(), name)
+        .icon(Icon::Folder)
+        .children(children)
+}
+
+struct TreeViewDemo {
+    theme: Theme,
+    files: Vec<TreeNode<String>>,
+    files_state: TreeViewState<String>,
+    large: Vec<TreeNode<String>>,
+    large_state: TreeViewState<String>,
+    last_event: String,
+}
+
+impl TreeViewDemo {
+    fn new() -> Self {
+        let files = vec![
+            folder(
+                "src",
+                vec![
+                    file("src/main.rs"),
+                    file("src/lib.rs"),
+                    TreeNode::new("src/widgets".to_string(), "widgets")
+                        .icon(Icon::Folder)
+                        .lazy(),
+                ],
+            ),
+            folder("assets", vec![file("assets/logo.svg")]),
+            file("Cargo.toml"),
+            file("README.md"),
+        ];
+        let large = (0..200)
+            .map(|group| {
+                folder(
+                    &format!("group-{group}"),
+                    (0..50)
+                        .map(|item| file(&format!("group-{group}/item-{item}")))
+                        .collect(),
+                )
+            })
+            .collect();
+        Self {
+            theme: Theme::default(),
+            files,
+            files_state: TreeViewState::new(),
+            large,
+            large_state: TreeViewState::new(),
+            last_event: "Drag rows to move them; double-click to open.".into(),
+        }
+    }
+}
+
+impl App for TreeViewDemo {
+    fn update(&mut self, ctx: &egui::Context, _frame: &mut Frame) {
+        screenshot::apply_screenshot_scale(ctx);
+        ensure_lucide_font(ctx);
+
+        egui::CentralPanel::default().show(ctx, |ui| {
+            let theme = &self.theme;
+            ui.columns(2, |columns| {
+                let ui = &mut columns[0];
+                ui.heading("Files");
+                ui.label(
+                    RichText::new(&self.last_event)
+                        .size(12.0)
+                        .color(theme.palette.muted_foreground),
+                );
+                ui.add_space(8.0);
+                let response = tree_view(
+                    ui,
+                    theme,
+                    TreeViewProps::new(Id::new("files"), &self.files, &mut self.files_state)
+                        .selection(TreeSelectionMode::Multi)
+                        .draggable(true),
+                );
+                if let Some(path) = response.load_requested {
+                    // A real explorer would read the directory in the background.
+                    if let Some(node) = self.files[0]
+                        .children
+                        .iter_mut()
+                        .flatten()
+                        .find(|node| node.id == path)
+                    {
+                        node.set_children(vec![
+                            file("src/widgets/button.rs"),
+                            file("src/widgets/tree.rs"),
+                        ]);
+                    }
+                }
+                if let Some(path) = response.activated {
+                    self.last_event = format!("Opened {path}");
+                }
+                if let Some(moved) = response.moved
+                    && apply_tree_move(&mut self.files, &moved)
+                {
+                    let parent = moved.parent.as_deref().unwrap_or("the root");
+                    self.last_event = format!("Moved {} into {parent}", moved.node);
+                }
+
+                let ui = &mut columns[1];
+                ui.heading("10,000 rows");
+                let checked = self.large_state.checked.len();
+                ui.label(
+                    RichText::new(format!("{checked} checked"))
+                        .size(12.0)
+                        .color(theme.palette.muted_foreground),
+                );
+                ui.add_space(8.0);
+                tree_view(
+                    ui,
+                    theme,
+                    TreeViewProps::new(Id::new("large"), &self.large, &mut self.large_state)
+                        .checkboxes(true)
+                        .max_height(420.0),
+                );
+            });
+        });
+    }
+}
+
+fn ensure_lucide_font(ctx: &egui::Context) {
+    let font_loaded_id = egui::Id::new("lucide_font_loaded");
+    let already_set = ctx.data(|d| d.get_temp::<bool>(font_loaded_id).unwrap_or(false));
+    if already_set {
+        return;
+    }
+
+    let mut fonts = FontDefinitions::default();
+    fonts.font_data.insert(
+        "lucide".into(),
+        FontData::from_static(LUCIDE_FONT_BYTES).into(),
+    );
+    fonts
+        .families
+        .entry(FontFamily::Proportional)
+        .or_default()
+        .push("lucide".into());
+    ctx.set_fonts(fonts);
+    ctx.data_mut(|d| d.insert_temp(font_loaded_id, true));
+}
+
+fn main() -> eframe::Result<()> {
+    env_logger::init();
+    let options = icon::native_options();
+    eframe::run_native(
+        "Tree view example",
+        options,
+        Box::new(|_cc| Ok(Box::new(TreeViewDemo::new()))),
+    )
+}
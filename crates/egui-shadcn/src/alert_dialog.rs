@@ -6,7 +6,7 @@
 //! ```
 
 use crate::button::{Button, ButtonVariant};
-use crate::dialog::{DialogProps, DialogSize, dialog};
+use crate::dialog::{DialogAutoFocusEvent, DialogProps, DialogSize, dialog};
 use crate::theme::Theme;
 use egui::{RichText, Ui};
 
@@ -22,6 +22,8 @@ pub struct AlertDialogProps<'a> {
     pub cancel_text: &'a str,
     pub action_text: &'a str,
     pub action_variant: ButtonVariant,
+    pub on_open_auto_focus: Option<&'a mut dyn FnMut(&mut DialogAutoFocusEvent)>,
+    pub on_close_auto_focus: Option<&'a mut dyn FnMut(&mut DialogAutoFocusEvent)>,
 }
 
 impl<'a> AlertDialogProps<'a> {
@@ -33,6 +35,8 @@ impl<'a> AlertDialogProps<'a> {
             cancel_text: "Cancel",
             action_text: "Continue",
             action_variant: ButtonVariant::Default,
+            on_open_auto_focus: None,
+            on_close_auto_focus: None,
         }
     }
 
@@ -55,6 +59,24 @@ impl<'a> AlertDialogProps<'a> {
         self.action_variant = variant;
         self
     }
+
+    /// Called when the dialog opens. Unless prevented, focus moves to the cancel button.
+    pub fn on_open_auto_focus(
+        mut self,
+        on_open_auto_focus: &'a mut dyn FnMut(&mut DialogAutoFocusEvent),
+    ) -> Self {
+        self.on_open_auto_focus = Some(on_open_auto_focus);
+        self
+    }
+
+    /// Called when the dialog closes. Unless prevented, focus returns to the opener.
+    pub fn on_close_auto_focus(
+        mut self,
+        on_close_auto_focus: &'a mut dyn FnMut(&mut DialogAutoFocusEvent),
+    ) -> Self {
+        self.on_close_auto_focus = Some(on_close_auto_focus);
+        self
+    }
 }
 
 /// Result of the alert dialog interaction.
@@ -73,75 +95,87 @@ pub enum AlertDialogResult {
 pub fn alert_dialog(ui: &mut Ui, theme: &Theme, props: AlertDialogProps<'_>) -> AlertDialogResult {
     use std::cell::Cell;
 
-    if !*props.open {
-        return AlertDialogResult::None;
-    }
-
     // Extract props to avoid borrow issues
     let title = props.title;
     let description = props.description;
     let cancel_text = props.cancel_text;
     let action_text = props.action_text;
     let action_variant = props.action_variant;
+    let mut on_open_auto_focus = props.on_open_auto_focus;
+    let on_close_auto_focus = props.on_close_auto_focus;
 
     // Use Cell to allow mutation inside closure
     let result = Cell::new(AlertDialogResult::None);
     let should_close = Cell::new(false);
+    let focus_cancel = Cell::new(false);
+
+    // Radix AlertDialog focuses the cancel action on open instead of the first focusable.
+    let mut open_auto_focus = |evt: &mut DialogAutoFocusEvent| {
+        if let Some(cb) = on_open_auto_focus.as_mut() {
+            cb(evt);
+        }
+        if !evt.preventable.default_prevented() {
+            evt.preventable.prevent_default();
+            focus_cancel.set(true);
+        }
+    };
+    let mut dialog_props = DialogProps::new("alert-dialog".into(), props.open)
+        .with_dialog_size(DialogSize::Size1)
+        .with_close_on_background(false)
+        .on_open_auto_focus(&mut open_auto_focus);
+    if let Some(cb) = on_close_auto_focus {
+        dialog_props = dialog_props.on_close_auto_focus(cb);
+    }
 
-    dialog(
-        ui,
-        theme,
-        DialogProps::new("alert-dialog".into(), props.open)
-            .with_dialog_size(DialogSize::Size1)
-            .with_close_on_background(false),
-        |ui| {
-            ui.vertical(|ui| {
-                ui.spacing_mut().item_spacing.y = 8.0;
-
-                // Title
-                ui.label(
-                    RichText::new(title)
-                        .size(18.0)
-                        .strong()
-                        .color(theme.palette.foreground),
-                );
-
-                // Description
-                ui.label(
-                    RichText::new(description)
-                        .size(14.0)
-                        .color(theme.palette.muted_foreground),
-                );
-
-                ui.add_space(16.0);
-
-                // Action buttons
-                ui.horizontal(|ui| {
-                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
-                        // Action button
-                        if Button::new(action_text)
-                            .variant(action_variant)
-                            .show(ui, theme)
-                            .clicked()
-                        {
-                            result.set(AlertDialogResult::Confirmed);
-                            should_close.set(true);
-                        }
-
-                        // Cancel button
-                        if Button::new(cancel_text)
-                            .variant(ButtonVariant::Outline)
-                            .show(ui, theme)
-                            .clicked()
-                        {
-                            result.set(AlertDialogResult::Cancelled);
-                            should_close.set(true);
-                        }
-                    });
+    dialog(ui, theme, dialog_props, |ui| {
+        ui.vertical(|ui| {
+            ui.spacing_mut().item_spacing.y = 8.0;
+
+            // Title
+            ui.label(
+                RichText::new(title)
+                    .size(18.0)
+                    .strong()
+                    .color(theme.palette.foreground),
+            );
+
+            // Description
+            ui.label(
+                RichText::new(description)
+                    .size(14.0)
+                    .color(theme.palette.muted_foreground),
+            );
+
+            ui.add_space(16.0);
+
+            // Action buttons
+            ui.horizontal(|ui| {
+                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                    // Action button
+                    if Button::new(action_text)
+                        .variant(action_variant)
+                        .show(ui, theme)
+                        .clicked()
+                    {
+                        result.set(AlertDialogResult::Confirmed);
+                        should_close.set(true);
+                    }
+
+                    // Cancel button
+                    let cancel = Button::new(cancel_text)
+                        .variant(ButtonVariant::Outline)
+                        .show(ui, theme);
+                    if focus_cancel.take() {
+                        cancel.request_focus();
+                    }
+                    if cancel.clicked() {
+                        result.set(AlertDialogResult::Cancelled);
+                        should_close.set(true);
+                    }
                 });
             });
-        },
-    );
+        });
+    });
 
     if should_close.get() {
         *props.open = false;
@@ -1,12 +1,19 @@
-//! Avatar component - user avatar with fallback.
+//! Avatar component - user avatar with an image and fallback.
+//!
+//! The image is cropped to the avatar circle. Until it has loaded, or when it fails, the
+//! fallback initials are shown, optionally only after a delay so fast loads do not flash.
 //!
 //! # Example
 //! ```ignore
-//! avatar(ui, &theme, AvatarProps::new("JD"));
+//! avatar(ui, &theme, AvatarProps::new("JD").image(egui::include_image!("jd.png")));
+//! avatar_group(ui, &theme, AvatarGroupProps::new(avatars).max(3));
 //! ```
 
 use crate::theme::Theme;
-use egui::{Color32, Ui, Vec2};
+use egui::load::TexturePoll;
+use egui::{
+    Color32, CornerRadius, Id, ImageSource, Rect, Response, Sense, TextureId, Ui, Vec2, pos2, vec2,
+};
 
 // =============================================================================
 // AvatarSize / AvatarVariant
@@ -57,12 +64,30 @@ pub enum AvatarVariant {
 // AvatarProps
 // =============================================================================
 
+/// Loading state of the avatar image, as in Radix `onLoadingStatusChange`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AvatarImageStatus {
+    /// No image was given.
+    Idle,
+    Loading,
+    Loaded,
+    Error,
+}
+
 #[derive(Clone, Debug)]
 pub struct AvatarProps<'a> {
     pub fallback: &'a str,
     pub size: AvatarSize,
     pub variant: AvatarVariant,
     pub color: Option<Color32>,
+
+    /// Bytes, a URI (e.g. `file://`, which needs image loaders installed) or a texture.
+    pub image: Option<ImageSource<'a>>,
+
+    /// Seconds to wait before showing the fallback while the image loads.
+    pub fallback_delay: Option<f32>,
+
+    pub id_source: Option<Id>,
 }
 
 impl<'a> AvatarProps<'a> {
@@ -72,9 +97,29 @@ impl<'a> AvatarProps<'a> {
             size: AvatarSize::Size3,
             variant: AvatarVariant::Soft,
             color: None,
+            image: None,
+            fallback_delay: None,
+            id_source: None,
         }
     }
 
+    pub fn image(mut self, image: impl Into<ImageSource<'a>>) -> Self {
+        self.image = Some(image.into());
+        self
+    }
+
+    pub fn fallback_delay(mut self, seconds: f32) -> Self {
+        self.fallback_delay = Some(seconds);
+        self
+    }
+
+    /// Identifies the avatar across frames for [`AvatarProps::fallback_delay`]. Defaults to
+    /// the next auto id of the parent `Ui`.
+    pub fn id_source(mut self, id_source: impl std::hash::Hash) -> Self {
+        self.id_source = Some(Id::new(id_source));
+        self
+    }
+
     pub fn size(mut self, size: AvatarSize) -> Self {
         self.size = size;
         self
@@ -91,13 +136,140 @@ impl<'a> AvatarProps<'a> {
     }
 }
 
+#[derive(Clone, Debug)]
+pub struct AvatarResponse {
+    pub response: Response,
+    pub status: AvatarImageStatus,
+}
+
+// =============================================================================
+// AvatarGroupProps
+// =============================================================================
+
+#[derive(Clone, Debug)]
+pub struct AvatarGroupProps<'a> {
+    pub avatars: Vec<AvatarProps<'a>>,
+
+    /// Avatars shown before the rest collapse into a "+N" chip.
+    pub max: Option<usize>,
+
+    /// Applied to every avatar of the group.
+    pub size: AvatarSize,
+
+    /// How much each avatar covers the previous one, as a fraction of its size.
+    pub overlap: f32,
+}
+
+impl<'a> AvatarGroupProps<'a> {
+    pub fn new(avatars: Vec<AvatarProps<'a>>) -> Self {
+        Self {
+            avatars,
+            max: None,
+            size: AvatarSize::Size5,
+            overlap: 0.25,
+        }
+    }
+
+    pub fn max(mut self, max: usize) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    pub fn size(mut self, size: AvatarSize) -> Self {
+        self.size = size;
+        self
+    }
+
+    pub fn overlap(mut self, overlap: f32) -> Self {
+        self.overlap = overlap.clamp(0.0, 0.9);
+        self
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct AvatarGroupResponse {
+    pub response: Response,
+
+    /// Avatars hidden behind the "+N" chip.
+    pub overflow: usize,
+}
+
 // =============================================================================
 // Main function
 // =============================================================================
 
-/// Render an avatar with fallback text (typically initials).
-pub fn avatar(ui: &mut Ui, theme: &Theme, props: AvatarProps<'_>) {
+/// Render an avatar: the image cropped to a circle once loaded, the fallback text (typically
+/// initials) otherwise.
+pub fn avatar(ui: &mut Ui, theme: &Theme, props: AvatarProps<'_>) -> AvatarResponse {
+    let id = props.id_source.unwrap_or_else(|| ui.next_auto_id());
+    let size = props.size.to_pixels();
+    let (rect, response) = ui.allocate_exact_size(Vec2::splat(size), Sense::hover());
+    let status = paint_avatar(ui, theme, &props, id, rect);
+    AvatarResponse { response, status }
+}
+
+/// Render overlapping avatars, ending with a "+N" chip for those beyond `max`.
+pub fn avatar_group(
+    ui: &mut Ui,
+    theme: &Theme,
+    props: AvatarGroupProps<'_>,
+) -> AvatarGroupResponse {
+    let id = ui.next_auto_id();
     let size = props.size.to_pixels();
+    let shown = props
+        .max
+        .unwrap_or(props.avatars.len())
+        .min(props.avatars.len());
+    let overflow = props.avatars.len() - shown;
+    let count = shown + usize::from(overflow > 0);
+    let step = size * (1.0 - props.overlap);
+    let width = if count == 0 {
+        0.0
+    } else {
+        size + step * (count - 1) as f32
+    };
+    let (rect, response) = ui.allocate_exact_size(vec2(width, size), Sense::hover());
+    let ring = (size * 0.05).max(2.0);
+
+    for index in 0..count {
+        let slot = Rect::from_min_size(
+            pos2(rect.left() + step * index as f32, rect.top()),
+            Vec2::splat(size),
+        );
+        // A ring in the background color separates overlapping avatars.
+        ui.painter()
+            .circle_filled(slot.center(), size / 2.0 + ring, theme.palette.background);
+        if let Some(avatar) = props.avatars.get(index).filter(|_| index < shown) {
+            let avatar = avatar.clone().size(props.size);
+            let avatar_id = avatar.id_source.unwrap_or_else(|| id.with(index));
+            paint_avatar(ui, theme, &avatar, avatar_id, slot);
+        } else {
+            ui.painter()
+                .circle_filled(slot.center(), size / 2.0, theme.palette.muted);
+            let galley = ui.painter().layout_no_wrap(
+                format!("+{overflow}"),
+                egui::FontId::proportional(props.size.font_size()),
+                theme.palette.muted_foreground,
+            );
+            ui.painter().galley(
+                slot.center() - galley.size() / 2.0,
+                galley,
+                theme.palette.muted_foreground,
+            );
+        }
+    }
+
+    AvatarGroupResponse { response, overflow }
+}
+
+fn paint_avatar(
+    ui: &Ui,
+    theme: &Theme,
+    props: &AvatarProps<'_>,
+    id: Id,
+    rect: Rect,
+) -> AvatarImageStatus {
+    let size = rect.width();
     let font_size = props.size.font_size();
     let accent = props.color.unwrap_or(theme.palette.primary);
 
@@ -106,13 +278,28 @@ pub fn avatar(ui: &mut Ui, theme: &Theme, props: AvatarProps<'_>) {
         AvatarVariant::Soft => (accent.gamma_multiply(0.2), accent),
     };
 
-    let (rect, _response) = ui.allocate_exact_size(Vec2::splat(size), egui::Sense::hover());
-
-    // Circle background
+    let (status, texture) = load_image(ui, props.image.as_ref(), rect.size());
     let center = rect.center();
     let radius = size / 2.0;
+
+    if let Some((texture_id, uv)) = texture {
+        let shape = egui::epaint::RectShape::filled(
+            rect,
+            CornerRadius::same(radius.round().min(255.0) as u8),
+            Color32::WHITE,
+        )
+        .with_texture(texture_id, uv);
+        ui.painter().add(shape);
+        return status;
+    }
+
+    // Circle background
     ui.painter().circle_filled(center, radius, bg_color);
 
+    if status == AvatarImageStatus::Loading && !fallback_delay_elapsed(ui, props, id) {
+        return status;
+    }
+
     // Fallback text (centered)
     let text = props
         .fallback
@@ -125,6 +312,61 @@ pub fn avatar(ui: &mut Ui, theme: &Theme, props: AvatarProps<'_>) {
             .layout_no_wrap(text, egui::FontId::proportional(font_size), text_color);
     let text_pos = center - galley.size() / 2.0;
     ui.painter().galley(text_pos, galley, text_color);
+    status
+}
+
+/// Polls the image and returns its texture with the UV rect that crops it to a centered square.
+fn load_image(
+    ui: &Ui,
+    image: Option<&ImageSource<'_>>,
+    size: Vec2,
+) -> (AvatarImageStatus, Option<(TextureId, Rect)>) {
+    let Some(image) = image else {
+        return (AvatarImageStatus::Idle, None);
+    };
+    match egui::Image::new(image.clone()).load_for_size(ui.ctx(), size) {
+        Ok(TexturePoll::Ready { texture }) => {
+            let aspect = texture.size.x / texture.size.y.max(f32::EPSILON);
+            let uv = if aspect > 1.0 {
+                let inset = (1.0 - 1.0 / aspect) / 2.0;
+                Rect::from_min_max(pos2(inset, 0.0), pos2(1.0 - inset, 1.0))
+            } else {
+                let inset = (1.0 - aspect) / 2.0;
+                Rect::from_min_max(pos2(0.0, inset), pos2(1.0, 1.0 - inset))
+            };
+            (AvatarImageStatus::Loaded, Some((texture.id, uv)))
+        }
+        Ok(TexturePoll::Pending { .. }) => (AvatarImageStatus::Loading, None),
+        Err(_) => (AvatarImageStatus::Error, None),
+    }
+}
+
+/// Whether the fallback delay has passed since the avatar started loading its current image.
+fn fallback_delay_elapsed(ui: &Ui, props: &AvatarProps<'_>, id: Id) -> bool {
+    let Some(delay) = props.fallback_delay else {
+        return true;
+    };
+    let source = match &props.image {
+        Some(ImageSource::Uri(uri)) => Id::new(uri.as_ref()),
+        Some(ImageSource::Bytes { uri, .. }) => Id::new(uri.as_ref()),
+        Some(ImageSource::Texture(texture)) => Id::new(texture.id),
+        None => Id::NULL,
+    };
+    let now = ui.input(|i| i.time);
+    let state_id = id.with("avatar-loading-since");
+    let started = ui.ctx().data_mut(|d| {
+        let entry = d.get_temp_mut_or_insert_with(state_id, || (source, now));
+        if entry.0 != source {
+            *entry = (source, now);
+        }
+        entry.1
+    });
+    let remaining = delay as f64 - (now - started);
+    if remaining > 0.0 {
+        ui.ctx()
+            .request_repaint_after(std::time::Duration::from_secs_f64(remaining));
+    }
+    remaining <= 0.0
 }
 
 #[cfg(test)]
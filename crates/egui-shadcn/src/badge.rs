@@ -6,7 +6,7 @@
 //! ```
 
 use crate::theme::Theme;
-use egui::{Color32, RichText, Ui, Vec2};
+use egui::{Color32, CursorIcon, Response, RichText, Sense, Stroke, Ui, Vec2, vec2};
 
 // =============================================================================
 // BadgeSize / BadgeVariant
@@ -80,6 +80,26 @@ impl<'a> BadgeProps<'a> {
 
 /// Render a badge.
 pub fn badge(ui: &mut Ui, theme: &Theme, props: BadgeProps<'_>) {
+    let _ = badge_frame(ui, theme, props, false);
+}
+
+/// A badge with a trailing × button, used for chips. Returns the badge response and whether
+/// the × was clicked.
+pub(crate) fn removable_badge(
+    ui: &mut Ui,
+    theme: &Theme,
+    props: BadgeProps<'_>,
+    enabled: bool,
+) -> (Response, bool) {
+    badge_frame(ui, theme, props, enabled)
+}
+
+fn badge_frame(
+    ui: &mut Ui,
+    theme: &Theme,
+    props: BadgeProps<'_>,
+    removable: bool,
+) -> (Response, bool) {
     let accent = props.color.unwrap_or(theme.palette.primary);
 
     let (bg_color, text_color, border_color) = match props.variant {
@@ -101,19 +121,44 @@ pub fn badge(ui: &mut Ui, theme: &Theme, props: BadgeProps<'_>) {
 
     let rounding = theme.radius.r6; // Use r6 as pill shape
 
-    egui::Frame::NONE
+    let mut removed = false;
+    let frame = egui::Frame::NONE
         .fill(bg_color)
         .stroke(egui::Stroke::new(1.0, border_color))
         .corner_radius(rounding)
         .inner_margin(padding)
         .show(ui, |ui| {
-            ui.label(
-                RichText::new(props.label)
-                    .size(font_size)
-                    .color(text_color)
-                    .strong(),
-            );
+            ui.spacing_mut().item_spacing.x = 4.0;
+            ui.horizontal(|ui| {
+                ui.label(
+                    RichText::new(props.label)
+                        .size(font_size)
+                        .color(text_color)
+                        .strong(),
+                );
+                if removable {
+                    let (rect, response) =
+                        ui.allocate_exact_size(Vec2::splat(font_size), Sense::click());
+                    let color = if response.hovered() {
+                        text_color
+                    } else {
+                        text_color.gamma_multiply(0.7)
+                    };
+                    let half = font_size * 0.25;
+                    let stroke = Stroke::new(1.5, color);
+                    let c = rect.center();
+                    ui.painter()
+                        .line_segment([c + vec2(-half, -half), c + vec2(half, half)], stroke);
+                    ui.painter()
+                        .line_segment([c + vec2(-half, half), c + vec2(half, -half)], stroke);
+                    if response.hovered() {
+                        ui.ctx().set_cursor_icon(CursorIcon::PointingHand);
+                    }
+                    removed = response.clicked();
+                }
+            });
         });
+    (frame.response, removed)
 }
 
 #[cfg(test)]
@@ -1,9 +1,15 @@
 //! Breadcrumb component - navigation chain with separators and ellipsis.
 
+use crate::dropdown_menu::{
+    DropdownMenuItemProps, DropdownMenuProps, DropdownMenuTriggerProps, dropdown_menu,
+    dropdown_menu_item, dropdown_menu_trigger,
+};
 use crate::theme::Theme;
 use egui::{
-    Color32, FontId, Label, Response, Sense, Stroke, TextStyle, Ui, Vec2, WidgetText, pos2, vec2,
+    Color32, FontId, Id, Label, Response, Sense, Stroke, TextStyle, Ui, Vec2, WidgetText, pos2,
+    vec2,
 };
+use std::ops::Range;
 
 #[derive(Clone, Copy, Debug)]
 pub struct BreadcrumbProps {
@@ -192,6 +198,205 @@ pub fn breadcrumb_ellipsis(ui: &mut Ui, ctx: &BreadcrumbContext) -> Response {
     response
 }
 
+#[derive(Clone, Debug)]
+pub struct BreadcrumbPathProps<'a> {
+    pub id_source: Id,
+
+    /// From the root to the current page; the last segment is not a link.
+    pub segments: &'a [&'a str],
+
+    /// Segments always shown before the ellipsis.
+    pub items_before_collapse: usize,
+
+    /// Segments always shown after the ellipsis, including the current page.
+    pub items_after_collapse: usize,
+
+    /// Collapses down to this many visible segments even when everything would fit.
+    pub max_items: Option<usize>,
+}
+
+impl<'a> BreadcrumbPathProps<'a> {
+    pub fn new(id_source: Id, segments: &'a [&'a str]) -> Self {
+        Self {
+            id_source,
+            segments,
+            items_before_collapse: 1,
+            items_after_collapse: 1,
+            max_items: None,
+        }
+    }
+
+    pub fn items_before_collapse(mut self, count: usize) -> Self {
+        self.items_before_collapse = count;
+        self
+    }
+
+    pub fn items_after_collapse(mut self, count: usize) -> Self {
+        self.items_after_collapse = count;
+        self
+    }
+
+    pub fn max_items(mut self, max_items: usize) -> Self {
+        self.max_items = Some(max_items);
+        self
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct BreadcrumbPathResponse {
+    pub response: Response,
+
+    /// Index of the segment picked from a link or from the ellipsis menu.
+    pub clicked: Option<usize>,
+
+    /// Segments moved into the ellipsis menu.
+    pub collapsed: Range<usize>,
+}
+
+/// A breadcrumb built from `segments` on one line. When they overflow the available width (or
+/// exceed `max_items`), the middle segments collapse into an ellipsis that opens a dropdown
+/// menu listing them.
+pub fn breadcrumb_path(
+    ui: &mut Ui,
+    theme: &Theme,
+    props: BreadcrumbProps,
+    path: BreadcrumbPathProps<'_>,
+) -> BreadcrumbPathResponse {
+    let collapsed = collapsed_segments(ui, &props, &path, ui.available_width());
+    let last = path.segments.len().saturating_sub(1);
+    let mut clicked = None;
+
+    let response = ui
+        .scope(|ui| {
+            breadcrumb(ui, theme, props.wrap(false), |ui, ctx| {
+                breadcrumb_list(ui, ctx, |ui, ctx| {
+                    for (index, segment) in path.segments.iter().enumerate() {
+                        if collapsed.contains(&index) {
+                            if index == collapsed.start {
+                                if let Some(picked) = collapsed_menu(
+                                    ui,
+                                    theme,
+                                    ctx,
+                                    path.id_source,
+                                    &path,
+                                    &collapsed,
+                                ) {
+                                    clicked = Some(picked);
+                                }
+                                breadcrumb_separator(ui, ctx, None);
+                            }
+                            continue;
+                        }
+                        breadcrumb_item(ui, ctx, |ui| {
+                            if index == last {
+                                breadcrumb_page(ui, ctx, *segment);
+                            } else if breadcrumb_link(ui, ctx, *segment).clicked() {
+                                clicked = Some(index);
+                            }
+                        });
+                        if index != last {
+                            breadcrumb_separator(ui, ctx, None);
+                        }
+                    }
+                })
+            })
+        })
+        .response;
+
+    BreadcrumbPathResponse {
+        response,
+        clicked,
+        collapsed,
+    }
+}
+
+fn collapsed_menu(
+    ui: &mut Ui,
+    theme: &Theme,
+    ctx: &BreadcrumbContext,
+    id_source: Id,
+    path: &BreadcrumbPathProps<'_>,
+    collapsed: &Range<usize>,
+) -> Option<usize> {
+    let trigger = dropdown_menu_trigger(
+        ui,
+        DropdownMenuTriggerProps::new(id_source.with("breadcrumb-ellipsis")),
+        |ui| {
+            let ellipsis = breadcrumb_ellipsis(ui, ctx);
+            ui.interact(ellipsis.rect, ellipsis.id.with("menu"), Sense::click())
+                .on_hover_cursor(egui::CursorIcon::PointingHand)
+        },
+    );
+    dropdown_menu(
+        ui,
+        theme,
+        DropdownMenuProps::new(&trigger.response),
+        |menu_ui| {
+            let mut picked = None;
+            for index in collapsed.clone() {
+                let item = DropdownMenuItemProps::new(path.segments[index]);
+                if dropdown_menu_item(menu_ui, theme, item).clicked() {
+                    picked = Some(index);
+                    menu_ui.close();
+                }
+            }
+            picked
+        },
+    )
+    .flatten()
+}
+
+/// Picks the segments hidden behind the ellipsis: keeps `items_before_collapse` at the start
+/// and adds segments from the end for as long as the line fits.
+fn collapsed_segments(
+    ui: &Ui,
+    props: &BreadcrumbProps,
+    path: &BreadcrumbPathProps<'_>,
+    available_width: f32,
+) -> Range<usize> {
+    let count = path.segments.len();
+    let before = path.items_before_collapse;
+    let min_after = path.items_after_collapse.max(1);
+    if count <= before + min_after {
+        return 0..0;
+    }
+
+    let font = FontId::proportional(props.text_size);
+    let widths: Vec<f32> = path
+        .segments
+        .iter()
+        .map(|segment| {
+            ui.fonts_mut(|fonts| {
+                fonts
+                    .layout_no_wrap(segment.to_string(), font.clone(), Color32::PLACEHOLDER)
+                    .size()
+                    .x
+            })
+        })
+        .collect();
+    // Every element but the first follows a separator, each with spacing on both sides.
+    let joint = props.separator_size + 2.0 * props.item_spacing;
+    let line_width =
+        |elements: &[f32]| elements.iter().sum::<f32>() + joint * (elements.len() - 1) as f32;
+
+    let fits_max = |visible: usize| path.max_items.is_none_or(|max| visible <= max);
+    if fits_max(count) && line_width(&widths) <= available_width {
+        return 0..0;
+    }
+
+    let mut after = min_after;
+    while before + after + 1 < count && fits_max(before + after + 1) {
+        let mut elements = widths[..before].to_vec();
+        elements.push(props.ellipsis_size);
+        elements.extend_from_slice(&widths[count - after - 1..]);
+        if line_width(&elements) > available_width {
+            break;
+        }
+        after += 1;
+    }
+    before..count - after
+}
+
 fn stroke_width(size: f32) -> f32 {
     (size * 0.12).clamp(1.3, 2.2)
 }
@@ -0,0 +1,93 @@
+//! Width breakpoints for responsive layouts, matching Tailwind's `sm`/`md`/`lg`/`xl` defaults.
+//!
+//! Components resolve a [`Breakpoint`] from the width they are given, so the same layout adapts
+//! to a small window and to a full-screen one.
+//!
+//! # Example
+//! ```ignore
+//! if breakpoint(ui) >= Breakpoint::Md {
+//!     ui.columns(2, |columns| { /* ... */ });
+//! }
+//! ```
+
+use egui::{Context, Id, Ui};
+
+const BREAKPOINTS_KEY: &str = "__egui_shadcn_breakpoints__";
+
+/// Ordered from narrowest to widest, so `breakpoint >= Breakpoint::Md` reads as "at least md".
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Breakpoint {
+    /// Narrower than `sm`.
+    #[default]
+    Xs,
+    Sm,
+    Md,
+    Lg,
+    Xl,
+}
+
+/// Minimum widths, in points, at which each breakpoint starts.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Breakpoints {
+    pub sm: f32,
+    pub md: f32,
+    pub lg: f32,
+    pub xl: f32,
+}
+
+impl Default for Breakpoints {
+    fn default() -> Self {
+        Self {
+            sm: 640.0,
+            md: 768.0,
+            lg: 1024.0,
+            xl: 1280.0,
+        }
+    }
+}
+
+impl Breakpoints {
+    pub fn resolve(&self, width: f32) -> Breakpoint {
+        if width >= self.xl {
+            Breakpoint::Xl
+        } else if width >= self.lg {
+            Breakpoint::Lg
+        } else if width >= self.md {
+            Breakpoint::Md
+        } else if width >= self.sm {
+            Breakpoint::Sm
+        } else {
+            Breakpoint::Xs
+        }
+    }
+
+    pub fn min_width(&self, breakpoint: Breakpoint) -> f32 {
+        match breakpoint {
+            Breakpoint::Xs => 0.0,
+            Breakpoint::Sm => self.sm,
+            Breakpoint::Md => self.md,
+            Breakpoint::Lg => self.lg,
+            Breakpoint::Xl => self.xl,
+        }
+    }
+}
+
+/// Replaces the breakpoints used by every component in this context.
+pub fn set_breakpoints(ctx: &Context, breakpoints: Breakpoints) {
+    ctx.data_mut(|d| d.insert_temp(Id::new(BREAKPOINTS_KEY), breakpoints));
+}
+
+pub fn breakpoints(ctx: &Context) -> Breakpoints {
+    ctx.data(|d| d.get_temp::<Breakpoints>(Id::new(BREAKPOINTS_KEY)))
+        .unwrap_or_default()
+}
+
+/// Breakpoint of the width still available in `ui`.
+pub fn breakpoint(ui: &Ui) -> Breakpoint {
+    breakpoints(ui.ctx()).resolve(ui.available_width())
+}
+
+/// Breakpoint of the whole window, like a CSS media query.
+pub fn viewport_breakpoint(ctx: &Context) -> Breakpoint {
+    breakpoints(ctx).resolve(ctx.content_rect().width())
+}
@@ -1,8 +1,20 @@
 use crate::button::{Button, ButtonSize, ButtonVariant};
 use crate::theme::Theme;
-use egui::{Align, Color32, Id, Key, Layout, Response, Sense, Stroke, Ui, Vec2};
+use egui::{
+    Align, Color32, CursorIcon, Id, Key, Layout, Rect, Response, ScrollArea, Sense, Stroke,
+    StrokeKind, Ui, UiBuilder, Vec2, vec2,
+};
 use std::time::Duration;
 
+/// Seconds of release velocity added to the offset when picking the snap after a drag.
+const MOMENTUM_PROJECTION: f32 = 0.2;
+/// Exponential decay of drag-free momentum, per second.
+const FRICTION: f32 = 4.0;
+/// How far a drag may pull past the first or last slide, relative to the pointer.
+const RUBBER_BAND: f32 = 0.35;
+/// Trackpad scrolling snaps once no scroll delta arrived for this long.
+const SCROLL_SETTLE_SECONDS: f64 = 0.15;
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
 pub enum CarouselOrientation {
     #[default]
@@ -10,11 +22,28 @@ pub enum CarouselOrientation {
     Vertical,
 }
 
+/// Where the selected slide sits inside the viewport.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum CarouselAlign {
+    #[default]
+    Start,
+    Center,
+    End,
+}
+
 #[derive(Clone, Copy, Debug)]
 pub struct CarouselOptions {
     pub autoplay: bool,
     pub looped: bool,
     pub autoplay_delay_ms: f32,
+
+    /// Slides moved per step; snap points are groups of this many slides.
+    pub slides_to_scroll: usize,
+    pub align: CarouselAlign,
+
+    /// Drags and trackpad scrolling glide with momentum and stop anywhere instead of snapping
+    /// to a slide.
+    pub drag_free: bool,
 }
 
 impl Default for CarouselOptions {
@@ -23,6 +52,9 @@ impl Default for CarouselOptions {
             autoplay: false,
             looped: false,
             autoplay_delay_ms: 3000.0,
+            slides_to_scroll: 1,
+            align: CarouselAlign::Start,
+            drag_free: false,
         }
     }
 }
@@ -42,6 +74,21 @@ impl CarouselOptions {
         self.autoplay_delay_ms = delay_ms;
         self
     }
+
+    pub fn slides_to_scroll(mut self, slides: usize) -> Self {
+        self.slides_to_scroll = slides.max(1);
+        self
+    }
+
+    pub fn align(mut self, align: CarouselAlign) -> Self {
+        self.align = align;
+        self
+    }
+
+    pub fn drag_free(mut self, drag_free: bool) -> Self {
+        self.drag_free = drag_free;
+        self
+    }
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -126,6 +173,50 @@ impl CarouselItemProps {
     }
 }
 
+#[derive(Clone, Copy, Debug)]
+pub struct CarouselThumbnailsProps {
+    pub size: Vec2,
+    pub spacing: f32,
+}
+
+impl Default for CarouselThumbnailsProps {
+    fn default() -> Self {
+        Self {
+            size: vec2(64.0, 48.0),
+            spacing: 8.0,
+        }
+    }
+}
+
+impl CarouselThumbnailsProps {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn size(mut self, size: Vec2) -> Self {
+        self.size = size;
+        self
+    }
+
+    pub fn spacing(mut self, spacing: f32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+}
+
+/// Scroll position of the content, in points along the main axis.
+#[derive(Clone, Copy, Debug, Default)]
+struct CarouselMotion {
+    offset: f32,
+    velocity: f32,
+    drag_origin: f32,
+    /// Distance between slide starts when the offset was last laid out.
+    step: f32,
+    /// The offset is not bound to the current slide: a drag-free glide or trackpad scroll.
+    free: bool,
+    last_scroll_time: f64,
+}
+
 #[derive(Clone, Copy, Debug, Default)]
 struct CarouselState {
     index: usize,
@@ -133,6 +224,7 @@ struct CarouselState {
     last_autoplay_time: f64,
     drag_total: Vec2,
     drag_active: bool,
+    motion: CarouselMotion,
 }
 
 pub struct CarouselContext {
@@ -147,9 +239,12 @@ pub struct CarouselContext {
     animated_offset: f32,
     measured_count: usize,
     changed: bool,
+    /// The last change came from a free-moving offset passing another snap point.
+    following: bool,
     interacted: bool,
     drag_total: Vec2,
     drag_active: bool,
+    motion: CarouselMotion,
 }
 
 impl CarouselContext {
@@ -164,7 +259,7 @@ impl CarouselContext {
         if self.item_count <= 1 {
             return false;
         }
-        self.opts.looped || self.current_index + 1 < self.item_count
+        self.opts.looped || self.current_index < self.last_snap_index()
     }
 
     pub fn scroll_prev(&mut self) {
@@ -175,33 +270,70 @@ impl CarouselContext {
         self.scroll_next_internal(true);
     }
 
+    /// Scrolls to the snap point holding slide `index`.
+    pub fn scroll_to(&mut self, index: usize) {
+        if self.item_count == 0 {
+            return;
+        }
+        let index = index.min(self.item_count - 1);
+        self.select(index - index % self.slides_to_scroll(), true);
+    }
+
+    /// One snap point per group of [`CarouselOptions::slides_to_scroll`] slides.
+    pub fn snap_count(&self) -> usize {
+        self.item_count.div_ceil(self.slides_to_scroll())
+    }
+
+    pub fn selected_snap(&self) -> usize {
+        self.current_index / self.slides_to_scroll()
+    }
+
+    pub fn is_dragging(&self) -> bool {
+        self.drag_active
+    }
+
+    fn slides_to_scroll(&self) -> usize {
+        self.opts.slides_to_scroll.max(1)
+    }
+
+    /// First slide of the last snap point.
+    fn last_snap_index(&self) -> usize {
+        let step = self.slides_to_scroll();
+        self.item_count.saturating_sub(1) / step * step
+    }
+
+    fn select(&mut self, index: usize, interacted: bool) {
+        if index != self.current_index {
+            self.current_index = index;
+            self.changed = true;
+            self.following = false;
+            if interacted {
+                self.interacted = true;
+            }
+        }
+    }
+
     fn scroll_prev_internal(&mut self, interacted: bool) {
         if self.item_count == 0 {
             return;
         }
         let next = if self.current_index == 0 {
             if self.opts.looped {
-                self.item_count.saturating_sub(1)
+                self.last_snap_index()
             } else {
                 return;
             }
         } else {
-            self.current_index - 1
+            self.current_index.saturating_sub(self.slides_to_scroll())
         };
-        if next != self.current_index {
-            self.current_index = next;
-            self.changed = true;
-            if interacted {
-                self.interacted = true;
-            }
-        }
+        self.select(next, interacted);
     }
 
     fn scroll_next_internal(&mut self, interacted: bool) {
         if self.item_count == 0 {
             return;
         }
-        let last = self.item_count.saturating_sub(1);
+        let last = self.last_snap_index();
         let next = if self.current_index >= last {
             if self.opts.looped {
                 0
@@ -209,14 +341,16 @@ impl CarouselContext {
                 return;
             }
         } else {
-            self.current_index + 1
+            (self.current_index + self.slides_to_scroll()).min(last)
         };
-        if next != self.current_index {
-            self.current_index = next;
-            self.changed = true;
-            if interacted {
-                self.interacted = true;
-            }
+        self.select(next, interacted);
+    }
+
+    /// Selects the snap nearest to a glide or trackpad scroll without stopping it.
+    fn follow(&mut self, index: usize) {
+        if index != self.current_index {
+            self.select(index, false);
+            self.following = true;
         }
     }
 }
@@ -226,6 +360,8 @@ pub struct CarouselResponse<R> {
     pub response: Response,
     pub index: usize,
     pub count: usize,
+    /// The selected slide changed this frame.
+    pub changed: bool,
     pub can_scroll_prev: bool,
     pub can_scroll_next: bool,
 }
@@ -253,14 +389,20 @@ pub fn carousel<R>(
         animated_offset: 0.0,
         measured_count: 0,
         changed: false,
+        following: false,
         interacted: false,
         drag_total: state.drag_total,
         drag_active: state.drag_active,
+        motion: state.motion,
     };
 
     let inner = ui.push_id(props.id_source, |ui| add_contents(ui, &mut context));
 
     if context.measured_count > 0 {
+        if context.measured_count != state.item_count {
+            // Dots and thumbnails drawn before the content need the new count.
+            ctx.request_repaint();
+        }
         state.item_count = context.measured_count;
     }
     context.item_count = state.item_count;
@@ -274,7 +416,9 @@ pub fn carousel<R>(
     state.index = context.current_index;
 
     let now = ui.input(|i| i.time);
-    if context.interacted || context.changed {
+    // Hovering or dragging holds autoplay; the full delay restarts afterwards.
+    let hovered = ui.rect_contains_pointer(inner.response.rect);
+    if context.interacted || context.changed || hovered || context.drag_active {
         state.last_autoplay_time = now;
     }
 
@@ -293,8 +437,15 @@ pub fn carousel<R>(
         }
     }
 
+    if context.changed && !context.following {
+        context.motion.free = false;
+        context.motion.velocity = 0.0;
+        ctx.request_repaint();
+    }
+
     state.drag_total = context.drag_total;
     state.drag_active = context.drag_active;
+    state.motion = context.motion;
     ctx.data_mut(|d| d.insert_temp(props.id_source, state));
 
     CarouselResponse {
@@ -302,6 +453,7 @@ pub fn carousel<R>(
         response: inner.response,
         index: context.current_index,
         count: state.item_count,
+        changed: context.changed,
         can_scroll_prev: context.can_scroll_prev(),
         can_scroll_next: context.can_scroll_next(),
     }
@@ -341,16 +493,38 @@ pub fn carousel_content<R>(
     context.item_basis = props.item_basis.max(0.1);
     context.measured_count = 0;
 
-    let (main_size, spacing) = match context.orientation {
-        CarouselOrientation::Horizontal => (rect.width(), context.item_spacing),
-        CarouselOrientation::Vertical => (rect.height(), context.item_spacing),
-    };
+    let horizontal = context.orientation == CarouselOrientation::Horizontal;
+    let main = |v: Vec2| if horizontal { v.x } else { v.y };
+    let main_size = main(rect.size());
     let item_main = main_size * context.item_basis;
-    let target_offset = (context.current_index as f32) * (item_main + spacing);
-    let anim_ms = theme.motion.base_ms.max(1.0) / 1000.0;
-    context.animated_offset =
-        ui.ctx()
-            .animate_value_with_time(context.id_source.with("offset"), target_offset, anim_ms);
+    let step = item_main + context.item_spacing;
+    let align_shift = match context.opts.align {
+        CarouselAlign::Start => 0.0,
+        CarouselAlign::Center => (main_size - item_main) / 2.0,
+        CarouselAlign::End => main_size - item_main,
+    };
+    let snap_offset = |index: usize| index as f32 * step - align_shift;
+    let group = context.slides_to_scroll();
+    let last_snap = context.last_snap_index();
+    let (min_offset, max_offset) = (snap_offset(0), snap_offset(last_snap));
+    let nearest_snap = |offset: f32| {
+        let snap = ((offset + align_shift) / (step * group as f32))
+            .round()
+            .max(0.0);
+        (snap as usize * group).min(last_snap)
+    };
+
+    let now = ui.input(|i| i.time);
+    let dt = ui.input(|i| i.stable_dt).min(0.1);
+    let mut motion = context.motion;
+    if motion.step != step {
+        // First layout or a resized viewport: jump straight to the current slide.
+        motion = CarouselMotion {
+            offset: snap_offset(context.current_index),
+            step,
+            ..Default::default()
+        };
+    }
 
     if response.clicked() {
         response.request_focus();
@@ -360,11 +534,19 @@ pub fn carousel_content<R>(
         context.drag_active = true;
         context.drag_total = Vec2::ZERO;
         context.interacted = true;
+        motion.drag_origin = motion.offset;
+        motion.velocity = 0.0;
+        motion.free = false;
     }
 
-    if response.dragged() {
+    if response.dragged() && context.drag_active {
         context.drag_total += response.drag_delta();
         context.interacted = true;
+        motion.offset = rubber_band(
+            motion.drag_origin - main(context.drag_total),
+            min_offset,
+            max_offset,
+        );
     }
 
     if response.has_focus() {
@@ -381,23 +563,88 @@ pub fn carousel_content<R>(
     }
 
     if response.drag_stopped() && context.drag_active {
-        let drag = match context.orientation {
-            CarouselOrientation::Horizontal => context.drag_total.x,
-            CarouselOrientation::Vertical => context.drag_total.y,
-        };
+        let drag = main(context.drag_total);
+        let velocity = -main(ui.input(|i| i.pointer.velocity()));
         context.drag_active = false;
         context.drag_total = Vec2::ZERO;
 
-        let threshold = item_main * 0.25;
-        if drag.abs() > threshold {
-            if drag > 0.0 {
-                context.scroll_prev();
-            } else {
-                context.scroll_next();
+        if context.opts.drag_free {
+            motion.free = true;
+            motion.velocity = velocity;
+        } else {
+            let target = nearest_snap(motion.offset + velocity * MOMENTUM_PROJECTION);
+            if target != context.current_index {
+                context.scroll_to(target);
+            } else if drag.abs() > item_main * 0.25 {
+                if drag > 0.0 {
+                    context.scroll_prev();
+                } else {
+                    context.scroll_next();
+                }
             }
         }
     }
 
+    // Trackpads and shift+wheel move horizontal carousels like a drag; vertical ones leave the
+    // wheel to the page.
+    if horizontal && !context.drag_active && response.contains_pointer() {
+        let delta = ui.input(|i| i.smooth_scroll_delta.x);
+        if delta != 0.0 {
+            ui.input_mut(|i| i.smooth_scroll_delta.x = 0.0);
+            motion.offset = (motion.offset - delta).clamp(min_offset, max_offset);
+            motion.velocity = 0.0;
+            motion.free = true;
+            motion.last_scroll_time = now;
+            context.interacted = true;
+        }
+    }
+
+    if motion.free && !context.drag_active {
+        if motion.velocity != 0.0 {
+            motion.offset += motion.velocity * dt;
+            motion.velocity *= (-FRICTION * dt).exp();
+            if motion.offset < min_offset
+                || motion.offset > max_offset
+                || motion.velocity.abs() < 10.0
+            {
+                motion.velocity = 0.0;
+            }
+        }
+        let settled =
+            motion.velocity == 0.0 && now - motion.last_scroll_time > SCROLL_SETTLE_SECONDS;
+        if context.opts.drag_free {
+            // Drag-free carousels rest wherever the glide stops.
+            context.follow(nearest_snap(motion.offset));
+            if motion.velocity != 0.0 {
+                ui.ctx().request_repaint();
+            }
+        } else if settled {
+            motion.free = false;
+            context.scroll_to(nearest_snap(motion.offset));
+        } else {
+            context.follow(nearest_snap(motion.offset));
+            ui.ctx().request_repaint();
+        }
+    }
+
+    let target = if context.drag_active {
+        motion.offset
+    } else if motion.free {
+        motion.offset.clamp(min_offset, max_offset)
+    } else {
+        snap_offset(context.current_index)
+    };
+    let remaining = target - motion.offset;
+    if remaining.abs() < 0.5 {
+        motion.offset = target;
+    } else {
+        let duration = theme.motion.base_ms.max(1.0) / 1000.0;
+        motion.offset += remaining * (1.0 - (-dt * 6.0 / duration).exp());
+        ui.ctx().request_repaint();
+    }
+    context.animated_offset = motion.offset;
+    context.motion = motion;
+
     CarouselContentResponse {
         inner: {
             let mut content_ui = ui.new_child(
@@ -412,6 +659,16 @@ pub fn carousel_content<R>(
     }
 }
 
+fn rubber_band(offset: f32, min: f32, max: f32) -> f32 {
+    if offset < min {
+        min - (min - offset) * RUBBER_BAND
+    } else if offset > max {
+        max + (offset - max) * RUBBER_BAND
+    } else {
+        offset
+    }
+}
+
 pub struct CarouselItemResponse<R> {
     pub inner: R,
     pub response: Response,
@@ -466,6 +723,129 @@ pub fn carousel_item<R>(
     }
 }
 
+/// One dot per snap point; the selected dot stretches into a pill. Clicking a dot scrolls to
+/// its snap point.
+pub fn carousel_dots(ui: &mut Ui, theme: &Theme, context: &mut CarouselContext) -> Response {
+    let dot = 8.0;
+    let selected_length = 20.0;
+    let gap = 6.0;
+    let horizontal = context.orientation == CarouselOrientation::Horizontal;
+    let count = context.snap_count();
+    let length = match count {
+        0 => 0.0,
+        count => (count - 1) as f32 * (dot + gap) + selected_length,
+    };
+    let size = if horizontal {
+        vec2(length, dot)
+    } else {
+        vec2(dot, length)
+    };
+    let (rect, response) = ui.allocate_exact_size(size, Sense::hover());
+
+    let selected = context.selected_snap();
+    let anim = theme.motion.fast_ms.max(1.0) / 1000.0;
+    let mut cursor = 0.0;
+    for snap in 0..count {
+        let id = context.id_source.with(("carousel-dot", snap));
+        let t = ui.ctx().animate_bool_with_time(id, snap == selected, anim);
+        let extent = egui::lerp(dot..=selected_length, t);
+        let dot_rect = if horizontal {
+            Rect::from_min_size(rect.min + vec2(cursor, 0.0), vec2(extent, dot))
+        } else {
+            Rect::from_min_size(rect.min + vec2(0.0, cursor), vec2(dot, extent))
+        };
+        cursor += extent + gap;
+
+        let dot_response = ui
+            .interact(dot_rect.expand(gap / 2.0), id, Sense::click())
+            .on_hover_cursor(CursorIcon::PointingHand);
+        let idle = if dot_response.hovered() {
+            theme.palette.muted_foreground
+        } else {
+            theme.palette.muted_foreground.gamma_multiply(0.4)
+        };
+        ui.painter().rect_filled(
+            dot_rect,
+            dot / 2.0,
+            idle.lerp_to_gamma(theme.palette.primary, t),
+        );
+        if dot_response.clicked() {
+            context.scroll_to(snap * context.slides_to_scroll());
+        }
+    }
+
+    response
+}
+
+/// A scrollable strip with one thumbnail per slide. The thumbnail of the selected slide is
+/// outlined and kept in view, and clicking a thumbnail scrolls the carousel to its slide.
+pub fn carousel_thumbnails(
+    ui: &mut Ui,
+    theme: &Theme,
+    context: &mut CarouselContext,
+    props: CarouselThumbnailsProps,
+    mut add_thumbnail: impl FnMut(&mut Ui, usize),
+) -> Response {
+    let horizontal = context.orientation == CarouselOrientation::Horizontal;
+    let strip_id = context.id_source.with("thumbnails");
+    let selected = context.selected_snap();
+    let previous = ui.ctx().data(|d| d.get_temp::<usize>(strip_id));
+    let scroll = if horizontal {
+        ScrollArea::horizontal()
+    } else {
+        ScrollArea::vertical()
+    };
+    let layout = if horizontal {
+        Layout::left_to_right(Align::Min)
+    } else {
+        Layout::top_down(Align::Min)
+    };
+    let rounding = theme.radius.r2;
+
+    let output = scroll.id_salt(strip_id).show(ui, |ui| {
+        ui.with_layout(layout, |ui| {
+            ui.spacing_mut().item_spacing = Vec2::splat(props.spacing);
+            for index in 0..context.item_count {
+                let (rect, response) = ui.allocate_exact_size(props.size, Sense::click());
+                let response = response.on_hover_cursor(CursorIcon::PointingHand);
+                let mut thumbnail_ui = ui.new_child(
+                    UiBuilder::new()
+                        .max_rect(rect)
+                        .id_salt(("carousel-thumbnail", index)),
+                );
+                thumbnail_ui.set_clip_rect(rect.intersect(ui.clip_rect()));
+                add_thumbnail(&mut thumbnail_ui, index);
+
+                let is_selected = index / context.slides_to_scroll() == selected;
+                if is_selected {
+                    ui.painter().rect_stroke(
+                        rect,
+                        rounding,
+                        Stroke::new(2.0, theme.palette.primary),
+                        StrokeKind::Inside,
+                    );
+                    if previous != Some(selected) {
+                        response.scroll_to_me(None);
+                    }
+                } else {
+                    let veil = if response.hovered() { 0.2 } else { 0.5 };
+                    ui.painter().rect_filled(
+                        rect,
+                        rounding,
+                        theme.palette.background.gamma_multiply(veil),
+                    );
+                }
+                if response.clicked() {
+                    context.scroll_to(index);
+                }
+            }
+        });
+    });
+
+    ui.ctx().data_mut(|d| d.insert_temp(strip_id, selected));
+    ui.interact(output.inner_rect, strip_id.with("strip"), Sense::hover())
+}
+
 pub fn carousel_previous(ui: &mut Ui, theme: &Theme, context: &mut CarouselContext) -> Response {
     let icon = match context.orientation {
         CarouselOrientation::Horizontal => icon_arrow_left,
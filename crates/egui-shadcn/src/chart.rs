@@ -1,10 +1,20 @@
 use crate::theme::Theme;
+use chrono::{DateTime, Local, TimeZone};
 use egui::{
-    Align, Color32, FontId, Frame, Id, InnerResponse, Layout, Margin, Pos2, RichText, Stroke, Ui,
-    Vec2, Vec2b, pos2, vec2,
+    Align, Color32, Frame, Id, InnerResponse, Layout, Margin, Pos2, RichText, Stroke, Ui, Vec2,
+    Vec2b, vec2,
 };
 use egui_plot::{
     Bar, BarChart as PlotBarChart, Corner, GridMark, Legend, Line, Plot, PlotPoint, PlotUi,
+    Polygon, Text, VLine, uniform_grid_spacer,
+};
+use lucide_icons::Icon;
+use std::collections::{HashSet, VecDeque};
+
+use crate::chart_primitives::legend_entry;
+pub use crate::chart_primitives::{
+    ChartIndicator, ChartLegend, ChartLegendItem, ChartTooltip, ChartTooltipItem, chart_color,
+    format_chart_value,
 };
 use std::ops::RangeInclusive;
 use std::sync::Arc;
@@ -24,6 +34,11 @@ pub struct ChartProps {
     pub margin: Vec2,
     pub x_axis_formatter: Option<AxisFormatter>,
     pub y_axis_formatter: Option<AxisFormatter>,
+    pub data_aspect: Option<f32>,
+    pub show_axes: bool,
+    pub interactive: bool,
+    pub crosshair: bool,
+    pub time_axis: Option<TimeAxis>,
 }
 
 impl std::fmt::Debug for ChartProps {
@@ -40,6 +55,11 @@ impl std::fmt::Debug for ChartProps {
             .field("margin", &self.margin)
             .field("x_axis_formatter", &self.x_axis_formatter.is_some())
             .field("y_axis_formatter", &self.y_axis_formatter.is_some())
+            .field("data_aspect", &self.data_aspect)
+            .field("show_axes", &self.show_axes)
+            .field("interactive", &self.interactive)
+            .field("crosshair", &self.crosshair)
+            .field("time_axis", &self.time_axis)
             .finish()
     }
 }
@@ -58,9 +78,26 @@ impl ChartProps {
             margin: vec2(8.0, 8.0),
             x_axis_formatter: None,
             y_axis_formatter: None,
+            data_aspect: None,
+            show_axes: true,
+            interactive: true,
+            crosshair: false,
+            time_axis: None,
         }
     }
 
+    /// Setup for [`PieChart`], [`RadarChart`] and [`RadialBarChart`]: square aspect, no axes,
+    /// grid or legend, and no panning or zooming.
+    pub fn polar(self) -> Self {
+        self.data_aspect(1.0)
+            .show_axes(false)
+            .show_grid(Vec2b::FALSE)
+            .show_legend(false)
+            .show_x(false)
+            .show_y(false)
+            .interactive(false)
+    }
+
     pub fn title(mut self, title: impl Into<String>) -> Self {
         self.title = Some(title.into());
         self
@@ -110,6 +147,36 @@ impl ChartProps {
         self.y_axis_formatter = Some(formatter);
         self
     }
+
+    pub fn data_aspect(mut self, aspect: f32) -> Self {
+        self.data_aspect = Some(aspect);
+        self
+    }
+
+    pub fn show_axes(mut self, show: bool) -> Self {
+        self.show_axes = show;
+        self
+    }
+
+    /// Allow dragging, zooming and scrolling the plot.
+    pub fn interactive(mut self, interactive: bool) -> Self {
+        self.interactive = interactive;
+        self
+    }
+
+    /// Draw a vertical line at the hovered category. Categories are whole x values, as for
+    /// series laid out by index.
+    pub fn crosshair(mut self, crosshair: bool) -> Self {
+        self.crosshair = crosshair;
+        self
+    }
+
+    /// Treat x values as seconds since the Unix epoch: ticks fall on whole seconds, minutes,
+    /// hours or days and are labeled with [`format_time_tick`] unless an x axis formatter is set.
+    pub fn time_x_axis(mut self, axis: TimeAxis) -> Self {
+        self.time_axis = Some(axis);
+        self
+    }
 }
 
 pub struct ChartResponse<R> {
@@ -119,6 +186,14 @@ pub struct ChartResponse<R> {
     pub pointer_pos: Option<Pos2>,
 }
 
+impl<R> ChartResponse<R> {
+    /// Index of the hovered category among `len` categories at whole x values.
+    pub fn hovered_index(&self, len: usize) -> Option<usize> {
+        let x = self.hovered_point?.x.round();
+        (x >= 0.0 && x < len as f64).then_some(x as usize)
+    }
+}
+
 pub struct ShadcnChart {
     props: ChartProps,
 }
@@ -152,7 +227,31 @@ impl ShadcnChart {
                 .show_background(false)
                 .show_grid(self.props.show_grid)
                 .show_x(self.props.show_x)
-                .show_y(self.props.show_y);
+                .show_y(self.props.show_y)
+                .show_axes(self.props.show_axes);
+
+            if let Some(aspect) = self.props.data_aspect {
+                plot = plot.data_aspect(aspect);
+            }
+
+            if !self.props.interactive {
+                plot = plot
+                    .allow_drag(false)
+                    .allow_zoom(false)
+                    .allow_scroll(false)
+                    .allow_boxed_zoom(false)
+                    .allow_double_click_reset(false);
+            }
+
+            if let Some(axis) = self.props.time_axis {
+                plot = plot
+                    .x_grid_spacer(uniform_grid_spacer(|input| {
+                        time_steps(input.base_step_size)
+                    }))
+                    .x_axis_formatter(move |mark, _range| {
+                        format_time_tick(mark.value, mark.step_size, axis)
+                    });
+            }
 
             if let Some(formatter) = &self.props.x_axis_formatter {
                 let formatter = Arc::clone(formatter);
@@ -169,10 +268,21 @@ impl ShadcnChart {
             }
 
             plot.show(frame_ui, |plot_ui| {
-                if self.props.show_tooltip {
+                if self.props.show_tooltip || self.props.crosshair {
                     hovered_point = plot_ui.pointer_coordinate();
                 }
-                add_plot(plot_ui)
+                let inner = add_plot(plot_ui);
+                if self.props.crosshair
+                    && let Some(point) = hovered_point
+                {
+                    plot_ui.vline(
+                        VLine::new("crosshair", point.x.round())
+                            .color(theme.palette.border)
+                            .width(1.0)
+                            .allow_hover(false),
+                    );
+                }
+                inner
             })
         });
 
@@ -261,12 +371,224 @@ impl LineChart {
     }
 }
 
+/// How a [`ChartProps::time_x_axis`] shows timestamps.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TimeAxis {
+    #[default]
+    Local,
+    Utc,
+}
+
+/// Tick intervals for time axes, in seconds.
+const TIME_STEPS: [f64; 23] = [
+    1.0, 2.0, 5.0, 10.0, 15.0, 30.0, 60.0, 120.0, 300.0, 600.0, 900.0, 1800.0, 3600.0, 7200.0,
+    10800.0, 21600.0, 43200.0, 86400.0, 172800.0, 604800.0, 2592000.0, 7776000.0, 31536000.0,
+];
+
+/// Three tick intervals, finest first, whose finest is at least `base_step` seconds.
+fn time_steps(base_step: f64) -> [f64; 3] {
+    let index = TIME_STEPS
+        .iter()
+        .position(|step| *step >= base_step)
+        .unwrap_or(TIME_STEPS.len() - 1);
+    let step = |offset: usize| {
+        TIME_STEPS
+            .get(index + offset)
+            .copied()
+            .unwrap_or(TIME_STEPS[TIME_STEPS.len() - 1] * (offset + 1) as f64)
+    };
+    [step(0), step(1), step(2)]
+}
+
+/// The x value of a timestamp on a time axis: seconds since the Unix epoch.
+pub fn time_x<Tz: TimeZone>(time: &DateTime<Tz>) -> f64 {
+    time.timestamp_millis() as f64 / 1000.0
+}
+
+/// Formats a time axis tick at `seconds` since the Unix epoch. The pattern follows the tick
+/// interval `step`: seconds, then hours and minutes, days, months and finally years.
+pub fn format_time_tick(seconds: f64, step: f64, axis: TimeAxis) -> String {
+    let Some(time) = DateTime::from_timestamp_millis((seconds * 1000.0).round() as i64) else {
+        return String::new();
+    };
+    let pattern = if step < 60.0 {
+        "%H:%M:%S"
+    } else if step < 86400.0 {
+        "%H:%M"
+    } else if step < 2592000.0 {
+        "%b %-d"
+    } else if step < 31536000.0 {
+        "%b %Y"
+    } else {
+        "%Y"
+    };
+    match axis {
+        TimeAxis::Local => time.with_timezone(&Local).format(pattern).to_string(),
+        TimeAxis::Utc => time.format(pattern).to_string(),
+    }
+}
+
+/// Reduces `points` to `threshold` points with Largest-Triangle-Three-Buckets, keeping the
+/// visual shape of the series. Points must be sorted by x.
+pub fn lttb(points: &[[f64; 2]], threshold: usize) -> Vec<[f64; 2]> {
+    if threshold >= points.len() || threshold < 3 {
+        return points.to_vec();
+    }
+    let mut sampled = Vec::with_capacity(threshold);
+    sampled.push(points[0]);
+    // The first and last points are kept; the rest is split into equal buckets.
+    let bucket = (points.len() - 2) as f64 / (threshold - 2) as f64;
+    let mut selected = 0;
+    for index in 0..threshold - 2 {
+        let start = (index as f64 * bucket) as usize + 1;
+        let end = ((index + 1) as f64 * bucket) as usize + 1;
+        let next_end = (((index + 2) as f64 * bucket) as usize + 1).min(points.len());
+        let next = &points[end..next_end.max(end + 1)];
+        let average = next
+            .iter()
+            .fold([0.0, 0.0], |sum, p| [sum[0] + p[0], sum[1] + p[1]]);
+        let average = [
+            average[0] / next.len() as f64,
+            average[1] / next.len() as f64,
+        ];
+        let [ax, ay] = points[selected];
+        let area = |p: &[f64; 2]| {
+            ((ax - average[0]) * (p[1] - ay) - (ax - p[0]) * (average[1] - ay)).abs()
+        };
+        selected = (start..end)
+            .max_by(|a, b| area(&points[*a]).total_cmp(&area(&points[*b])))
+            .unwrap_or(start);
+        sampled.push(points[selected]);
+    }
+    sampled.push(points[points.len() - 1]);
+    sampled
+}
+
+/// Fixed-capacity series for streaming data: pushing past the capacity drops the oldest
+/// point, so a live chart keeps its memory and per-frame cost bounded.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RingSeries {
+    capacity: usize,
+    points: VecDeque<[f64; 2]>,
+}
+
+impl RingSeries {
+    pub fn new(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        Self {
+            capacity,
+            points: VecDeque::with_capacity(capacity),
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    /// Appends a point and returns the one it evicted, if the series was full.
+    pub fn push(&mut self, x: f64, y: f64) -> Option<[f64; 2]> {
+        let evicted = if self.points.len() == self.capacity {
+            self.points.pop_front()
+        } else {
+            None
+        };
+        self.points.push_back([x, y]);
+        evicted
+    }
+
+    /// Appends a point at `time`, for charts using [`ChartProps::time_x_axis`].
+    pub fn push_time<Tz: TimeZone>(&mut self, time: &DateTime<Tz>, y: f64) -> Option<[f64; 2]> {
+        self.push(time_x(time), y)
+    }
+
+    pub fn extend(&mut self, points: impl IntoIterator<Item = [f64; 2]>) {
+        for [x, y] in points {
+            self.push(x, y);
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.points.clear();
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &[f64; 2]> {
+        self.points.iter()
+    }
+
+    pub fn latest(&self) -> Option<[f64; 2]> {
+        self.points.back().copied()
+    }
+
+    pub fn to_vec(&self) -> Vec<[f64; 2]> {
+        self.points.iter().copied().collect()
+    }
+
+    /// The points, reduced with [`lttb`] when there are more than `max_points`.
+    pub fn downsampled(&self, max_points: usize) -> Vec<[f64; 2]> {
+        if self.points.len() <= max_points {
+            self.to_vec()
+        } else {
+            lttb(&self.to_vec(), max_points)
+        }
+    }
+
+    /// A [`LineChart`] of at most `max_points` points.
+    pub fn line(&self, max_points: usize) -> LineChart {
+        LineChart::new(self.downsampled(max_points))
+    }
+
+    /// Keeps the newest `window` x units in view, see [`follow_latest`].
+    pub fn follow_latest(&self, plot_ui: &mut PlotUi, window: f64) -> bool {
+        match self.latest() {
+            Some([x, _]) => follow_latest(plot_ui, x, window),
+            None => false,
+        }
+    }
+}
+
+/// Scrolls the x axis so it ends at `latest_x` and spans `window`, while the y axis keeps
+/// fitting the data. Dragging or zooming the plot pauses following; double-clicking resumes.
+/// Returns whether the plot is following.
+pub fn follow_latest(plot_ui: &mut PlotUi, latest_x: f64, window: f64) -> bool {
+    let response = plot_ui.response().clone();
+    let paused_id = response.id.with("follow-latest-paused");
+    let mut paused = plot_ui
+        .ctx()
+        .data(|d| d.get_temp::<bool>(paused_id).unwrap_or(false));
+    let zoomed = response.hovered()
+        && plot_ui
+            .ctx()
+            .input(|i| i.zoom_delta() != 1.0 || i.smooth_scroll_delta != Vec2::ZERO);
+    if response.dragged() || zoomed {
+        paused = true;
+    }
+    if response.double_clicked() {
+        paused = false;
+    }
+    plot_ui.ctx().data_mut(|d| d.insert_temp(paused_id, paused));
+
+    if !paused {
+        plot_ui.set_plot_bounds_x(latest_x - window..=latest_x);
+        plot_ui.set_auto_bounds(Vec2b::new(false, true));
+    }
+    !paused
+}
+
 #[derive(Clone, Debug)]
 pub struct BarChart {
     bars: Vec<Bar>,
     label: Option<String>,
     color: Color32,
     bar_width: Option<f64>,
+    horizontal: bool,
 }
 
 impl BarChart {
@@ -277,6 +599,7 @@ impl BarChart {
             label: None,
             color: Color32::WHITE,
             bar_width: Some(0.6),
+            horizontal: false,
         }
     }
 
@@ -286,6 +609,7 @@ impl BarChart {
             label: None,
             color: Color32::WHITE,
             bar_width: None,
+            horizontal: false,
         }
     }
 
@@ -304,186 +628,987 @@ impl BarChart {
         self
     }
 
+    /// Lay bars out along the y axis, growing to the right.
+    pub fn horizontal(mut self, horizontal: bool) -> Self {
+        self.horizontal = horizontal;
+        self
+    }
+
     pub fn show(&self, plot_ui: &mut PlotUi) {
         let name = self.label.clone().unwrap_or_else(|| "Series".to_string());
         let mut chart = PlotBarChart::new(name, self.bars.clone()).color(self.color);
         if let Some(width) = self.bar_width {
             chart = chart.width(width);
         }
+        if self.horizontal {
+            chart = chart.horizontal();
+        }
         plot_ui.bar_chart(chart);
     }
 }
 
-#[derive(Clone, Debug)]
-pub struct ChartLegendItem {
+/// A named series of values, one per category (x position) of a multi-series chart.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ChartSeries {
     pub label: String,
-    pub color: Color32,
+    pub values: Vec<f64>,
+
+    /// Defaults to [`chart_color`] for the series position.
+    pub color: Option<Color32>,
 }
 
-pub struct ChartLegend<'a> {
-    items: &'a [ChartLegendItem],
+impl ChartSeries {
+    pub fn new(label: impl Into<String>, values: Vec<f64>) -> Self {
+        Self {
+            label: label.into(),
+            values,
+            color: None,
+        }
+    }
+
+    pub fn color(mut self, color: Color32) -> Self {
+        self.color = Some(color);
+        self
+    }
 }
 
-impl<'a> ChartLegend<'a> {
-    pub fn new(items: &'a [ChartLegendItem]) -> Self {
-        Self { items }
+/// A named value of a [`PieChart`] or [`RadialBarChart`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct ChartSlice {
+    pub label: String,
+    pub value: f64,
+    pub color: Option<Color32>,
+}
+
+impl ChartSlice {
+    pub fn new(label: impl Into<String>, value: f64) -> Self {
+        Self {
+            label: label.into(),
+            value,
+            color: None,
+        }
     }
 
-    pub fn show(self, ui: &mut Ui, theme: &Theme) -> InnerResponse<()> {
-        ui.with_layout(Layout::left_to_right(Align::Center), |legend_ui| {
-            legend_ui.spacing_mut().item_spacing.x = 16.0;
-            for item in self.items {
-                legend_ui.horizontal(|row| {
-                    row.spacing_mut().item_spacing.x = 6.0;
-                    let (rect, _) = row.allocate_exact_size(vec2(8.0, 8.0), egui::Sense::hover());
-                    row.painter().rect_filled(rect, theme.radius.r2, item.color);
-                    row.label(
-                        RichText::new(&item.label)
-                            .size(12.0)
-                            .color(theme.palette.muted_foreground),
-                    );
-                });
-            }
+    pub fn color(mut self, color: Color32) -> Self {
+        self.color = Some(color);
+        self
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ChartStacking {
+    /// Areas overlap and bars are grouped side by side.
+    #[default]
+    None,
+    /// Positive values stack up from zero and negative values stack down from it.
+    Stacked,
+    /// Stacked and normalized so the magnitudes in every category sum to 100.
+    Percent,
+}
+
+/// Lower and upper bound of every value of every series after stacking.
+pub fn stack_series(series: &[ChartSeries], stacking: ChartStacking) -> Vec<Vec<(f64, f64)>> {
+    let len = series.iter().map(|s| s.values.len()).max().unwrap_or(0);
+    let value = |s: &ChartSeries, index: usize| s.values.get(index).copied().unwrap_or(0.0);
+    let totals: Vec<f64> = (0..len)
+        .map(|index| series.iter().map(|s| value(s, index).abs()).sum())
+        .collect();
+    let mut positive = vec![0.0; len];
+    let mut negative = vec![0.0; len];
+    series
+        .iter()
+        .map(|s| {
+            (0..len)
+                .map(|index| {
+                    let mut v = value(s, index);
+                    match stacking {
+                        ChartStacking::None => return (0.0, v),
+                        ChartStacking::Stacked => {}
+                        ChartStacking::Percent => {
+                            v = if totals[index] == 0.0 {
+                                0.0
+                            } else {
+                                v / totals[index] * 100.0
+                            };
+                        }
+                    }
+                    if v < 0.0 {
+                        let upper = negative[index];
+                        negative[index] += v;
+                        (upper + v, upper)
+                    } else {
+                        let lower = positive[index];
+                        positive[index] += v;
+                        (lower, lower + v)
+                    }
+                })
+                .collect()
         })
+        .collect()
+}
+
+fn series_color(series: &ChartSeries, theme: &Theme, index: usize) -> Color32 {
+    series.color.unwrap_or_else(|| chart_color(theme, index))
+}
+
+fn slice_color(slice: &ChartSlice, theme: &Theme, index: usize) -> Color32 {
+    slice.color.unwrap_or_else(|| chart_color(theme, index))
+}
+
+fn series_legend_items(series: &[ChartSeries], theme: &Theme) -> Vec<ChartLegendItem> {
+    series
+        .iter()
+        .enumerate()
+        .map(|(index, s)| ChartLegendItem {
+            label: s.label.clone(),
+            color: series_color(s, theme, index),
+        })
+        .collect()
+}
+
+fn series_tooltip_items(
+    series: &[ChartSeries],
+    theme: &Theme,
+    index: usize,
+    percent: bool,
+) -> Vec<ChartTooltipItem> {
+    let totals: f64 = series
+        .iter()
+        .filter_map(|s| s.values.get(index))
+        .map(|value| value.abs())
+        .sum::<f64>();
+    series
+        .iter()
+        .enumerate()
+        .filter_map(|(series_index, s)| {
+            let value = *s.values.get(index)?;
+            let value = if percent && totals != 0.0 {
+                format!("{:.0}%", value / totals * 100.0)
+            } else {
+                format_chart_value(value)
+            };
+            Some(ChartTooltipItem {
+                label: s.label.clone(),
+                value,
+                color: series_color(s, theme, series_index),
+            })
+        })
+        .collect()
+}
+
+fn slice_legend_items(slices: &[ChartSlice], theme: &Theme) -> Vec<ChartLegendItem> {
+    slices
+        .iter()
+        .enumerate()
+        .map(|(index, slice)| ChartLegendItem {
+            label: slice.label.clone(),
+            color: slice_color(slice, theme, index),
+        })
+        .collect()
+}
+
+fn slice_tooltip_item(
+    slices: &[ChartSlice],
+    theme: &Theme,
+    index: usize,
+) -> Option<ChartTooltipItem> {
+    let slice = slices.get(index)?;
+    Some(ChartTooltipItem {
+        label: slice.label.clone(),
+        value: format_chart_value(slice.value),
+        color: slice_color(slice, theme, index),
+    })
+}
+
+/// Area chart over shared categories, optionally stacked or normalized to percentages.
+/// Categories sit at x = 0, 1, 2, … unless [`AreaChart::x_values`] is set.
+#[derive(Clone, Debug)]
+pub struct AreaChart {
+    series: Vec<ChartSeries>,
+    x_values: Option<Vec<f64>>,
+    stacking: ChartStacking,
+    fill_alpha: f32,
+    stroke_width: f32,
+}
+
+impl AreaChart {
+    pub fn new(series: Vec<ChartSeries>) -> Self {
+        Self {
+            series,
+            x_values: None,
+            stacking: ChartStacking::None,
+            fill_alpha: 0.4,
+            stroke_width: 2.0,
+        }
+    }
+
+    pub fn x_values(mut self, x_values: Vec<f64>) -> Self {
+        self.x_values = Some(x_values);
+        self
+    }
+
+    pub fn stacking(mut self, stacking: ChartStacking) -> Self {
+        self.stacking = stacking;
+        self
+    }
+
+    pub fn fill_alpha(mut self, alpha: f32) -> Self {
+        self.fill_alpha = alpha;
+        self
+    }
+
+    pub fn stroke_width(mut self, width: f32) -> Self {
+        self.stroke_width = width;
+        self
+    }
+
+    pub fn legend_items(&self, theme: &Theme) -> Vec<ChartLegendItem> {
+        series_legend_items(&self.series, theme)
+    }
+
+    /// One row per series for the category at `index`.
+    pub fn tooltip_items(&self, theme: &Theme, index: usize) -> Vec<ChartTooltipItem> {
+        series_tooltip_items(
+            &self.series,
+            theme,
+            index,
+            self.stacking == ChartStacking::Percent,
+        )
+    }
+
+    pub fn show(&self, plot_ui: &mut PlotUi, theme: &Theme) {
+        let bands = stack_series(&self.series, self.stacking);
+        let x = |index: usize| {
+            self.x_values
+                .as_ref()
+                .and_then(|values| values.get(index).copied())
+                .unwrap_or(index as f64)
+        };
+        // Drawn last to first so the first series stays on top when areas overlap.
+        for (series_index, (series, band)) in self.series.iter().zip(&bands).enumerate().rev() {
+            let color = series_color(series, theme, series_index);
+            let fill = color.gamma_multiply(self.fill_alpha);
+            // Polygons must be convex, so the band is filled one segment at a time.
+            for (index, pair) in band.windows(2).enumerate() {
+                let (x0, x1) = (x(index), x(index + 1));
+                let points = vec![
+                    [x0, pair[0].0],
+                    [x1, pair[1].0],
+                    [x1, pair[1].1],
+                    [x0, pair[0].1],
+                ];
+                plot_ui.polygon(
+                    Polygon::new(series.label.clone(), points)
+                        .fill_color(fill)
+                        .stroke(Stroke::new(0.0, color))
+                        .allow_hover(false),
+                );
+            }
+            let outline: Vec<[f64; 2]> = band
+                .iter()
+                .enumerate()
+                // The outline follows the edge away from zero, the lower one for negative values.
+                .map(|(index, (lower, upper))| {
+                    let edge = if lower.abs() > upper.abs() {
+                        lower
+                    } else {
+                        upper
+                    };
+                    [x(index), *edge]
+                })
+                .collect();
+            plot_ui.line(
+                Line::new(series.label.clone(), outline)
+                    .color(color)
+                    .width(self.stroke_width),
+            );
+        }
     }
 }
 
+/// Bars for several series over shared categories: grouped side by side, stacked or
+/// stacked to 100%, vertical or horizontal.
 #[derive(Clone, Debug)]
-pub struct ChartTooltipItem {
-    pub label: String,
-    pub value: String,
-    pub color: Color32,
+pub struct MultiBarChart {
+    series: Vec<ChartSeries>,
+    stacking: ChartStacking,
+    horizontal: bool,
+    bar_width: f64,
+}
+
+impl MultiBarChart {
+    pub fn new(series: Vec<ChartSeries>) -> Self {
+        Self {
+            series,
+            stacking: ChartStacking::None,
+            horizontal: false,
+            bar_width: 0.7,
+        }
+    }
+
+    pub fn stacking(mut self, stacking: ChartStacking) -> Self {
+        self.stacking = stacking;
+        self
+    }
+
+    pub fn horizontal(mut self, horizontal: bool) -> Self {
+        self.horizontal = horizontal;
+        self
+    }
+
+    /// Width of a whole category; grouped bars share it.
+    pub fn bar_width(mut self, width: f64) -> Self {
+        self.bar_width = width;
+        self
+    }
+
+    pub fn legend_items(&self, theme: &Theme) -> Vec<ChartLegendItem> {
+        series_legend_items(&self.series, theme)
+    }
+
+    pub fn tooltip_items(&self, theme: &Theme, index: usize) -> Vec<ChartTooltipItem> {
+        series_tooltip_items(
+            &self.series,
+            theme,
+            index,
+            self.stacking == ChartStacking::Percent,
+        )
+    }
+
+    pub fn show(&self, plot_ui: &mut PlotUi, theme: &Theme) {
+        let bands = stack_series(&self.series, self.stacking);
+        let count = self.series.len().max(1) as f64;
+        for (series_index, (series, band)) in self.series.iter().zip(&bands).enumerate() {
+            let color = series_color(series, theme, series_index);
+            let (width, offset) = if self.stacking == ChartStacking::None {
+                let width = self.bar_width / count;
+                let offset = -self.bar_width / 2.0 + (series_index as f64 + 0.5) * width;
+                (width, offset)
+            } else {
+                (self.bar_width, 0.0)
+            };
+            let bars = band
+                .iter()
+                .enumerate()
+                .map(|(index, (lower, upper))| {
+                    Bar::new(index as f64 + offset, upper - lower)
+                        .base_offset(*lower)
+                        .width(width)
+                        .fill(color)
+                        .stroke(Stroke::new(1.0, Color32::TRANSPARENT))
+                })
+                .collect();
+            let mut chart = PlotBarChart::new(series.label.clone(), bars).color(color);
+            if self.horizontal {
+                chart = chart.horizontal();
+            }
+            plot_ui.bar_chart(chart);
+        }
+    }
+}
+
+/// Point at `radius` for a fraction of a full turn, starting at 12 o'clock and going clockwise.
+fn polar_point(fraction: f64, radius: f64) -> [f64; 2] {
+    let angle = std::f64::consts::FRAC_PI_2 - fraction * std::f64::consts::TAU;
+    [radius * angle.cos(), radius * angle.sin()]
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
-pub enum ChartIndicator {
-    Dot,
-    Line,
-    Dashed,
+/// Fraction of a full turn and radius of a plot point, the inverse of [`polar_point`].
+fn polar_position(point: PlotPoint) -> (f64, f64) {
+    let angle = point.y.atan2(point.x);
+    let fraction = ((std::f64::consts::FRAC_PI_2 - angle) / std::f64::consts::TAU).rem_euclid(1.0);
+    (fraction, point.x.hypot(point.y))
 }
 
-pub struct ChartTooltip<'a> {
-    title: Option<&'a str>,
-    items: &'a [ChartTooltipItem],
-    indicator: ChartIndicator,
-    hide_label: bool,
-    hide_indicator: bool,
+/// Fills the ring sector between two turn fractions with convex pieces.
+fn fill_sector(
+    plot_ui: &mut PlotUi,
+    name: &str,
+    (start, end): (f64, f64),
+    (inner, outer): (f64, f64),
+    color: Color32,
+) {
+    let steps = ((end - start) * 96.0).ceil().max(1.0) as usize;
+    let step = (end - start) / steps as f64;
+    for i in 0..steps {
+        let a = start + step * i as f64;
+        // Overlap neighbours slightly so anti-aliasing leaves no seams.
+        let b = (a + step * 1.05).min(end);
+        let points = if inner <= 0.0 {
+            vec![[0.0, 0.0], polar_point(a, outer), polar_point(b, outer)]
+        } else {
+            vec![
+                polar_point(a, inner),
+                polar_point(a, outer),
+                polar_point(b, outer),
+                polar_point(b, inner),
+            ]
+        };
+        plot_ui.polygon(
+            Polygon::new(name, points)
+                .fill_color(color)
+                .stroke(Stroke::new(0.0, color))
+                .allow_hover(false),
+        );
+    }
+}
+
+/// Pie chart with radius 1 around the origin; a donut when the inner radius is set. Show it
+/// in a chart using [`ChartProps::polar`].
+#[derive(Clone, Debug)]
+pub struct PieChart {
+    slices: Vec<ChartSlice>,
+    inner_radius: f64,
+    center_label: Option<(String, String)>,
 }
 
-impl<'a> ChartTooltip<'a> {
-    pub fn new(items: &'a [ChartTooltipItem]) -> Self {
+impl PieChart {
+    pub fn new(slices: Vec<ChartSlice>) -> Self {
         Self {
-            title: None,
-            items,
-            indicator: ChartIndicator::Dot,
-            hide_label: false,
-            hide_indicator: false,
+            slices,
+            inner_radius: 0.0,
+            center_label: None,
+        }
+    }
+
+    /// Inner radius as a fraction of the outer one, e.g. `0.6`.
+    pub fn donut(mut self, inner_radius: f64) -> Self {
+        self.inner_radius = inner_radius.clamp(0.0, 0.95);
+        self
+    }
+
+    /// Large value text with a caption below, shown in the hole of a donut.
+    pub fn center_label(mut self, value: impl Into<String>, caption: impl Into<String>) -> Self {
+        self.center_label = Some((value.into(), caption.into()));
+        self
+    }
+
+    fn total(&self) -> f64 {
+        self.slices.iter().map(|slice| slice.value.max(0.0)).sum()
+    }
+
+    /// Start and end of every slice as fractions of a full turn.
+    fn ranges(&self) -> Vec<(f64, f64)> {
+        let total = self.total();
+        let mut start = 0.0;
+        self.slices
+            .iter()
+            .map(|slice| {
+                let share = if total > 0.0 {
+                    slice.value.max(0.0) / total
+                } else {
+                    0.0
+                };
+                let range = (start, start + share);
+                start += share;
+                range
+            })
+            .collect()
+    }
+
+    /// Index of the slice under a plot point, e.g. [`ChartResponse::hovered_point`].
+    pub fn slice_at(&self, point: PlotPoint) -> Option<usize> {
+        let (fraction, radius) = polar_position(point);
+        if radius < self.inner_radius || radius > 1.0 {
+            return None;
+        }
+        self.ranges()
+            .iter()
+            .position(|(start, end)| fraction >= *start && fraction < *end)
+    }
+
+    pub fn legend_items(&self, theme: &Theme) -> Vec<ChartLegendItem> {
+        slice_legend_items(&self.slices, theme)
+    }
+
+    pub fn tooltip_item(&self, theme: &Theme, index: usize) -> Option<ChartTooltipItem> {
+        slice_tooltip_item(&self.slices, theme, index)
+    }
+
+    pub fn show(&self, plot_ui: &mut PlotUi, theme: &Theme) {
+        let ranges = self.ranges();
+        for (index, (slice, range)) in self.slices.iter().zip(&ranges).enumerate() {
+            if range.1 > range.0 {
+                fill_sector(
+                    plot_ui,
+                    &slice.label,
+                    *range,
+                    (self.inner_radius, 1.0),
+                    slice_color(slice, theme, index),
+                );
+            }
+        }
+        if ranges.iter().filter(|(start, end)| end > start).count() > 1 {
+            for (start, _) in &ranges {
+                plot_ui.line(
+                    Line::new(
+                        "separator",
+                        vec![
+                            polar_point(*start, self.inner_radius),
+                            polar_point(*start, 1.0),
+                        ],
+                    )
+                    .color(theme.palette.card)
+                    .width(2.0)
+                    .allow_hover(false),
+                );
+            }
+        }
+        if let Some((value, caption)) = &self.center_label {
+            let offset = self.inner_radius.max(0.3) * 0.2;
+            plot_ui.text(
+                Text::new(
+                    "center-value",
+                    PlotPoint::new(0.0, offset * 0.5),
+                    RichText::new(value).size(24.0).strong(),
+                )
+                .color(theme.palette.foreground)
+                .allow_hover(false),
+            );
+            plot_ui.text(
+                Text::new(
+                    "center-caption",
+                    PlotPoint::new(0.0, -offset),
+                    RichText::new(caption).size(12.0),
+                )
+                .color(theme.palette.muted_foreground)
+                .allow_hover(false),
+            );
+        }
+    }
+}
+
+/// Radar chart: one spoke per axis label, one closed polygon per series. Values are scaled so
+/// that `max` (the largest value by default) reaches radius 1.
+#[derive(Clone, Debug)]
+pub struct RadarChart {
+    axes: Vec<String>,
+    series: Vec<ChartSeries>,
+    max: Option<f64>,
+    grid_levels: usize,
+    fill_alpha: f32,
+}
+
+impl RadarChart {
+    pub fn new(axes: Vec<String>, series: Vec<ChartSeries>) -> Self {
+        Self {
+            axes,
+            series,
+            max: None,
+            grid_levels: 4,
+            fill_alpha: 0.6,
         }
     }
 
-    pub fn title(mut self, title: &'a str) -> Self {
-        self.title = Some(title);
+    pub fn max(mut self, max: f64) -> Self {
+        self.max = Some(max);
         self
     }
 
-    pub fn indicator(mut self, indicator: ChartIndicator) -> Self {
-        self.indicator = indicator;
+    pub fn grid_levels(mut self, levels: usize) -> Self {
+        self.grid_levels = levels;
         self
     }
 
-    pub fn hide_label(mut self, hide: bool) -> Self {
-        self.hide_label = hide;
+    pub fn fill_alpha(mut self, alpha: f32) -> Self {
+        self.fill_alpha = alpha;
         self
     }
 
-    pub fn hide_indicator(mut self, hide: bool) -> Self {
-        self.hide_indicator = hide;
+    fn scale(&self) -> f64 {
+        let max = self.max.unwrap_or_else(|| {
+            self.series
+                .iter()
+                .flat_map(|s| s.values.iter().copied())
+                .fold(0.0, f64::max)
+        });
+        if max > 0.0 { 1.0 / max } else { 0.0 }
+    }
+
+    fn fraction(&self, axis: usize) -> f64 {
+        axis as f64 / self.axes.len().max(1) as f64
+    }
+
+    /// Index of the axis nearest to a plot point.
+    pub fn axis_at(&self, point: PlotPoint) -> Option<usize> {
+        let count = self.axes.len();
+        let (fraction, radius) = polar_position(point);
+        if count == 0 || radius > 1.2 {
+            return None;
+        }
+        Some((fraction * count as f64).round() as usize % count)
+    }
+
+    pub fn legend_items(&self, theme: &Theme) -> Vec<ChartLegendItem> {
+        series_legend_items(&self.series, theme)
+    }
+
+    pub fn tooltip_items(&self, theme: &Theme, axis: usize) -> Vec<ChartTooltipItem> {
+        series_tooltip_items(&self.series, theme, axis, false)
+    }
+
+    pub fn show(&self, plot_ui: &mut PlotUi, theme: &Theme) {
+        let count = self.axes.len();
+        if count < 3 {
+            return;
+        }
+        let grid = Stroke::new(1.0, theme.palette.border);
+        for level in 1..=self.grid_levels {
+            let radius = level as f64 / self.grid_levels as f64;
+            let ring: Vec<[f64; 2]> = (0..=count)
+                .map(|axis| polar_point(self.fraction(axis), radius))
+                .collect();
+            plot_ui.line(Line::new("grid", ring).stroke(grid).allow_hover(false));
+        }
+        for (axis, label) in self.axes.iter().enumerate() {
+            let fraction = self.fraction(axis);
+            plot_ui.line(
+                Line::new("grid", vec![[0.0, 0.0], polar_point(fraction, 1.0)])
+                    .stroke(grid)
+                    .allow_hover(false),
+            );
+            let [x, y] = polar_point(fraction, 1.15);
+            plot_ui.text(
+                Text::new(
+                    "axis-label",
+                    PlotPoint::new(x, y),
+                    RichText::new(label).size(11.0),
+                )
+                .color(theme.palette.muted_foreground)
+                .allow_hover(false),
+            );
+        }
+
+        let scale = self.scale();
+        for (series_index, series) in self.series.iter().enumerate().rev() {
+            let color = series_color(series, theme, series_index);
+            let points: Vec<[f64; 2]> = (0..count)
+                .map(|axis| {
+                    let value = series.values.get(axis).copied().unwrap_or(0.0).max(0.0);
+                    polar_point(self.fraction(axis), value * scale)
+                })
+                .collect();
+            // The outline may be concave, so it is filled as triangles around the center.
+            let fill = color.gamma_multiply(self.fill_alpha);
+            for axis in 0..count {
+                let next = points[(axis + 1) % count];
+                plot_ui.polygon(
+                    Polygon::new(series.label.clone(), vec![[0.0, 0.0], points[axis], next])
+                        .fill_color(fill)
+                        .stroke(Stroke::new(0.0, color))
+                        .allow_hover(false),
+                );
+            }
+            let mut outline = points;
+            outline.push(outline[0]);
+            plot_ui.line(
+                Line::new(series.label.clone(), outline)
+                    .color(color)
+                    .width(2.0),
+            );
+        }
+    }
+}
+
+/// Concentric rings, one per slice from the inside out, each filled to `value / max` of a
+/// full turn over a muted track.
+#[derive(Clone, Debug)]
+pub struct RadialBarChart {
+    slices: Vec<ChartSlice>,
+    inner_radius: f64,
+    max: Option<f64>,
+    show_track: bool,
+}
+
+impl RadialBarChart {
+    pub fn new(slices: Vec<ChartSlice>) -> Self {
+        Self {
+            slices,
+            inner_radius: 0.3,
+            max: None,
+            show_track: true,
+        }
+    }
+
+    pub fn inner_radius(mut self, radius: f64) -> Self {
+        self.inner_radius = radius.clamp(0.0, 0.9);
         self
     }
 
-    pub fn show(self, ctx: &egui::Context, theme: &Theme, id: Id, pos: Pos2) {
-        let frame = Frame::NONE
-            .fill(theme.palette.popover)
-            .stroke(Stroke::new(1.0, theme.palette.border))
-            .corner_radius(theme.radius.r3)
-            .inner_margin(Margin::symmetric(10, 8));
-
-        egui::Area::new(id)
-            .fixed_pos(pos + vec2(12.0, 12.0))
-            .order(egui::Order::Tooltip)
-            .show(ctx, |ui| {
-                frame.show(ui, |tooltip_ui| {
-                    tooltip_ui.spacing_mut().item_spacing.y = 6.0;
-                    if let Some(title) = self.title
-                        && !self.hide_label
-                    {
-                        tooltip_ui.label(
-                            RichText::new(title)
-                                .size(12.0)
-                                .strong()
-                                .color(theme.palette.foreground),
-                        );
-                    }
+    pub fn max(mut self, max: f64) -> Self {
+        self.max = Some(max);
+        self
+    }
 
-                    for item in self.items {
-                        tooltip_ui.horizontal(|row| {
-                            row.spacing_mut().item_spacing.x = 8.0;
-                            if !self.hide_indicator {
-                                let indicator_size = match self.indicator {
-                                    ChartIndicator::Dot => vec2(8.0, 8.0),
-                                    ChartIndicator::Line | ChartIndicator::Dashed => {
-                                        vec2(6.0, 12.0)
-                                    }
-                                };
-                                let (rect, _) =
-                                    row.allocate_exact_size(indicator_size, egui::Sense::hover());
-                                let center_x = rect.center().x;
-                                let painter = row.painter();
-                                match self.indicator {
-                                    ChartIndicator::Dot => {
-                                        painter.rect_filled(rect, theme.radius.r2, item.color);
-                                    }
-                                    ChartIndicator::Line => {
-                                        let line_rect = rect.shrink2(vec2(2.0, 0.0));
-                                        painter.rect_filled(line_rect, theme.radius.r1, item.color);
-                                    }
-                                    ChartIndicator::Dashed => {
-                                        let top = rect.top() + 1.0;
-                                        let bottom = rect.bottom() - 1.0;
-                                        let mid = rect.center().y;
-                                        let stroke = Stroke::new(1.5, item.color);
-                                        painter.line_segment(
-                                            [pos2(center_x, top), pos2(center_x, mid - 1.0)],
-                                            stroke,
-                                        );
-                                        painter.line_segment(
-                                            [pos2(center_x, mid + 1.0), pos2(center_x, bottom)],
-                                            stroke,
-                                        );
-                                    }
-                                }
-                            }
-                            row.label(
-                                RichText::new(&item.label)
-                                    .size(11.0)
-                                    .color(theme.palette.muted_foreground),
-                            );
-                            row.allocate_ui_with_layout(
-                                row.available_size(),
-                                Layout::right_to_left(Align::Center),
-                                |right| {
-                                    right.label(
-                                        RichText::new(&item.value)
-                                            .size(11.0)
-                                            .font(FontId::monospace(11.0))
-                                            .strong()
-                                            .color(theme.palette.foreground),
-                                    );
-                                },
-                            );
-                        });
-                    }
-                });
-            });
+    pub fn show_track(mut self, show: bool) -> Self {
+        self.show_track = show;
+        self
+    }
+
+    /// Inner and outer radius of the ring at `index`.
+    fn ring(&self, index: usize) -> (f64, f64) {
+        let count = self.slices.len().max(1) as f64;
+        let band = (1.0 - self.inner_radius) / count;
+        let inner = self.inner_radius + band * index as f64;
+        (inner + band * 0.15, inner + band * 0.85)
+    }
+
+    /// Index of the ring under a plot point.
+    pub fn slice_at(&self, point: PlotPoint) -> Option<usize> {
+        let (_, radius) = polar_position(point);
+        (0..self.slices.len()).find(|index| {
+            let (inner, outer) = self.ring(*index);
+            radius >= inner && radius <= outer
+        })
+    }
+
+    pub fn legend_items(&self, theme: &Theme) -> Vec<ChartLegendItem> {
+        slice_legend_items(&self.slices, theme)
+    }
+
+    pub fn tooltip_item(&self, theme: &Theme, index: usize) -> Option<ChartTooltipItem> {
+        slice_tooltip_item(&self.slices, theme, index)
+    }
+
+    pub fn show(&self, plot_ui: &mut PlotUi, theme: &Theme) {
+        let max = self.max.unwrap_or_else(|| {
+            self.slices
+                .iter()
+                .map(|slice| slice.value)
+                .fold(0.0, f64::max)
+        });
+        for (index, slice) in self.slices.iter().enumerate() {
+            let ring = self.ring(index);
+            if self.show_track {
+                fill_sector(plot_ui, "track", (0.0, 1.0), ring, theme.palette.muted);
+            }
+            let fraction = if max > 0.0 {
+                (slice.value / max).clamp(0.0, 1.0)
+            } else {
+                0.0
+            };
+            if fraction > 0.0 {
+                fill_sector(
+                    plot_ui,
+                    &slice.label,
+                    (0.0, fraction),
+                    ring,
+                    slice_color(slice, theme, index),
+                );
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct ChartConfigItem {
+    pub label: String,
+
+    /// Shown instead of the color swatch in the legend.
+    pub icon: Option<Icon>,
+
+    /// Defaults to [`chart_color`] for the item's position in the config.
+    pub color: Option<Color32>,
+}
+
+impl ChartConfigItem {
+    pub fn new(label: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            icon: None,
+            color: None,
+        }
+    }
+
+    pub fn icon(mut self, icon: Icon) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+
+    pub fn color(mut self, color: Color32) -> Self {
+        self.color = Some(color);
+        self
+    }
+}
+
+/// Labels, icons and colors of a chart's series, keyed by series id, plus which series the
+/// user hid from the legend. Keep it in app state so toggles survive across frames.
+///
+/// # Example
+/// ```ignore
+/// let config = ChartConfig::new()
+///     .item("desktop", ChartConfigItem::new("Desktop"))
+///     .item("mobile", ChartConfigItem::new("Mobile").icon(Icon::Smartphone));
+/// let series = config.visible_series(theme, [("desktop", &desktop), ("mobile", &mobile)]);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ChartConfig {
+    items: Vec<(String, ChartConfigItem)>,
+    hidden: HashSet<String>,
+}
+
+impl ChartConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds or replaces the item for `key`. Items keep their insertion order.
+    pub fn item(mut self, key: impl Into<String>, item: ChartConfigItem) -> Self {
+        let key = key.into();
+        match self.items.iter_mut().find(|(k, _)| *k == key) {
+            Some((_, existing)) => *existing = item,
+            None => self.items.push((key, item)),
+        }
+        self
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &str> {
+        self.items.iter().map(|(key, _)| key.as_str())
+    }
+
+    pub fn get(&self, key: &str) -> Option<&ChartConfigItem> {
+        self.position(key).map(|index| &self.items[index].1)
+    }
+
+    fn position(&self, key: &str) -> Option<usize> {
+        self.items.iter().position(|(k, _)| k == key)
+    }
+
+    /// The configured label, or the key itself for unknown series.
+    pub fn label<'k>(&'k self, key: &'k str) -> &'k str {
+        self.get(key).map_or(key, |item| item.label.as_str())
+    }
+
+    pub fn color(&self, theme: &Theme, key: &str) -> Color32 {
+        let index = self.position(key).unwrap_or(self.items.len());
+        self.get(key)
+            .and_then(|item| item.color)
+            .unwrap_or_else(|| chart_color(theme, index))
+    }
+
+    pub fn is_hidden(&self, key: &str) -> bool {
+        self.hidden.contains(key)
+    }
+
+    pub fn set_hidden(&mut self, key: &str, hidden: bool) {
+        if hidden {
+            self.hidden.insert(key.to_string());
+        } else {
+            self.hidden.remove(key);
+        }
+    }
+
+    pub fn toggle(&mut self, key: &str) {
+        let hidden = self.is_hidden(key);
+        self.set_hidden(key, !hidden);
+    }
+
+    /// A labeled, colored series for `key`.
+    pub fn series(&self, theme: &Theme, key: &str, values: Vec<f64>) -> ChartSeries {
+        ChartSeries::new(self.label(key), values).color(self.color(theme, key))
+    }
+
+    /// Series for every visible key in config order; keys missing from `data` are skipped.
+    pub fn visible_series<K, V>(
+        &self,
+        theme: &Theme,
+        data: impl IntoIterator<Item = (K, V)>,
+    ) -> Vec<ChartSeries>
+    where
+        K: AsRef<str>,
+        V: AsRef<[f64]>,
+    {
+        self.visible_data(data)
+            .into_iter()
+            .map(|(key, values)| self.series(theme, key, values.as_ref().to_vec()))
+            .collect()
+    }
+
+    /// A [`LineChart`] for `key`, or `None` when the series is hidden.
+    pub fn line(&self, theme: &Theme, key: &str, points: Vec<[f64; 2]>) -> Option<LineChart> {
+        (!self.is_hidden(key)).then(|| {
+            LineChart::new(points)
+                .label(self.label(key))
+                .color(self.color(theme, key))
+        })
+    }
+
+    /// A [`BarChart`] for `key`, or `None` when the series is hidden.
+    pub fn bar(&self, theme: &Theme, key: &str, values: Vec<(f64, f64)>) -> Option<BarChart> {
+        (!self.is_hidden(key)).then(|| {
+            BarChart::new(values)
+                .label(self.label(key))
+                .color(self.color(theme, key))
+        })
+    }
+
+    pub fn legend_items(&self, theme: &Theme) -> Vec<ChartLegendItem> {
+        self.keys()
+            .map(|key| ChartLegendItem {
+                label: self.label(key).to_string(),
+                color: self.color(theme, key),
+            })
+            .collect()
+    }
+
+    /// One row per visible series with its value at `index`, for a shared tooltip.
+    pub fn tooltip_items<K, V>(
+        &self,
+        theme: &Theme,
+        index: usize,
+        data: impl IntoIterator<Item = (K, V)>,
+    ) -> Vec<ChartTooltipItem>
+    where
+        K: AsRef<str>,
+        V: AsRef<[f64]>,
+    {
+        self.visible_data(data)
+            .into_iter()
+            .filter_map(|(key, values)| {
+                let value = *values.as_ref().get(index)?;
+                Some(ChartTooltipItem {
+                    label: self.label(key).to_string(),
+                    value: format_chart_value(value),
+                    color: self.color(theme, key),
+                })
+            })
+            .collect()
+    }
+
+    fn visible_data<K, V>(&self, data: impl IntoIterator<Item = (K, V)>) -> Vec<(&str, V)>
+    where
+        K: AsRef<str>,
+    {
+        let mut data: Vec<(K, V)> = data.into_iter().collect();
+        self.keys()
+            .filter(|key| !self.is_hidden(key))
+            .filter_map(|key| {
+                let index = data.iter().position(|(k, _)| k.as_ref() == key)?;
+                Some((key, data.swap_remove(index).1))
+            })
+            .collect()
+    }
+
+    /// Legend whose entries hide or show their series when clicked. Returns the toggled key.
+    pub fn show_legend(&mut self, ui: &mut Ui, theme: &Theme) -> InnerResponse<Option<String>> {
+        ui.with_layout(Layout::left_to_right(Align::Center), |legend_ui| {
+            legend_ui.spacing_mut().item_spacing.x = 16.0;
+            let mut toggled = None;
+            for (key, item) in &self.items {
+                let color = self.color(theme, key);
+                let hidden = self.is_hidden(key);
+                let response =
+                    legend_entry(legend_ui, theme, &item.label, color, item.icon, hidden)
+                        .interact(egui::Sense::click())
+                        .on_hover_cursor(egui::CursorIcon::PointingHand);
+                if response.clicked() {
+                    toggled = Some(key.clone());
+                }
+            }
+            if let Some(key) = &toggled {
+                self.toggle(key);
+            }
+            toggled
+        })
     }
 }
@@ -0,0 +1,229 @@
+//! Chart building blocks that need no plotting backend: series colors, value formatting,
+//! legends and tooltips. Shared by [`crate::mini_chart`] and, with the `plot` feature, by
+//! `crate::chart`.
+
+use crate::theme::Theme;
+use egui::{
+    Align, Color32, FontId, Frame, Id, InnerResponse, Layout, Margin, Pos2, RichText, Stroke, Ui,
+    pos2, vec2,
+};
+use lucide_icons::Icon;
+
+/// Default color for the series at `index`, cycling through `chart_1..chart_5`.
+pub fn chart_color(theme: &Theme, index: usize) -> Color32 {
+    let palette = &theme.palette;
+    [
+        palette.chart_1,
+        palette.chart_2,
+        palette.chart_3,
+        palette.chart_4,
+        palette.chart_5,
+    ][index % 5]
+}
+
+/// Formats a value the way tooltips show it: whole numbers without decimals.
+pub fn format_chart_value(value: f64) -> String {
+    if value.fract() == 0.0 {
+        format!("{value:.0}")
+    } else {
+        format!("{value:.2}")
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct ChartLegendItem {
+    pub label: String,
+    pub color: Color32,
+}
+
+pub struct ChartLegend<'a> {
+    items: &'a [ChartLegendItem],
+}
+
+impl<'a> ChartLegend<'a> {
+    pub fn new(items: &'a [ChartLegendItem]) -> Self {
+        Self { items }
+    }
+
+    pub fn show(self, ui: &mut Ui, theme: &Theme) -> InnerResponse<()> {
+        ui.with_layout(Layout::left_to_right(Align::Center), |legend_ui| {
+            legend_ui.spacing_mut().item_spacing.x = 16.0;
+            for item in self.items {
+                legend_entry(legend_ui, theme, &item.label, item.color, None, false);
+            }
+        })
+    }
+}
+
+/// Paints one legend entry: a color swatch (or the icon, in the series color) and the label.
+pub(crate) fn legend_entry(
+    ui: &mut Ui,
+    theme: &Theme,
+    label: &str,
+    color: Color32,
+    icon: Option<Icon>,
+    hidden: bool,
+) -> egui::Response {
+    let opacity = if hidden { 0.4 } else { 1.0 };
+    ui.horizontal(|row| {
+        row.set_opacity(opacity);
+        row.spacing_mut().item_spacing.x = 6.0;
+        if let Some(icon) = icon {
+            row.add(
+                egui::Label::new(RichText::new(icon.unicode()).size(12.0).color(color))
+                    .selectable(false),
+            );
+        } else {
+            let (rect, _) = row.allocate_exact_size(vec2(8.0, 8.0), egui::Sense::hover());
+            row.painter().rect_filled(rect, theme.radius.r2, color);
+        }
+        let mut text = RichText::new(label)
+            .size(12.0)
+            .color(theme.palette.muted_foreground);
+        if hidden {
+            text = text.strikethrough();
+        }
+        row.add(egui::Label::new(text).selectable(false));
+    })
+    .response
+}
+
+#[derive(Clone, Debug)]
+pub struct ChartTooltipItem {
+    pub label: String,
+    pub value: String,
+    pub color: Color32,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ChartIndicator {
+    Dot,
+    Line,
+    Dashed,
+}
+
+pub struct ChartTooltip<'a> {
+    title: Option<&'a str>,
+    items: &'a [ChartTooltipItem],
+    indicator: ChartIndicator,
+    hide_label: bool,
+    hide_indicator: bool,
+}
+
+impl<'a> ChartTooltip<'a> {
+    pub fn new(items: &'a [ChartTooltipItem]) -> Self {
+        Self {
+            title: None,
+            items,
+            indicator: ChartIndicator::Dot,
+            hide_label: false,
+            hide_indicator: false,
+        }
+    }
+
+    pub fn title(mut self, title: &'a str) -> Self {
+        self.title = Some(title);
+        self
+    }
+
+    pub fn indicator(mut self, indicator: ChartIndicator) -> Self {
+        self.indicator = indicator;
+        self
+    }
+
+    pub fn hide_label(mut self, hide: bool) -> Self {
+        self.hide_label = hide;
+        self
+    }
+
+    pub fn hide_indicator(mut self, hide: bool) -> Self {
+        self.hide_indicator = hide;
+        self
+    }
+
+    pub fn show(self, ctx: &egui::Context, theme: &Theme, id: Id, pos: Pos2) {
+        let frame = Frame::NONE
+            .fill(theme.palette.popover)
+            .stroke(Stroke::new(1.0, theme.palette.border))
+            .corner_radius(theme.radius.r3)
+            .inner_margin(Margin::symmetric(10, 8));
+
+        egui::Area::new(id)
+            .fixed_pos(pos + vec2(12.0, 12.0))
+            .order(egui::Order::Tooltip)
+            .show(ctx, |ui| {
+                frame.show(ui, |tooltip_ui| {
+                    tooltip_ui.spacing_mut().item_spacing.y = 6.0;
+                    if let Some(title) = self.title
+                        && !self.hide_label
+                    {
+                        tooltip_ui.label(
+                            RichText::new(title)
+                                .size(12.0)
+                                .strong()
+                                .color(theme.palette.foreground),
+                        );
+                    }
+
+                    for item in self.items {
+                        tooltip_ui.horizontal(|row| {
+                            row.spacing_mut().item_spacing.x = 8.0;
+                            if !self.hide_indicator {
+                                let indicator_size = match self.indicator {
+                                    ChartIndicator::Dot => vec2(8.0, 8.0),
+                                    ChartIndicator::Line | ChartIndicator::Dashed => {
+                                        vec2(6.0, 12.0)
+                                    }
+                                };
+                                let (rect, _) =
+                                    row.allocate_exact_size(indicator_size, egui::Sense::hover());
+                                let center_x = rect.center().x;
+                                let painter = row.painter();
+                                match self.indicator {
+                                    ChartIndicator::Dot => {
+                                        painter.rect_filled(rect, theme.radius.r2, item.color);
+                                    }
+                                    ChartIndicator::Line => {
+                                        let line_rect = rect.shrink2(vec2(2.0, 0.0));
+                                        painter.rect_filled(line_rect, theme.radius.r1, item.color);
+                                    }
+                                    ChartIndicator::Dashed => {
+                                        let top = rect.top() + 1.0;
+                                        let bottom = rect.bottom() - 1.0;
+                                        let mid = rect.center().y;
+                                        let stroke = Stroke::new(1.5, item.color);
+                                        painter.line_segment(
+                                            [pos2(center_x, top), pos2(center_x, mid - 1.0)],
+                                            stroke,
+                                        );
+                                        painter.line_segment(
+                                            [pos2(center_x, mid + 1.0), pos2(center_x, bottom)],
+                                            stroke,
+                                        );
+                                    }
+                                }
+                            }
+                            row.label(
+                                RichText::new(&item.label)
+                                    .size(11.0)
+                                    .color(theme.palette.muted_foreground),
+                            );
+                            row.allocate_ui_with_layout(
+                                row.available_size(),
+                                Layout::right_to_left(Align::Center),
+                                |right| {
+                                    right.label(
+                                        RichText::new(&item.value)
+                                            .size(11.0)
+                                            .font(FontId::monospace(11.0))
+                                            .strong()
+                                            .color(theme.palette.foreground),
+                                    );
+                                },
+                            );
+                        });
+                    }
+                });
+            });
+    }
+}
@@ -159,6 +159,7 @@ impl<'a, Id: Hash + Debug> ComboboxProps<'a, Id> {
     }
 }
 
+#[allow(clippy::collapsible_match)]
 fn get_selected_label(items: &[SelectItem], value: &Option<String>) -> Option<String> {
     if let Some(val) = value {
         for item in items {
@@ -184,7 +185,7 @@ fn get_selected_label(items: &[SelectItem], value: &Option<String>) -> Option<St
     None
 }
 
-fn filter_items(items: &[SelectItem], search: &str) -> Vec<SelectItem> {
+pub(crate) fn filter_items(items: &[SelectItem], search: &str) -> Vec<SelectItem> {
     if search.is_empty() {
         return items.to_vec();
     }
@@ -556,6 +557,249 @@ where
     final_response
 }
 
+/// Inputs for the shared option list rendered inside the combobox popover.
+pub(crate) struct ComboboxList<'a> {
+    pub items: &'a [SelectItem],
+    pub search: &'a str,
+    pub selected: Option<&'a str>,
+    pub width: f32,
+    pub size: ComboboxSize,
+    pub empty_text: &'a str,
+    pub active_index_id: egui::Id,
+    /// Move the highlight to the selected item.
+    pub focus_selected: bool,
+    /// Choose the highlighted item, e.g. because Enter was pressed.
+    pub confirm: bool,
+}
+
+/// Renders filtered options with arrow-key highlighting and returns the value that was chosen.
+pub(crate) fn combobox_list(ui: &mut Ui, theme: &Theme, list: ComboboxList<'_>) -> Option<String> {
+    let width = list.width;
+    let input_size: InputSize = list.size.into();
+    let select_size: SelectSize = list.size.into();
+
+    let filtered_items = filter_items(list.items, list.search);
+    let mut flat = Vec::new();
+    flatten_options(&filtered_items, &mut flat);
+    let enabled: Vec<&FlatOption> = flat.iter().filter(|opt| !opt.disabled).collect();
+
+    let mut active = ui.ctx().memory_mut(|m| {
+        m.data
+            .get_persisted::<usize>(list.active_index_id)
+            .unwrap_or(0)
+    });
+    if active >= enabled.len() {
+        active = enabled.len().saturating_sub(1);
+    }
+
+    if list.focus_selected
+        && let Some(current) = list.selected
+        && let Some(pos) = enabled.iter().position(|opt| opt.value == current)
+    {
+        active = pos;
+    }
+
+    ui.ctx().input_mut(|i| {
+        if i.consume_key(Modifiers::NONE, Key::ArrowDown) && !enabled.is_empty() {
+            active = (active + 1).min(enabled.len() - 1);
+        }
+        if i.consume_key(Modifiers::NONE, Key::ArrowUp) && !enabled.is_empty() {
+            active = active.saturating_sub(1);
+        }
+    });
+
+    let mut chosen: Option<String> = None;
+
+    if enabled.is_empty() {
+        ui.label(
+            RichText::new(list.empty_text)
+                .color(theme.palette.muted_foreground)
+                .size(input_size.font_size()),
+        );
+    } else {
+        let item_height = select_size.item_height();
+        let padding_left = 10.0;
+        let right_gutter = 18.0;
+
+        ScrollArea::vertical().max_height(260.0).show(ui, |ui| {
+            ui.set_width(width);
+            let mut enabled_pos = 0usize;
+
+            for item in &filtered_items {
+                match item {
+                    SelectItem::Label(text) => {
+                        ui.label(
+                            RichText::new(text.clone())
+                                .color(theme.palette.muted_foreground)
+                                .size(select_size.font_size() * 0.9),
+                        );
+                    }
+                    SelectItem::Separator => {
+                        let available = ui.available_rect_before_wrap();
+                        let cursor = ui.cursor();
+                        let sep_rect = Rect::from_min_size(
+                            pos2(available.left(), cursor.max.y),
+                            vec2(width, 1.0),
+                        );
+                        ui.painter().rect_filled(
+                            sep_rect,
+                            CornerRadius::same(0),
+                            theme.palette.border,
+                        );
+                        ui.allocate_space(vec2(width, 8.0));
+                    }
+                    SelectItem::Group { label, items } => {
+                        ui.label(
+                            RichText::new(label.clone())
+                                .color(theme.palette.muted_foreground)
+                                .size(select_size.font_size() * 0.9),
+                        );
+                        for nested in items {
+                            if let SelectItem::Option {
+                                value,
+                                label,
+                                disabled,
+                                ..
+                            } = nested
+                            {
+                                let (_id, item_rect) = ui.allocate_space(vec2(width, item_height));
+                                let resp = ui.allocate_response(item_rect.size(), Sense::click());
+
+                                let mut is_active = false;
+                                if !*disabled {
+                                    is_active = enabled_pos == active;
+                                    enabled_pos += 1;
+                                }
+
+                                let is_hovered = item_rect.contains(
+                                    ui.input(|i| i.pointer.hover_pos().unwrap_or_default()),
+                                );
+                                let is_selected = list.selected == Some(value.as_str());
+
+                                let bg_color = if *disabled {
+                                    Color32::TRANSPARENT
+                                } else if is_active || is_hovered {
+                                    theme.palette.muted
+                                } else {
+                                    Color32::TRANSPARENT
+                                };
+
+                                let text_color = if *disabled {
+                                    theme.palette.muted_foreground
+                                } else {
+                                    theme.palette.foreground
+                                };
+
+                                let painter = ui.painter();
+                                painter.rect_filled(item_rect, CornerRadius::same(6), bg_color);
+
+                                let galley = painter.layout_no_wrap(
+                                    label.clone(),
+                                    FontId::proportional(select_size.font_size()),
+                                    text_color,
+                                );
+                                let text_pos = pos2(
+                                    item_rect.left() + padding_left,
+                                    item_rect.center().y - galley.size().y * 0.5,
+                                );
+                                painter.galley(text_pos, galley, Color32::TRANSPARENT);
+
+                                if is_selected {
+                                    let icon_center = pos2(
+                                        item_rect.right() - right_gutter,
+                                        item_rect.center().y,
+                                    );
+                                    icon_check(
+                                        painter,
+                                        icon_center,
+                                        select_size.font_size() * 1.1,
+                                        theme.palette.foreground,
+                                    );
+                                }
+
+                                if resp.clicked() && !*disabled {
+                                    chosen = Some(value.clone());
+                                }
+                            }
+                        }
+                    }
+                    SelectItem::Option {
+                        value,
+                        label,
+                        disabled,
+                        ..
+                    } => {
+                        let (_id, item_rect) = ui.allocate_space(vec2(width, item_height));
+                        let resp = ui.allocate_response(item_rect.size(), Sense::click());
+
+                        let mut is_active = false;
+                        if !*disabled {
+                            is_active = enabled_pos == active;
+                            enabled_pos += 1;
+                        }
+
+                        let is_hovered = item_rect
+                            .contains(ui.input(|i| i.pointer.hover_pos().unwrap_or_default()));
+                        let is_selected = list.selected == Some(value.as_str());
+
+                        let bg_color = if *disabled {
+                            Color32::TRANSPARENT
+                        } else if is_active || is_hovered {
+                            theme.palette.muted
+                        } else {
+                            Color32::TRANSPARENT
+                        };
+
+                        let text_color = if *disabled {
+                            theme.palette.muted_foreground
+                        } else {
+                            theme.palette.foreground
+                        };
+
+                        let painter = ui.painter();
+                        painter.rect_filled(item_rect, CornerRadius::same(6), bg_color);
+
+                        let galley = painter.layout_no_wrap(
+                            label.clone(),
+                            FontId::proportional(select_size.font_size()),
+                            text_color,
+                        );
+                        let text_pos = pos2(
+                            item_rect.left() + padding_left,
+                            item_rect.center().y - galley.size().y * 0.5,
+                        );
+                        painter.galley(text_pos, galley, Color32::TRANSPARENT);
+
+                        if is_selected {
+                            let icon_center =
+                                pos2(item_rect.right() - right_gutter, item_rect.center().y);
+                            icon_check(
+                                painter,
+                                icon_center,
+                                select_size.font_size() * 1.1,
+                                theme.palette.foreground,
+                            );
+                        }
+
+                        if resp.clicked() && !*disabled {
+                            chosen = Some(value.clone());
+                        }
+                    }
+                }
+            }
+        });
+    }
+
+    if list.confirm && !enabled.is_empty() {
+        chosen = Some(enabled[active].value.clone());
+    }
+
+    ui.ctx()
+        .memory_mut(|m| m.data.insert_persisted(list.active_index_id, active));
+
+    chosen
+}
+
 pub fn combobox_with_props<Id>(
     ui: &mut Ui,
     theme: &Theme,
@@ -632,7 +876,6 @@ where
             ui.spacing_mut().item_spacing.y = 8.0;
 
             let input_size: InputSize = props.size.into();
-            let select_size: SelectSize = props.size.into();
             let search_id = id.with("search");
 
             let _ = text_input_with_props(
@@ -652,238 +895,24 @@ where
                 focus_applied = true;
             }
 
-            let filtered_items = filter_items(props.items, props.search_value);
-            let mut flat = Vec::new();
-            flatten_options(&filtered_items, &mut flat);
-            let enabled: Vec<&FlatOption> = flat.iter().filter(|opt| !opt.disabled).collect();
-
-            let mut active = ui
+            let confirm = ui
                 .ctx()
-                .memory_mut(|m| m.data.get_persisted::<usize>(active_index_id).unwrap_or(0));
-            if active >= enabled.len() {
-                active = enabled.len().saturating_sub(1);
-            }
-
-            if just_opened
-                && let Some(current) = props.value.as_deref()
-                && let Some(pos) = enabled.iter().position(|opt| opt.value == current)
-            {
-                active = pos;
-            }
-
-            ui.ctx().input_mut(|i| {
-                if i.consume_key(Modifiers::NONE, Key::ArrowDown) && !enabled.is_empty() {
-                    active = (active + 1).min(enabled.len() - 1);
-                }
-                if i.consume_key(Modifiers::NONE, Key::ArrowUp) && !enabled.is_empty() {
-                    active = active.saturating_sub(1);
-                }
-            });
-
-            let mut enter_pressed = false;
-            ui.ctx()
-                .input_mut(|i| enter_pressed = i.consume_key(Modifiers::NONE, Key::Enter));
-
-            let mut chosen: Option<String> = None;
-
-            if enabled.is_empty() {
-                ui.label(
-                    RichText::new(props.empty_text)
-                        .color(theme.palette.muted_foreground)
-                        .size(input_size.font_size()),
-                );
-            } else {
-                let item_height = select_size.item_height();
-                let padding_left = 10.0;
-                let right_gutter = 18.0;
-
-                ScrollArea::vertical().max_height(260.0).show(ui, |ui| {
-                    ui.set_width(width);
-                    let mut enabled_pos = 0usize;
-
-                    for item in &filtered_items {
-                        match item {
-                            SelectItem::Label(text) => {
-                                ui.label(
-                                    RichText::new(text.clone())
-                                        .color(theme.palette.muted_foreground)
-                                        .size(select_size.font_size() * 0.9),
-                                );
-                            }
-                            SelectItem::Separator => {
-                                let available = ui.available_rect_before_wrap();
-                                let cursor = ui.cursor();
-                                let sep_rect = Rect::from_min_size(
-                                    pos2(available.left(), cursor.max.y),
-                                    vec2(width, 1.0),
-                                );
-                                ui.painter().rect_filled(
-                                    sep_rect,
-                                    CornerRadius::same(0),
-                                    theme.palette.border,
-                                );
-                                ui.allocate_space(vec2(width, 8.0));
-                            }
-                            SelectItem::Group { label, items } => {
-                                ui.label(
-                                    RichText::new(label.clone())
-                                        .color(theme.palette.muted_foreground)
-                                        .size(select_size.font_size() * 0.9),
-                                );
-                                for nested in items {
-                                    if let SelectItem::Option {
-                                        value,
-                                        label,
-                                        disabled,
-                                        ..
-                                    } = nested
-                                    {
-                                        let (_id, item_rect) =
-                                            ui.allocate_space(vec2(width, item_height));
-                                        let resp =
-                                            ui.allocate_response(item_rect.size(), Sense::click());
-
-                                        let mut is_active = false;
-                                        if !*disabled {
-                                            is_active = enabled_pos == active;
-                                            enabled_pos += 1;
-                                        }
-
-                                        let is_hovered =
-                                            item_rect.contains(ui.input(|i| {
-                                                i.pointer.hover_pos().unwrap_or_default()
-                                            }));
-                                        let is_selected = props.value.as_deref() == Some(value);
-
-                                        let bg_color = if *disabled {
-                                            Color32::TRANSPARENT
-                                        } else if is_active || is_hovered {
-                                            theme.palette.muted
-                                        } else {
-                                            Color32::TRANSPARENT
-                                        };
-
-                                        let text_color = if *disabled {
-                                            theme.palette.muted_foreground
-                                        } else {
-                                            theme.palette.foreground
-                                        };
-
-                                        let painter = ui.painter();
-                                        painter.rect_filled(
-                                            item_rect,
-                                            CornerRadius::same(6),
-                                            bg_color,
-                                        );
-
-                                        let galley = painter.layout_no_wrap(
-                                            label.clone(),
-                                            FontId::proportional(select_size.font_size()),
-                                            text_color,
-                                        );
-                                        let text_pos = pos2(
-                                            item_rect.left() + padding_left,
-                                            item_rect.center().y - galley.size().y * 0.5,
-                                        );
-                                        painter.galley(text_pos, galley, Color32::TRANSPARENT);
-
-                                        if is_selected {
-                                            let icon_center = pos2(
-                                                item_rect.right() - right_gutter,
-                                                item_rect.center().y,
-                                            );
-                                            icon_check(
-                                                painter,
-                                                icon_center,
-                                                select_size.font_size() * 1.1,
-                                                theme.palette.foreground,
-                                            );
-                                        }
-
-                                        if resp.clicked() && !*disabled {
-                                            chosen = Some(value.clone());
-                                        }
-                                    }
-                                }
-                            }
-                            SelectItem::Option {
-                                value,
-                                label,
-                                disabled,
-                                ..
-                            } => {
-                                let (_id, item_rect) = ui.allocate_space(vec2(width, item_height));
-                                let resp = ui.allocate_response(item_rect.size(), Sense::click());
-
-                                let mut is_active = false;
-                                if !*disabled {
-                                    is_active = enabled_pos == active;
-                                    enabled_pos += 1;
-                                }
-
-                                let is_hovered = item_rect.contains(
-                                    ui.input(|i| i.pointer.hover_pos().unwrap_or_default()),
-                                );
-                                let is_selected = props.value.as_deref() == Some(value);
-
-                                let bg_color = if *disabled {
-                                    Color32::TRANSPARENT
-                                } else if is_active || is_hovered {
-                                    theme.palette.muted
-                                } else {
-                                    Color32::TRANSPARENT
-                                };
-
-                                let text_color = if *disabled {
-                                    theme.palette.muted_foreground
-                                } else {
-                                    theme.palette.foreground
-                                };
-
-                                let painter = ui.painter();
-                                painter.rect_filled(item_rect, CornerRadius::same(6), bg_color);
-
-                                let galley = painter.layout_no_wrap(
-                                    label.clone(),
-                                    FontId::proportional(select_size.font_size()),
-                                    text_color,
-                                );
-                                let text_pos = pos2(
-                                    item_rect.left() + padding_left,
-                                    item_rect.center().y - galley.size().y * 0.5,
-                                );
-                                painter.galley(text_pos, galley, Color32::TRANSPARENT);
-
-                                if is_selected {
-                                    let icon_center = pos2(
-                                        item_rect.right() - right_gutter,
-                                        item_rect.center().y,
-                                    );
-                                    icon_check(
-                                        painter,
-                                        icon_center,
-                                        select_size.font_size() * 1.1,
-                                        theme.palette.foreground,
-                                    );
-                                }
-
-                                if resp.clicked() && !*disabled {
-                                    chosen = Some(value.clone());
-                                }
-                            }
-                        }
-                    }
-                });
-            }
-
-            if enter_pressed && !enabled.is_empty() {
-                chosen = Some(enabled[active].value.clone());
-            }
-
-            ui.ctx()
-                .memory_mut(|m| m.data.insert_persisted(active_index_id, active));
-
-            chosen
+                .input_mut(|i| i.consume_key(Modifiers::NONE, Key::Enter));
+            combobox_list(
+                ui,
+                theme,
+                ComboboxList {
+                    items: props.items,
+                    search: props.search_value,
+                    selected: props.value.as_deref(),
+                    width,
+                    size: props.size,
+                    empty_text: props.empty_text,
+                    active_index_id,
+                    focus_selected: just_opened,
+                    confirm,
+                },
+            )
         },
     );
 
@@ -25,12 +25,25 @@ impl<'a> Debug for OnCommandSelect<'a> {
     }
 }
 
+/// Key presses forwarded by a widget that owns the keyboard, such as a textarea showing the
+/// list as suggestions.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CommandNavigation {
+    pub up: bool,
+    pub down: bool,
+    pub enter: bool,
+}
+
 #[derive(Debug)]
 pub struct CommandProps {
     pub id_source: Id,
     pub min_width: Option<f32>,
     pub show_border: bool,
     pub show_shadow: bool,
+    /// Filters items by this text instead of the `command_input` value.
+    pub query: Option<String>,
+    /// Replaces the arrow and Enter keys read from input.
+    pub navigation: Option<CommandNavigation>,
 }
 
 impl CommandProps {
@@ -40,6 +53,8 @@ impl CommandProps {
             min_width: None,
             show_border: true,
             show_shadow: true,
+            query: None,
+            navigation: None,
         }
     }
 
@@ -57,6 +72,16 @@ impl CommandProps {
         self.show_shadow = show;
         self
     }
+
+    pub fn query(mut self, query: impl Into<String>) -> Self {
+        self.query = Some(query.into());
+        self
+    }
+
+    pub fn navigation(mut self, navigation: CommandNavigation) -> Self {
+        self.navigation = Some(navigation);
+        self
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -224,12 +249,19 @@ pub fn command<R>(
         .data(|data| data.get_temp::<CommandState>(state_id))
         .unwrap_or_default();
 
-    let (up, down, enter) = ui.input(|i| {
-        (
-            i.key_pressed(Key::ArrowUp),
-            i.key_pressed(Key::ArrowDown),
-            i.key_pressed(Key::Enter),
-        )
+    if let Some(query) = props.query
+        && query != state.query
+    {
+        state.query = query;
+        state.selected_index = 0;
+    }
+
+    let CommandNavigation { up, down, enter } = props.navigation.unwrap_or_else(|| {
+        ui.input(|i| CommandNavigation {
+            up: i.key_pressed(Key::ArrowUp),
+            down: i.key_pressed(Key::ArrowDown),
+            enter: i.key_pressed(Key::Enter),
+        })
     });
 
     if state.selectable_count > 0 {
@@ -504,7 +536,7 @@ pub fn command_shortcut(ui: &mut Ui, ctx: &CommandContext, text: &str) -> Respon
     )
 }
 
-fn command_matches(query: &str, label: &str, keywords: &[String]) -> bool {
+pub(crate) fn command_matches(query: &str, label: &str, keywords: &[String]) -> bool {
     if query.is_empty() {
         return true;
     }
@@ -3,6 +3,7 @@
 //! Provides a context menu that appears on secondary (right) click.
 //! Built on top of egui's response.context_menu() with shadcn styling.
 
+use crate::overlay::{OverlayKind, OverlayLayer, register_overlay};
 use crate::theme::Theme;
 use egui::{Response, Ui, Vec2};
 
@@ -43,11 +44,21 @@ pub use crate::menu_primitives::{
 pub fn context_menu(response: &Response, theme: &Theme, add_contents: impl FnOnce(&mut Ui)) {
     let tokens = context_menu_tokens(theme);
 
-    response.context_menu(|ui| {
+    let inner = response.context_menu(|ui| {
         ui.visuals_mut().override_text_color = Some(tokens.text);
         ui.spacing_mut().item_spacing = Vec2::new(0.0, 2.0);
         ui.set_min_width(tokens.min_width);
 
         add_contents(ui);
     });
+    if let Some(inner) = inner {
+        register_overlay(
+            &response.ctx,
+            OverlayLayer::new(
+                response.id.with("context-menu"),
+                OverlayKind::Menu,
+                inner.response.layer_id,
+            ),
+        );
+    }
 }
@@ -8,9 +8,11 @@ use crate::dropdown_menu::{
 };
 use crate::input::Input;
 use crate::pagination::{
-    PaginationLinkProps, PaginationProps, pagination, pagination_content, pagination_ellipsis,
-    pagination_item, pagination_link, pagination_next, pagination_previous,
+    PageItem, PaginationLinkProps, PaginationProps, pagination, pagination_content,
+    pagination_ellipsis, pagination_item, pagination_items, pagination_link, pagination_next,
+    pagination_previous,
 };
+use crate::sortable::{SortableMove, SortableProps, sortable};
 use crate::table::{
     TableCellProps, TableProps, TableRowProps, table, table_body, table_cell, table_head,
     table_header, table_row,
@@ -140,6 +142,9 @@ pub struct DataTableProps<'a, T> {
     pub filter_fn: Option<Box<dyn Fn(&T, &str) -> bool + 'a>>,
     pub enable_selection: bool,
     pub show_column_toggle: bool,
+
+    /// Lets rows be dragged to a new position while the table is neither sorted nor filtered.
+    pub reorderable: bool,
 }
 
 impl<'a, T> DataTableProps<'a, T> {
@@ -153,6 +158,7 @@ impl<'a, T> DataTableProps<'a, T> {
             filter_fn: None,
             enable_selection: true,
             show_column_toggle: true,
+            reorderable: false,
         }
     }
 
@@ -180,6 +186,11 @@ impl<'a, T> DataTableProps<'a, T> {
         self.show_column_toggle = show;
         self
     }
+
+    pub fn reorderable(mut self, reorderable: bool) -> Self {
+        self.reorderable = reorderable;
+        self
+    }
 }
 
 #[derive(Clone, Debug, Default)]
@@ -199,46 +210,10 @@ pub struct DataTableResponse {
     pub total_rows: usize,
     pub page: usize,
     pub page_count: usize,
-}
 
-enum PageItem {
-    Page(usize),
-    Ellipsis,
-}
-
-fn pagination_items(current: usize, total: usize) -> Vec<PageItem> {
-    if total <= 7 {
-        return (1..=total).map(PageItem::Page).collect();
-    }
-
-    let mut items = Vec::new();
-    items.push(PageItem::Page(1));
-
-    let mut start = current.saturating_sub(1).max(2);
-    let mut end = (current + 1).min(total.saturating_sub(1));
-
-    if current <= 3 {
-        start = 2;
-        end = 4;
-    } else if current >= total.saturating_sub(2) {
-        start = total.saturating_sub(3);
-        end = total.saturating_sub(1);
-    }
-
-    if start > 2 {
-        items.push(PageItem::Ellipsis);
-    }
-
-    for page in start..=end {
-        items.push(PageItem::Page(page));
-    }
-
-    if end < total.saturating_sub(1) {
-        items.push(PageItem::Ellipsis);
-    }
-
-    items.push(PageItem::Page(total));
-    items
+    /// A row dropped at a new position, in indices into `data`. Apply it with
+    /// [`reorder_items`](crate::reorder_items); the selection already follows it.
+    pub moved: Option<SortableMove>,
 }
 
 pub fn data_table<'a, T>(
@@ -386,6 +361,10 @@ where
     } else {
         Vec::new()
     };
+    // Row positions only mean something in the data's own order.
+    let reorderable =
+        props.reorderable && state.sort.is_none() && indices.len() == props.data.len();
+    let mut moved: Option<SortableMove> = None;
 
     table(ui, theme, TableProps::new(), |ui, ctx| {
         table_header(ui, ctx, |ui| {
@@ -516,11 +495,11 @@ where
                 return;
             }
 
-            for index in page_indices.iter().copied() {
+            let mut show_row = |row_ui: &mut Ui, index: usize| {
                 let row = &props.data[index];
                 let is_selected = state.selected.contains(&index);
                 table_row(
-                    ui,
+                    row_ui,
                     ctx,
                     TableRowProps::new(index).selected(is_selected),
                     |row_ui| {
@@ -575,6 +554,23 @@ where
                         }
                     },
                 );
+            };
+            if reorderable {
+                let sortable_props = SortableProps::new(state_id.with("rows"), page_indices.len())
+                    .spacing(ui.spacing().item_spacing.y)
+                    .card(false);
+                let response = sortable(ui, theme, sortable_props, |row_ui, item| {
+                    show_row(row_ui, page_indices[item.index]);
+                });
+                moved = response.moved.map(|mut moved| {
+                    moved.from.index += start;
+                    moved.to.index += start;
+                    moved
+                });
+            } else {
+                for index in page_indices.iter().copied() {
+                    show_row(ui, index);
+                }
             }
         });
     });
@@ -587,7 +583,7 @@ where
             |ui, props| {
                 pagination_content(ui, |ui| {
                     pagination_item(ui, |ui| pagination_previous(ui, theme, props));
-                    for item in pagination_items(*props.current_page, total_pages) {
+                    for item in pagination_items(*props.current_page, total_pages, 1, 1) {
                         match item {
                             PageItem::Page(page) => {
                                 pagination_item(ui, |ui| {
@@ -611,6 +607,14 @@ where
         );
     }
 
+    if let Some(moved) = moved {
+        state.selected = state
+            .selected
+            .iter()
+            .map(|index| moved_index(*index, moved.from.index, moved.to.index))
+            .collect();
+    }
+
     ui.ctx()
         .data_mut(|data| data.insert_temp(state_id, state.clone()));
 
@@ -620,5 +624,15 @@ where
         total_rows: props.data.len(),
         page: state.page,
         page_count: total_pages,
+        moved,
+    }
+}
+
+/// Where `index` ends up after the row at `from` is moved to `to`.
+fn moved_index(index: usize, from: usize, to: usize) -> usize {
+    if index == from {
+        return to;
     }
+    let index = if index > from { index - 1 } else { index };
+    if index >= to { index + 1 } else { index }
 }
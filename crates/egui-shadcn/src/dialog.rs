@@ -1,8 +1,11 @@
+use crate::overlay::{
+    OverlayKind, OverlayLayer, is_topmost_overlay, overlay_accepts_outside_click, register_overlay,
+};
 use crate::scroll_area::{ScrollAreaProps, ScrollDirection, scroll_area};
 use crate::theme::Theme;
 use egui::{
-    Align2, Color32, CornerRadius, FontId, Frame, Id, LayerId, Margin, Order, Rect, Sense, Stroke,
-    StrokeKind, Ui, Vec2, pos2, vec2,
+    Align2, Color32, Context, CornerRadius, CursorIcon, FocusDirection, FontId, Frame, Id, LayerId,
+    Margin, Order, Pos2, Rect, Sense, Stroke, StrokeKind, Ui, Vec2, pos2, vec2,
 };
 use log::trace;
 use lucide_icons::Icon;
@@ -100,7 +103,57 @@ pub fn dialog_tokens_with_options(
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct DialogPreventable {
+    default_prevented: bool,
+}
+
+impl DialogPreventable {
+    pub fn prevent_default(&mut self) {
+        self.default_prevented = true;
+    }
+
+    pub fn default_prevented(&self) -> bool {
+        self.default_prevented
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DialogAutoFocusEvent {
+    pub preventable: DialogPreventable,
+}
+
+const RESIZE_HANDLE_THICKNESS: f32 = 8.0;
+const MIN_RESIZE_SIZE: Vec2 = vec2(240.0, 120.0);
+
+/// User-applied position and size of a draggable or resizable dialog, kept in egui memory
+/// under the dialog's `id_source` so it survives closing and reopening.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct DialogGeometry {
+    /// Added to [`DialogProps::offset`].
+    pub offset: Vec2,
+    /// Content size chosen by resizing; overrides the width and height props.
+    pub size: Option<Vec2>,
+}
+
+fn dialog_geometry_id(id_source: Id) -> Id {
+    id_source.with("dialog-geometry")
+}
+
+pub fn dialog_geometry(ctx: &Context, id_source: Id) -> DialogGeometry {
+    ctx.data(|d| d.get_temp(dialog_geometry_id(id_source)))
+        .unwrap_or_default()
+}
+
+pub fn set_dialog_geometry(ctx: &Context, id_source: Id, geometry: DialogGeometry) {
+    ctx.data_mut(|d| d.insert_temp(dialog_geometry_id(id_source), geometry));
+}
+
+/// Forgets any drag or resize, returning the dialog to its laid-out rect.
+pub fn reset_dialog_geometry(ctx: &Context, id_source: Id) {
+    ctx.data_mut(|d| d.remove::<DialogGeometry>(dialog_geometry_id(id_source)));
+}
+
 pub struct DialogProps<'a> {
     pub id_source: Id,
     pub open: &'a mut bool,
@@ -132,6 +185,54 @@ pub struct DialogProps<'a> {
     pub show_close_button: bool,
     pub close_button_text: Option<String>,
     pub tokens_override: Option<DialogTokens>,
+
+    pub draggable: bool,
+    pub resizable: bool,
+
+    pub trap_focus: bool,
+    pub initial_focus: Option<Id>,
+    pub return_focus_to: Option<Id>,
+    pub on_open_auto_focus: Option<&'a mut dyn FnMut(&mut DialogAutoFocusEvent)>,
+    pub on_close_auto_focus: Option<&'a mut dyn FnMut(&mut DialogAutoFocusEvent)>,
+}
+
+impl std::fmt::Debug for DialogProps<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DialogProps")
+            .field("id_source", &self.id_source)
+            .field("open", &self.open)
+            .field("title", &self.title)
+            .field("description", &self.description)
+            .field("size", &self.size)
+            .field("dialog_size", &self.dialog_size)
+            .field("align", &self.align)
+            .field("as_child", &self.as_child)
+            .field("width", &self.width)
+            .field("min_width", &self.min_width)
+            .field("max_width", &self.max_width)
+            .field("height", &self.height)
+            .field("min_height", &self.min_height)
+            .field("max_height", &self.max_height)
+            .field("scrollable", &self.scrollable)
+            .field("close_on_background", &self.close_on_background)
+            .field("close_on_escape", &self.close_on_escape)
+            .field("scrim_opacity", &self.scrim_opacity)
+            .field("scrim_color", &self.scrim_color)
+            .field("offset", &self.offset)
+            .field("animate", &self.animate)
+            .field("high_contrast", &self.high_contrast)
+            .field("show_close_button", &self.show_close_button)
+            .field("close_button_text", &self.close_button_text)
+            .field("tokens_override", &self.tokens_override)
+            .field("draggable", &self.draggable)
+            .field("resizable", &self.resizable)
+            .field("trap_focus", &self.trap_focus)
+            .field("initial_focus", &self.initial_focus)
+            .field("return_focus_to", &self.return_focus_to)
+            .field("on_open_auto_focus", &self.on_open_auto_focus.is_some())
+            .field("on_close_auto_focus", &self.on_close_auto_focus.is_some())
+            .finish()
+    }
 }
 
 impl<'a> DialogProps<'a> {
@@ -162,6 +263,13 @@ impl<'a> DialogProps<'a> {
             show_close_button: true,
             close_button_text: Some(Icon::X.unicode().to_string()),
             tokens_override: None,
+            draggable: false,
+            resizable: false,
+            trap_focus: true,
+            initial_focus: None,
+            return_focus_to: None,
+            on_open_auto_focus: None,
+            on_close_auto_focus: None,
         }
     }
 
@@ -279,6 +387,139 @@ impl<'a> DialogProps<'a> {
         self.tokens_override = Some(tokens);
         self
     }
+
+    /// Lets the user move the dialog by dragging its header.
+    pub fn draggable(mut self, draggable: bool) -> Self {
+        self.draggable = draggable;
+        self
+    }
+
+    /// Lets the user resize the dialog from its edges and corners, within the min/max props.
+    pub fn resizable(mut self, resizable: bool) -> Self {
+        self.resizable = resizable;
+        self
+    }
+
+    pub fn trap_focus(mut self, trap: bool) -> Self {
+        self.trap_focus = trap;
+        self
+    }
+
+    pub fn initial_focus(mut self, id: Id) -> Self {
+        self.initial_focus = Some(id);
+        self
+    }
+
+    pub fn return_focus_to(mut self, id: Id) -> Self {
+        self.return_focus_to = Some(id);
+        self
+    }
+
+    pub fn on_open_auto_focus(
+        mut self,
+        on_open_auto_focus: &'a mut dyn FnMut(&mut DialogAutoFocusEvent),
+    ) -> Self {
+        self.on_open_auto_focus = Some(on_open_auto_focus);
+        self
+    }
+
+    pub fn on_close_auto_focus(
+        mut self,
+        on_close_auto_focus: &'a mut dyn FnMut(&mut DialogAutoFocusEvent),
+    ) -> Self {
+        self.on_close_auto_focus = Some(on_close_auto_focus);
+        self
+    }
+}
+
+/// Radix `FocusScope` behaviour shared by modal overlays (dialog, alert dialog, sheet).
+///
+/// Trapping itself comes from registering the backdrop as a modal layer in the overlay stack,
+/// which keeps Tab/Shift+Tab on widgets drawn on or above it. The previously focused widget
+/// (or the widget that was clicked to open the overlay) is remembered and refocused on close.
+pub(crate) struct FocusScope<'a> {
+    pub id: Id,
+    pub initial_focus: Option<Id>,
+    pub return_focus_to: Option<Id>,
+    pub on_open_auto_focus: Option<&'a mut dyn FnMut(&mut DialogAutoFocusEvent)>,
+    pub on_close_auto_focus: Option<&'a mut dyn FnMut(&mut DialogAutoFocusEvent)>,
+}
+
+// egui areas run an invisible sizing pass on their first frame, so auto-focus is retried once.
+const AUTO_FOCUS_FRAMES: u8 = 2;
+
+impl FocusScope<'_> {
+    fn return_focus_id(&self) -> Id {
+        self.id.with("focus-scope-return")
+    }
+
+    fn pending_focus_id(&self) -> Id {
+        self.id.with("focus-scope-pending")
+    }
+
+    /// Must run before the overlay content is laid out so the first focusable widget inside it
+    /// can pick up focus in the same frame.
+    pub(crate) fn enter(&mut self, ctx: &Context, opened_now: bool, open: bool) {
+        let pending_id = self.pending_focus_id();
+        if opened_now {
+            let target = self.return_focus_to.or_else(|| {
+                ctx.memory(|m| m.focused())
+                    .or_else(|| ctx.interaction_snapshot(|i| i.clicked))
+            });
+            let return_id = self.return_focus_id();
+            ctx.data_mut(|d| d.insert_temp(return_id, target));
+
+            let mut evt = DialogAutoFocusEvent {
+                preventable: DialogPreventable::default(),
+            };
+            if let Some(cb) = self.on_open_auto_focus.as_mut() {
+                cb(&mut evt);
+            }
+            if !evt.preventable.default_prevented() {
+                ctx.data_mut(|d| d.insert_temp(pending_id, AUTO_FOCUS_FRAMES));
+            }
+        }
+
+        let pending = ctx.data(|d| d.get_temp::<u8>(pending_id)).unwrap_or(0);
+        if open && pending > 0 {
+            ctx.data_mut(|d| d.insert_temp(pending_id, pending - 1));
+            ctx.memory_mut(|m| match self.initial_focus {
+                Some(id) => m.request_focus(id),
+                None => {
+                    m.stop_text_input();
+                    m.move_focus(FocusDirection::Next);
+                }
+            });
+        }
+    }
+
+    /// Runs after the content; restores focus once the overlay has closed.
+    pub(crate) fn exit(&mut self, ctx: &Context, was_open: bool, open: bool) {
+        let pending_id = self.pending_focus_id();
+        if ctx.memory(|m| m.focused()).is_some() || !open {
+            ctx.data_mut(|d| d.remove_temp::<u8>(pending_id));
+        }
+
+        if !was_open || open {
+            return;
+        }
+        let return_id = self.return_focus_id();
+        let target = ctx
+            .data_mut(|d| d.remove_temp::<Option<Id>>(return_id))
+            .flatten();
+
+        let mut evt = DialogAutoFocusEvent {
+            preventable: DialogPreventable::default(),
+        };
+        if let Some(cb) = self.on_close_auto_focus.as_mut() {
+            cb(&mut evt);
+        }
+        if !evt.preventable.default_prevented()
+            && let Some(target) = target
+        {
+            ctx.memory_mut(|m| m.request_focus(target));
+        }
+    }
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -355,6 +596,13 @@ pub fn dialog<R>(
         show_close_button,
         close_button_text,
         tokens_override,
+        draggable,
+        resizable,
+        trap_focus,
+        initial_focus,
+        return_focus_to,
+        on_open_auto_focus,
+        on_close_auto_focus,
     } = props;
 
     let ctx = ui.ctx();
@@ -362,10 +610,16 @@ pub fn dialog<R>(
     let last_open = ctx.data(|d| d.get_temp::<bool>(state_id)).unwrap_or(false);
     let was_open = *open || last_open;
     let opened_now = *open && !last_open;
+    let mut focus_scope = FocusScope {
+        id: id_source,
+        initial_focus,
+        return_focus_to,
+        on_open_auto_focus,
+        on_close_auto_focus,
+    };
 
     trace!("render dialog {:?}", id_source);
     let screen = ctx.available_rect();
-    let overlay_id = LayerId::new(Order::Foreground, id_source.with("overlay"));
     let anim_key = id_source.with("open-anim");
     let anim_t = if animate {
         ctx.animate_bool(anim_key, *open)
@@ -375,31 +629,48 @@ pub fn dialog<R>(
         0.0
     };
     if !*open && anim_t <= 0.0 {
+        focus_scope.exit(ctx, last_open, *open);
         ctx.data_mut(|d| d.insert_temp(state_id, *open));
         return None;
     }
 
-    let overlay_painter = ctx.layer_painter(overlay_id);
     let scrim_alpha = (scrim_opacity as f32 * anim_t).round().clamp(0.0, 255.0) as u8;
     let base_scrim = scrim_color.unwrap_or(Color32::BLACK);
-    overlay_painter.rect_filled(
-        screen,
-        CornerRadius::same(0),
-        Color32::from_rgba_unmultiplied(
-            base_scrim.r(),
-            base_scrim.g(),
-            base_scrim.b(),
-            scrim_alpha,
-        ),
-    );
-    egui::Area::new(id_source.with("scrim-area"))
+    let scrim_area_id = id_source.with("scrim-area");
+    let scrim_layer = LayerId::new(Order::Foreground, scrim_area_id);
+    if *open {
+        register_overlay(
+            ctx,
+            OverlayLayer::new(id_source, OverlayKind::Dialog, scrim_layer).modal(trap_focus),
+        );
+    }
+    // The backdrop and content share `Order::Foreground`, so a dialog opened from inside another
+    // one stacks its backdrop above the parent content. Non-focusable senses keep the backdrop
+    // out of the Tab order.
+    egui::Area::new(scrim_area_id)
         .order(Order::Foreground)
         .interactable(true)
+        .sense(Sense::CLICK)
         .movable(false)
         .fixed_pos(screen.min)
         .show(ctx, |scrim_ui| {
-            scrim_ui.allocate_exact_size(screen.size(), Sense::click());
+            scrim_ui.painter().rect_filled(
+                screen,
+                CornerRadius::same(0),
+                Color32::from_rgba_unmultiplied(
+                    base_scrim.r(),
+                    base_scrim.g(),
+                    base_scrim.b(),
+                    scrim_alpha,
+                ),
+            );
+            scrim_ui.allocate_exact_size(screen.size(), Sense::CLICK);
         });
+    // egui raises any area that is pressed, so the content is kept as a sublayer of its own
+    // backdrop; otherwise a click on the backdrop would cover the dialog.
+    let content_id = id_source.with("content");
+    ctx.set_sublayer(scrim_layer, LayerId::new(Order::Foreground, content_id));
+    focus_scope.enter(ctx, opened_now, *open);
 
     let slide = if align == DialogAlign::Center {
         Vec2::ZERO
@@ -407,32 +678,44 @@ pub fn dialog<R>(
         Vec2::new(0.0, 8.0)
     };
 
-    let resolved_width = width.or(Some(size.x));
-    let resolved_height = height.or((size.y > 0.0).then_some(size.y));
-    let dialog_rect = compute_dialog_rect(
-        screen,
-        resolved_width,
-        min_width,
-        max_width,
-        resolved_height,
-        min_height,
-        max_height,
-        offset,
-        align,
-    );
+    let tokens = tokens_override
+        .unwrap_or_else(|| dialog_tokens_with_options(theme, dialog_size, high_contrast));
+    let chrome = tokens.layout.padding.sum() + Vec2::splat(tokens.border.width * 2.0);
+    let interactive_geometry = draggable || resizable;
+    let mut geometry = if interactive_geometry {
+        dialog_geometry(ctx, id_source)
+    } else {
+        DialogGeometry::default()
+    };
+
+    let resolved_width = geometry.size.map(|s| s.x).or(width).or(Some(size.x));
+    let resolved_height = geometry
+        .size
+        .map(|s| s.y)
+        .or(height)
+        .or((size.y > 0.0).then_some(size.y));
+    let layout_rect = |width: Option<f32>, height: Option<f32>, offset: Vec2| {
+        compute_dialog_rect(
+            screen, width, min_width, max_width, height, min_height, max_height, offset, align,
+        )
+    };
+    let mut dialog_rect = layout_rect(resolved_width, resolved_height, offset + geometry.offset);
+    if interactive_geometry {
+        let min = clamp_frame_to_screen(dialog_rect.min, dialog_rect.size() + chrome, screen);
+        geometry.offset += min - dialog_rect.min;
+        dialog_rect = dialog_rect.translate(min - dialog_rect.min);
+    }
     let animated_pos = dialog_rect.min + slide * (1.0 - anim_t);
 
     let mut result = None;
-    let content_id = id_source.with("content");
     let area = egui::Area::new(content_id)
-        .order(Order::Tooltip)
+        .order(Order::Foreground)
         .interactable(true)
+        .sense(Sense::CLICK)
         .movable(false)
         .fixed_pos(animated_pos);
 
     area.show(ctx, |area_ui| {
-        let tokens = tokens_override
-            .unwrap_or_else(|| dialog_tokens_with_options(theme, dialog_size, high_contrast));
         let frame = Frame::popup(area_ui.style())
             .fill(tokens.background)
             .stroke(tokens.border)
@@ -440,65 +723,73 @@ pub fn dialog<R>(
             .corner_radius(tokens.layout.rounding)
             .inner_margin(tokens.layout.padding);
 
+        let mut header_bottom = None;
         let frame_resp = frame.show(area_ui, |content_ui| {
             content_ui.set_min_width(dialog_rect.width());
             content_ui.set_max_width(dialog_rect.width());
             content_ui.set_max_height(dialog_rect.height());
+            if geometry.size.is_some() {
+                content_ui.set_min_height(dialog_rect.height());
+            }
 
             if title.is_some() {
-                content_ui.with_layout(
-                    egui::Layout::left_to_right(egui::Align::Min),
-                    |header_ui| {
-                        if let Some(t) = &title {
-                            header_ui.label(
-                                egui::RichText::new(t)
-                                    .strong()
-                                    .size(18.0)
-                                    .color(theme.palette.foreground),
-                            );
-                        }
-                        if false {
-                            header_ui.with_layout(
-                                egui::Layout::right_to_left(egui::Align::Min),
-                                |close_ui| {
-                                    let close_text = close_button_text.as_deref().unwrap_or("×");
-                                    let size = vec2(20.0, 20.0);
-                                    let (rect, close_resp) =
-                                        close_ui.allocate_exact_size(size, Sense::click());
-
-                                    let icon_color = if close_resp.hovered() {
-                                        theme.palette.foreground
-                                    } else {
-                                        theme.palette.muted_foreground
-                                    };
-
-                                    close_ui.painter().text(
-                                        rect.center(),
-                                        Align2::CENTER_CENTER,
-                                        close_text,
-                                        FontId::proportional(16.0),
-                                        icon_color,
-                                    );
-
-                                    if close_resp.has_focus() {
-                                        let ring_color = theme.palette.ring;
-                                        let ring_rect = rect.expand(2.0);
-                                        close_ui.painter().rect_stroke(
-                                            ring_rect,
-                                            CornerRadius::same(4),
-                                            Stroke::new(2.0, ring_color),
-                                            StrokeKind::Outside,
+                header_bottom = Some(
+                    content_ui
+                        .with_layout(egui::Layout::left_to_right(egui::Align::Min), |header_ui| {
+                            if let Some(t) = &title {
+                                header_ui.label(
+                                    egui::RichText::new(t)
+                                        .strong()
+                                        .size(18.0)
+                                        .color(theme.palette.foreground),
+                                );
+                            }
+                            if false {
+                                header_ui.with_layout(
+                                    egui::Layout::right_to_left(egui::Align::Min),
+                                    |close_ui| {
+                                        let close_text =
+                                            close_button_text.as_deref().unwrap_or("×");
+                                        let size = vec2(20.0, 20.0);
+                                        let (rect, close_resp) =
+                                            close_ui.allocate_exact_size(size, Sense::click());
+
+                                        let icon_color = if close_resp.hovered() {
+                                            theme.palette.foreground
+                                        } else {
+                                            theme.palette.muted_foreground
+                                        };
+
+                                        close_ui.painter().text(
+                                            rect.center(),
+                                            Align2::CENTER_CENTER,
+                                            close_text,
+                                            FontId::proportional(16.0),
+                                            icon_color,
                                         );
-                                    }
-
-                                    if close_resp.clicked() {
-                                        close_resp.request_focus();
-                                        *open = false;
-                                    }
-                                },
-                            );
-                        }
-                    },
+
+                                        if close_resp.has_focus() {
+                                            let ring_color = theme.palette.ring;
+                                            let ring_rect = rect.expand(2.0);
+                                            close_ui.painter().rect_stroke(
+                                                ring_rect,
+                                                CornerRadius::same(4),
+                                                Stroke::new(2.0, ring_color),
+                                                StrokeKind::Outside,
+                                            );
+                                        }
+
+                                        if close_resp.clicked() {
+                                            close_resp.request_focus();
+                                            *open = false;
+                                        }
+                                    },
+                                );
+                            }
+                        })
+                        .response
+                        .rect
+                        .bottom(),
                 );
             }
             if let Some(desc) = &description {
@@ -530,6 +821,63 @@ pub fn dialog<R>(
             }
         });
 
+        let frame_rect = frame_resp.response.rect;
+        if draggable {
+            let header_bottom =
+                header_bottom.unwrap_or(frame_rect.top() + tokens.layout.padding.left_top().y);
+            let header_rect =
+                Rect::from_min_max(frame_rect.min, pos2(frame_rect.right(), header_bottom));
+            let header_resp =
+                area_ui.interact(header_rect, id_source.with("drag-header"), Sense::DRAG);
+            if header_resp.dragged() {
+                area_ui.ctx().set_cursor_icon(CursorIcon::Grabbing);
+                geometry.offset += header_resp.drag_delta();
+            } else if header_resp.hovered() {
+                area_ui.ctx().set_cursor_icon(CursorIcon::Grab);
+            }
+        }
+        if resizable && let Some(resized) = resize_handles(area_ui, id_source, frame_rect) {
+            let max_w = max_width
+                .unwrap_or(f32::INFINITY)
+                .min(screen.width() - chrome.x);
+            let max_h = max_height
+                .unwrap_or(f32::INFINITY)
+                .min(screen.height() - chrome.y);
+            let min_w = min_width.unwrap_or(MIN_RESIZE_SIZE.x).min(max_w);
+            let min_h = min_height.unwrap_or(MIN_RESIZE_SIZE.y).min(max_h);
+            // Only the axis being dragged is clamped; the other keeps its laid-out size.
+            let content = vec2(
+                if resized.width() != frame_rect.width() {
+                    (resized.width() - chrome.x).clamp(min_w, max_w)
+                } else {
+                    frame_rect.width() - chrome.x
+                },
+                if resized.height() != frame_rect.height() {
+                    (resized.height() - chrome.y).clamp(min_h, max_h)
+                } else {
+                    frame_rect.height() - chrome.y
+                },
+            );
+            // Keep the edge opposite the dragged one fixed when the size hits a limit.
+            let frame_size = content + chrome;
+            let min_x = if resized.min.x != frame_rect.min.x {
+                resized.max.x - frame_size.x
+            } else {
+                resized.min.x
+            };
+            let min_y = if resized.min.y != frame_rect.min.y {
+                resized.max.y - frame_size.y
+            } else {
+                resized.min.y
+            };
+            let base = layout_rect(Some(content.x), Some(content.y), offset);
+            geometry.size = Some(vec2(content.x, base.height()));
+            geometry.offset = pos2(min_x, min_y) - base.min;
+        }
+        if interactive_geometry {
+            set_dialog_geometry(area_ui.ctx(), id_source, geometry);
+        }
+
         if show_close_button {
             let close_text = close_button_text
                 .clone()
@@ -576,21 +924,100 @@ pub fn dialog<R>(
             }
         }
 
-        let escape = close_on_escape && area_ui.input(|i| i.key_pressed(egui::Key::Escape));
+        let escape = close_on_escape
+            && area_ui.input(|i| i.key_pressed(egui::Key::Escape))
+            && is_topmost_overlay(area_ui.ctx(), id_source);
         let any_click = area_ui.input(|i| i.pointer.any_click());
         let interact = area_ui.input(|i| i.pointer.interact_pos());
-        let contains = interact
-            .map(|pos| frame_resp.response.rect.contains(pos))
-            .unwrap_or(false);
-        let outside_click =
-            !opened_now && was_open && close_on_background && any_click && !contains;
+        let hit_rect = if resizable {
+            frame_rect.expand(RESIZE_HANDLE_THICKNESS * 0.5)
+        } else {
+            frame_rect
+        };
+        let contains = interact.map(|pos| hit_rect.contains(pos)).unwrap_or(false);
+        let outside_click = !opened_now
+            && was_open
+            && close_on_background
+            && any_click
+            && !contains
+            && overlay_accepts_outside_click(area_ui.ctx(), id_source);
 
         if escape || outside_click {
             *open = false;
         }
     });
 
+    focus_scope.exit(ctx, last_open, *open);
     ctx.data_mut(|d| d.insert_temp(state_id, *open));
 
     result
 }
+
+fn clamp_frame_to_screen(min: Pos2, frame_size: Vec2, screen: Rect) -> Pos2 {
+    let max = (screen.max - frame_size).max(screen.min);
+    min.clamp(screen.min, max)
+}
+
+/// Edge and corner handles around `frame_rect`. Returns the dragged rect while a handle moves.
+fn resize_handles(ui: &Ui, id_source: Id, frame_rect: Rect) -> Option<Rect> {
+    // (moves left, moves right, moves top, moves bottom)
+    const HANDLES: [(bool, bool, bool, bool); 8] = [
+        (true, false, false, false),
+        (false, true, false, false),
+        (false, false, true, false),
+        (false, false, false, true),
+        (true, false, true, false),
+        (false, true, true, false),
+        (true, false, false, true),
+        (false, true, false, true),
+    ];
+    let half = RESIZE_HANDLE_THICKNESS * 0.5;
+    let mut resized = None;
+    for (idx, (left, right, top, bottom)) in HANDLES.into_iter().enumerate() {
+        let x_range = if left {
+            (frame_rect.left() - half, frame_rect.left() + half)
+        } else if right {
+            (frame_rect.right() - half, frame_rect.right() + half)
+        } else {
+            (frame_rect.left() + half, frame_rect.right() - half)
+        };
+        let y_range = if top {
+            (frame_rect.top() - half, frame_rect.top() + half)
+        } else if bottom {
+            (frame_rect.bottom() - half, frame_rect.bottom() + half)
+        } else {
+            (frame_rect.top() + half, frame_rect.bottom() - half)
+        };
+        let rect = Rect::from_min_max(pos2(x_range.0, y_range.0), pos2(x_range.1, y_range.1));
+        let resp = ui.interact(rect, id_source.with(("resize-handle", idx)), Sense::DRAG);
+        if resp.hovered() || resp.dragged() {
+            let horizontal = left || right;
+            let vertical = top || bottom;
+            let icon = match (horizontal, vertical) {
+                (true, false) => CursorIcon::ResizeHorizontal,
+                (false, true) => CursorIcon::ResizeVertical,
+                _ if (left && top) || (right && bottom) => CursorIcon::ResizeNwSe,
+                _ => CursorIcon::ResizeNeSw,
+            };
+            ui.ctx().set_cursor_icon(icon);
+        }
+        if resp.dragged() {
+            let delta = resp.drag_delta();
+            let mut rect = frame_rect;
+            if left {
+                rect.min.x += delta.x;
+            }
+            if right {
+                rect.max.x += delta.x;
+            }
+            if top {
+                rect.min.y += delta.y;
+            }
+            if bottom {
+                rect.max.y += delta.y;
+            }
+            resized = Some(rect);
+        }
+    }
+    resized
+}
@@ -15,6 +15,7 @@ use crate::menu_primitives::{
     menu_checkbox_item as base_menu_checkbox_item, menu_item as base_menu_item,
     menu_radio_item as base_menu_radio_item, menu_sub as base_menu_sub,
 };
+use crate::overlay::{OverlayKind, OverlayLayer, register_overlay};
 use crate::theme::Theme;
 use egui::{Context, Frame, Id, Popup, Response, Stroke, Ui, Vec2};
 
@@ -209,6 +210,12 @@ pub fn dropdown_menu<R>(
         });
 
     let is_open = inner.is_some();
+    if let Some(response) = &inner {
+        register_overlay(
+            ui.ctx(),
+            OverlayLayer::new(popup_id, OverlayKind::Menu, response.response.layer_id),
+        );
+    }
     if is_open
         && !was_open
         && let Some(first_id) = first_focusable_id(ui.ctx(), popup_id)
@@ -53,6 +53,7 @@ pub struct FieldState {
     pub dirty: bool,
     validator: Validator,
     initialized: bool,
+    validation_attempted: bool,
     initial: FieldValue,
 }
 
@@ -65,6 +66,7 @@ impl FieldState {
             dirty: false,
             validator,
             initialized: false,
+            validation_attempted: false,
             initial: FieldValue::Text(String::new()),
         }
     }
@@ -105,7 +107,7 @@ impl fmt::Debug for FieldState {
     }
 }
 
-#[derive(Default)]
+#[derive(Debug, Default)]
 pub struct FormState {
     pub mode: ValidationMode,
     fields: HashMap<String, FieldState>,
@@ -165,6 +167,25 @@ impl FormState {
         valid
     }
 
+    /// Validates only the named fields, e.g. one step of a wizard. Those fields then
+    /// revalidate on change like after a submit.
+    pub fn validate_fields<S: AsRef<str>>(&mut self, names: &[S]) -> bool {
+        let mut valid = true;
+        for name in names {
+            let field = self.ensure_field(name.as_ref());
+            field.validation_attempted = true;
+            if !field.validate() {
+                valid = false;
+            }
+        }
+        valid
+    }
+
+    /// Whether any of the named fields currently shows an error.
+    pub fn has_errors<S: AsRef<str>>(&self, names: &[S]) -> bool {
+        names.iter().any(|name| self.error(name.as_ref()).is_some())
+    }
+
     pub fn is_valid(&self) -> bool {
         self.fields.values().all(|field| field.error.is_none())
     }
@@ -194,7 +215,7 @@ impl FormState {
         let should_validate = match mode {
             ValidationMode::OnChange | ValidationMode::All => true,
             ValidationMode::OnTouched => field.touched,
-            ValidationMode::OnSubmit => submit_attempted,
+            ValidationMode::OnSubmit => submit_attempted || field.validation_attempted,
             ValidationMode::OnBlur => false,
         };
 
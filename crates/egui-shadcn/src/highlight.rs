@@ -0,0 +1,251 @@
+//! Syntax highlighters that turn plain text into a [`LayoutJob`], used by
+//! [`crate::textarea`] for code, query and log editing panes.
+//!
+//! Any `Fn(&str, &HighlightStyle) -> LayoutJob` is a [`TextHighlighter`], so a custom
+//! highlighter is just a closure that appends sections with the colors of the style.
+//!
+//! # Example
+//! ```ignore
+//! Textarea::new("query")
+//!     .highlighter(KeywordHighlighter::sql())
+//!     .line_numbers(true)
+//!     .show(ui, &theme, &mut sql);
+//! ```
+
+use crate::tokens::ColorPalette;
+use egui::text::{LayoutJob, TextFormat};
+use egui::{Color32, FontId};
+
+/// Font and token colors handed to a [`TextHighlighter`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct HighlightStyle {
+    pub font: FontId,
+
+    pub text: Color32,
+
+    pub keyword: Color32,
+
+    /// Object keys and other names, e.g. JSON keys.
+    pub key: Color32,
+
+    pub string: Color32,
+
+    pub number: Color32,
+
+    pub comment: Color32,
+
+    pub punctuation: Color32,
+}
+
+impl HighlightStyle {
+    pub fn from_palette(palette: &ColorPalette, font: FontId, text: Color32) -> Self {
+        Self {
+            font,
+            text,
+            keyword: palette.chart_1,
+            key: palette.chart_3,
+            string: palette.chart_2,
+            number: palette.chart_5,
+            comment: palette.muted_foreground,
+            punctuation: palette.muted_foreground,
+        }
+    }
+
+    /// Appends `text` to `job`, extending the last section when the color matches.
+    pub fn append(&self, job: &mut LayoutJob, text: &str, color: Color32) {
+        if text.is_empty() {
+            return;
+        }
+        if let Some(last) = job.sections.last_mut()
+            && last.format.color == color
+            && last.byte_range.end == job.text.len()
+        {
+            job.text.push_str(text);
+            last.byte_range.end = job.text.len();
+            return;
+        }
+        job.append(text, 0.0, TextFormat::simple(self.font.clone(), color));
+    }
+}
+
+pub trait TextHighlighter {
+    fn highlight(&self, text: &str, style: &HighlightStyle) -> LayoutJob;
+}
+
+impl<F> TextHighlighter for F
+where
+    F: Fn(&str, &HighlightStyle) -> LayoutJob,
+{
+    fn highlight(&self, text: &str, style: &HighlightStyle) -> LayoutJob {
+        self(text, style)
+    }
+}
+
+/// Highlights JSON: keys, strings, numbers, `true`/`false`/`null` and punctuation.
+/// Invalid input is still colored token by token.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct JsonHighlighter;
+
+impl TextHighlighter for JsonHighlighter {
+    fn highlight(&self, text: &str, style: &HighlightStyle) -> LayoutJob {
+        let mut job = LayoutJob::default();
+        let mut rest = text;
+        while let Some(ch) = rest.chars().next() {
+            let (len, color) = match ch {
+                '"' => {
+                    let len = quoted_len(rest, '"');
+                    let is_key = rest[len..].trim_start().starts_with(':');
+                    (len, if is_key { style.key } else { style.string })
+                }
+                '{' | '}' | '[' | ']' | ',' | ':' => (1, style.punctuation),
+                '-' | '0'..='9' => (
+                    prefix_len(rest, |c| {
+                        c.is_ascii_digit() || matches!(c, '-' | '+' | '.' | 'e' | 'E')
+                    }),
+                    style.number,
+                ),
+                c if c.is_alphabetic() => {
+                    let len = prefix_len(rest, char::is_alphanumeric);
+                    let color = match &rest[..len] {
+                        "true" | "false" | "null" => style.keyword,
+                        _ => style.text,
+                    };
+                    (len, color)
+                }
+                c => (c.len_utf8(), style.text),
+            };
+            style.append(&mut job, &rest[..len], color);
+            rest = &rest[len..];
+        }
+        job
+    }
+}
+
+/// Highlights words from a keyword list, plus numbers, quoted strings and line comments.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct KeywordHighlighter {
+    pub keywords: Vec<String>,
+
+    pub case_sensitive: bool,
+
+    /// Quote characters that start a string.
+    pub quotes: Vec<char>,
+
+    /// Prefix that comments out the rest of the line, e.g. `--` or `#`.
+    pub line_comment: Option<String>,
+}
+
+impl KeywordHighlighter {
+    pub fn new<S: Into<String>>(keywords: impl IntoIterator<Item = S>) -> Self {
+        Self {
+            keywords: keywords.into_iter().map(Into::into).collect(),
+            case_sensitive: true,
+            quotes: vec!['"', '\''],
+            line_comment: None,
+        }
+    }
+
+    /// Common SQL keywords, matched case-insensitively, with `--` comments.
+    pub fn sql() -> Self {
+        Self::new([
+            "select", "from", "where", "and", "or", "not", "in", "is", "null", "like", "between",
+            "join", "inner", "left", "right", "outer", "full", "on", "as", "group", "by", "order",
+            "having", "limit", "offset", "insert", "into", "values", "update", "set", "delete",
+            "create", "table", "drop", "alter", "index", "distinct", "union", "all", "case",
+            "when", "then", "else", "end", "asc", "desc", "with", "count", "sum", "avg", "min",
+            "max",
+        ])
+        .case_sensitive(false)
+        .line_comment("--")
+    }
+
+    /// Log levels, with timestamps and other numbers highlighted.
+    pub fn log() -> Self {
+        Self::new([
+            "TRACE", "DEBUG", "INFO", "WARN", "WARNING", "ERROR", "FATAL", "PANIC",
+        ])
+        .quotes(['"'])
+    }
+
+    pub fn case_sensitive(mut self, case_sensitive: bool) -> Self {
+        self.case_sensitive = case_sensitive;
+        self
+    }
+
+    pub fn quotes(mut self, quotes: impl Into<Vec<char>>) -> Self {
+        self.quotes = quotes.into();
+        self
+    }
+
+    pub fn line_comment(mut self, prefix: impl Into<String>) -> Self {
+        self.line_comment = Some(prefix.into());
+        self
+    }
+
+    pub fn is_keyword(&self, word: &str) -> bool {
+        if self.case_sensitive {
+            self.keywords.iter().any(|keyword| keyword == word)
+        } else {
+            self.keywords
+                .iter()
+                .any(|keyword| keyword.eq_ignore_ascii_case(word))
+        }
+    }
+}
+
+impl TextHighlighter for KeywordHighlighter {
+    fn highlight(&self, text: &str, style: &HighlightStyle) -> LayoutJob {
+        let mut job = LayoutJob::default();
+        let mut rest = text;
+        while let Some(ch) = rest.chars().next() {
+            let comment = self
+                .line_comment
+                .as_deref()
+                .filter(|prefix| !prefix.is_empty() && rest.starts_with(prefix));
+            let (len, color) = if comment.is_some() {
+                (rest.find('\n').unwrap_or(rest.len()), style.comment)
+            } else if self.quotes.contains(&ch) {
+                (quoted_len(rest, ch), style.string)
+            } else if ch.is_ascii_digit() {
+                (
+                    prefix_len(rest, |c| c.is_ascii_alphanumeric() || c == '.'),
+                    style.number,
+                )
+            } else if ch.is_alphabetic() || ch == '_' {
+                let len = prefix_len(rest, |c| c.is_alphanumeric() || c == '_');
+                let color = if self.is_keyword(&rest[..len]) {
+                    style.keyword
+                } else {
+                    style.text
+                };
+                (len, color)
+            } else {
+                (ch.len_utf8(), style.text)
+            };
+            style.append(&mut job, &rest[..len], color);
+            rest = &rest[len..];
+        }
+        job
+    }
+}
+
+/// Byte length of the leading run of chars matching `pred`.
+fn prefix_len(text: &str, pred: impl Fn(char) -> bool) -> usize {
+    text.find(|c: char| !pred(c)).unwrap_or(text.len())
+}
+
+/// Byte length of a string starting with `quote`, including the closing quote. Backslash
+/// escapes are skipped; an unterminated string runs to the end of the line.
+fn quoted_len(text: &str, quote: char) -> usize {
+    let mut escaped = false;
+    for (index, ch) in text.char_indices().skip(1) {
+        match ch {
+            _ if escaped => escaped = false,
+            '\\' => escaped = true,
+            '\n' => return index,
+            c if c == quote => return index + c.len_utf8(),
+            _ => {}
+        }
+    }
+    text.len()
+}
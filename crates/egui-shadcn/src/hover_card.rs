@@ -1,11 +1,12 @@
 //! Hover Card component - popover-like card shown on hover.
 
+use crate::overlay::{OverlayKind, OverlayLayer, register_overlay};
 use crate::popover::{
-    PopoverAlign, PopoverCollisionPadding, PopoverSide, PopoverSticky,
-    compute_popover_rect_with_collision,
+    PopoverAlign, PopoverAnchor, PopoverCollisionPadding, PopoverSide, PopoverSticky,
+    compute_popover_rect_with_collision, is_anchor_detached,
 };
 use crate::theme::Theme;
-use egui::{CornerRadius, Frame, Id, Margin, Order, Response, Stroke, Ui, vec2};
+use egui::{CornerRadius, Frame, Id, LayerId, Margin, Order, Response, Stroke, Ui, vec2};
 use std::time::Duration;
 
 const DEFAULT_OPEN_DELAY_MS: u64 = 700;
@@ -25,6 +26,11 @@ pub struct HoverCardProps {
     pub width: Option<f32>,
     pub max_height: Option<f32>,
     pub content_padding: Margin,
+    pub anchor: PopoverAnchor,
+    pub avoid_collisions: bool,
+    pub collision_padding: PopoverCollisionPadding,
+    pub sticky: PopoverSticky,
+    pub hide_when_detached: bool,
 }
 
 impl HoverCardProps {
@@ -40,6 +46,11 @@ impl HoverCardProps {
             width: None,
             max_height: None,
             content_padding: Margin::same(16),
+            anchor: PopoverAnchor::Trigger,
+            avoid_collisions: true,
+            collision_padding: PopoverCollisionPadding::default(),
+            sticky: PopoverSticky::default(),
+            hide_when_detached: false,
         }
     }
 
@@ -87,6 +98,39 @@ impl HoverCardProps {
         self.content_padding = padding;
         self
     }
+
+    pub fn anchor(mut self, anchor: PopoverAnchor) -> Self {
+        self.anchor = anchor;
+        self
+    }
+
+    pub fn follow_cursor(self, follow: bool) -> Self {
+        self.anchor(if follow {
+            PopoverAnchor::Cursor
+        } else {
+            PopoverAnchor::Trigger
+        })
+    }
+
+    pub fn avoid_collisions(mut self, avoid: bool) -> Self {
+        self.avoid_collisions = avoid;
+        self
+    }
+
+    pub fn collision_padding(mut self, padding: PopoverCollisionPadding) -> Self {
+        self.collision_padding = padding;
+        self
+    }
+
+    pub fn sticky(mut self, sticky: PopoverSticky) -> Self {
+        self.sticky = sticky;
+        self
+    }
+
+    pub fn hide_when_detached(mut self, hide: bool) -> Self {
+        self.hide_when_detached = hide;
+        self
+    }
 }
 
 fn hover_card_open_id(id_source: Id) -> Id {
@@ -236,8 +280,15 @@ pub fn hover_card_content<R>(
     let max_height = props.max_height.unwrap_or(DEFAULT_MAX_HEIGHT);
 
     let boundary = ctx.available_rect();
+    let anchor_rect = props
+        .anchor
+        .resolve(ctx, props.id_source, trigger_response.rect);
+    if props.hide_when_detached && is_anchor_detached(boundary, anchor_rect) {
+        ctx.data_mut(|d| d.insert_temp(hover_card_content_hover_id(props.id_source), false));
+        return None;
+    }
     let (position_rect, computed_side) = compute_popover_rect_with_collision(
-        trigger_response.rect,
+        anchor_rect,
         boundary,
         props.side,
         props.align,
@@ -245,9 +296,9 @@ pub fn hover_card_content<R>(
         props.align_offset,
         width,
         max_height,
-        true,
-        PopoverCollisionPadding::default(),
-        PopoverSticky::default(),
+        props.avoid_collisions,
+        props.collision_padding,
+        props.sticky,
     );
 
     let slide_offset = match computed_side {
@@ -261,6 +312,16 @@ pub fn hover_card_content<R>(
     let mut popup_rect = position_rect;
     let mut inner: Option<R> = None;
     let content_id = props.id_source.with("content");
+    if open {
+        register_overlay(
+            ctx,
+            OverlayLayer::new(
+                props.id_source,
+                OverlayKind::HoverCard,
+                LayerId::new(Order::Tooltip, content_id),
+            ),
+        );
+    }
 
     egui::Area::new(content_id)
         .order(Order::Tooltip)
@@ -1,7 +1,9 @@
+use crate::input_mask::{InputMask, remask_edit};
 use crate::theme::Theme;
 use crate::tokens::{
     ColorPalette, ControlSize, InputVariant as TokenInputVariant, input_tokens, mix,
 };
+use egui::text::{CCursor, CCursorRange};
 use egui::{
     Color32, CornerRadius, FontId, Painter, Rect, Response, Sense, Stroke, StrokeKind, TextEdit,
     TextStyle, Ui, UiBuilder, Vec2, WidgetText, pos2, vec2,
@@ -381,6 +383,9 @@ where
 
     pub high_contrast: bool,
 
+    /// Constrains typing to a pattern; the value holds the masked text.
+    pub mask: Option<&'a InputMask>,
+
     #[allow(clippy::type_complexity)]
     pub left_slot: BoxedSlotFn<'a>,
 
@@ -406,6 +411,7 @@ impl<'a, Id: Hash + Debug> InputProps<'a, Id> {
             style: None,
             accent_color: None,
             high_contrast: false,
+            mask: None,
             left_slot: None,
             right_slot: None,
         }
@@ -476,6 +482,11 @@ impl<'a, Id: Hash + Debug> InputProps<'a, Id> {
         self
     }
 
+    pub fn mask(mut self, mask: &'a InputMask) -> Self {
+        self.mask = Some(mask);
+        self
+    }
+
     pub fn left_slot<F>(mut self, slot_fn: F) -> Self
     where
         F: Fn(&Painter, Rect, Color32) + 'a,
@@ -511,6 +522,7 @@ where
     pub style: Option<InputStyle>,
     pub accent_color: Option<Color32>,
     pub high_contrast: bool,
+    pub mask: Option<&'a InputMask>,
     #[allow(clippy::type_complexity)]
     pub left_slot: BoxedSlotFn<'a>,
     #[allow(clippy::type_complexity)]
@@ -534,6 +546,7 @@ impl<'a, Id: Hash + Debug> Input<'a, Id> {
             style: None,
             accent_color: None,
             high_contrast: false,
+            mask: None,
             left_slot: None,
             right_slot: None,
         }
@@ -604,6 +617,11 @@ impl<'a, Id: Hash + Debug> Input<'a, Id> {
         self
     }
 
+    pub fn mask(mut self, mask: &'a InputMask) -> Self {
+        self.mask = Some(mask);
+        self
+    }
+
     pub fn left_slot<F>(mut self, slot_fn: F) -> Self
     where
         F: Fn(&Painter, Rect, Color32) + 'a,
@@ -637,6 +655,7 @@ impl<'a, Id: Hash + Debug> Input<'a, Id> {
             style: self.style,
             accent_color: self.accent_color,
             high_contrast: self.high_contrast,
+            mask: self.mask,
             left_slot: self.left_slot,
             right_slot: self.right_slot,
         };
@@ -671,6 +690,18 @@ pub fn resolve_input_style(palette: &ColorPalette, config: &InputConfig) -> Inpu
 }
 
 pub fn text_input_with_props<Id>(ui: &mut Ui, theme: &Theme, props: InputProps<'_, Id>) -> Response
+where
+    Id: Hash + Debug,
+{
+    input_frame(ui, theme, props).1
+}
+
+/// Renders the input and also returns its outer frame, for widgets that overlay the slots.
+pub(crate) fn input_frame<Id>(
+    ui: &mut Ui,
+    theme: &Theme,
+    props: InputProps<'_, Id>,
+) -> (Rect, Response)
 where
     Id: Hash + Debug,
 {
@@ -732,6 +763,20 @@ where
     let (rect, response) = ui.allocate_exact_size(desired_size, Sense::click());
 
     let edit_id = id.with("edit");
+
+    // Values set by the host may be raw or partially formatted.
+    let mut previous_cursor = 0;
+    if let Some(mask) = props.mask {
+        let masked = mask.value(props.value).masked;
+        if *props.value != masked {
+            *props.value = masked;
+        }
+        previous_cursor = TextEdit::load_state(ui.ctx(), edit_id)
+            .and_then(|state| state.cursor.char_range())
+            .map_or(0, |range| range.primary.index);
+    }
+    let previous_text = props.mask.map(|_| props.value.clone());
+
     let has_focus = response.has_focus() || ui.memory(|m| m.has_focus(edit_id));
 
     let bg_color = if effectively_disabled {
@@ -870,7 +915,9 @@ where
             edit = edit.password(true);
         }
 
-        if let Some(limit) = props.max_len {
+        if let Some(limit) = props.max_len
+            && props.mask.is_none()
+        {
             edit = edit.char_limit(limit);
         }
 
@@ -881,13 +928,29 @@ where
         inner_ui.add_enabled(props.enabled, edit)
     });
 
+    if let (Some(mask), Some(previous)) = (props.mask, previous_text)
+        && response.inner.changed()
+        && let Some(mut edit_state) = TextEdit::load_state(ui.ctx(), edit_id)
+    {
+        let cursor = edit_state
+            .cursor
+            .char_range()
+            .map_or(props.value.chars().count(), |range| range.primary.index);
+        let (masked, cursor) = remask_edit(mask, &previous, previous_cursor, props.value, cursor);
+        *props.value = masked;
+        edit_state
+            .cursor
+            .set_char_range(Some(CCursorRange::one(CCursor::new(cursor))));
+        edit_state.store(ui.ctx(), edit_id);
+    }
+
     if response.inner.clicked_elsewhere()
         && rect.contains(ui.ctx().pointer_hover_pos().unwrap_or_default())
     {
         ui.memory_mut(|m| m.request_focus(edit_id));
     }
 
-    response.inner
+    (rect, response.inner)
 }
 
 pub fn text_input(ui: &mut Ui, theme: &Theme, value: &mut String) -> Response {
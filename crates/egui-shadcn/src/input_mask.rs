@@ -0,0 +1,274 @@
+//! Pattern-based input masks shared by [`crate::input`] and [`crate::input_otp`].
+//!
+//! Pattern syntax:
+//! - `9` — a digit
+//! - `a` — a letter
+//! - `A` — a letter, converted to upper case
+//! - `*` — a letter or digit
+//! - `\` — the next character is a literal
+//!
+//! Every other character is a literal that is inserted as soon as the user types past it.
+//!
+//! # Example
+//! ```ignore
+//! let mask = InputMask::new("(999) 999-9999");
+//! assert_eq!(mask.apply("5551234567"), "(555) 123-4567");
+//! assert_eq!(mask.unmask("(555) 123-45"), "55512345");
+//! ```
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MaskToken {
+    Digit,
+    Letter,
+    UpperLetter,
+    Alphanumeric,
+    Literal(char),
+}
+
+impl MaskToken {
+    pub fn is_slot(self) -> bool {
+        !matches!(self, MaskToken::Literal(_))
+    }
+
+    /// The character stored for `ch` in this slot, or `None` if the slot rejects it.
+    pub fn accept(self, ch: char) -> Option<char> {
+        match self {
+            MaskToken::Digit => ch.is_ascii_digit().then_some(ch),
+            MaskToken::Letter => ch.is_alphabetic().then_some(ch),
+            MaskToken::UpperLetter => ch
+                .is_alphabetic()
+                .then(|| ch.to_uppercase().next().unwrap_or(ch)),
+            MaskToken::Alphanumeric => ch.is_alphanumeric().then_some(ch),
+            MaskToken::Literal(_) => None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MaskedValue {
+    /// Only the characters typed into slots.
+    pub raw: String,
+    /// The raw characters with literals inserted.
+    pub masked: String,
+    /// Every slot is filled.
+    pub complete: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InputMask {
+    tokens: Vec<MaskToken>,
+}
+
+impl InputMask {
+    pub fn new(pattern: &str) -> Self {
+        let mut tokens = Vec::new();
+        let mut chars = pattern.chars();
+        while let Some(ch) = chars.next() {
+            let token = match ch {
+                '9' => MaskToken::Digit,
+                'a' => MaskToken::Letter,
+                'A' => MaskToken::UpperLetter,
+                '*' => MaskToken::Alphanumeric,
+                '\\' => MaskToken::Literal(chars.next().unwrap_or('\\')),
+                other => MaskToken::Literal(other),
+            };
+            tokens.push(token);
+        }
+        Self { tokens }
+    }
+
+    pub fn from_tokens(tokens: Vec<MaskToken>) -> Self {
+        Self { tokens }
+    }
+
+    /// US phone number, `(555) 123-4567`.
+    pub fn phone_us() -> Self {
+        Self::new("(999) 999-9999")
+    }
+
+    /// 16-digit card number in groups of four.
+    pub fn card_number() -> Self {
+        Self::new("9999 9999 9999 9999")
+    }
+
+    /// Card expiry date, `MM/YY`.
+    pub fn card_expiry() -> Self {
+        Self::new("99/99")
+    }
+
+    /// IBAN in groups of four. Shorter national formats are simply left incomplete.
+    pub fn iban() -> Self {
+        Self::new("AA99 **** **** **** **** **** **** **")
+    }
+
+    pub fn tokens(&self) -> &[MaskToken] {
+        &self.tokens
+    }
+
+    pub fn slot_count(&self) -> usize {
+        self.tokens.iter().filter(|token| token.is_slot()).count()
+    }
+
+    /// The token for the `index`-th slot, ignoring literals.
+    pub fn slot(&self, index: usize) -> Option<MaskToken> {
+        self.tokens
+            .iter()
+            .copied()
+            .filter(|token| token.is_slot())
+            .nth(index)
+    }
+
+    /// The character stored for `ch` typed into the `index`-th slot.
+    pub fn accept(&self, index: usize, ch: char) -> Option<char> {
+        self.slot(index).and_then(|token| token.accept(ch))
+    }
+
+    /// Extracts the slot characters from any text, masked or not. Literals that appear where
+    /// the pattern expects them are skipped; characters no slot accepts are dropped.
+    pub fn unmask(&self, text: &str) -> String {
+        let mut raw = String::new();
+        let mut pos = 0;
+        for ch in text.chars() {
+            if pos >= self.tokens.len() {
+                break;
+            }
+            if self.tokens[pos] == MaskToken::Literal(ch) {
+                pos += 1;
+                continue;
+            }
+            let Some(slot_pos) = self.tokens[pos..]
+                .iter()
+                .position(|token| token.is_slot())
+                .map(|offset| pos + offset)
+            else {
+                break;
+            };
+            if let Some(accepted) = self.tokens[slot_pos].accept(ch) {
+                raw.push(accepted);
+                pos = slot_pos + 1;
+            }
+        }
+        raw
+    }
+
+    /// Places raw characters into consecutive slots, dropping any the next slot rejects.
+    /// Unlike [`Self::unmask`], characters are never matched against literals.
+    pub fn fit(&self, raw: &str) -> String {
+        let mut out = String::new();
+        let mut slots = self
+            .tokens
+            .iter()
+            .filter(|token| token.is_slot())
+            .peekable();
+        for ch in raw.chars() {
+            let Some(slot) = slots.peek() else {
+                break;
+            };
+            if let Some(accepted) = slot.accept(ch) {
+                out.push(accepted);
+                slots.next();
+            }
+        }
+        out
+    }
+
+    /// Formats raw slot characters, inserting literals up to the last filled slot.
+    pub fn apply(&self, raw: &str) -> String {
+        self.layout(raw).0
+    }
+
+    pub fn value(&self, text: &str) -> MaskedValue {
+        let raw = self.unmask(text);
+        let masked = self.apply(&raw);
+        let complete = raw.chars().count() == self.slot_count();
+        MaskedValue {
+            raw,
+            masked,
+            complete,
+        }
+    }
+
+    pub fn is_complete(&self, text: &str) -> bool {
+        self.value(text).complete
+    }
+
+    /// Char index in the masked text where the `raw_index`-th slot character sits (or would be
+    /// typed next), after any literals that precede it.
+    pub fn masked_cursor(&self, raw: &str, raw_index: usize) -> usize {
+        let (masked, slot_positions) = self.layout(raw);
+        slot_positions
+            .get(raw_index)
+            .copied()
+            .unwrap_or_else(|| masked.chars().count())
+    }
+
+    /// Number of slot characters before the char index `cursor` of arbitrary edited text.
+    pub fn raw_cursor(&self, text: &str, cursor: usize) -> usize {
+        let prefix: String = text.chars().take(cursor).collect();
+        self.unmask(&prefix).chars().count()
+    }
+
+    fn layout(&self, raw: &str) -> (String, Vec<usize>) {
+        let mut masked = String::new();
+        let mut slot_positions = Vec::new();
+        let mut pending = String::new();
+        let mut raw_chars = raw.chars();
+        let mut len = 0;
+        for token in &self.tokens {
+            match token {
+                MaskToken::Literal(ch) => pending.push(*ch),
+                slot => {
+                    let Some(ch) = raw_chars.next() else {
+                        break;
+                    };
+                    let Some(ch) = slot.accept(ch) else {
+                        break;
+                    };
+                    len += pending.chars().count();
+                    masked.push_str(&pending);
+                    pending.clear();
+                    slot_positions.push(len);
+                    masked.push(ch);
+                    len += 1;
+                }
+            }
+        }
+        (masked, slot_positions)
+    }
+}
+
+/// Re-masks `text` after an edit. `previous` and `previous_cursor` describe the text and caret
+/// (char index) before the edit, `cursor` the caret in `text`. Returns the masked text and the
+/// caret position in it.
+///
+/// Deleting only a literal removes the slot character next to it instead, so Backspace and
+/// Delete never get stuck on separators.
+pub fn remask_edit(
+    mask: &InputMask,
+    previous: &str,
+    previous_cursor: usize,
+    text: &str,
+    cursor: usize,
+) -> (String, usize) {
+    let previous_raw = mask.unmask(previous);
+    let mut raw: Vec<char> = mask.unmask(text).chars().collect();
+    let mut raw_cursor = mask.raw_cursor(text, cursor);
+
+    let deleted_literal = text.chars().count() < previous.chars().count()
+        && raw.iter().copied().eq(previous_raw.chars());
+    if deleted_literal {
+        if cursor < previous_cursor {
+            if raw_cursor > 0 {
+                raw_cursor -= 1;
+                raw.remove(raw_cursor);
+            }
+        } else if raw_cursor < raw.len() {
+            raw.remove(raw_cursor);
+        }
+    }
+
+    // Removing a character can shift others into slots that reject them.
+    let raw = mask.fit(&raw.into_iter().collect::<String>());
+    let raw_cursor = raw_cursor.min(raw.chars().count());
+    (mask.apply(&raw), mask.masked_cursor(&raw, raw_cursor))
+}
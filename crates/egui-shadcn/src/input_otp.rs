@@ -1,3 +1,4 @@
+use crate::input_mask::InputMask;
 use crate::theme::Theme;
 use egui::{
     Color32, CornerRadius, Event, Id, Key, Response, Sense, Stroke, StrokeKind, Ui, Vec2, vec2,
@@ -23,6 +24,8 @@ pub struct InputOTPState {
 pub struct InputOTPProps<'a> {
     pub max_length: usize,
     pub pattern: Option<&'a Regex>,
+    /// Per-slot character classes; literals in the mask are ignored.
+    pub mask: Option<&'a InputMask>,
     pub on_complete: Option<InputOTPOnComplete<'a>>,
 }
 
@@ -31,6 +34,7 @@ impl<'a> Default for InputOTPProps<'a> {
         Self {
             max_length: 6,
             pattern: None,
+            mask: None,
             on_complete: None,
         }
     }
@@ -41,6 +45,7 @@ impl<'a> InputOTPProps<'a> {
         Self {
             max_length,
             pattern: None,
+            mask: None,
             on_complete: None,
         }
     }
@@ -50,6 +55,20 @@ impl<'a> InputOTPProps<'a> {
         self
     }
 
+    /// Uses the mask's slots for validation and its slot count as `max_length`.
+    pub fn mask(mut self, mask: &'a InputMask) -> Self {
+        self.max_length = mask.slot_count();
+        self.mask = Some(mask);
+        self
+    }
+
+    fn filter(&self) -> CharFilter<'a> {
+        CharFilter {
+            pattern: self.pattern,
+            mask: self.mask,
+        }
+    }
+
     pub fn on_complete(mut self, callback: impl FnMut(&str) + 'a) -> Self {
         self.on_complete = Some(InputOTPOnComplete(Box::new(callback)));
         self
@@ -68,6 +87,7 @@ pub struct InputOTPContext<'a> {
     group_first_index: std::cell::Cell<Option<usize>>,
 }
 
+#[allow(clippy::collapsible_match)]
 pub fn input_otp(
     ui: &mut Ui,
     theme: &Theme,
@@ -81,7 +101,8 @@ pub fn input_otp(
         .data(|d| d.get_temp::<InputOTPState>(id))
         .unwrap_or_default();
 
-    let (mut chars, normalized) = normalized_chars(value, props.max_length, props.pattern);
+    let filter = props.filter();
+    let (mut chars, normalized) = normalized_chars(value, props.max_length, filter);
     if normalized {
         *value = chars.iter().collect();
     }
@@ -95,13 +116,7 @@ pub fn input_otp(
             match event {
                 Event::Text(text) | Event::Paste(text) => {
                     if !text.is_empty()
-                        && apply_text(
-                            &mut chars,
-                            &mut cursor,
-                            &text,
-                            props.max_length,
-                            props.pattern,
-                        )
+                        && apply_text(&mut chars, &mut cursor, &text, props.max_length, filter)
                     {
                         changed = true;
                     }
@@ -323,26 +338,38 @@ pub fn input_otp_separator(ui: &mut Ui, theme: &Theme) -> Response {
     ui.label(egui::RichText::new("-").color(theme.palette.muted_foreground))
 }
 
-fn is_allowed_char(pattern: Option<&Regex>, ch: char) -> bool {
-    if ch.is_control() {
-        return false;
-    }
-    match pattern {
-        Some(pattern) => {
+#[derive(Clone, Copy)]
+struct CharFilter<'a> {
+    pattern: Option<&'a Regex>,
+    mask: Option<&'a InputMask>,
+}
+
+impl CharFilter<'_> {
+    /// The character stored in slot `index` for `ch`, or `None` if it is rejected.
+    fn accept(self, index: usize, ch: char) -> Option<char> {
+        if ch.is_control() {
+            return None;
+        }
+        if let Some(pattern) = self.pattern {
             let mut buffer = [0u8; 4];
-            pattern.is_match(ch.encode_utf8(&mut buffer))
+            if !pattern.is_match(ch.encode_utf8(&mut buffer)) {
+                return None;
+            }
+        }
+        match self.mask {
+            Some(mask) => mask.accept(index, ch),
+            None => Some(ch),
         }
-        None => true,
     }
 }
 
-fn normalized_chars(value: &str, max_length: usize, pattern: Option<&Regex>) -> (Vec<char>, bool) {
+fn normalized_chars(value: &str, max_length: usize, filter: CharFilter<'_>) -> (Vec<char>, bool) {
     let mut chars = Vec::new();
     for ch in value.chars() {
         if chars.len() >= max_length {
             break;
         }
-        if is_allowed_char(pattern, ch) {
+        if let Some(ch) = filter.accept(chars.len(), ch) {
             chars.push(ch);
         }
     }
@@ -355,13 +382,13 @@ fn apply_text(
     cursor: &mut usize,
     text: &str,
     max_length: usize,
-    pattern: Option<&Regex>,
+    filter: CharFilter<'_>,
 ) -> bool {
     let mut changed = false;
     for ch in text.chars() {
-        if !is_allowed_char(pattern, ch) {
+        let Some(ch) = filter.accept(*cursor, ch) else {
             continue;
-        }
+        };
         if chars.len() >= max_length {
             break;
         }
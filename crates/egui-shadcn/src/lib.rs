@@ -7,6 +7,7 @@ pub mod aspect_ratio;
 pub mod avatar;
 pub mod badge;
 pub mod breadcrumb;
+pub mod breakpoint;
 pub mod button;
 pub mod button_group;
 pub mod calendar;
@@ -14,6 +15,7 @@ pub mod card;
 pub mod carousel;
 #[cfg(feature = "plot")]
 pub mod chart;
+pub mod chart_primitives;
 pub mod checkbox;
 pub mod collapsible;
 pub mod combobox;
@@ -24,18 +26,25 @@ pub mod date_picker;
 pub mod dialog;
 pub mod dropdown_menu;
 pub mod form;
+pub mod highlight;
 pub mod hover_card;
 pub mod icons;
 pub mod input;
+pub mod input_mask;
 pub mod input_otp;
 pub mod label;
+pub mod mention;
 pub mod menu_primitives;
+pub mod mini_chart;
 pub mod navigation_menu;
+pub mod number_input;
+pub mod overlay;
 pub mod pagination;
 pub mod popover;
 pub mod progress;
 pub mod radio;
 pub mod resizable;
+pub mod router;
 pub mod scroll_area;
 pub mod select;
 pub mod separator;
@@ -43,10 +52,13 @@ pub mod sheet;
 pub mod sidebar;
 pub mod skeleton;
 pub mod slider;
+pub mod sortable;
 pub mod spinner;
+pub mod stepper;
 pub mod switch;
 pub mod table;
 pub mod tabs;
+pub mod tags_input;
 pub mod textarea;
 pub mod theme;
 pub mod toast;
@@ -54,6 +66,7 @@ pub mod toggle;
 pub mod toggle_group;
 pub mod tokens;
 pub mod tooltip;
+pub mod tree_view;
 pub mod typography;
 
 pub use accordion::{
@@ -63,12 +76,18 @@ pub use accordion::{
 pub use alert::{AlertProps, AlertVariant, alert};
 pub use alert_dialog::{AlertDialogProps, AlertDialogResult, alert_dialog};
 pub use aspect_ratio::{AspectRatioProps, aspect_ratio};
-pub use avatar::{AvatarProps, AvatarSize, AvatarVariant, avatar};
+pub use avatar::{
+    AvatarGroupProps, AvatarGroupResponse, AvatarImageStatus, AvatarProps, AvatarResponse,
+    AvatarSize, AvatarVariant, avatar, avatar_group,
+};
 pub use badge::{BadgeProps, BadgeSize, BadgeVariant, badge};
 pub use breadcrumb::{
-    BreadcrumbContext, BreadcrumbMetrics, BreadcrumbProps, BreadcrumbTokens, breadcrumb,
-    breadcrumb_ellipsis, breadcrumb_item, breadcrumb_link, breadcrumb_list, breadcrumb_page,
-    breadcrumb_separator,
+    BreadcrumbContext, BreadcrumbMetrics, BreadcrumbPathProps, BreadcrumbPathResponse,
+    BreadcrumbProps, BreadcrumbTokens, breadcrumb, breadcrumb_ellipsis, breadcrumb_item,
+    breadcrumb_link, breadcrumb_list, breadcrumb_page, breadcrumb_path, breadcrumb_separator,
+};
+pub use breakpoint::{
+    Breakpoint, Breakpoints, breakpoint, breakpoints, set_breakpoints, viewport_breakpoint,
 };
 pub use button::{
     Button, ButtonJustify, ButtonProps, ButtonRadius, ButtonSize, ButtonStyle, ButtonVariant,
@@ -80,14 +99,21 @@ pub use calendar::{
 };
 pub use card::{CardProps, CardSize, CardTokens, CardVariant, card, card_tokens_with_options};
 pub use carousel::{
-    CarouselContentProps, CarouselContentResponse, CarouselContext, CarouselItemProps,
-    CarouselItemResponse, CarouselOptions, CarouselOrientation, CarouselProps, CarouselResponse,
-    carousel, carousel_content, carousel_item, carousel_next, carousel_previous,
+    CarouselAlign, CarouselContentProps, CarouselContentResponse, CarouselContext,
+    CarouselItemProps, CarouselItemResponse, CarouselOptions, CarouselOrientation, CarouselProps,
+    CarouselResponse, CarouselThumbnailsProps, carousel, carousel_content, carousel_dots,
+    carousel_item, carousel_next, carousel_previous, carousel_thumbnails,
 };
 #[cfg(feature = "plot")]
 pub use chart::{
-    BarChart, ChartIndicator, ChartLegend, ChartLegendItem, ChartProps, ChartResponse,
-    ChartTooltip, ChartTooltipItem, LineChart, ShadcnChart, chart,
+    AreaChart, BarChart, ChartConfig, ChartConfigItem, ChartProps, ChartResponse, ChartSeries,
+    ChartSlice, ChartStacking, LineChart, MultiBarChart, PieChart, RadarChart, RadialBarChart,
+    RingSeries, ShadcnChart, TimeAxis, chart, follow_latest, format_time_tick, lttb, stack_series,
+    time_x,
+};
+pub use chart_primitives::{
+    ChartIndicator, ChartLegend, ChartLegendItem, ChartTooltip, ChartTooltipItem, chart_color,
+    format_chart_value,
 };
 pub use checkbox::{
     CheckboxCycle, CheckboxOptions, CheckboxProps, CheckboxSize, CheckboxState, CheckboxVariant,
@@ -97,8 +123,9 @@ pub use collapsible::{CollapsibleContentProps, CollapsibleContext, CollapsiblePr
 pub use combobox::{ComboboxProps, ComboboxSize, combobox, combobox_with_props};
 pub use command::{
     CommandContext, CommandDialogProps, CommandGroupProps, CommandInputProps, CommandItemProps,
-    CommandListProps, CommandProps, OnCommandSelect, command, command_dialog, command_empty,
-    command_group, command_input, command_item, command_list, command_separator, command_shortcut,
+    CommandListProps, CommandNavigation, CommandProps, OnCommandSelect, command, command_dialog,
+    command_empty, command_group, command_input, command_item, command_list, command_separator,
+    command_shortcut,
 };
 pub use context_menu::{
     ContextMenuCheckboxItemProps, ContextMenuItemProps, ContextMenuItemVariant,
@@ -116,8 +143,9 @@ pub use date_picker::{
     date_picker_with_props, date_range_picker, date_range_picker_with_props,
 };
 pub use dialog::{
-    DialogAlign, DialogLayoutTokens, DialogProps, DialogSize, DialogTokens, compute_dialog_rect,
-    dialog, dialog_layout_tokens, dialog_tokens_with_options,
+    DialogAlign, DialogAutoFocusEvent, DialogGeometry, DialogLayoutTokens, DialogPreventable,
+    DialogProps, DialogSize, DialogTokens, compute_dialog_rect, dialog, dialog_geometry,
+    dialog_layout_tokens, dialog_tokens_with_options, reset_dialog_geometry, set_dialog_geometry,
 };
 pub use dropdown_menu::{
     DropdownMenuCheckboxItemProps, DropdownMenuItemProps, DropdownMenuItemVariant,
@@ -134,35 +162,52 @@ pub use form::{
     FormState, ValidationMode, Validator, compose, email, form_control, form_description,
     form_item, form_label, form_message, max_length, min_length, none, pattern, required,
 };
+pub use highlight::{HighlightStyle, JsonHighlighter, KeywordHighlighter, TextHighlighter};
 pub use hover_card::{HoverCardProps, hover_card, hover_card_content, hover_card_trigger};
 pub use icons::{icon_calendar, icon_check, icon_chevrons_up_down};
 pub use input::{
     Input, InputConfig, InputProps, InputRadius, InputSize, InputStyle, InputType, InputVariant,
     resolve_input_style, text_input, text_input_with_config, text_input_with_props,
 };
+pub use input_mask::{InputMask, MaskToken, MaskedValue, remask_edit};
 pub use input_otp::{
     InputOTPContext, InputOTPProps, input_otp, input_otp_group, input_otp_separator,
     input_otp_slot, input_otp_slot_last,
 };
 pub use label::{Label, LabelProps, LabelVariant, label, label_with_props};
+pub use mention::{
+    Mention, MentionItem, MentionQuery, MentionTrigger, TextareaMentions, insert_mention,
+    mention_query, sync_mentions,
+};
 pub use menu_primitives::{
     MenuCheckboxItemProps, MenuItemProps, MenuItemVariant, MenuLabelProps, MenuRadioGroupProps,
     MenuRadioItemProps, MenuSubProps, MenuTokens, menu_checkbox_item, menu_item, menu_label,
     menu_radio_group, menu_radio_item, menu_separator, menu_shortcut, menu_sub, menu_tokens,
 };
+pub use mini_chart::{MiniChartProps, MiniChartResponse, mini_area, mini_bar, sparkline};
 pub use navigation_menu::{
     NavigationMenuContentProps, NavigationMenuContext, NavigationMenuItemContext,
     NavigationMenuLinkProps, NavigationMenuLinkResponse, NavigationMenuLinkState,
     NavigationMenuProps, navigation_menu, navigation_menu_content, navigation_menu_item,
     navigation_menu_link, navigation_menu_list, navigation_menu_trigger,
 };
+pub use number_input::{
+    NumberFormat, NumberInputProps, NumberInputResponse, NumberUnit, NumberValue, format_number,
+    number_input, parse_number,
+};
+pub use overlay::{
+    OverlayKind, OverlayLayer, is_topmost_overlay, overlay_accepts_outside_click, overlay_stack,
+    register_overlay, topmost_overlay,
+};
 pub use pagination::{
-    OnPageChange, PaginationLinkProps, PaginationProps, pagination, pagination_content,
-    pagination_ellipsis, pagination_item, pagination_link, pagination_next, pagination_previous,
+    OnPageChange, PageItem, PaginationControlsProps, PaginationLinkProps, PaginationProps,
+    pagination, pagination_content, pagination_controls, pagination_ellipsis, pagination_item,
+    pagination_items, pagination_link, pagination_next, pagination_previous,
 };
 pub use popover::{
-    PopoverAlign, PopoverCollisionPadding, PopoverPlacement, PopoverPortalContainer, PopoverProps,
-    PopoverSide, PopoverSticky, PopoverUpdatePositionStrategy, popover,
+    PopoverAlign, PopoverAnchor, PopoverCollisionPadding, PopoverPlacement, PopoverPortalContainer,
+    PopoverProps, PopoverSide, PopoverSticky, PopoverUpdatePositionStrategy, is_anchor_detached,
+    popover,
 };
 pub use progress::{ProgressProps, ProgressSize, ProgressVariant, progress};
 pub use radio::{
@@ -171,7 +216,12 @@ pub use radio::{
 };
 pub use resizable::{
     ResizableContext, ResizableDirection, ResizableHandleProps, ResizablePanelGroupProps,
-    ResizablePanelProps, resizable_handle, resizable_panel, resizable_panel_group,
+    ResizablePanelProps, adjust_layout, resizable_handle, resizable_panel, resizable_panel_group,
+    validate_layout,
+};
+pub use router::{
+    Route, Router, router_breadcrumb, router_navigation_menu_link, router_sidebar_menu_button,
+    router_tabs,
 };
 pub use scroll_area::{
     ScrollAreaColors, ScrollAreaDir, ScrollAreaProps, ScrollAreaRadius, ScrollAreaSize,
@@ -186,22 +236,32 @@ pub use select::{
 };
 pub use separator::{SeparatorOrientation, SeparatorProps, SeparatorSize, separator};
 pub use sheet::{
-    SheetContext, SheetProps, SheetSide, sheet, sheet_content, sheet_description, sheet_footer,
-    sheet_header, sheet_title, sheet_trigger,
+    SheetContext, SheetGeometry, SheetProps, SheetSide, resolve_sheet_snap, set_sheet_geometry,
+    sheet, sheet_content, sheet_description, sheet_footer, sheet_geometry, sheet_header,
+    sheet_title, sheet_trigger,
 };
 pub use sidebar::{
-    SidebarContext, SidebarGroupLabelProps, SidebarGroupProps, SidebarMenuButtonProps,
-    SidebarMenuButtonSize, SidebarProps, SidebarProviderProps, SidebarResponse, SidebarSide,
-    sidebar, sidebar_content, sidebar_footer, sidebar_group, sidebar_group_content,
-    sidebar_group_label, sidebar_header, sidebar_menu, sidebar_menu_button, sidebar_menu_item,
-    sidebar_provider, sidebar_trigger,
+    SidebarCollapsibleProps, SidebarCollapsibleResponse, SidebarContext, SidebarGroupLabelProps,
+    SidebarGroupProps, SidebarMenuActionProps, SidebarMenuButtonProps, SidebarMenuButtonSize,
+    SidebarProps, SidebarProviderProps, SidebarResponse, SidebarSide, SidebarVariant, sidebar,
+    sidebar_content, sidebar_footer, sidebar_group, sidebar_group_collapsible,
+    sidebar_group_content, sidebar_group_label, sidebar_header, sidebar_inset, sidebar_menu,
+    sidebar_menu_action, sidebar_menu_badge, sidebar_menu_button, sidebar_menu_collapsible,
+    sidebar_menu_item, sidebar_menu_sub, sidebar_provider, sidebar_rail, sidebar_trigger,
 };
 pub use skeleton::{SkeletonProps, skeleton, skeleton_text};
 pub use slider::{
     SliderOrientation, SliderProps, SliderRadius, SliderSize, SliderVariant, slider,
     slider_with_props,
 };
+pub use sortable::{
+    SortableAxis, SortableItem, SortableLocation, SortableMove, SortableProps, SortableResponse,
+    apply_sortable_move, reorder_items, sortable,
+};
 pub use spinner::{SpinnerProps, SpinnerSize, SpinnerVariant, spinner, spinner_with_content};
+pub use stepper::{
+    StepItem, StepStatus, StepperOrientation, StepperProps, StepperResponse, StepperState, stepper,
+};
 pub use switch::{
     OnCheckedChange, SwitchOptions, SwitchProps, switch, switch_with_options, switch_with_props,
 };
@@ -214,9 +274,11 @@ pub use tabs::{
     TabItem, TabsActivationMode, TabsContentForceMount, TabsDirection, TabsDirectionality,
     TabsJustify, TabsListLoop, TabsOrientation, TabsProps, TabsSize, TabsVariant, TabsWrap, tabs,
 };
+pub use tags_input::{TagsInputProps, TagsInputResponse, tags_input};
 pub use textarea::{
-    TextareaBuilder, TextareaBuilder as Textarea, TextareaProps, TextareaRadius, TextareaResize,
-    TextareaSize, TextareaStyle, TextareaVariant, textarea_with_props,
+    TextareaBuilder, TextareaBuilder as Textarea, TextareaHighlighter, TextareaProps,
+    TextareaRadius, TextareaResize, TextareaSize, TextareaStyle, TextareaVariant,
+    textarea_with_props,
 };
 pub use theme::{ControlVisuals, InputVisuals, Theme};
 pub use toast::{Toast, ToastPosition, ToastPromise, ToastVariant, Toaster};
@@ -235,8 +297,13 @@ pub use tokens::{
 pub use tooltip::{
     TooltipAlign, TooltipAnimationState, TooltipCollisionPadding, TooltipEscapeKeyDownEvent,
     TooltipOpenState, TooltipPointerDownOutsideEvent, TooltipPortalContainer, TooltipPosition,
-    TooltipProps, TooltipSide, TooltipState, TooltipSticky, TooltipStyle,
-    TooltipUpdatePositionStrategy, tooltip,
+    TooltipProps, TooltipProviderProps, TooltipSide, TooltipState, TooltipSticky, TooltipStyle,
+    TooltipUpdatePositionStrategy, current_tooltip_provider, set_tooltips_enabled, tooltip,
+    tooltip_provider, tooltips_enabled,
+};
+pub use tree_view::{
+    TreeMove, TreeNode, TreeSelectionMode, TreeViewProps, TreeViewResponse, TreeViewState,
+    apply_tree_move, tree_view,
 };
 pub use typography::{
     BlockquoteProps, CodeProps, CodeVariant, HeadingAs, HeadingProps, KbdProps, LinkProps,
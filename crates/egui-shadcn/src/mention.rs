@@ -0,0 +1,243 @@
+//! Mentions and slash commands for [`crate::textarea`].
+//!
+//! Typing a trigger character such as `@` or `/` at the start of a word opens a suggestion list
+//! at the caret, rendered with the [`crate::command`] list. Arrow keys move through it, Enter or
+//! Tab inserts `trigger + label` and Escape closes it. Every insertion is recorded as a
+//! [`Mention`] whose byte range is kept in sync while the text is edited, so the host can store
+//! structured references next to the plain text.
+//!
+//! # Example
+//! ```ignore
+//! let people = [MentionItem::new("u1", "alice"), MentionItem::new("u2", "bob")];
+//! Textarea::new("message")
+//!     .mentions(TextareaMentions::new(&mut mentions).trigger(MentionTrigger::new('@', &people)))
+//!     .show(ui, &theme, &mut message);
+//! ```
+
+use lucide_icons::Icon;
+use std::ops::Range;
+
+#[derive(Clone, Debug)]
+pub struct MentionItem {
+    pub id: String,
+
+    /// Inserted after the trigger character and shown in the list.
+    pub label: String,
+
+    pub keywords: Vec<String>,
+
+    pub icon: Option<Icon>,
+}
+
+impl MentionItem {
+    pub fn new(id: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            label: label.into(),
+            keywords: Vec::new(),
+            icon: None,
+        }
+    }
+
+    pub fn keywords(mut self, keywords: &[&str]) -> Self {
+        self.keywords = keywords.iter().map(|k| k.to_string()).collect();
+        self
+    }
+
+    pub fn icon(mut self, icon: Icon) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct MentionTrigger<'a> {
+    pub trigger: char,
+
+    pub items: &'a [MentionItem],
+
+    pub heading: Option<String>,
+
+    /// Only open when the trigger is the first character of a line, as for slash commands.
+    pub line_start: bool,
+}
+
+impl<'a> MentionTrigger<'a> {
+    pub fn new(trigger: char, items: &'a [MentionItem]) -> Self {
+        Self {
+            trigger,
+            items,
+            heading: None,
+            line_start: false,
+        }
+    }
+
+    pub fn heading(mut self, heading: impl Into<String>) -> Self {
+        self.heading = Some(heading.into());
+        self
+    }
+
+    pub fn line_start(mut self, line_start: bool) -> Self {
+        self.line_start = line_start;
+        self
+    }
+}
+
+/// An inserted mention. `range` is the byte range of `trigger + label` in the text.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Mention {
+    pub trigger: char,
+    pub id: String,
+    pub label: String,
+    pub range: Range<usize>,
+}
+
+impl Mention {
+    pub fn token(&self) -> String {
+        format!("{}{}", self.trigger, self.label)
+    }
+}
+
+/// A trigger being typed: `range` spans the trigger character up to the caret.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MentionQuery {
+    pub trigger: char,
+    pub range: Range<usize>,
+    pub query: String,
+}
+
+#[derive(Debug)]
+pub struct TextareaMentions<'a> {
+    pub triggers: Vec<MentionTrigger<'a>>,
+
+    pub mentions: &'a mut Vec<Mention>,
+
+    pub empty_text: &'a str,
+
+    pub width: f32,
+}
+
+impl<'a> TextareaMentions<'a> {
+    pub fn new(mentions: &'a mut Vec<Mention>) -> Self {
+        Self {
+            triggers: Vec::new(),
+            mentions,
+            empty_text: "No results found.",
+            width: 220.0,
+        }
+    }
+
+    pub fn trigger(mut self, trigger: MentionTrigger<'a>) -> Self {
+        self.triggers.push(trigger);
+        self
+    }
+
+    pub fn empty_text(mut self, text: &'a str) -> Self {
+        self.empty_text = text;
+        self
+    }
+
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = width;
+        self
+    }
+}
+
+/// Finds the trigger the caret (a byte index) is in. The trigger has to start the text or
+/// follow whitespace, and the query between it and the caret must not contain whitespace.
+pub fn mention_query(
+    text: &str,
+    cursor: usize,
+    triggers: &[MentionTrigger<'_>],
+) -> Option<MentionQuery> {
+    let before = text.get(..cursor)?;
+    let (start, trigger) = before
+        .char_indices()
+        .rev()
+        .take_while(|(_, ch)| !ch.is_whitespace())
+        .find(|(_, ch)| triggers.iter().any(|t| t.trigger == *ch))?;
+    let preceding = before[..start].chars().next_back();
+    if preceding.is_some_and(|ch| !ch.is_whitespace()) {
+        return None;
+    }
+    let config = triggers.iter().find(|t| t.trigger == trigger)?;
+    if config.line_start && preceding.is_some_and(|ch| ch != '\n') {
+        return None;
+    }
+    let query = &before[start + trigger.len_utf8()..];
+    if query
+        .chars()
+        .any(|ch| triggers.iter().any(|t| t.trigger == ch))
+    {
+        return None;
+    }
+    Some(MentionQuery {
+        trigger,
+        range: start..cursor,
+        query: query.to_string(),
+    })
+}
+
+/// Shifts mention ranges after `previous` was edited into `text`, dropping mentions the edit
+/// touched or whose text no longer matches.
+pub fn sync_mentions(previous: &str, text: &str, mentions: &mut Vec<Mention>) {
+    if previous == text {
+        return;
+    }
+    let prefix = previous
+        .char_indices()
+        .zip(text.chars())
+        .find(|((_, a), b)| a != b)
+        .map_or(previous.len().min(text.len()), |((index, _), _)| index);
+    let suffix = previous[prefix..]
+        .chars()
+        .rev()
+        .zip(text[prefix..].chars().rev())
+        .take_while(|(a, b)| a == b)
+        .map(|(a, _)| a.len_utf8())
+        .sum::<usize>();
+    let edited_end = previous.len() - suffix;
+    let delta = text.len() as isize - previous.len() as isize;
+
+    mentions.retain_mut(|mention| {
+        if mention.range.end <= prefix {
+            // Untouched, before the edit.
+        } else if mention.range.start >= edited_end {
+            mention.range.start = (mention.range.start as isize + delta) as usize;
+            mention.range.end = (mention.range.end as isize + delta) as usize;
+        } else {
+            return false;
+        }
+        text.get(mention.range.clone()) == Some(mention.token().as_str())
+    });
+}
+
+/// Replaces the typed query with `trigger + label` and a trailing space, records the mention
+/// and returns the byte index for the caret.
+pub fn insert_mention(
+    text: &mut String,
+    query: &MentionQuery,
+    item: &MentionItem,
+    mentions: &mut Vec<Mention>,
+) -> usize {
+    let previous = text.clone();
+    let token = format!("{}{}", query.trigger, item.label);
+    let needs_space = !text[query.range.end..].starts_with(' ');
+    let replacement = if needs_space {
+        format!("{token} ")
+    } else {
+        token.clone()
+    };
+    text.replace_range(query.range.clone(), &replacement);
+    sync_mentions(&previous, text, mentions);
+
+    let start = query.range.start;
+    mentions.push(Mention {
+        trigger: query.trigger,
+        id: item.id.clone(),
+        label: item.label.clone(),
+        range: start..start + token.len(),
+    });
+    mentions.sort_by_key(|mention| mention.range.start);
+    start + token.len() + 1
+}
@@ -0,0 +1,288 @@
+//! Small painter-drawn charts for cards and table cells: [`sparkline`], [`mini_bar`] and
+//! [`mini_area`]. They need no plotting backend, use the palette's `chart_*` colors and show
+//! hovered values in a [`ChartTooltip`].
+//!
+//! # Example
+//! ```ignore
+//! sparkline(ui, &theme, MiniChartProps::new(Id::new("revenue"), &values).label("Revenue"));
+//! ```
+
+use crate::chart_primitives::{ChartTooltip, ChartTooltipItem, format_chart_value};
+use crate::theme::Theme;
+use egui::epaint::{Mesh, PathShape};
+use egui::{Color32, Id, Pos2, Rect, Response, Sense, Stroke, Ui, Vec2, pos2, vec2};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum MiniChartKind {
+    Line,
+    Bar,
+    Area,
+}
+
+#[derive(Clone, Debug)]
+pub struct MiniChartProps<'a> {
+    pub id_source: Id,
+    pub values: &'a [f64],
+    pub size: Vec2,
+
+    /// Defaults to `chart_1`.
+    pub color: Option<Color32>,
+
+    /// Series name shown in the tooltip.
+    pub label: &'a str,
+
+    /// Category names, used as the tooltip title.
+    pub categories: Option<&'a [&'a str]>,
+
+    /// Value range mapped to the height. Defaults to the data range; bars and areas always
+    /// include zero.
+    pub range: Option<(f64, f64)>,
+
+    pub stroke_width: f32,
+    pub show_tooltip: bool,
+}
+
+impl<'a> MiniChartProps<'a> {
+    pub fn new(id_source: Id, values: &'a [f64]) -> Self {
+        Self {
+            id_source,
+            values,
+            size: vec2(120.0, 32.0),
+            color: None,
+            label: "Value",
+            categories: None,
+            range: None,
+            stroke_width: 1.5,
+            show_tooltip: true,
+        }
+    }
+
+    pub fn size(mut self, size: Vec2) -> Self {
+        self.size = size;
+        self
+    }
+
+    pub fn color(mut self, color: Color32) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    pub fn label(mut self, label: &'a str) -> Self {
+        self.label = label;
+        self
+    }
+
+    pub fn categories(mut self, categories: &'a [&'a str]) -> Self {
+        self.categories = Some(categories);
+        self
+    }
+
+    pub fn range(mut self, min: f64, max: f64) -> Self {
+        self.range = Some((min, max));
+        self
+    }
+
+    pub fn stroke_width(mut self, width: f32) -> Self {
+        self.stroke_width = width;
+        self
+    }
+
+    pub fn show_tooltip(mut self, show: bool) -> Self {
+        self.show_tooltip = show;
+        self
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct MiniChartResponse {
+    pub response: Response,
+
+    /// Index of the value under the pointer.
+    pub hovered_index: Option<usize>,
+}
+
+/// A line without axes, marking the last value with a dot.
+pub fn sparkline(ui: &mut Ui, theme: &Theme, props: MiniChartProps<'_>) -> MiniChartResponse {
+    mini_chart(ui, theme, props, MiniChartKind::Line)
+}
+
+/// One bar per value; the hovered bar stays opaque while the others fade.
+pub fn mini_bar(ui: &mut Ui, theme: &Theme, props: MiniChartProps<'_>) -> MiniChartResponse {
+    mini_chart(ui, theme, props, MiniChartKind::Bar)
+}
+
+/// A line over a fill that fades towards the baseline.
+pub fn mini_area(ui: &mut Ui, theme: &Theme, props: MiniChartProps<'_>) -> MiniChartResponse {
+    mini_chart(ui, theme, props, MiniChartKind::Area)
+}
+
+fn mini_chart(
+    ui: &mut Ui,
+    theme: &Theme,
+    props: MiniChartProps<'_>,
+    kind: MiniChartKind,
+) -> MiniChartResponse {
+    let (rect, response) = ui.allocate_exact_size(props.size, Sense::hover());
+    let values = props.values;
+    let color = props.color.unwrap_or(theme.palette.chart_1);
+    // Keep line strokes and markers inside the rect.
+    let plot = if kind == MiniChartKind::Bar {
+        rect
+    } else {
+        rect.shrink(props.stroke_width + 2.5)
+    };
+    let hovered_index = response
+        .hover_pos()
+        .and_then(|pos| hovered_index(kind, plot, values.len(), pos.x));
+
+    if values.is_empty() || !ui.is_rect_visible(rect) {
+        return MiniChartResponse {
+            response,
+            hovered_index,
+        };
+    }
+
+    let (min, max) = props.range.unwrap_or_else(|| value_range(values, kind));
+    let y = |value: f64| {
+        let t = if max > min {
+            ((value - min) / (max - min)).clamp(0.0, 1.0)
+        } else {
+            0.5
+        };
+        plot.bottom() - t as f32 * plot.height()
+    };
+    let painter = ui.painter_at(rect);
+
+    match kind {
+        MiniChartKind::Bar => {
+            let slot = plot.width() / values.len() as f32;
+            let width = (slot * 0.7).max(1.0);
+            let base = y(0.0_f64.clamp(min, max));
+            for (index, value) in values.iter().enumerate() {
+                let x = plot.left() + slot * (index as f32 + 0.5);
+                let top = y(*value);
+                let bar = Rect::from_x_y_ranges(
+                    x - width / 2.0..=x + width / 2.0,
+                    top.min(base)..=top.max(base),
+                );
+                let fill = match hovered_index {
+                    Some(hovered) if hovered != index => color.gamma_multiply(0.5),
+                    _ => color,
+                };
+                painter.rect_filled(bar, theme.radius.r1, fill);
+            }
+        }
+        MiniChartKind::Line | MiniChartKind::Area => {
+            let points: Vec<Pos2> = values
+                .iter()
+                .enumerate()
+                .map(|(index, value)| pos2(line_x(plot, values.len(), index), y(*value)))
+                .collect();
+            if kind == MiniChartKind::Area {
+                let base = y(0.0_f64.clamp(min, max));
+                painter.add(area_mesh(&points, base, color));
+            }
+            painter.add(PathShape::line(
+                points.clone(),
+                Stroke::new(props.stroke_width, color),
+            ));
+            let marker = hovered_index.or(match kind {
+                MiniChartKind::Line => Some(points.len() - 1),
+                _ => None,
+            });
+            if let Some(index) = marker {
+                if hovered_index.is_some() {
+                    painter.vline(
+                        points[index].x,
+                        rect.y_range(),
+                        Stroke::new(1.0, theme.palette.border),
+                    );
+                }
+                painter.circle(
+                    points[index],
+                    props.stroke_width + 1.5,
+                    color,
+                    Stroke::new(1.0, theme.palette.background),
+                );
+            }
+        }
+    }
+
+    if props.show_tooltip
+        && let (Some(index), Some(pos)) = (hovered_index, response.hover_pos())
+    {
+        let items = [ChartTooltipItem {
+            label: props.label.to_string(),
+            value: format_chart_value(values[index]),
+            color,
+        }];
+        let title = props
+            .categories
+            .and_then(|categories| categories.get(index).copied());
+        let mut tooltip = ChartTooltip::new(&items);
+        if let Some(title) = title {
+            tooltip = tooltip.title(title);
+        }
+        tooltip.show(
+            ui.ctx(),
+            theme,
+            props.id_source.with("mini-chart-tooltip"),
+            pos,
+        );
+    }
+
+    MiniChartResponse {
+        response,
+        hovered_index,
+    }
+}
+
+fn value_range(values: &[f64], kind: MiniChartKind) -> (f64, f64) {
+    let (mut min, mut max) = values
+        .iter()
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), value| {
+            (min.min(*value), max.max(*value))
+        });
+    if kind != MiniChartKind::Line {
+        min = min.min(0.0);
+        max = max.max(0.0);
+    }
+    (min, max)
+}
+
+fn line_x(rect: Rect, len: usize, index: usize) -> f32 {
+    if len <= 1 {
+        rect.center().x
+    } else {
+        rect.left() + rect.width() * index as f32 / (len - 1) as f32
+    }
+}
+
+fn hovered_index(kind: MiniChartKind, rect: Rect, len: usize, x: f32) -> Option<usize> {
+    if len == 0 {
+        return None;
+    }
+    let t = ((x - rect.left()) / rect.width()).clamp(0.0, 1.0);
+    let index = match kind {
+        MiniChartKind::Bar => (t * len as f32).floor() as usize,
+        MiniChartKind::Line | MiniChartKind::Area => (t * (len - 1) as f32).round() as usize,
+    };
+    Some(index.min(len - 1))
+}
+
+/// Fill between the line and the baseline, strongest at the line.
+fn area_mesh(points: &[Pos2], base: f32, color: Color32) -> Mesh {
+    let mut mesh = Mesh::default();
+    let top = color.gamma_multiply(0.4);
+    let bottom = color.gamma_multiply(0.05);
+    for point in points {
+        mesh.colored_vertex(*point, top);
+        mesh.colored_vertex(pos2(point.x, base), bottom);
+    }
+    for index in 1..points.len() as u32 {
+        let (a, b) = (2 * (index - 1), 2 * index);
+        mesh.add_triangle(a, a + 1, b);
+        mesh.add_triangle(a + 1, b + 1, b);
+    }
+    mesh
+}
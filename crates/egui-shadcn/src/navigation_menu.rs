@@ -1,4 +1,9 @@
 //! Navigation Menu component - top navigation with dropdown content.
+//!
+//! All items share one viewport that resizes smoothly when the pointer or keyboard moves
+//! between triggers, while an indicator slides under the open trigger. Arrow keys, Home and
+//! End move between triggers and top-level links; ArrowDown moves into the open content and
+//! Escape closes it again.
 
 use crate::icons::icon_chevrons_up_down;
 use crate::popover::{
@@ -8,12 +13,15 @@ use crate::popover::{
 use crate::theme::Theme;
 use crate::{Button, ButtonSize, ButtonVariant};
 use egui::{
-    Color32, CornerRadius, Frame, Id, Margin, Order, Rect, Response, Sense, Stroke, Ui, UiBuilder,
-    vec2,
+    Color32, Context, CornerRadius, FocusDirection, Frame, Id, Key, Margin, Order, Rect, Response,
+    Sense, Stroke, Ui, UiBuilder, Vec2, vec2,
 };
 
 const DEFAULT_CONTENT_WIDTH: f32 = 320.0;
 const DEFAULT_CONTENT_MAX_HEIGHT: f32 = 360.0;
+const NAVIGATION_MENU_STACK_KEY: &str = "egui_shadcn_navigation_menu_stack";
+// Rate of the exponential approach used by the viewport and the indicator.
+const MOTION_SPEED: f32 = 18.0;
 
 #[derive(Clone, Copy, Debug)]
 pub struct NavigationMenuProps {
@@ -22,6 +30,15 @@ pub struct NavigationMenuProps {
     pub indicator_width: f32,
     pub indicator_height: f32,
     pub indicator_offset: f32,
+
+    /// How long the pointer has to rest on a trigger before its content opens.
+    pub delay_ms: u64,
+
+    /// Once content has closed, other triggers open without the delay for this long.
+    pub skip_delay_ms: u64,
+
+    /// Animates the viewport between contents and slides the indicator between triggers.
+    pub animate: bool,
 }
 
 impl NavigationMenuProps {
@@ -32,9 +49,27 @@ impl NavigationMenuProps {
             indicator_width: 24.0,
             indicator_height: 2.0,
             indicator_offset: 2.0,
+            delay_ms: 200,
+            skip_delay_ms: 300,
+            animate: true,
         }
     }
 
+    pub fn delay_ms(mut self, delay_ms: u64) -> Self {
+        self.delay_ms = delay_ms;
+        self
+    }
+
+    pub fn skip_delay_ms(mut self, skip_delay_ms: u64) -> Self {
+        self.skip_delay_ms = skip_delay_ms;
+        self
+    }
+
+    pub fn animate(mut self, animate: bool) -> Self {
+        self.animate = animate;
+        self
+    }
+
     pub fn item_gap(mut self, item_gap: f32) -> Self {
         self.item_gap = item_gap;
         self
@@ -63,6 +98,9 @@ pub struct NavigationMenuContext {
     pub indicator_width: f32,
     pub indicator_height: f32,
     pub indicator_offset: f32,
+    pub delay_ms: u64,
+    pub skip_delay_ms: u64,
+    pub animate: bool,
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -215,16 +253,129 @@ struct NavigationMenuState {
     menu_hovered: bool,
     content_hovered: bool,
     hover_bounds: Option<Rect>,
+
+    /// Keyboard focus is on the open trigger or inside its content.
+    focus_within: bool,
+    hovered_trigger: Option<Id>,
+
+    /// Trigger the pointer is resting on and when it arrived, while the open delay runs.
+    pending: Option<(Id, f64)>,
+    last_closed: Option<f64>,
+
+    /// Set by Escape so the trigger under the pointer doesn't reopen until it is left.
+    suppress_hover: bool,
+
+    /// Focus the first focusable of the open content on the next frame.
+    focus_content: bool,
+
+    /// Rects currently drawn, easing towards their targets.
+    indicator: Option<Rect>,
+    viewport: Option<Rect>,
+}
+
+/// Focusable widgets in the menu bar or in one content, in layout order, with the item each
+/// one opens.
+#[derive(Clone, Default)]
+struct NavigationMenuScope {
+    focusables: Vec<(Id, Option<Id>)>,
+}
+
+struct NavigationMenuScopeGuard {
+    ctx: Context,
+    scope_id: Id,
+}
+
+impl NavigationMenuScopeGuard {
+    fn new(ctx: &Context, scope_id: Id) -> Self {
+        ctx.data_mut(|data| {
+            let stack_id = Id::new(NAVIGATION_MENU_STACK_KEY);
+            let mut stack = data.get_temp::<Vec<Id>>(stack_id).unwrap_or_default();
+            stack.push(scope_id);
+            data.insert_temp(stack_id, stack);
+            data.insert_temp(nav_scope_id(scope_id), NavigationMenuScope::default());
+        });
+        Self {
+            ctx: ctx.clone(),
+            scope_id,
+        }
+    }
+
+    fn focusables(&self) -> Vec<(Id, Option<Id>)> {
+        self.ctx.data(|data| {
+            data.get_temp::<NavigationMenuScope>(nav_scope_id(self.scope_id))
+                .map(|scope| scope.focusables)
+                .unwrap_or_default()
+        })
+    }
+}
+
+impl Drop for NavigationMenuScopeGuard {
+    fn drop(&mut self) {
+        self.ctx.data_mut(|data| {
+            let stack_id = Id::new(NAVIGATION_MENU_STACK_KEY);
+            if let Some(mut stack) = data.get_temp::<Vec<Id>>(stack_id)
+                && stack.last().copied() == Some(self.scope_id)
+            {
+                stack.pop();
+                data.insert_temp(stack_id, stack);
+            }
+        });
+    }
 }
 
 fn nav_state_id(id_source: Id) -> Id {
     id_source.with("navigation-menu-state")
 }
 
+fn nav_scope_id(scope_id: Id) -> Id {
+    scope_id.with("navigation-menu-scope")
+}
+
 fn trigger_rect_id(item_id: Id) -> Id {
     item_id.with("navigation-menu-trigger-rect")
 }
 
+fn content_size_id(content_id: Id) -> Id {
+    content_id.with("navigation-menu-content-size")
+}
+
+fn register_focusable(ctx: &Context, response: &Response, item_id: Option<Id>) {
+    ctx.data_mut(|data| {
+        let Some(scope_id) = data
+            .get_temp::<Vec<Id>>(Id::new(NAVIGATION_MENU_STACK_KEY))
+            .and_then(|stack| stack.last().copied())
+        else {
+            return;
+        };
+        let mut scope = data
+            .get_temp::<NavigationMenuScope>(nav_scope_id(scope_id))
+            .unwrap_or_default();
+        scope.focusables.push((response.id, item_id));
+        data.insert_temp(nav_scope_id(scope_id), scope);
+    });
+}
+
+/// Moves focus to `id`, overriding egui's own spatial arrow-key navigation for this frame.
+fn move_focus_to(ctx: &Context, id: Id) {
+    ctx.memory_mut(|m| {
+        m.request_focus(id);
+        m.move_focus(FocusDirection::None);
+    });
+}
+
+fn approach(current: Rect, target: Rect, dt: f32) -> Rect {
+    let t = 1.0 - (-MOTION_SPEED * dt).exp();
+    let next = Rect::from_min_max(
+        current.min.lerp(target.min, t),
+        current.max.lerp(target.max, t),
+    );
+    if (next.min - target.min).length() < 0.5 && (next.max - target.max).length() < 0.5 {
+        target
+    } else {
+        next
+    }
+}
+
 pub fn navigation_menu<R>(
     ui: &mut Ui,
     theme: &Theme,
@@ -237,6 +388,9 @@ pub fn navigation_menu<R>(
         indicator_width: props.indicator_width,
         indicator_height: props.indicator_height,
         indicator_offset: props.indicator_offset,
+        delay_ms: props.delay_ms,
+        skip_delay_ms: props.skip_delay_ms,
+        animate: props.animate,
     };
 
     let state_id = nav_state_id(ctx.id_source);
@@ -248,15 +402,25 @@ pub fn navigation_menu<R>(
         state.content_hovered = false;
         state.indicator_rect = None;
         state.hover_bounds = None;
+        state.focus_within = false;
+        state.hovered_trigger = None;
         d.insert_temp(state_id, state);
     });
 
+    let scope = NavigationMenuScopeGuard::new(ui.ctx(), ctx.id_source);
     let inner = add_contents(ui, &ctx);
+    let focusables = scope.focusables();
+    drop(scope);
 
     let mut state = ui
         .ctx()
         .data(|d| d.get_temp::<NavigationMenuState>(state_id))
         .unwrap_or_default();
+    let was_open = state.open_item;
+    let (now, dt) = ui.input(|i| (i.time, i.stable_dt.min(0.1)));
+
+    handle_menu_keys(ui.ctx(), &mut state, &focusables);
+    update_hover_intent(ui.ctx(), &ctx, &mut state, now);
 
     let pointer_pos = ui.ctx().input(|i| i.pointer.hover_pos());
     let pointer_in_bounds = pointer_pos.is_some_and(|pos| {
@@ -264,28 +428,167 @@ pub fn navigation_menu<R>(
             .hover_bounds
             .is_some_and(|bounds| bounds.contains(pos))
     });
-    if !state.menu_hovered && !state.content_hovered && !pointer_in_bounds {
+    if state.open_item == was_open
+        && !state.menu_hovered
+        && !state.content_hovered
+        && !state.focus_within
+        && !pointer_in_bounds
+    {
         state.open_item = None;
     }
+    if state.open_item.is_none() {
+        state.viewport = None;
+        if was_open.is_some() {
+            state.last_closed = Some(now);
+        }
+    }
+    if state.open_item != was_open {
+        ui.ctx().request_repaint();
+    }
+
+    paint_indicator(ui, theme, &ctx, &mut state, dt);
+
+    ui.ctx().data_mut(|d| d.insert_temp(state_id, state));
+
+    inner
+}
 
-    if let Some(rect) = state.indicator_rect {
-        let indicator_rect = Rect::from_center_size(
+/// Arrow keys, Home and End move between the menu's triggers and links (following the open
+/// content while one is open), ArrowDown enters a trigger's content and Escape closes it.
+fn handle_menu_keys(
+    ctx: &Context,
+    state: &mut NavigationMenuState,
+    focusables: &[(Id, Option<Id>)],
+) {
+    // egui drops focus on Escape before widgets run, so this can't depend on focus.
+    if let Some(open) = state.open_item
+        && ctx.input(|i| i.key_pressed(Key::Escape))
+    {
+        state.open_item = None;
+        state.suppress_hover = true;
+        if let Some((trigger, _)) = focusables.iter().find(|(_, item)| *item == Some(open)) {
+            move_focus_to(ctx, *trigger);
+        }
+        return;
+    }
+
+    let focused = ctx.memory(|m| m.focused());
+    let Some(index) = focused.and_then(|id| focusables.iter().position(|(f, _)| *f == id)) else {
+        return;
+    };
+    let last = focusables.len() - 1;
+    let target = ctx.input_mut(|i| {
+        if i.consume_key(egui::Modifiers::NONE, Key::ArrowRight) {
+            Some(if index == last { 0 } else { index + 1 })
+        } else if i.consume_key(egui::Modifiers::NONE, Key::ArrowLeft) {
+            Some(if index == 0 { last } else { index - 1 })
+        } else if i.consume_key(egui::Modifiers::NONE, Key::Home) {
+            Some(0)
+        } else if i.consume_key(egui::Modifiers::NONE, Key::End) {
+            Some(last)
+        } else {
+            None
+        }
+    });
+    if let Some(target) = target {
+        move_focus_to(ctx, focusables[target].0);
+        if state.open_item.is_some() {
+            state.open_item = focusables[target].1;
+        }
+        return;
+    }
+
+    if let Some(item) = focusables[index].1
+        && ctx.input_mut(|i| i.consume_key(egui::Modifiers::NONE, Key::ArrowDown))
+    {
+        state.open_item = Some(item);
+        state.focus_content = true;
+        ctx.memory_mut(|m| m.move_focus(FocusDirection::None));
+    }
+}
+
+/// Opens the hovered trigger once the pointer has rested on it for the delay, or right away
+/// shortly after other content closed.
+fn update_hover_intent(
+    ctx: &Context,
+    menu: &NavigationMenuContext,
+    state: &mut NavigationMenuState,
+    now: f64,
+) {
+    let Some(item) = state.hovered_trigger else {
+        state.pending = None;
+        state.suppress_hover = false;
+        return;
+    };
+    if state.open_item.is_some() || state.suppress_hover {
+        state.pending = None;
+        return;
+    }
+
+    let delay = menu.delay_ms as f64 / 1000.0;
+    let skip = state
+        .last_closed
+        .is_some_and(|closed| now - closed < menu.skip_delay_ms as f64 / 1000.0);
+    let start = match state.pending {
+        Some((pending, start)) if pending == item => start,
+        _ => now,
+    };
+    if skip || now - start >= delay {
+        state.open_item = Some(item);
+        state.pending = None;
+    } else {
+        state.pending = Some((item, start));
+        ctx.request_repaint_after_secs((delay - (now - start)) as f32);
+    }
+}
+
+fn paint_indicator(
+    ui: &Ui,
+    theme: &Theme,
+    ctx: &NavigationMenuContext,
+    state: &mut NavigationMenuState,
+    dt: f32,
+) {
+    let target = state.indicator_rect.map(|rect| {
+        Rect::from_center_size(
             egui::pos2(
                 rect.center().x,
                 rect.bottom() + ctx.indicator_offset + ctx.indicator_height / 2.0,
             ),
             vec2(ctx.indicator_width, ctx.indicator_height),
-        );
+        )
+    });
+    let opacity = if ctx.animate {
+        ui.ctx().animate_bool_with_time(
+            ctx.id_source.with("navigation-menu-indicator"),
+            target.is_some(),
+            0.15,
+        )
+    } else if target.is_some() {
+        1.0
+    } else {
+        0.0
+    };
+
+    // Keep the last rect while fading out; the next open starts from scratch.
+    state.indicator = match (state.indicator, target) {
+        (_, None) if opacity <= 0.0 => None,
+        (current, None) => current,
+        (Some(current), Some(target)) if ctx.animate => Some(approach(current, target, dt)),
+        (_, Some(target)) => Some(target),
+    };
+    let settling = target.is_some() && state.indicator != target;
+    if settling || (opacity > 0.0 && opacity < 1.0) {
+        ui.ctx().request_repaint();
+    }
+
+    if let Some(rect) = state.indicator {
         ui.painter().rect_filled(
-            indicator_rect,
+            rect,
             CornerRadius::same(ctx.indicator_height.round() as u8),
-            theme.palette.border,
+            theme.palette.border.gamma_multiply(opacity),
         );
     }
-
-    ui.ctx().data_mut(|d| d.insert_temp(state_id, state));
-
-    inner
 }
 
 pub fn navigation_menu_list<R>(
@@ -327,21 +630,34 @@ pub fn navigation_menu_trigger(
         .size(ButtonSize::Sm)
         .trailing_icon(&icon_chevrons_up_down)
         .show(ui, theme);
+    register_focusable(ui.ctx(), &response, Some(item_ctx.item_id));
 
     let state_id = nav_state_id(ctx.id_source);
-    let hovered = response.hovered() || response.has_focus();
+    let hovered = response.hovered();
+    let focused = response.has_focus();
     let clicked = response.clicked();
     ui.ctx().data_mut(|d| {
         d.insert_temp(trigger_rect_id(item_ctx.item_id), response.rect);
         let mut state = d
             .get_temp::<NavigationMenuState>(state_id)
             .unwrap_or_default();
-        if hovered || clicked {
+        if hovered {
+            state.hovered_trigger = Some(item_ctx.item_id);
+            // Moving between triggers while content is open switches it without a delay.
+            if state.open_item.is_some() && !state.suppress_hover {
+                state.open_item = Some(item_ctx.item_id);
+            }
+        }
+        if clicked {
             state.open_item = Some(item_ctx.item_id);
+            state.pending = None;
+        }
+        if hovered || focused || clicked {
             state.menu_hovered = true;
         }
         if state.open_item == Some(item_ctx.item_id) {
             state.indicator_rect = Some(response.rect);
+            state.focus_within |= focused;
         }
         d.insert_temp(state_id, state);
     });
@@ -374,6 +690,14 @@ pub fn navigation_menu_content<R>(
     let width = props.width.unwrap_or(DEFAULT_CONTENT_WIDTH);
     let max_height = props.max_height.unwrap_or(DEFAULT_CONTENT_MAX_HEIGHT);
     let boundary = ui.ctx().available_rect();
+    let content_id = item_ctx.item_id.with("content");
+    // Measured on the previous frame. Content that was never shown keeps the current viewport
+    // for a frame, or grows from nothing when the menu was closed.
+    let measured = ui
+        .ctx()
+        .data(|d| d.get_temp::<Vec2>(content_size_id(content_id)))
+        .or(state.viewport.map(|viewport| viewport.size()))
+        .unwrap_or(vec2(width, 0.0));
 
     let (position_rect, _computed_side) = compute_popover_rect_with_collision(
         trigger_rect,
@@ -382,39 +706,61 @@ pub fn navigation_menu_content<R>(
         props.align,
         props.side_offset,
         props.align_offset,
-        width,
-        max_height,
+        measured.x,
+        measured.y.min(max_height),
         true,
         PopoverCollisionPadding::default(),
         PopoverSticky::default(),
     );
 
-    let mut popup_rect = position_rect;
-    let mut inner: Option<R> = None;
-    let content_id = item_ctx.item_id.with("content");
+    // The viewport is shared by all items, easing between the rects of successive contents.
+    let dt = ui.input(|i| i.stable_dt.min(0.1));
+    let viewport = match state.viewport {
+        Some(current) if ctx.animate => approach(current, position_rect, dt),
+        _ => position_rect,
+    };
+    if viewport != position_rect {
+        ui.ctx().request_repaint();
+    }
 
+    let mut inner: Option<R> = None;
+    let scope = NavigationMenuScopeGuard::new(ui.ctx(), content_id);
     egui::Area::new(content_id)
         .order(Order::Tooltip)
         .interactable(true)
         .movable(false)
-        .fixed_pos(position_rect.min)
+        .fixed_pos(viewport.min)
         .show(ui.ctx(), |popup_ui| {
             popup_ui.visuals_mut().override_text_color = Some(palette.popover_foreground);
             popup_ui.set_min_width(width);
-            popup_ui.set_max_height(position_rect.height());
+            popup_ui.set_max_height(max_height);
 
             let frame = Frame::popup(popup_ui.style())
                 .fill(palette.popover)
                 .stroke(Stroke::new(1.0, palette.border))
-                .corner_radius(CornerRadius::same(theme.radius.r3.round() as u8))
-                .inner_margin(props.padding);
-
-            let frame_resp = frame.show(popup_ui, |content_ui| {
-                inner = Some(add_contents(content_ui));
-            });
-            popup_rect = frame_resp.response.rect;
+                .corner_radius(CornerRadius::same(theme.radius.r3.round() as u8));
+            popup_ui
+                .painter()
+                .add(frame.paint(viewport.shrink(frame.stroke.width)));
+            popup_ui.set_clip_rect(viewport.intersect(popup_ui.clip_rect()));
+
+            let frame_resp = Frame::NONE
+                .inner_margin(props.padding)
+                .outer_margin(Margin::same(frame.stroke.width.round() as i8))
+                .show(popup_ui, |content_ui| {
+                    inner = Some(add_contents(content_ui));
+                });
+            let size = frame_resp.response.rect.size();
+            popup_ui
+                .ctx()
+                .data_mut(|d| d.insert_temp(content_size_id(content_id), size));
         });
+    let focusables = scope.focusables();
+    drop(scope);
+    let focus_within =
+        handle_content_keys(ui.ctx(), state.focus_content, &focusables, ctx, item_ctx);
 
+    let popup_rect = viewport;
     let expanded_rect = popup_rect.expand(4.0);
     let content_hovered = ui
         .ctx()
@@ -427,6 +773,9 @@ pub fn navigation_menu_content<R>(
             .unwrap_or_default();
         state.content_hovered = content_hovered;
         state.hover_bounds = Some(trigger_rect.union(popup_rect).expand(6.0));
+        state.viewport = Some(viewport);
+        state.focus_within |= focus_within;
+        state.focus_content = false;
         if content_hovered {
             state.menu_hovered = true;
         }
@@ -436,6 +785,50 @@ pub fn navigation_menu_content<R>(
     inner
 }
 
+/// ArrowDown/ArrowUp move between the content's focusables; ArrowUp from the first one returns
+/// to the trigger. Returns whether focus is inside the content.
+fn handle_content_keys(
+    ctx: &Context,
+    focus_first: bool,
+    focusables: &[(Id, Option<Id>)],
+    menu: &NavigationMenuContext,
+    item_ctx: &NavigationMenuItemContext,
+) -> bool {
+    if focus_first && let Some((first, _)) = focusables.first() {
+        move_focus_to(ctx, *first);
+        return true;
+    }
+
+    let focused = ctx.memory(|m| m.focused());
+    let Some(index) = focused.and_then(|id| focusables.iter().position(|(f, _)| *f == id)) else {
+        return false;
+    };
+    let (down, up) = ctx.input_mut(|i| {
+        (
+            i.consume_key(egui::Modifiers::NONE, Key::ArrowDown),
+            i.consume_key(egui::Modifiers::NONE, Key::ArrowUp),
+        )
+    });
+    if down {
+        move_focus_to(ctx, focusables[(index + 1).min(focusables.len() - 1)].0);
+    } else if up && index > 0 {
+        move_focus_to(ctx, focusables[index - 1].0);
+    } else if up {
+        let menu_focusables = ctx.data(|data| {
+            data.get_temp::<NavigationMenuScope>(nav_scope_id(menu.id_source))
+                .map(|scope| scope.focusables)
+                .unwrap_or_default()
+        });
+        if let Some((trigger, _)) = menu_focusables
+            .iter()
+            .find(|(_, item)| *item == Some(item_ctx.item_id))
+        {
+            move_focus_to(ctx, *trigger);
+        }
+    }
+    true
+}
+
 pub fn navigation_menu_link<R>(
     ui: &mut Ui,
     theme: &Theme,
@@ -451,6 +844,9 @@ pub fn navigation_menu_link<R>(
         Sense::click()
     };
     let (rect, response) = ui.allocate_exact_size(vec2(desired_width, desired_height), sense);
+    if !props.disabled {
+        register_focusable(ui.ctx(), &response, None);
+    }
     let hovered = response.hovered() || response.has_focus();
 
     let fill = if hovered && !props.disabled {
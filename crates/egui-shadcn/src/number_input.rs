@@ -0,0 +1,606 @@
+//! Numeric input built on [`crate::input`], with stepper buttons, keyboard/wheel increments and
+//! locale-aware formatting.
+//!
+//! While focused the field shows a plain editable number; the text is parsed when the field
+//! loses focus (or on Enter) and the value is either clamped to `min..=max` or flagged invalid.
+
+use crate::icons::icon_chevrons_up_down;
+use crate::input::{InputProps, InputSize, InputVariant, input_frame};
+use crate::theme::Theme;
+use egui::text::{CCursor, CCursorRange};
+use egui::{Key, Modifiers, Rect, Response, Sense, TextEdit, Ui, pos2, vec2};
+use std::fmt::Debug;
+use std::hash::Hash;
+
+/// Values a [`number_input`] can edit.
+pub trait NumberValue: Copy + PartialEq {
+    /// Integers round parsed and stepped values and never show decimals.
+    const INTEGER: bool;
+
+    fn to_f64(self) -> f64;
+
+    fn from_f64(value: f64) -> Self;
+}
+
+macro_rules! impl_number_value {
+    ($integer:expr => $($ty:ty),*) => {
+        $(
+            impl NumberValue for $ty {
+                const INTEGER: bool = $integer;
+
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+
+                fn from_f64(value: f64) -> Self {
+                    value as $ty
+                }
+            }
+        )*
+    };
+}
+
+impl_number_value!(false => f32, f64);
+impl_number_value!(true => i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+#[derive(Clone, Debug, PartialEq, Default)]
+pub enum NumberUnit {
+    #[default]
+    None,
+
+    /// Currency symbol shown before the number, e.g. `"$"`.
+    Currency(String),
+
+    /// Shows a `%` after the number. The value itself is not scaled.
+    Percent,
+
+    /// Arbitrary suffix such as `" kg"`.
+    Suffix(String),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct NumberFormat {
+    /// Fixed number of decimals; `None` shows as many as the value needs.
+    pub decimals: Option<usize>,
+    pub thousands_separator: Option<char>,
+    pub decimal_separator: char,
+    pub unit: NumberUnit,
+}
+
+impl Default for NumberFormat {
+    fn default() -> Self {
+        Self {
+            decimals: None,
+            thousands_separator: None,
+            decimal_separator: '.',
+            unit: NumberUnit::None,
+        }
+    }
+}
+
+impl NumberFormat {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Separators for a BCP 47 language tag such as `"en-US"` or `"de-DE"`. Unknown tags fall
+    /// back to English conventions.
+    pub fn locale(locale: &str) -> Self {
+        let language = locale
+            .split(['-', '_'])
+            .next()
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+        let region = locale
+            .split(['-', '_'])
+            .nth(1)
+            .unwrap_or_default()
+            .to_ascii_uppercase();
+        let (thousands, decimal) = match (language.as_str(), region.as_str()) {
+            (_, "CH") | ("rm", _) => ('\'', '.'),
+            ("fr" | "ru" | "pl" | "cs" | "sv" | "fi" | "nb" | "uk", _) => ('\u{202f}', ','),
+            ("de" | "es" | "it" | "pt" | "nl" | "da" | "id" | "tr", _) => ('.', ','),
+            _ => (',', '.'),
+        };
+        Self {
+            thousands_separator: Some(thousands),
+            decimal_separator: decimal,
+            ..Self::default()
+        }
+    }
+
+    pub fn decimals(mut self, decimals: usize) -> Self {
+        self.decimals = Some(decimals);
+        self
+    }
+
+    pub fn thousands_separator(mut self, separator: char) -> Self {
+        self.thousands_separator = Some(separator);
+        self
+    }
+
+    pub fn decimal_separator(mut self, separator: char) -> Self {
+        self.decimal_separator = separator;
+        self
+    }
+
+    pub fn currency(mut self, symbol: impl Into<String>) -> Self {
+        self.unit = NumberUnit::Currency(symbol.into());
+        self
+    }
+
+    pub fn percent(mut self) -> Self {
+        self.unit = NumberUnit::Percent;
+        self
+    }
+
+    pub fn suffix(mut self, suffix: impl Into<String>) -> Self {
+        self.unit = NumberUnit::Suffix(suffix.into());
+        self
+    }
+
+    /// The form used while the field is being edited: same decimals, no grouping or unit.
+    fn editing(&self) -> Self {
+        Self {
+            decimals: self.decimals,
+            thousands_separator: None,
+            decimal_separator: self.decimal_separator,
+            unit: NumberUnit::None,
+        }
+    }
+}
+
+pub fn format_number(value: f64, format: &NumberFormat) -> String {
+    if !value.is_finite() {
+        return String::new();
+    }
+    let plain = match format.decimals {
+        Some(decimals) => format!("{:.*}", decimals, value.abs()),
+        // Round away float noise such as 0.1 + 0.2 before printing the shortest form.
+        None => format!("{}", (value.abs() * 1e9).round() / 1e9),
+    };
+    let (int_part, frac_part) = match plain.split_once('.') {
+        Some((int_part, frac_part)) => (int_part, Some(frac_part)),
+        None => (plain.as_str(), None),
+    };
+
+    let mut out = String::new();
+    let negative = value < 0.0 && plain.chars().any(|c| c.is_ascii_digit() && c != '0');
+    if negative {
+        out.push('-');
+    }
+    if let NumberUnit::Currency(symbol) = &format.unit {
+        out.push_str(symbol);
+    }
+    match format.thousands_separator {
+        Some(separator) => {
+            let len = int_part.len();
+            for (idx, digit) in int_part.chars().enumerate() {
+                if idx > 0 && (len - idx) % 3 == 0 {
+                    out.push(separator);
+                }
+                out.push(digit);
+            }
+        }
+        None => out.push_str(int_part),
+    }
+    if let Some(frac_part) = frac_part {
+        out.push(format.decimal_separator);
+        out.push_str(frac_part);
+    }
+    match &format.unit {
+        NumberUnit::Percent => out.push('%'),
+        NumberUnit::Suffix(suffix) => out.push_str(suffix),
+        NumberUnit::None | NumberUnit::Currency(_) => {}
+    }
+    out
+}
+
+/// Parses text in either the displayed or the editing form of `format`.
+pub fn parse_number(text: &str, format: &NumberFormat) -> Option<f64> {
+    let mut text = text.trim();
+    match &format.unit {
+        NumberUnit::Percent => text = text.trim_end_matches('%'),
+        NumberUnit::Suffix(suffix) => {
+            text = text.strip_suffix(suffix.trim()).unwrap_or(text);
+        }
+        NumberUnit::None | NumberUnit::Currency(_) => {}
+    }
+    let mut normalized = String::with_capacity(text.len());
+    for c in text.chars() {
+        if c == format.decimal_separator {
+            normalized.push('.');
+        } else if c.is_ascii_digit() || c == '-' || c == '+' {
+            normalized.push(c);
+        } else if Some(c) == format.thousands_separator || c.is_whitespace() {
+            continue;
+        } else if let NumberUnit::Currency(symbol) = &format.unit
+            && symbol.contains(c)
+        {
+            continue;
+        } else {
+            return None;
+        }
+    }
+    normalized
+        .parse::<f64>()
+        .ok()
+        .filter(|value| value.is_finite())
+}
+
+pub struct NumberInputProps<'a, Id, T>
+where
+    Id: Hash + Debug,
+    T: NumberValue,
+{
+    pub id_source: Id,
+
+    pub value: &'a mut T,
+
+    pub min: Option<f64>,
+
+    pub max: Option<f64>,
+
+    pub step: f64,
+
+    /// Multiplier applied to `step` while Shift is held.
+    pub large_step_factor: f64,
+
+    pub format: NumberFormat,
+
+    /// Clamp out-of-range values on commit; when false they are kept and flagged invalid.
+    pub clamp: bool,
+
+    pub show_stepper: bool,
+
+    /// Step with the mouse wheel while the field is focused and hovered.
+    pub wheel_step: bool,
+
+    pub placeholder: &'a str,
+
+    pub variant: InputVariant,
+
+    pub size: InputSize,
+
+    pub enabled: bool,
+
+    pub read_only: bool,
+
+    pub is_invalid: bool,
+
+    pub width: Option<f32>,
+}
+
+impl<'a, Id: Hash + Debug, T: NumberValue> NumberInputProps<'a, Id, T> {
+    pub fn new(id_source: Id, value: &'a mut T) -> Self {
+        Self {
+            id_source,
+            value,
+            min: None,
+            max: None,
+            step: 1.0,
+            large_step_factor: 10.0,
+            format: NumberFormat::default(),
+            clamp: true,
+            show_stepper: true,
+            wheel_step: true,
+            placeholder: "",
+            variant: InputVariant::Surface,
+            size: InputSize::Size2,
+            enabled: true,
+            read_only: false,
+            is_invalid: false,
+            width: None,
+        }
+    }
+
+    pub fn min(mut self, min: f64) -> Self {
+        self.min = Some(min);
+        self
+    }
+
+    pub fn max(mut self, max: f64) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    pub fn range(self, min: f64, max: f64) -> Self {
+        self.min(min).max(max)
+    }
+
+    pub fn step(mut self, step: f64) -> Self {
+        self.step = step.abs();
+        self
+    }
+
+    pub fn large_step_factor(mut self, factor: f64) -> Self {
+        self.large_step_factor = factor.abs();
+        self
+    }
+
+    pub fn format(mut self, format: NumberFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    pub fn clamp(mut self, clamp: bool) -> Self {
+        self.clamp = clamp;
+        self
+    }
+
+    pub fn show_stepper(mut self, show_stepper: bool) -> Self {
+        self.show_stepper = show_stepper;
+        self
+    }
+
+    pub fn wheel_step(mut self, wheel_step: bool) -> Self {
+        self.wheel_step = wheel_step;
+        self
+    }
+
+    pub fn placeholder(mut self, placeholder: &'a str) -> Self {
+        self.placeholder = placeholder;
+        self
+    }
+
+    pub fn variant(mut self, variant: InputVariant) -> Self {
+        self.variant = variant;
+        self
+    }
+
+    pub fn size(mut self, size: InputSize) -> Self {
+        self.size = size;
+        self
+    }
+
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    pub fn read_only(mut self, read_only: bool) -> Self {
+        self.read_only = read_only;
+        self
+    }
+
+    pub fn invalid(mut self, is_invalid: bool) -> Self {
+        self.is_invalid = is_invalid;
+        self
+    }
+
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    fn clamp_value(&self, value: f64) -> f64 {
+        let value = value
+            .max(self.min.unwrap_or(f64::NEG_INFINITY))
+            .min(self.max.unwrap_or(f64::INFINITY));
+        if T::INTEGER { value.round() } else { value }
+    }
+
+    fn in_range(&self, value: f64) -> bool {
+        self.min.is_none_or(|min| value >= min) && self.max.is_none_or(|max| value <= max)
+    }
+
+    fn display_format(&self) -> NumberFormat {
+        let mut format = self.format.clone();
+        if T::INTEGER {
+            format.decimals = Some(0);
+        }
+        format
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+struct NumberInputState {
+    text: String,
+    editing: bool,
+    invalid: bool,
+    synced: Option<f64>,
+}
+
+#[derive(Clone, Debug)]
+pub struct NumberInputResponse {
+    pub response: Response,
+    /// The last committed text failed to parse or was out of range.
+    pub invalid: bool,
+}
+
+pub fn number_input<Id, T>(
+    ui: &mut Ui,
+    theme: &Theme,
+    props: NumberInputProps<'_, Id, T>,
+) -> NumberInputResponse
+where
+    Id: Hash + Debug,
+    T: NumberValue,
+{
+    let id = ui.make_persistent_id(&props.id_source);
+    let edit_id = id.with("edit");
+    let state_id = id.with("number-state");
+    let mut state = ui
+        .ctx()
+        .data(|d| d.get_temp::<NumberInputState>(state_id))
+        .unwrap_or_default();
+    let display_format = props.display_format();
+    let interactive = props.enabled && !props.read_only;
+    let focused = ui.memory(|m| m.has_focus(edit_id));
+
+    let mut value = props.value.to_f64();
+    let mut changed = false;
+
+    // Arrow keys would otherwise move the text cursor, so take them before the text edit runs.
+    let mut steps = 0.0;
+    if focused && interactive {
+        steps += ui.input_mut(|i| {
+            // Shift first: the unmodified pattern would also match Shift+Arrow.
+            let mut steps = 0.0;
+            if i.consume_key(Modifiers::SHIFT, Key::ArrowUp) {
+                steps += props.large_step_factor;
+            }
+            if i.consume_key(Modifiers::SHIFT, Key::ArrowDown) {
+                steps -= props.large_step_factor;
+            }
+            if i.consume_key(Modifiers::NONE, Key::ArrowUp) {
+                steps += 1.0;
+            }
+            if i.consume_key(Modifiers::NONE, Key::ArrowDown) {
+                steps -= 1.0;
+            }
+            steps
+        });
+    }
+
+    if !state.editing {
+        // The host changed the value since the last frame; drop any stale invalid text.
+        if state.synced != Some(value) {
+            state.invalid = false;
+        }
+        if !state.invalid || parse_number(&state.text, &display_format).is_some() {
+            state.text = format_number(value, &display_format);
+        }
+    }
+    let mut text = state.text.clone();
+
+    let size = props.size;
+    let stepper_color = theme.palette.accent;
+    let mut input = InputProps::new(&props.id_source, &mut text)
+        .placeholder(props.placeholder)
+        .variant(props.variant)
+        .size(size)
+        .enabled(props.enabled)
+        .read_only(props.read_only)
+        .invalid(props.is_invalid || state.invalid);
+    if let Some(width) = props.width {
+        input = input.width(width);
+    }
+    if props.show_stepper {
+        input = input.right_slot(move |painter, rect, color| {
+            icon_chevrons_up_down(painter, rect.center(), rect.width(), color);
+        });
+    }
+    let (frame_rect, mut response) = input_frame(ui, theme, input);
+
+    if props.show_stepper && interactive {
+        let slot_width = size.slot_icon_size() + size.slot_gap() * 2.0;
+        let column = Rect::from_min_max(
+            pos2(frame_rect.right() - slot_width, frame_rect.top()),
+            frame_rect.max,
+        );
+        let halves = [
+            (
+                Rect::from_min_max(column.min, pos2(column.right(), column.center().y)),
+                1.0,
+            ),
+            (
+                Rect::from_min_max(pos2(column.left(), column.center().y), column.max),
+                -1.0,
+            ),
+        ];
+        for (idx, (rect, direction)) in halves.into_iter().enumerate() {
+            let button = ui.interact(rect, id.with(("stepper", idx)), Sense::CLICK);
+            if button.hovered() {
+                ui.painter().rect_filled(
+                    rect.shrink2(vec2(2.0, 1.0)),
+                    2.0,
+                    stepper_color.gamma_multiply(0.5),
+                );
+                ui.ctx().set_cursor_icon(egui::CursorIcon::PointingHand);
+            }
+            if button.clicked() {
+                let factor = if ui.input(|i| i.modifiers.shift) {
+                    props.large_step_factor
+                } else {
+                    1.0
+                };
+                steps += direction * factor;
+                ui.memory_mut(|m| m.request_focus(edit_id));
+            }
+        }
+    }
+
+    if props.wheel_step && focused && interactive && ui.rect_contains_pointer(frame_rect) {
+        let scroll = ui.input(|i| i.raw_scroll_delta.y);
+        if scroll != 0.0 {
+            steps += scroll.signum() as f64;
+            ui.input_mut(|i| {
+                i.raw_scroll_delta = egui::Vec2::ZERO;
+                i.smooth_scroll_delta = egui::Vec2::ZERO;
+            });
+        }
+    }
+
+    let has_focus = ui.memory(|m| m.has_focus(edit_id));
+    let commit = response.lost_focus() || (has_focus && ui.input(|i| i.key_pressed(Key::Enter)));
+
+    if steps != 0.0 {
+        // Step from the text being edited so a typed value is not lost.
+        let base = parse_number(&text, &display_format).unwrap_or(value);
+        let base = if props.step > 0.0 {
+            let origin = props.min.unwrap_or(0.0);
+            origin + ((base - origin) / props.step).round() * props.step
+        } else {
+            base
+        };
+        let next = props.clamp_value(base + steps * props.step);
+        if next != value {
+            value = next;
+            changed = true;
+        }
+        state.invalid = false;
+        text = format_number(value, &display_format.editing());
+    } else if commit {
+        match parse_number(&text, &display_format) {
+            Some(parsed) => {
+                let committed = if props.clamp {
+                    props.clamp_value(parsed)
+                } else if T::INTEGER {
+                    parsed.round()
+                } else {
+                    parsed
+                };
+                state.invalid = !props.in_range(committed);
+                if committed != value {
+                    value = committed;
+                    changed = true;
+                }
+            }
+            None => state.invalid = true,
+        }
+    }
+
+    if has_focus {
+        if !state.editing {
+            text = format_number(value, &display_format.editing());
+            // The cursor was placed in the displayed text; keep it valid in the editing text.
+            if let Some(mut edit_state) = TextEdit::load_state(ui.ctx(), edit_id) {
+                let end = CCursor::new(text.chars().count());
+                edit_state
+                    .cursor
+                    .set_char_range(Some(CCursorRange::one(end)));
+                edit_state.store(ui.ctx(), edit_id);
+            }
+        }
+        state.editing = true;
+        state.text = text;
+    } else {
+        state.editing = false;
+        // Unparsable text stays visible so the user can see what was rejected.
+        state.text = if state.invalid && parse_number(&text, &display_format).is_none() {
+            text
+        } else {
+            format_number(value, &display_format)
+        };
+    }
+    state.synced = Some(value);
+    let invalid = state.invalid;
+    ui.ctx().data_mut(|d| d.insert_temp(state_id, state));
+
+    if changed {
+        *props.value = T::from_f64(value);
+        response.mark_changed();
+    }
+    NumberInputResponse { response, invalid }
+}
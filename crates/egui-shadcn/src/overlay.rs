@@ -0,0 +1,177 @@
+//! Overlay stack shared by dialogs, sheets, popovers, menus, hover cards and tooltips.
+//!
+//! Every open overlay registers itself once per frame. Registrations are committed at the next
+//! pass, in the order the overlays were opened, so a nested overlay always sits above the one
+//! that opened it. Dismissal (Escape, pointer-down outside) is only honoured by the topmost
+//! layer, and the topmost modal layer becomes egui's modal layer, which blocks focus and pointer
+//! interaction for everything underneath it. Dialogs and sheets also cover the screen with their
+//! backdrop, which is what keeps the content behind them from scrolling.
+//!
+//! # Example
+//! ```ignore
+//! register_overlay(ctx, OverlayLayer::new(id, OverlayKind::Popover, layer_id));
+//! if escape_pressed && is_topmost_overlay(ctx, id) {
+//!     open = false;
+//! }
+//! ```
+
+use egui::{Context, Id, LayerId};
+
+const OVERLAY_STACK_KEY: &str = "egui_shadcn_overlay_stack";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OverlayKind {
+    Dialog,
+    Sheet,
+    Popover,
+    Menu,
+    HoverCard,
+    Tooltip,
+}
+
+impl OverlayKind {
+    /// Hover-driven layers never block dismissal of the layers beneath them.
+    pub fn is_transient(self) -> bool {
+        matches!(self, OverlayKind::HoverCard | OverlayKind::Tooltip)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct OverlayLayer {
+    pub id: Id,
+    pub kind: OverlayKind,
+    pub layer_id: LayerId,
+    pub modal: bool,
+}
+
+impl OverlayLayer {
+    pub fn new(id: Id, kind: OverlayKind, layer_id: LayerId) -> Self {
+        Self {
+            id,
+            kind,
+            layer_id,
+            modal: false,
+        }
+    }
+
+    pub fn modal(mut self, modal: bool) -> Self {
+        self.modal = modal;
+        self
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+struct OverlayEntry {
+    seq: u64,
+    layer: OverlayLayer,
+}
+
+#[derive(Clone, Debug, Default)]
+struct OverlayStackState {
+    pass: u64,
+    next_seq: u64,
+    committed: Vec<OverlayEntry>,
+    current: Vec<OverlayEntry>,
+}
+
+impl OverlayStackState {
+    fn roll_over(&mut self, pass: u64) {
+        if self.pass == pass {
+            return;
+        }
+        let mut current = std::mem::take(&mut self.current);
+        current.sort_by_key(|entry| entry.seq);
+        // A gap of more than one pass means nothing registered in between.
+        self.committed = if pass == self.pass + 1 {
+            current
+        } else {
+            Vec::new()
+        };
+        self.pass = pass;
+    }
+
+    fn seq_for(&mut self, id: Id) -> u64 {
+        let existing = self
+            .current
+            .iter()
+            .chain(self.committed.iter())
+            .find(|entry| entry.layer.id == id)
+            .map(|entry| entry.seq);
+        existing.unwrap_or_else(|| {
+            self.next_seq += 1;
+            self.next_seq
+        })
+    }
+}
+
+fn overlay_stack_id() -> Id {
+    Id::new(OVERLAY_STACK_KEY)
+}
+
+fn with_stack<R>(ctx: &Context, f: impl FnOnce(&mut OverlayStackState) -> R) -> R {
+    let pass = ctx.cumulative_pass_nr();
+    ctx.data_mut(|data| {
+        let state = data.get_temp_mut_or_default::<OverlayStackState>(overlay_stack_id());
+        state.roll_over(pass);
+        f(state)
+    })
+}
+
+/// Registers an open overlay for this frame. Call it every frame the overlay is visible.
+pub fn register_overlay(ctx: &Context, layer: OverlayLayer) {
+    with_stack(ctx, |state| {
+        if let Some(entry) = state
+            .current
+            .iter_mut()
+            .find(|entry| entry.layer.id == layer.id)
+        {
+            entry.layer = layer;
+            return;
+        }
+        let seq = state.seq_for(layer.id);
+        state.current.push(OverlayEntry { seq, layer });
+    });
+    if layer.modal {
+        ctx.memory_mut(|m| m.set_modal_layer(layer.layer_id));
+    }
+}
+
+/// Overlays that were open last frame, bottom to top.
+pub fn overlay_stack(ctx: &Context) -> Vec<OverlayLayer> {
+    with_stack(ctx, |state| {
+        state.committed.iter().map(|entry| entry.layer).collect()
+    })
+}
+
+pub fn topmost_overlay(ctx: &Context) -> Option<OverlayLayer> {
+    overlay_stack(ctx).last().copied()
+}
+
+/// Whether `id` should react to Escape. Overlays that have not registered yet count as topmost;
+/// an overlay stops being topmost as soon as one opened after it registers, even this frame.
+pub fn is_topmost_overlay(ctx: &Context, id: Id) -> bool {
+    with_stack(ctx, |state| {
+        let mut entries = state.committed.iter().chain(state.current.iter());
+        let Some(own) = entries.clone().find(|entry| entry.layer.id == id) else {
+            return true;
+        };
+        !entries.any(|entry| entry.seq > own.seq)
+    })
+}
+
+/// Whether a pointer-down outside `id` should dismiss it. Tooltips and hover cards above the
+/// layer are ignored, so they never keep a popover or dialog from closing.
+pub fn overlay_accepts_outside_click(ctx: &Context, id: Id) -> bool {
+    let stack = overlay_stack(ctx);
+    let Some(own) = stack.iter().find(|layer| layer.id == id) else {
+        return true;
+    };
+    if own.kind.is_transient() {
+        return true;
+    }
+    stack
+        .iter()
+        .rev()
+        .find(|layer| !layer.kind.is_transient())
+        .is_some_and(|layer| layer.id == id)
+}
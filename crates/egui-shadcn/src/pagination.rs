@@ -1,8 +1,14 @@
 //! Pagination component - page navigation controls.
 
 use crate::button::{Button, ButtonSize, ButtonVariant};
+use crate::input::Input;
+use crate::select::{SelectPropsSimple, select};
 use crate::theme::Theme;
-use egui::{Color32, Painter, Pos2, Response, Sense, Stroke, Ui, Vec2, WidgetText, pos2, vec2};
+use crate::tokens::ControlSize;
+use egui::{
+    Color32, Id, Key, Painter, Pos2, Response, RichText, Sense, Stroke, Ui, Vec2, WidgetText, pos2,
+    vec2,
+};
 use std::fmt::{self, Debug};
 
 pub struct OnPageChange<'a>(pub Box<dyn FnMut(usize) + 'a>);
@@ -71,6 +77,47 @@ impl PaginationLinkProps {
     }
 }
 
+/// One slot of a page list.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PageItem {
+    Page(usize),
+    Ellipsis,
+}
+
+/// Lays out the pages for `current` out of `total`: `boundaries` pages at each end and
+/// `siblings` pages on each side of the current one, with ellipses for the gaps.
+pub fn pagination_items(
+    current: usize,
+    total: usize,
+    siblings: usize,
+    boundaries: usize,
+) -> Vec<PageItem> {
+    let boundaries = boundaries.max(1);
+    if total <= 2 * boundaries + 2 * siblings + 3 {
+        return (1..=total).map(PageItem::Page).collect();
+    }
+
+    let current = current.clamp(1, total);
+    let (start, end) = if current <= boundaries + siblings + 1 {
+        (boundaries + 1, boundaries + 2 * siblings + 1)
+    } else if current >= total - boundaries - siblings {
+        (total - boundaries - 2 * siblings, total - boundaries)
+    } else {
+        (current - siblings, current + siblings)
+    };
+
+    let mut items: Vec<PageItem> = (1..=boundaries).map(PageItem::Page).collect();
+    if start > boundaries + 1 {
+        items.push(PageItem::Ellipsis);
+    }
+    items.extend((start..=end).map(PageItem::Page));
+    if end < total - boundaries {
+        items.push(PageItem::Ellipsis);
+    }
+    items.extend((total - boundaries + 1..=total).map(PageItem::Page));
+    items
+}
+
 pub fn pagination<R>(
     ui: &mut Ui,
     mut props: PaginationProps<'_>,
@@ -188,6 +235,228 @@ pub fn pagination_ellipsis(ui: &mut Ui, theme: &Theme) -> Response {
     response
 }
 
+#[derive(Debug)]
+pub struct PaginationControlsProps<'a> {
+    pub id_source: Id,
+    pub total_pages: usize,
+    pub current_page: &'a mut usize,
+
+    /// Pages shown on each side of the current one.
+    pub siblings: usize,
+
+    /// Pages always shown at the start and at the end.
+    pub boundaries: usize,
+
+    /// Shows an input that jumps to the typed page on Enter.
+    pub show_jump: bool,
+
+    /// Shows a page-size select when set. Changing the size keeps the first row of the current
+    /// page in view; `total_pages` is expected to follow on the next frame.
+    pub page_size: Option<&'a mut usize>,
+    pub page_size_options: &'a [usize],
+    pub page_size_label: &'a str,
+    pub on_page_change: Option<OnPageChange<'a>>,
+}
+
+impl<'a> PaginationControlsProps<'a> {
+    pub fn new(id_source: Id, total_pages: usize, current_page: &'a mut usize) -> Self {
+        Self {
+            id_source,
+            total_pages,
+            current_page,
+            siblings: 1,
+            boundaries: 1,
+            show_jump: false,
+            page_size: None,
+            page_size_options: &[],
+            page_size_label: "Rows per page",
+            on_page_change: None,
+        }
+    }
+
+    pub fn siblings(mut self, siblings: usize) -> Self {
+        self.siblings = siblings;
+        self
+    }
+
+    pub fn boundaries(mut self, boundaries: usize) -> Self {
+        self.boundaries = boundaries;
+        self
+    }
+
+    pub fn show_jump(mut self, show_jump: bool) -> Self {
+        self.show_jump = show_jump;
+        self
+    }
+
+    pub fn page_size(mut self, page_size: &'a mut usize, options: &'a [usize]) -> Self {
+        self.page_size = Some(page_size);
+        self.page_size_options = options;
+        self
+    }
+
+    pub fn page_size_label(mut self, label: &'a str) -> Self {
+        self.page_size_label = label;
+        self
+    }
+
+    pub fn with_on_page_change(mut self, callback: impl FnMut(usize) + 'a) -> Self {
+        self.on_page_change = Some(OnPageChange(Box::new(callback)));
+        self
+    }
+}
+
+/// Previous/next buttons around the page list from [`pagination_items`], with an optional
+/// page-size select and "go to page" input. The response is marked changed when the page or
+/// the page size changed.
+pub fn pagination_controls(
+    ui: &mut Ui,
+    theme: &Theme,
+    props: PaginationControlsProps<'_>,
+) -> Response {
+    let PaginationControlsProps {
+        id_source,
+        total_pages,
+        current_page,
+        siblings,
+        boundaries,
+        show_jump,
+        page_size,
+        page_size_options,
+        page_size_label,
+        on_page_change,
+    } = props;
+    let mut changed = false;
+    let mut pagination_props = PaginationProps::new(total_pages, current_page);
+    pagination_props.on_page_change = on_page_change;
+
+    let mut response = ui
+        .horizontal(|ui| {
+            if let Some(page_size) = page_size {
+                changed |= page_size_select(
+                    ui,
+                    theme,
+                    id_source.with("page-size"),
+                    page_size_label,
+                    page_size,
+                    page_size_options,
+                    &mut pagination_props,
+                );
+                ui.add_space(12.0);
+            }
+
+            let before = *pagination_props.current_page;
+            pagination(ui, pagination_props, |ui, props| {
+                pagination_content(ui, |ui| {
+                    let total = props.total_pages.max(1);
+                    pagination_item(ui, |ui| pagination_previous(ui, theme, props));
+                    for item in pagination_items(*props.current_page, total, siblings, boundaries) {
+                        match item {
+                            PageItem::Page(page) => {
+                                pagination_item(ui, |ui| {
+                                    pagination_link(
+                                        ui,
+                                        theme,
+                                        props,
+                                        PaginationLinkProps::new(page, page.to_string()),
+                                    )
+                                });
+                            }
+                            PageItem::Ellipsis => {
+                                pagination_item(ui, |ui| pagination_ellipsis(ui, theme));
+                            }
+                        }
+                    }
+                    pagination_item(ui, |ui| pagination_next(ui, theme, props));
+                });
+
+                if show_jump {
+                    ui.add_space(12.0);
+                    page_jump(ui, theme, id_source.with("jump"), props);
+                }
+                changed |= *props.current_page != before;
+            });
+        })
+        .response;
+
+    if changed {
+        response.mark_changed();
+    }
+    response
+}
+
+fn page_size_select(
+    ui: &mut Ui,
+    theme: &Theme,
+    id: Id,
+    label: &str,
+    page_size: &mut usize,
+    options: &[usize],
+    props: &mut PaginationProps<'_>,
+) -> bool {
+    ui.label(
+        RichText::new(label)
+            .size(13.0)
+            .color(theme.palette.muted_foreground),
+    );
+    let option_labels: Vec<String> = options.iter().map(usize::to_string).collect();
+    let mut selected = Some(page_size.to_string());
+    let _ = select(
+        ui,
+        theme,
+        SelectPropsSimple {
+            id_source: id,
+            selected: &mut selected,
+            options: &option_labels,
+            placeholder: "",
+            size: ControlSize::Sm,
+            enabled: true,
+            is_invalid: false,
+        },
+    );
+
+    let Some(new_size) = selected
+        .and_then(|value| value.parse::<usize>().ok())
+        .filter(|size| *size > 0 && *size != *page_size)
+    else {
+        return false;
+    };
+    // The page count still reflects the old size, so this skips `set_page`'s clamping.
+    let first_row = (*props.current_page).saturating_sub(1) * *page_size;
+    *page_size = new_size;
+    let page = first_row / new_size + 1;
+    if page != *props.current_page {
+        *props.current_page = page;
+        if let Some(callback) = props.on_page_change.as_mut() {
+            (callback.0)(page);
+        }
+    }
+    true
+}
+
+fn page_jump(ui: &mut Ui, theme: &Theme, id: Id, props: &mut PaginationProps<'_>) {
+    ui.label(
+        RichText::new("Go to")
+            .size(13.0)
+            .color(theme.palette.muted_foreground),
+    );
+    let mut text = ui
+        .ctx()
+        .data(|d| d.get_temp::<String>(id))
+        .unwrap_or_default();
+    let response = Input::new(id)
+        .placeholder("Page")
+        .width(64.0)
+        .show(ui, theme, &mut text);
+    if response.lost_focus() && ui.input(|i| i.key_pressed(Key::Enter)) {
+        if let Ok(page) = text.trim().parse::<usize>() {
+            set_page(props, page);
+        }
+        text.clear();
+    }
+    ui.ctx().data_mut(|d| d.insert_temp(id, text));
+}
+
 fn set_page(props: &mut PaginationProps<'_>, page: usize) {
     let total_pages = props.total_pages.max(1);
     let clamped = page.clamp(1, total_pages);
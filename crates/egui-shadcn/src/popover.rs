@@ -1,6 +1,10 @@
+use crate::overlay::{
+    OverlayKind, OverlayLayer, is_topmost_overlay, overlay_accepts_outside_click, register_overlay,
+};
 use crate::theme::Theme;
 use egui::{
-    CornerRadius, Frame, Id, Margin, Order, Pos2, Rect, Response, Stroke, Ui, Vec2, pos2, vec2,
+    Context, CornerRadius, Frame, Id, LayerId, Margin, Order, Pos2, Rect, Response, Sense, Stroke,
+    Ui, Vec2, pos2, vec2,
 };
 use log::trace;
 
@@ -88,6 +92,57 @@ impl Default for PopoverCollisionPadding {
     }
 }
 
+/// What floating content is positioned against. The trigger still drives opening and closing;
+/// virtual anchors only move where the content is placed.
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub enum PopoverAnchor {
+    #[default]
+    Trigger,
+    Rect(Rect),
+    /// A zero-sized anchor, e.g. `ChartResponse::pointer_pos`.
+    Point(Pos2),
+    /// Tracks the pointer while it is over the screen and keeps the last position otherwise.
+    Cursor,
+}
+
+impl PopoverAnchor {
+    /// Rect the content is placed against this frame.
+    pub fn resolve(self, ctx: &Context, id: Id, trigger_rect: Rect) -> Rect {
+        match self {
+            PopoverAnchor::Trigger => trigger_rect,
+            PopoverAnchor::Rect(rect) => rect,
+            PopoverAnchor::Point(pos) => Rect::from_min_size(pos, Vec2::ZERO),
+            PopoverAnchor::Cursor => {
+                let key = id.with("anchor-cursor");
+                let pos = ctx
+                    .pointer_hover_pos()
+                    .or_else(|| ctx.data(|d| d.get_temp::<Pos2>(key)));
+                match pos {
+                    Some(pos) => {
+                        ctx.data_mut(|d| d.insert_temp(key, pos));
+                        Rect::from_min_size(pos, Vec2::ZERO)
+                    }
+                    None => trigger_rect,
+                }
+            }
+        }
+    }
+
+    pub fn is_virtual(self) -> bool {
+        self != PopoverAnchor::Trigger
+    }
+}
+
+/// Whether the anchor has left the collision boundary, for `hide_when_detached`.
+pub fn is_anchor_detached(boundary: Rect, anchor_rect: Rect) -> bool {
+    // `intersects` is false for a zero-sized anchor, so points are tested by containment.
+    if anchor_rect.area() > 0.0 {
+        !boundary.intersects(anchor_rect)
+    } else {
+        !boundary.contains(anchor_rect.min)
+    }
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum PopoverPortalContainer {
     Tooltip,
@@ -163,6 +218,7 @@ pub struct PopoverProps<'a> {
     pub default_open: bool,
     pub on_open_change: Option<&'a mut dyn FnMut(bool)>,
     pub modal: bool,
+    pub anchor: PopoverAnchor,
     pub side: Option<PopoverSide>,
     pub placement: PopoverPlacement,
     pub align: PopoverAlign,
@@ -198,6 +254,7 @@ impl std::fmt::Debug for PopoverProps<'_> {
             .field("open", &self.open)
             .field("default_open", &self.default_open)
             .field("modal", &self.modal)
+            .field("anchor", &self.anchor)
             .field("side", &self.side)
             .field("placement", &self.placement)
             .field("align", &self.align)
@@ -240,6 +297,7 @@ impl<'a> PopoverProps<'a> {
             default_open: false,
             on_open_change: None,
             modal: false,
+            anchor: PopoverAnchor::Trigger,
             side: None,
             placement: PopoverPlacement::Below,
             align: PopoverAlign::Center,
@@ -304,6 +362,27 @@ impl<'a> PopoverProps<'a> {
         self
     }
 
+    pub fn anchor(mut self, anchor: PopoverAnchor) -> Self {
+        self.anchor = anchor;
+        self
+    }
+
+    pub fn anchor_rect(self, rect: Rect) -> Self {
+        self.anchor(PopoverAnchor::Rect(rect))
+    }
+
+    pub fn anchor_point(self, point: Pos2) -> Self {
+        self.anchor(PopoverAnchor::Point(point))
+    }
+
+    pub fn follow_cursor(self, follow: bool) -> Self {
+        self.anchor(if follow {
+            PopoverAnchor::Cursor
+        } else {
+            PopoverAnchor::Trigger
+        })
+    }
+
     pub fn side(mut self, side: PopoverSide) -> Self {
         self.side = Some(side);
         self.placement = side.to_placement();
@@ -502,16 +581,19 @@ pub fn popover<R>(
         let bg = palette.popover.gamma_multiply(anim_t);
         let border = palette.border.gamma_multiply(anim_t);
         let rounding = CornerRadius::same(theme.radius.r3.round() as u8);
+        let anchor_rect = props
+            .anchor
+            .resolve(ctx, props.id_source, trigger_response.rect);
         let width = match (props.match_trigger_width, props.width) {
             (true, _) => trigger_response.rect.width().max(180.0),
             (false, Some(w)) => w,
-            (false, None) => trigger_response.rect.width().max(220.0),
+            (false, None) => anchor_rect.width().max(220.0),
         };
         let max_height = props.max_height.unwrap_or(320.0);
 
         let screen = ui.ctx().available_rect();
         let boundary = props.collision_boundary.unwrap_or(screen);
-        if props.hide_when_detached && !boundary.intersects(trigger_response.rect) {
+        if props.hide_when_detached && is_anchor_detached(boundary, anchor_rect) {
             ctx.data_mut(|d| d.insert_temp(state_id, *props.open));
             return (trigger_response, None);
         }
@@ -520,7 +602,7 @@ pub fn popover<R>(
             .side
             .unwrap_or_else(|| PopoverSide::from(props.placement));
         let (position_rect, computed_side) = compute_popover_rect_with_collision(
-            trigger_response.rect,
+            anchor_rect,
             boundary,
             side,
             props.align,
@@ -533,16 +615,17 @@ pub fn popover<R>(
             props.sticky,
         );
 
+        let scrim_id = props.id_source.with("scrim");
         if props.modal && is_visible {
-            let scrim_id = props.id_source.with("scrim");
             let scrim_rect = ui.ctx().available_rect();
             let _ = egui::Area::new(scrim_id)
                 .order(Order::Foreground)
                 .interactable(true)
+                .sense(Sense::CLICK)
                 .movable(false)
                 .fixed_pos(scrim_rect.min)
                 .show(ui.ctx(), |scrim_ui| {
-                    let _resp = scrim_ui.interact(scrim_rect, scrim_id, egui::Sense::click());
+                    let _resp = scrim_ui.interact(scrim_rect, scrim_id, Sense::CLICK);
                 });
         }
 
@@ -585,6 +668,19 @@ pub fn popover<R>(
                 popup_rect = frame_resp.response.rect;
             });
 
+        if *props.open {
+            let layer_id = if props.modal {
+                LayerId::new(Order::Foreground, scrim_id)
+            } else {
+                LayerId::new(order, content_id)
+            };
+            register_overlay(
+                ctx,
+                OverlayLayer::new(props.id_source, OverlayKind::Popover, layer_id)
+                    .modal(props.modal),
+            );
+        }
+
         if opened_now && props.modal {
             let mut evt = PopoverAutoFocusEvent {
                 preventable: PopoverPreventable::default(),
@@ -598,7 +694,7 @@ pub fn popover<R>(
         }
 
         let escape = ui.input(|i| i.key_pressed(egui::Key::Escape));
-        if escape && is_visible {
+        if escape && is_visible && is_topmost_overlay(ctx, props.id_source) {
             let mut evt = PopoverEscapeKeyDownEvent {
                 key: egui::Key::Escape,
                 preventable: PopoverPreventable::default(),
@@ -631,7 +727,8 @@ pub fn popover<R>(
             && pointer_any_click
             && pointer_pos
                 .map(|pos| !popup_rect.contains(pos))
-                .unwrap_or(false);
+                .unwrap_or(false)
+            && overlay_accepts_outside_click(ctx, props.id_source);
         if outside_click {
             let mut pointer_evt = PopoverPointerDownOutsideEvent {
                 pointer_pos,
@@ -6,7 +6,7 @@
 //! ```
 
 use crate::theme::Theme;
-use egui::{Color32, Ui, Vec2};
+use egui::{Color32, Ui, Vec2, WidgetInfo, WidgetType};
 
 // =============================================================================
 // ProgressSize / ProgressVariant
@@ -40,6 +40,9 @@ pub struct ProgressProps {
     pub variant: ProgressVariant,
     pub color: Option<Color32>,
     pub high_contrast: bool,
+
+    /// Announced to screen readers along with the value.
+    pub label: Option<String>,
 }
 
 impl ProgressProps {
@@ -51,6 +54,7 @@ impl ProgressProps {
             variant: ProgressVariant::Surface,
             color: None,
             high_contrast: false,
+            label: None,
         }
     }
 
@@ -78,6 +82,11 @@ impl ProgressProps {
         self.high_contrast = hc;
         self
     }
+
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
 }
 
 // =============================================================================
@@ -103,8 +112,20 @@ pub fn progress(ui: &mut Ui, theme: &Theme, props: ProgressProps) {
     let available_width = ui.available_width();
     let rounding = height / 2.0;
 
-    let (rect, _response) =
+    let (rect, response) =
         ui.allocate_exact_size(Vec2::new(available_width, height), egui::Sense::hover());
+    response.widget_info(|| {
+        let mut info = match &props.label {
+            Some(label) => {
+                WidgetInfo::labeled(WidgetType::ProgressIndicator, ui.is_enabled(), label)
+            }
+            None => WidgetInfo::new(WidgetType::ProgressIndicator),
+        };
+        info.value = props
+            .value
+            .map(|value| ((value / props.max).clamp(0.0, 1.0) * 100.0).floor() as f64);
+        info
+    });
 
     // Background
     ui.painter().rect_filled(rect, rounding, bg_color);
@@ -1,6 +1,7 @@
 //! Resizable panel component - panels with draggable resize handles.
 //!
-//! Based on react-resizable-panels API.
+//! Based on react-resizable-panels API. Panels report their constraints when shown; handles
+//! enforce them on drag and keyboard input (arrows, Home/End, Enter to toggle collapse).
 //!
 //! # Example
 //! ```ignore
@@ -13,9 +14,14 @@
 //! ```
 
 use crate::theme::Theme;
-use egui::{CursorIcon, Id, Response, Sense, Ui, Vec2};
+use egui::{
+    CornerRadius, CursorIcon, EventFilter, Id, Key, Response, Sense, Stroke, StrokeKind, Ui, Vec2,
+};
 use std::hash::Hash;
 
+const LAYOUT_EPSILON: f32 = 0.01;
+const PERSISTED_LAYOUT_KEY: &str = "egui_shadcn_resizable_layout";
+
 // =============================================================================
 // ResizableDirection
 // =============================================================================
@@ -37,7 +43,10 @@ pub enum ResizableDirection {
 pub struct ResizablePanelGroupProps<Id: Hash> {
     pub id_source: Id,
     pub direction: ResizableDirection,
+    /// Layouts are saved in egui's persisted memory under this key and restored on first show.
     pub auto_save_id: Option<String>,
+    /// Percentage moved per arrow-key press on a focused handle.
+    pub keyboard_resize_by: f32,
 }
 
 impl<IdType: Hash> ResizablePanelGroupProps<IdType> {
@@ -46,9 +55,15 @@ impl<IdType: Hash> ResizablePanelGroupProps<IdType> {
             id_source,
             direction: ResizableDirection::Horizontal,
             auto_save_id: None,
+            keyboard_resize_by: 10.0,
         }
     }
 
+    pub fn keyboard_resize_by(mut self, percent: f32) -> Self {
+        self.keyboard_resize_by = percent.clamp(0.0, 100.0);
+        self
+    }
+
     pub fn direction(mut self, direction: ResizableDirection) -> Self {
         self.direction = direction;
         self
@@ -71,6 +86,14 @@ pub struct ResizablePanelProps {
     pub min_size: Option<f32>,
     pub max_size: Option<f32>,
     pub collapsible: bool,
+    /// Size a collapsible panel snaps to when dragged below half of `min_size`.
+    pub collapsed_size: f32,
+}
+
+impl Default for ResizablePanelProps {
+    fn default() -> Self {
+        Self::new(0.0)
+    }
 }
 
 impl ResizablePanelProps {
@@ -80,6 +103,7 @@ impl ResizablePanelProps {
             min_size: None,
             max_size: None,
             collapsible: false,
+            collapsed_size: 0.0,
         }
     }
 
@@ -98,12 +122,137 @@ impl ResizablePanelProps {
         self
     }
 
+    pub fn collapsed_size(mut self, size: f32) -> Self {
+        self.collapsed_size = size.clamp(0.0, 100.0);
+        self
+    }
+
     /// Clamp a size value to the min/max constraints.
     pub fn clamp_size(&self, size: f32) -> f32 {
         let min = self.min_size.unwrap_or(0.0);
         let max = self.max_size.unwrap_or(100.0);
         size.clamp(min, max)
     }
+
+    /// Like [`Self::clamp_size`], but a collapsible panel below half of its minimum collapses.
+    pub fn constrain_size(&self, size: f32) -> f32 {
+        let min = self.min_size.unwrap_or(0.0);
+        if self.collapsible && size < min {
+            let collapsed = self.collapsed_size.min(min);
+            if size < (collapsed + min) * 0.5 {
+                return collapsed;
+            }
+        }
+        self.clamp_size(size)
+    }
+
+    /// Smallest size the panel can take, counting collapse.
+    fn floor_size(&self) -> f32 {
+        let min = self.min_size.unwrap_or(0.0);
+        if self.collapsible {
+            self.collapsed_size.min(min)
+        } else {
+            min
+        }
+    }
+
+    pub fn is_collapsed_at(&self, size: f32) -> bool {
+        self.collapsible
+            && size <= self.collapsed_size + LAYOUT_EPSILON
+            && self.collapsed_size < self.min_size.unwrap_or(0.0)
+    }
+}
+
+// =============================================================================
+// Layout
+// =============================================================================
+
+/// Moves the handle between panels `handle_index` and `handle_index + 1` by `delta` percent.
+///
+/// The panel on the growing side is clamped to its max; the shrinking side gives up space
+/// nearest-first, cascading into further panels once a neighbour reaches its min or collapses.
+/// Returns the original layout when the move cannot be satisfied.
+pub fn adjust_layout(
+    sizes: &[f32],
+    constraints: &[ResizablePanelProps],
+    handle_index: usize,
+    delta: f32,
+) -> Vec<f32> {
+    let n = sizes.len();
+    if handle_index + 1 >= n || delta.abs() < f32::EPSILON {
+        return sizes.to_vec();
+    }
+    let default_constraint = ResizablePanelProps::default();
+    let constraint = |idx: usize| constraints.get(idx).unwrap_or(&default_constraint);
+
+    let (grow, shrink_order): (usize, Vec<usize>) = if delta > 0.0 {
+        (handle_index, (handle_index + 1..n).collect())
+    } else {
+        (handle_index + 1, (0..=handle_index).rev().collect())
+    };
+
+    let mut out = sizes.to_vec();
+    let capacity: f32 = shrink_order
+        .iter()
+        .map(|&idx| (out[idx] - constraint(idx).floor_size()).max(0.0))
+        .sum();
+    let grow_target = constraint(grow).constrain_size(out[grow] + delta.abs().min(capacity));
+    let needed = grow_target - out[grow];
+    if needed <= LAYOUT_EPSILON {
+        return sizes.to_vec();
+    }
+
+    let mut remaining = needed;
+    for &idx in &shrink_order {
+        if remaining <= LAYOUT_EPSILON {
+            break;
+        }
+        let target = constraint(idx).constrain_size(out[idx] - remaining);
+        let reduction = (out[idx] - target).max(0.0);
+        out[idx] -= reduction;
+        remaining -= reduction;
+    }
+
+    // A neighbour that collapsed may have given up more than asked for; the growing panel takes
+    // it all or the move is rejected, so the layout always sums to the same total.
+    let new_grow = out[grow] + (needed - remaining);
+    if (constraint(grow).constrain_size(new_grow) - new_grow).abs() > LAYOUT_EPSILON {
+        return sizes.to_vec();
+    }
+    out[grow] = new_grow;
+    out
+}
+
+/// Clamps every panel to its constraints and spreads any difference so the layout sums to 100.
+pub fn validate_layout(sizes: &[f32], constraints: &[ResizablePanelProps]) -> Vec<f32> {
+    let default_constraint = ResizablePanelProps::default();
+    let constraint = |idx: usize| constraints.get(idx).unwrap_or(&default_constraint);
+    let mut out: Vec<f32> = sizes
+        .iter()
+        .enumerate()
+        .map(|(idx, size)| {
+            let c = constraint(idx);
+            if c.is_collapsed_at(*size) {
+                c.collapsed_size
+            } else {
+                c.clamp_size(*size)
+            }
+        })
+        .collect();
+    let mut diff = 100.0 - out.iter().sum::<f32>();
+    for (idx, size) in out.iter_mut().enumerate() {
+        if diff.abs() <= LAYOUT_EPSILON {
+            break;
+        }
+        let c = constraint(idx);
+        if c.is_collapsed_at(*size) {
+            continue;
+        }
+        let adjusted = c.clamp_size(*size + diff);
+        diff -= adjusted - *size;
+        *size = adjusted;
+    }
+    out
 }
 
 // =============================================================================
@@ -143,6 +292,10 @@ pub struct ResizableContext<'a> {
     direction: ResizableDirection,
     sizes: &'a mut Vec<f32>,
     total_size: f32,
+    keyboard_resize_by: f32,
+    /// Panel props from this frame, falling back to last frame's for panels not yet shown.
+    constraints: Vec<ResizablePanelProps>,
+    egui_ctx: egui::Context,
     #[allow(dead_code)]
     theme: &'a Theme,
 }
@@ -161,30 +314,91 @@ impl<'a> ResizableContext<'a> {
 
     /// Resize panels around a handle.
     pub fn resize(&mut self, handle_index: usize, delta_percent: f32) {
-        if handle_index >= self.sizes.len().saturating_sub(1) {
+        *self.sizes = adjust_layout(self.sizes, &self.constraints, handle_index, delta_percent);
+    }
+
+    pub fn direction(&self) -> ResizableDirection {
+        self.direction
+    }
+
+    pub fn layout(&self) -> &[f32] {
+        self.sizes
+    }
+
+    /// Replaces the layout, clamped to the panels' constraints.
+    pub fn set_layout(&mut self, sizes: &[f32]) {
+        *self.sizes = validate_layout(sizes, &self.constraints);
+    }
+
+    /// Resizes one panel to `size` percent, taking the space from its neighbours.
+    pub fn resize_panel(&mut self, index: usize, size: f32) {
+        let n = self.sizes.len();
+        if index >= n || n < 2 {
             return;
         }
+        let delta = size - self.sizes[index];
+        // Prefer the handle after the panel; the last panel only has one before it.
+        if index + 1 < n {
+            self.resize(index, delta);
+        } else {
+            self.resize(index - 1, -delta);
+        }
+    }
 
-        let left_idx = handle_index;
-        let right_idx = handle_index + 1;
-
-        let left_size = self.sizes[left_idx];
-        let right_size = self.sizes[right_idx];
+    pub fn is_collapsed(&self, index: usize) -> bool {
+        self.constraints
+            .get(index)
+            .is_some_and(|c| c.is_collapsed_at(self.get_size(index)))
+    }
 
-        // Calculate new sizes
-        let new_left = (left_size + delta_percent).clamp(5.0, 95.0);
+    /// Collapses a collapsible panel, remembering its size for [`Self::expand`].
+    pub fn collapse(&mut self, index: usize) {
+        let Some(constraint) = self.constraints.get(index).cloned() else {
+            return;
+        };
+        if !constraint.collapsible || self.is_collapsed(index) {
+            return;
+        }
+        let size = self.get_size(index);
+        self.egui_ctx
+            .data_mut(|d| d.insert_temp(self.id.with(("expanded-size", index)), size));
+        self.resize_panel(index, constraint.collapsed_size);
+    }
 
-        // Ensure they still sum to the original total
-        let total = left_size + right_size;
-        let adjusted_left = new_left.min(total - 5.0);
-        let adjusted_right = total - adjusted_left;
+    /// Restores a collapsed panel to its size before collapsing, or to its minimum.
+    pub fn expand(&mut self, index: usize) {
+        let Some(constraint) = self.constraints.get(index).cloned() else {
+            return;
+        };
+        if !self.is_collapsed(index) {
+            return;
+        }
+        let remembered = self
+            .egui_ctx
+            .data(|d| d.get_temp::<f32>(self.id.with(("expanded-size", index))));
+        let min = constraint.min_size.unwrap_or(0.0);
+        let target = remembered.unwrap_or(constraint.default_size).max(min);
+        self.resize_panel(index, target);
+    }
 
-        self.sizes[left_idx] = adjusted_left;
-        self.sizes[right_idx] = adjusted_right;
+    pub fn toggle_collapsed(&mut self, index: usize) {
+        if self.is_collapsed(index) {
+            self.expand(index);
+        } else {
+            self.collapse(index);
+        }
     }
 
-    pub fn direction(&self) -> ResizableDirection {
-        self.direction
+    fn register_panel(&mut self, index: usize, props: &ResizablePanelProps) {
+        if self.constraints.len() <= index {
+            self.constraints
+                .resize_with(index + 1, ResizablePanelProps::default);
+        }
+        self.constraints[index] = props.clone();
+        if self.sizes.len() <= index {
+            self.sizes.resize(index + 1, 0.0);
+            self.sizes[index] = props.default_size;
+        }
     }
 }
 
@@ -201,6 +415,7 @@ pub fn resizable_panel_group<'a, IdType: Hash, R>(
     add_contents: impl FnOnce(&mut Ui, &mut ResizableContext<'a>) -> R,
 ) -> R {
     let id = ui.id().with(&props.id_source);
+    let egui_ctx = ui.ctx().clone();
 
     // Calculate total available size based on direction
     let available = ui.available_size();
@@ -209,16 +424,41 @@ pub fn resizable_panel_group<'a, IdType: Hash, R>(
         ResizableDirection::Vertical => available.y,
     };
 
+    let saved_id = props
+        .auto_save_id
+        .as_ref()
+        .map(|key| Id::new((PERSISTED_LAYOUT_KEY, key)));
+    let restored_id = id.with("layout-restored");
+    if let Some(saved_id) = saved_id
+        && !egui_ctx.data(|d| d.get_temp::<bool>(restored_id).unwrap_or(false))
+    {
+        let saved = egui_ctx.data_mut(|d| d.get_persisted::<Vec<f32>>(saved_id));
+        if let Some(saved) = saved
+            && (sizes.is_empty() || saved.len() == sizes.len())
+        {
+            *sizes = saved;
+        }
+        egui_ctx.data_mut(|d| d.insert_temp(restored_id, true));
+    }
+
+    let constraints_id = id.with("panel-constraints");
+    let constraints = egui_ctx
+        .data(|d| d.get_temp::<Vec<ResizablePanelProps>>(constraints_id))
+        .unwrap_or_default();
+
     let mut ctx = ResizableContext {
         id,
         direction: props.direction,
         sizes,
         total_size,
+        keyboard_resize_by: props.keyboard_resize_by,
+        constraints,
+        egui_ctx: egui_ctx.clone(),
         theme,
     };
 
     // Layout based on direction
-    match props.direction {
+    let result = match props.direction {
         ResizableDirection::Horizontal => {
             ui.horizontal(|ui| {
                 ui.spacing_mut().item_spacing.x = 0.0;
@@ -233,17 +473,40 @@ pub fn resizable_panel_group<'a, IdType: Hash, R>(
             })
             .inner
         }
+    };
+
+    let ResizableContext {
+        sizes, constraints, ..
+    } = ctx;
+    if !constraints.is_empty() && constraints.len() == sizes.len() {
+        let validated = validate_layout(sizes, &constraints);
+        if validated != *sizes {
+            *sizes = validated;
+            egui_ctx.request_repaint();
+        }
+    }
+    if let Some(saved_id) = saved_id {
+        let changed = egui_ctx
+            .data_mut(|d| d.get_persisted::<Vec<f32>>(saved_id))
+            .is_none_or(|saved| saved != *sizes);
+        if changed {
+            egui_ctx.data_mut(|d| d.insert_persisted(saved_id, sizes.clone()));
+        }
     }
+    egui_ctx.data_mut(|d| d.insert_temp(constraints_id, constraints));
+
+    result
 }
 
 /// Render an individual resizable panel.
 pub fn resizable_panel<R>(
     ui: &mut Ui,
-    ctx: &ResizableContext<'_>,
+    ctx: &mut ResizableContext<'_>,
     props: ResizablePanelProps,
     index: usize,
     add_contents: impl FnOnce(&mut Ui) -> R,
 ) -> R {
+    ctx.register_panel(index, &props);
     let size_percent = ctx.get_size(index);
     let clamped_percent = props.clamp_size(size_percent);
 
@@ -277,7 +540,7 @@ pub fn resizable_handle(
     props: ResizableHandleProps,
     handle_index: usize,
 ) -> Response {
-    let _handle_id = ctx.id.with(("handle", handle_index));
+    let handle_id = ctx.id.with(("handle", handle_index));
 
     let (handle_size, cursor) = match ctx.direction {
         ResizableDirection::Horizontal => (
@@ -290,7 +553,13 @@ pub fn resizable_handle(
         ),
     };
 
-    let (rect, response) = ui.allocate_exact_size(handle_size, Sense::drag());
+    let (rect, _) = ui.allocate_exact_size(handle_size, Sense::hover());
+    let sense = if props.disabled {
+        Sense::hover()
+    } else {
+        Sense::drag()
+    };
+    let response = ui.interact(rect, handle_id, sense);
 
     // Visual styling
     let is_hovered = response.hovered();
@@ -305,6 +574,14 @@ pub fn resizable_handle(
     };
 
     ui.painter().rect_filled(rect, 0.0, bg_color);
+    if response.has_focus() {
+        ui.painter().rect_stroke(
+            rect.expand(1.0),
+            CornerRadius::same(1),
+            Stroke::new(2.0, theme.palette.ring),
+            StrokeKind::Outside,
+        );
+    }
 
     // Handle grip icon
     if props.with_handle {
@@ -345,7 +622,13 @@ pub fn resizable_handle(
         ui.ctx().set_cursor_icon(cursor);
     }
 
-    // Handle dragging
+    // Handle dragging. The layout is recomputed from the one at drag start so a panel dragged
+    // past its minimum can still collapse once the pointer travels far enough.
+    let drag_state_id = handle_id.with("drag-start");
+    if response.drag_started() && !props.disabled {
+        let start = (ctx.sizes.clone(), 0.0_f32);
+        ui.ctx().data_mut(|d| d.insert_temp(drag_state_id, start));
+    }
     if response.dragged() && !props.disabled {
         let delta = response.drag_delta();
         let delta_px = match ctx.direction {
@@ -360,9 +643,70 @@ pub fn resizable_handle(
             0.0
         };
 
-        ctx.resize(handle_index, delta_percent);
+        let state = ui
+            .ctx()
+            .data(|d| d.get_temp::<(Vec<f32>, f32)>(drag_state_id));
+        match state {
+            Some((start, offset)) => {
+                let offset = offset + delta_percent;
+                *ctx.sizes = adjust_layout(&start, &ctx.constraints, handle_index, offset);
+                ui.ctx()
+                    .data_mut(|d| d.insert_temp(drag_state_id, (start, offset)));
+            }
+            None => ctx.resize(handle_index, delta_percent),
+        }
         ui.ctx().request_repaint();
     }
+    if response.drag_stopped() {
+        ui.ctx()
+            .data_mut(|d| d.remove::<(Vec<f32>, f32)>(drag_state_id));
+    }
+
+    if response.has_focus() && !props.disabled {
+        // Keeps egui's arrow-key focus navigation from moving focus off the handle.
+        ui.memory_mut(|m| {
+            m.set_focus_lock_filter(
+                handle_id,
+                EventFilter {
+                    horizontal_arrows: true,
+                    vertical_arrows: true,
+                    ..Default::default()
+                },
+            )
+        });
+        let (back, forward) = match ctx.direction {
+            ResizableDirection::Horizontal => (Key::ArrowLeft, Key::ArrowRight),
+            ResizableDirection::Vertical => (Key::ArrowUp, Key::ArrowDown),
+        };
+        let step = ctx.keyboard_resize_by;
+        let (back, forward, home, end, enter) = ui.input(|i| {
+            (
+                i.key_pressed(back),
+                i.key_pressed(forward),
+                i.key_pressed(Key::Home),
+                i.key_pressed(Key::End),
+                i.key_pressed(Key::Enter),
+            )
+        });
+        if back {
+            ctx.resize(handle_index, -step);
+        }
+        if forward {
+            ctx.resize(handle_index, step);
+        }
+        if home {
+            ctx.resize(handle_index, -100.0);
+        }
+        if end {
+            ctx.resize(handle_index, 100.0);
+        }
+        if enter {
+            ctx.toggle_collapsed(handle_index);
+        }
+        if back || forward || home || end || enter {
+            ui.ctx().request_repaint();
+        }
+    }
 
     response
 }
@@ -0,0 +1,307 @@
+//! Typed in-app navigation: a [`Router`] keeps the history of [`Route`]s with back/forward,
+//! and the `router_*` helpers bind breadcrumbs, tabs, sidebar buttons and navigation menu
+//! links to it so they highlight the active route and navigate when clicked.
+//!
+//! Routes are usually an enum whose variants carry their parameters:
+//!
+//! ```ignore
+//! #[derive(Clone, Debug, PartialEq)]
+//! enum Screen {
+//!     Projects,
+//!     Project { id: u32 },
+//! }
+//!
+//! impl Route for Screen {
+//!     fn title(&self) -> String {
+//!         match self {
+//!             Screen::Projects => "Projects".into(),
+//!             Screen::Project { id } => format!("Project {id}"),
+//!         }
+//!     }
+//!
+//!     fn parent(&self) -> Option<Self> {
+//!         match self {
+//!             Screen::Project { .. } => Some(Screen::Projects),
+//!             Screen::Projects => None,
+//!         }
+//!     }
+//! }
+//!
+//! router.handle_input(ctx);
+//! match router.current() { /* ... */ }
+//! ```
+
+use crate::breadcrumb::{
+    BreadcrumbPathProps, BreadcrumbPathResponse, BreadcrumbProps, breadcrumb_path,
+};
+use crate::navigation_menu::{
+    NavigationMenuLinkProps, NavigationMenuLinkResponse, NavigationMenuLinkState,
+    navigation_menu_link,
+};
+use crate::sidebar::{SidebarContext, SidebarMenuButtonProps, sidebar_menu_button};
+use crate::tabs::{TabItem, TabsProps, TabsResult, tabs};
+use crate::theme::Theme;
+use egui::{Context, Id, Key, Modifiers, PointerButton, Response, Ui};
+use std::fmt::Debug;
+
+pub trait Route: Clone + PartialEq + Debug {
+    /// Label used by breadcrumbs and tabs.
+    fn title(&self) -> String;
+
+    /// The route one level up. It forms the breadcrumb trail and stays highlighted while one
+    /// of its descendants is shown.
+    fn parent(&self) -> Option<Self> {
+        None
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Router<R: Route> {
+    history: Vec<R>,
+    index: usize,
+    max_history: usize,
+}
+
+impl<R: Route> Router<R> {
+    pub fn new(initial: R) -> Self {
+        Self {
+            history: vec![initial],
+            index: 0,
+            max_history: 100,
+        }
+    }
+
+    /// Caps the number of remembered entries; the oldest ones are dropped first.
+    pub fn with_max_history(mut self, max_history: usize) -> Self {
+        self.max_history = max_history.max(1);
+        self.trim();
+        self
+    }
+
+    pub fn current(&self) -> &R {
+        &self.history[self.index]
+    }
+
+    /// Entries from the oldest up to and including the current route.
+    pub fn stack(&self) -> &[R] {
+        &self.history[..=self.index]
+    }
+
+    /// Every remembered entry, including the ones `forward` would return to.
+    pub fn history(&self) -> &[R] {
+        &self.history
+    }
+
+    /// Pushes `route` and drops the forward entries. Navigating to the current route does
+    /// nothing.
+    pub fn navigate(&mut self, route: R) {
+        if *self.current() == route {
+            return;
+        }
+        self.history.truncate(self.index + 1);
+        self.history.push(route);
+        self.index += 1;
+        self.trim();
+    }
+
+    /// Swaps the current entry without adding to the history.
+    pub fn replace(&mut self, route: R) {
+        self.history[self.index] = route;
+    }
+
+    /// Clears the history and starts over at `route`.
+    pub fn reset(&mut self, route: R) {
+        self.history = vec![route];
+        self.index = 0;
+    }
+
+    pub fn can_go_back(&self) -> bool {
+        self.index > 0
+    }
+
+    pub fn can_go_forward(&self) -> bool {
+        self.index + 1 < self.history.len()
+    }
+
+    pub fn back(&mut self) -> bool {
+        let moved = self.can_go_back();
+        if moved {
+            self.index -= 1;
+        }
+        moved
+    }
+
+    pub fn forward(&mut self) -> bool {
+        let moved = self.can_go_forward();
+        if moved {
+            self.index += 1;
+        }
+        moved
+    }
+
+    pub fn is_active(&self, route: &R) -> bool {
+        self.current() == route
+    }
+
+    /// Whether `route` is the current route or one of its ancestors.
+    pub fn is_within(&self, route: &R) -> bool {
+        let mut next = Some(self.current().clone());
+        while let Some(candidate) = next {
+            if candidate == *route {
+                return true;
+            }
+            next = candidate.parent();
+        }
+        false
+    }
+
+    /// The current route's ancestors followed by the route itself.
+    pub fn trail(&self) -> Vec<R> {
+        let mut trail = vec![self.current().clone()];
+        while let Some(parent) = trail.last().and_then(Route::parent) {
+            trail.push(parent);
+        }
+        trail.reverse();
+        trail
+    }
+
+    /// Goes back on the mouse's back button, `Alt+Left` or the browser back key, and forward
+    /// on their counterparts. Returns whether the route changed.
+    pub fn handle_input(&mut self, ctx: &Context) -> bool {
+        let (back, forward) = ctx.input_mut(|i| {
+            let back = i.pointer.button_pressed(PointerButton::Extra1)
+                | i.consume_key(Modifiers::ALT, Key::ArrowLeft)
+                | i.consume_key(Modifiers::NONE, Key::BrowserBack);
+            let forward = i.pointer.button_pressed(PointerButton::Extra2)
+                | i.consume_key(Modifiers::ALT, Key::ArrowRight);
+            (back, forward)
+        });
+        let moved = (back && self.back()) | (forward && self.forward());
+        if moved {
+            ctx.request_repaint();
+        }
+        moved
+    }
+
+    fn trim(&mut self) {
+        let excess = self.history.len().saturating_sub(self.max_history);
+        if excess > 0 {
+            self.history.drain(..excess);
+            self.index = self.index.saturating_sub(excess);
+        }
+    }
+}
+
+/// [`breadcrumb_path`] over the current route's trail; clicking a segment navigates to it.
+pub fn router_breadcrumb<R: Route>(
+    ui: &mut Ui,
+    theme: &Theme,
+    props: BreadcrumbProps,
+    id_source: Id,
+    router: &mut Router<R>,
+) -> BreadcrumbPathResponse {
+    let trail = router.trail();
+    let titles: Vec<String> = trail.iter().map(Route::title).collect();
+    let segments: Vec<&str> = titles.iter().map(String::as_str).collect();
+    let response = breadcrumb_path(
+        ui,
+        theme,
+        props,
+        BreadcrumbPathProps::new(id_source, &segments),
+    );
+    if let Some(index) = response.clicked {
+        router.navigate(trail[index].clone());
+    }
+    response
+}
+
+/// [`tabs`] with one tab per route. The tab for the current route, or for its closest ancestor,
+/// is selected, and clicking a tab navigates to its route. When no tab matches, the content is
+/// rendered for the current route.
+pub fn router_tabs<R: Route, T>(
+    ui: &mut Ui,
+    theme: &Theme,
+    id_source: Id,
+    router: &mut Router<R>,
+    routes: &[R],
+    render_content: impl FnOnce(&mut Ui, &R) -> T,
+) -> TabsResult<T> {
+    let items: Vec<TabItem> = routes
+        .iter()
+        .enumerate()
+        .map(|(index, route)| TabItem::new(index.to_string(), route.title()))
+        .collect();
+    // The closest match wins, so a tab for a parent route doesn't shadow one for its child.
+    let selected = router
+        .trail()
+        .iter()
+        .rev()
+        .find_map(|step| routes.iter().position(|route| route == step));
+    let mut active = selected.map(|index| index.to_string()).unwrap_or_default();
+    let current = router.current().clone();
+
+    let result = tabs(
+        ui,
+        theme,
+        TabsProps::new(id_source, &items, &mut active),
+        |ui, item| {
+            // Without a match `tabs` still marks the first tab; the current route stays shown
+            // until that tab is clicked.
+            let route = item
+                .id
+                .parse::<usize>()
+                .ok()
+                .filter(|index| selected.is_some() || *index != 0)
+                .and_then(|index| routes.get(index))
+                .unwrap_or(&current);
+            render_content(ui, route)
+        },
+    );
+    // Clicks navigate even on the tab that is already shown as selected, e.g. to leave a child
+    // route for the tab's own route.
+    if let Some(route) = result
+        .activated
+        .as_ref()
+        .and_then(|id| id.parse::<usize>().ok())
+        .and_then(|index| routes.get(index))
+    {
+        router.navigate(route.clone());
+    }
+    result
+}
+
+/// [`sidebar_menu_button`] that is active while `route` or one of its descendants is shown
+/// and navigates to `route` when clicked.
+pub fn router_sidebar_menu_button<R: Route>(
+    ui: &mut Ui,
+    theme: &Theme,
+    ctx: &SidebarContext,
+    props: SidebarMenuButtonProps,
+    router: &mut Router<R>,
+    route: R,
+) -> Response {
+    let active = router.is_within(&route);
+    let response = sidebar_menu_button(ui, theme, ctx, props.active(active));
+    if response.clicked() {
+        router.navigate(route);
+    }
+    response
+}
+
+/// [`navigation_menu_link`] that is active while `route` or one of its descendants is shown
+/// and navigates to `route` when clicked.
+pub fn router_navigation_menu_link<R: Route, T>(
+    ui: &mut Ui,
+    theme: &Theme,
+    props: NavigationMenuLinkProps,
+    router: &mut Router<R>,
+    route: R,
+    add_contents: impl FnOnce(&mut Ui, NavigationMenuLinkState) -> T,
+) -> NavigationMenuLinkResponse<T> {
+    let active = router.is_within(&route);
+    let response = navigation_menu_link(ui, theme, props.active(active), add_contents);
+    if response.response.clicked() {
+        router.navigate(route);
+    }
+    response
+}
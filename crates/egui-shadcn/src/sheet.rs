@@ -1,12 +1,21 @@
+use crate::dialog::{DialogAutoFocusEvent, FocusScope};
+use crate::overlay::{
+    OverlayKind, OverlayLayer, is_topmost_overlay, overlay_accepts_outside_click, register_overlay,
+};
 use crate::theme::Theme;
 use egui::{
-    Align, Align2, Color32, CornerRadius, FontId, Frame, Id, LayerId, Margin, Order, Rect,
-    Response, Sense, Stroke, StrokeKind, Ui, Vec2, pos2, vec2,
+    Align, Align2, Color32, Context, CornerRadius, CursorIcon, FontId, Frame, Id, LayerId, Margin,
+    Order, Rect, Response, Sense, Stroke, StrokeKind, Ui, UiBuilder, Vec2, pos2, vec2,
 };
 use lucide_icons::Icon;
 
 const DEFAULT_MAX_WIDTH: f32 = 384.0;
 const DEFAULT_MAX_HEIGHT: f32 = 320.0;
+const DRAG_HANDLE_THICKNESS: f32 = 28.0;
+/// Seconds of pointer velocity projected forward when picking a snap point on release.
+const FLING_PROJECTION: f32 = 0.2;
+/// Fraction of the smallest snap point below which a release dismisses the sheet.
+const DISMISS_THRESHOLD: f32 = 0.5;
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
 pub enum SheetSide {
@@ -17,11 +26,94 @@ pub enum SheetSide {
     Left,
 }
 
-#[derive(Debug)]
+impl SheetSide {
+    /// Unit vector pointing from the open position towards the closed one.
+    pub fn closing_direction(self) -> Vec2 {
+        match self {
+            SheetSide::Top => vec2(0.0, -1.0),
+            SheetSide::Right => vec2(1.0, 0.0),
+            SheetSide::Bottom => vec2(0.0, 1.0),
+            SheetSide::Left => vec2(-1.0, 0.0),
+        }
+    }
+
+    fn is_vertical(self) -> bool {
+        matches!(self, SheetSide::Top | SheetSide::Bottom)
+    }
+}
+
+/// Drag state of a sheet, kept in egui memory under the sheet's `id_source`.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct SheetGeometry {
+    /// Index into [`SheetProps::snap_points`] the sheet rests at.
+    pub active_snap: Option<usize>,
+    /// Distance the sheet is currently dragged towards closing, in points.
+    pub drag_offset: f32,
+    pub dragging: bool,
+}
+
+fn sheet_geometry_id(id_source: Id) -> Id {
+    id_source.with("sheet-geometry")
+}
+
+pub fn sheet_geometry(ctx: &Context, id_source: Id) -> SheetGeometry {
+    ctx.data(|d| d.get_temp(sheet_geometry_id(id_source)))
+        .unwrap_or_default()
+}
+
+pub fn set_sheet_geometry(ctx: &Context, id_source: Id, geometry: SheetGeometry) {
+    ctx.data_mut(|d| d.insert_temp(sheet_geometry_id(id_source), geometry));
+}
+
+/// Picks where a released sheet settles. `extent` is the visible size along the sheet axis,
+/// `velocity` the pointer speed towards closing. Returns `None` when the sheet should dismiss.
+pub fn resolve_sheet_snap(
+    extent: f32,
+    snap_extents: &[f32],
+    velocity: f32,
+    dismissible: bool,
+) -> Option<usize> {
+    let projected = extent - velocity * FLING_PROJECTION;
+    let smallest = snap_extents.iter().copied().fold(f32::INFINITY, f32::min);
+    if dismissible && smallest.is_finite() && projected < smallest * DISMISS_THRESHOLD {
+        return None;
+    }
+    snap_extents
+        .iter()
+        .enumerate()
+        .min_by(|(_, a), (_, b)| (*a - projected).abs().total_cmp(&(*b - projected).abs()))
+        .map(|(idx, _)| idx)
+}
+
 pub struct SheetProps<'a> {
     pub id_source: Id,
     pub open: &'a mut bool,
     pub side: SheetSide,
+    /// Resting sizes as fractions of the screen along the sheet axis, e.g. `[0.25, 0.5, 1.0]`.
+    pub snap_points: Vec<f32>,
+    pub drag_to_dismiss: bool,
+    pub show_drag_handle: bool,
+    pub trap_focus: bool,
+    pub initial_focus: Option<Id>,
+    pub on_open_auto_focus: Option<&'a mut dyn FnMut(&mut DialogAutoFocusEvent)>,
+    pub on_close_auto_focus: Option<&'a mut dyn FnMut(&mut DialogAutoFocusEvent)>,
+}
+
+impl std::fmt::Debug for SheetProps<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SheetProps")
+            .field("id_source", &self.id_source)
+            .field("open", &self.open)
+            .field("side", &self.side)
+            .field("snap_points", &self.snap_points)
+            .field("drag_to_dismiss", &self.drag_to_dismiss)
+            .field("show_drag_handle", &self.show_drag_handle)
+            .field("trap_focus", &self.trap_focus)
+            .field("initial_focus", &self.initial_focus)
+            .field("on_open_auto_focus", &self.on_open_auto_focus.is_some())
+            .field("on_close_auto_focus", &self.on_close_auto_focus.is_some())
+            .finish()
+    }
 }
 
 impl<'a> SheetProps<'a> {
@@ -30,6 +122,13 @@ impl<'a> SheetProps<'a> {
             id_source,
             open,
             side: SheetSide::Right,
+            snap_points: Vec::new(),
+            drag_to_dismiss: false,
+            show_drag_handle: false,
+            trap_focus: true,
+            initial_focus: None,
+            on_open_auto_focus: None,
+            on_close_auto_focus: None,
         }
     }
 
@@ -37,12 +136,75 @@ impl<'a> SheetProps<'a> {
         self.side = side;
         self
     }
+
+    /// Snap points are sorted and clamped to `0.0..=1.0`; the sheet opens at the first one.
+    pub fn snap_points(mut self, points: impl IntoIterator<Item = f32>) -> Self {
+        let mut points: Vec<f32> = points
+            .into_iter()
+            .filter(|p| p.is_finite() && *p > 0.0)
+            .map(|p| p.min(1.0))
+            .collect();
+        points.sort_by(f32::total_cmp);
+        points.dedup();
+        self.snap_points = points;
+        self
+    }
+
+    /// Drawer-style sheet: a drag handle on the leading edge, dismissed by flinging it closed.
+    pub fn drawer(self) -> Self {
+        self.drag_to_dismiss(true).show_drag_handle(true)
+    }
+
+    pub fn drag_to_dismiss(mut self, enabled: bool) -> Self {
+        self.drag_to_dismiss = enabled;
+        self
+    }
+
+    pub fn show_drag_handle(mut self, show: bool) -> Self {
+        self.show_drag_handle = show;
+        self
+    }
+
+    pub fn trap_focus(mut self, trap: bool) -> Self {
+        self.trap_focus = trap;
+        self
+    }
+
+    pub fn initial_focus(mut self, id: Id) -> Self {
+        self.initial_focus = Some(id);
+        self
+    }
+
+    pub fn on_open_auto_focus(
+        mut self,
+        on_open_auto_focus: &'a mut dyn FnMut(&mut DialogAutoFocusEvent),
+    ) -> Self {
+        self.on_open_auto_focus = Some(on_open_auto_focus);
+        self
+    }
+
+    pub fn on_close_auto_focus(
+        mut self,
+        on_close_auto_focus: &'a mut dyn FnMut(&mut DialogAutoFocusEvent),
+    ) -> Self {
+        self.on_close_auto_focus = Some(on_close_auto_focus);
+        self
+    }
 }
 
 pub struct SheetContext<'a> {
     pub id_source: Id,
     pub open: &'a mut bool,
     pub side: SheetSide,
+    pub snap_points: Vec<f32>,
+    pub drag_to_dismiss: bool,
+    pub show_drag_handle: bool,
+    pub trap_focus: bool,
+    pub initial_focus: Option<Id>,
+    /// Widget refocused when the sheet closes; set by [`sheet_trigger`].
+    pub trigger_id: Option<Id>,
+    pub on_open_auto_focus: Option<&'a mut dyn FnMut(&mut DialogAutoFocusEvent)>,
+    pub on_close_auto_focus: Option<&'a mut dyn FnMut(&mut DialogAutoFocusEvent)>,
 }
 
 pub fn sheet<R>(
@@ -54,6 +216,14 @@ pub fn sheet<R>(
         id_source: props.id_source,
         open: props.open,
         side: props.side,
+        snap_points: props.snap_points,
+        drag_to_dismiss: props.drag_to_dismiss,
+        show_drag_handle: props.show_drag_handle,
+        trap_focus: props.trap_focus,
+        initial_focus: props.initial_focus,
+        trigger_id: None,
+        on_open_auto_focus: props.on_open_auto_focus,
+        on_close_auto_focus: props.on_close_auto_focus,
     };
     add_contents(ui, &mut context)
 }
@@ -66,6 +236,7 @@ pub fn sheet_trigger(
     let response = ui
         .push_id(context.id_source.with("trigger"), |ui| add_trigger(ui))
         .inner;
+    context.trigger_id = Some(response.id);
     if response.clicked() {
         *context.open = !*context.open;
         ui.ctx().request_repaint();
@@ -84,40 +255,98 @@ pub fn sheet_content<R>(
     let last_open = ctx.data(|d| d.get_temp::<bool>(state_id)).unwrap_or(false);
     let was_open = *context.open || last_open;
     let opened_now = *context.open && !last_open;
+    let mut focus_scope = FocusScope {
+        id: context.id_source,
+        initial_focus: context.initial_focus,
+        return_focus_to: context.trigger_id,
+        on_open_auto_focus: context
+            .on_open_auto_focus
+            .as_mut()
+            .map(|cb| &mut **cb as &mut dyn FnMut(&mut DialogAutoFocusEvent)),
+        on_close_auto_focus: context
+            .on_close_auto_focus
+            .as_mut()
+            .map(|cb| &mut **cb as &mut dyn FnMut(&mut DialogAutoFocusEvent)),
+    };
 
     let anim_t = ctx.animate_bool(context.id_source.with("open-anim"), *context.open);
     if !*context.open && anim_t <= 0.0 {
+        focus_scope.exit(ctx, last_open, *context.open);
         ctx.data_mut(|d| d.insert_temp(state_id, *context.open));
         return None;
     }
 
     let screen = ctx.available_rect();
-    let (panel_size, base_pos, offset) = sheet_layout(screen, context.side);
-    let animated_pos = base_pos + offset * (1.0 - anim_t);
+    let side = context.side;
+    let (panel_size, base_pos, offset) = if context.snap_points.is_empty() {
+        sheet_layout(screen, side)
+    } else {
+        let largest = context.snap_points.iter().copied().fold(0.0, f32::max);
+        sheet_layout_with_extent(screen, side, largest * screen_extent(screen, side))
+    };
+    let full_extent = axis_extent(panel_size, side);
+    let snap_extents: Vec<f32> = if context.snap_points.is_empty() {
+        vec![full_extent]
+    } else {
+        context
+            .snap_points
+            .iter()
+            .map(|p| p * screen_extent(screen, side))
+            .collect()
+    };
 
-    let overlay_id = LayerId::new(Order::Foreground, context.id_source.with("overlay"));
-    let overlay_painter = ctx.layer_painter(overlay_id);
-    let scrim_alpha = (160.0 * anim_t).round().clamp(0.0, 255.0) as u8;
-    overlay_painter.rect_filled(
-        screen,
-        CornerRadius::same(0),
-        Color32::from_rgba_unmultiplied(0, 0, 0, scrim_alpha),
-    );
+    let mut geometry = sheet_geometry(ctx, context.id_source);
+    if opened_now
+        || geometry
+            .active_snap
+            .is_none_or(|idx| idx >= snap_extents.len())
+    {
+        geometry = SheetGeometry {
+            active_snap: Some(0),
+            ..SheetGeometry::default()
+        };
+    }
+    let resting_extent = snap_extents[geometry.active_snap.unwrap_or(0)];
+    let visible_extent = (resting_extent - geometry.drag_offset).clamp(0.0, full_extent);
+    let closing = side.closing_direction();
+    let drag_shift = closing * (full_extent - visible_extent);
+    let animated_pos = base_pos + drag_shift + offset * (1.0 - anim_t);
 
-    egui::Area::new(context.id_source.with("scrim"))
+    let scrim_alpha = (160.0 * anim_t).round().clamp(0.0, 255.0) as u8;
+    let scrim_id = context.id_source.with("scrim");
+    let scrim_layer = LayerId::new(Order::Foreground, scrim_id);
+    if *context.open {
+        register_overlay(
+            ctx,
+            OverlayLayer::new(context.id_source, OverlayKind::Sheet, scrim_layer)
+                .modal(context.trap_focus),
+        );
+    }
+    // Non-focusable senses keep the backdrop out of the Tab order.
+    egui::Area::new(scrim_id)
         .order(Order::Foreground)
         .interactable(true)
+        .sense(Sense::CLICK)
         .movable(false)
         .fixed_pos(screen.min)
         .show(ctx, |scrim_ui| {
-            scrim_ui.allocate_exact_size(screen.size(), Sense::click());
+            scrim_ui.painter().rect_filled(
+                screen,
+                CornerRadius::same(0),
+                Color32::from_rgba_unmultiplied(0, 0, 0, scrim_alpha),
+            );
+            scrim_ui.allocate_exact_size(screen.size(), Sense::CLICK);
         });
+    // Keeps the panel above its backdrop when a click on the backdrop raises it.
+    let content_id = context.id_source.with("content");
+    ctx.set_sublayer(scrim_layer, LayerId::new(Order::Foreground, content_id));
+    focus_scope.enter(ctx, opened_now, *context.open);
 
     let mut result = None;
-    let content_id = context.id_source.with("content");
     egui::Area::new(content_id)
-        .order(Order::Tooltip)
+        .order(Order::Foreground)
         .interactable(true)
+        .sense(Sense::CLICK)
         .movable(false)
         .fixed_pos(animated_pos)
         .show(ctx, |area_ui| {
@@ -139,6 +368,11 @@ pub fn sheet_content<R>(
                 .shadow(shadow)
                 .corner_radius(CornerRadius::same(0));
 
+            let handle_padding = if context.show_drag_handle {
+                DRAG_HANDLE_THICKNESS
+            } else {
+                0.0
+            };
             let frame_resp = frame.show(area_ui, |content_ui| {
                 content_ui.set_min_width(panel_size.x);
                 content_ui.set_max_width(panel_size.x);
@@ -146,15 +380,74 @@ pub fn sheet_content<R>(
                 content_ui.set_max_height(panel_size.y);
                 content_ui.spacing_mut().item_spacing.y = 16.0;
                 content_ui.set_min_size(panel_size);
-                result = Some(add_contents(content_ui));
+                // The handle sits on the edge facing the screen centre.
+                let mut content_rect = content_ui.max_rect();
+                match side {
+                    SheetSide::Bottom => content_rect.min.y += handle_padding,
+                    SheetSide::Top => content_rect.max.y -= handle_padding,
+                    SheetSide::Right => content_rect.min.x += handle_padding,
+                    SheetSide::Left => content_rect.max.x -= handle_padding,
+                }
+                let inner =
+                    content_ui.scope_builder(UiBuilder::new().max_rect(content_rect), add_contents);
+                result = Some(inner.inner);
             });
 
+            let panel_rect = frame_resp.response.rect;
+            if context.drag_to_dismiss || context.snap_points.len() > 1 {
+                let handle_rect = drag_handle_rect(panel_rect, side);
+                let handle_resp = area_ui.interact(
+                    handle_rect,
+                    context.id_source.with("drag-handle"),
+                    Sense::DRAG,
+                );
+                if handle_resp.hovered() || handle_resp.dragged() {
+                    area_ui.ctx().set_cursor_icon(if handle_resp.dragged() {
+                        CursorIcon::Grabbing
+                    } else {
+                        CursorIcon::Grab
+                    });
+                }
+                if handle_resp.dragged() {
+                    geometry.dragging = true;
+                    geometry.drag_offset += handle_resp.drag_delta().dot(closing);
+                    geometry.drag_offset = geometry
+                        .drag_offset
+                        .clamp(resting_extent - full_extent, resting_extent);
+                }
+                if handle_resp.drag_stopped() {
+                    let velocity = area_ui.input(|i| i.pointer.velocity()).dot(closing);
+                    match resolve_sheet_snap(
+                        visible_extent,
+                        &snap_extents,
+                        velocity,
+                        context.drag_to_dismiss,
+                    ) {
+                        Some(idx) => {
+                            geometry.active_snap = Some(idx);
+                            geometry.drag_offset = 0.0;
+                        }
+                        // Keep the offset so the close animation starts where the sheet was let go.
+                        None => *context.open = false,
+                    }
+                    geometry.dragging = false;
+                }
+                if context.show_drag_handle {
+                    paint_drag_handle(area_ui, theme, handle_rect, side);
+                }
+            }
+
             let close_text = Icon::X.unicode().to_string();
             let close_size = vec2(24.0, 24.0);
             let offset = 16.0;
+            let close_right = if side == SheetSide::Left {
+                frame_resp.response.rect.right() - handle_padding
+            } else {
+                frame_resp.response.rect.right()
+            };
             let close_rect = Rect::from_min_size(
                 pos2(
-                    frame_resp.response.rect.right() - offset - close_size.x,
+                    close_right - offset - close_size.x,
                     frame_resp.response.rect.top() + offset,
                 ),
                 close_size,
@@ -191,19 +484,30 @@ pub fn sheet_content<R>(
                 *context.open = false;
             }
 
-            let escape = area_ui.input(|i| i.key_pressed(egui::Key::Escape));
+            let escape = area_ui.input(|i| i.key_pressed(egui::Key::Escape))
+                && is_topmost_overlay(area_ui.ctx(), context.id_source);
             let any_click = area_ui.input(|i| i.pointer.any_click());
             let interact = area_ui.input(|i| i.pointer.interact_pos());
             let contains = interact
                 .map(|pos| frame_resp.response.rect.contains(pos))
                 .unwrap_or(false);
-            let outside_click = !opened_now && was_open && any_click && !contains;
+            let outside_click = !opened_now
+                && was_open
+                && !geometry.dragging
+                && any_click
+                && !contains
+                && overlay_accepts_outside_click(area_ui.ctx(), context.id_source);
 
             if escape || outside_click {
                 *context.open = false;
             }
         });
 
+    set_sheet_geometry(ctx, context.id_source, geometry);
+    if geometry.dragging {
+        ctx.request_repaint();
+    }
+    focus_scope.exit(ctx, last_open, *context.open);
     ctx.data_mut(|d| d.insert_temp(state_id, *context.open));
 
     result
@@ -253,6 +557,82 @@ pub fn sheet_description(
     ui.label(base.size(12.0).color(theme.palette.muted_foreground))
 }
 
+fn screen_extent(screen: Rect, side: SheetSide) -> f32 {
+    axis_extent(screen.size(), side)
+}
+
+fn axis_extent(size: Vec2, side: SheetSide) -> f32 {
+    if side.is_vertical() { size.y } else { size.x }
+}
+
+fn drag_handle_rect(panel: Rect, side: SheetSide) -> Rect {
+    match side {
+        SheetSide::Bottom => Rect::from_min_max(
+            panel.min,
+            pos2(panel.max.x, panel.min.y + DRAG_HANDLE_THICKNESS),
+        ),
+        SheetSide::Top => Rect::from_min_max(
+            pos2(panel.min.x, panel.max.y - DRAG_HANDLE_THICKNESS),
+            panel.max,
+        ),
+        SheetSide::Right => Rect::from_min_max(
+            panel.min,
+            pos2(panel.min.x + DRAG_HANDLE_THICKNESS, panel.max.y),
+        ),
+        SheetSide::Left => Rect::from_min_max(
+            pos2(panel.max.x - DRAG_HANDLE_THICKNESS, panel.min.y),
+            panel.max,
+        ),
+    }
+}
+
+fn paint_drag_handle(ui: &Ui, theme: &Theme, handle_rect: Rect, side: SheetSide) {
+    let size = if side.is_vertical() {
+        vec2(100.0, 6.0)
+    } else {
+        vec2(6.0, 100.0)
+    };
+    ui.painter().rect_filled(
+        Rect::from_center_size(handle_rect.center(), size),
+        CornerRadius::same(3),
+        theme.palette.muted,
+    );
+}
+
+fn sheet_layout_with_extent(
+    screen: Rect,
+    side: SheetSide,
+    extent: f32,
+) -> (Vec2, egui::Pos2, Vec2) {
+    let extent = extent.clamp(0.0, screen_extent(screen, side));
+    match side {
+        SheetSide::Left => {
+            let size = Vec2::new(extent, screen.height());
+            (size, screen.min, vec2(-size.x, 0.0))
+        }
+        SheetSide::Right => {
+            let size = Vec2::new(extent, screen.height());
+            (
+                size,
+                pos2(screen.right() - size.x, screen.top()),
+                vec2(size.x, 0.0),
+            )
+        }
+        SheetSide::Top => {
+            let size = Vec2::new(screen.width(), extent);
+            (size, screen.min, vec2(0.0, -size.y))
+        }
+        SheetSide::Bottom => {
+            let size = Vec2::new(screen.width(), extent);
+            (
+                size,
+                pos2(screen.left(), screen.bottom() - size.y),
+                vec2(0.0, size.y),
+            )
+        }
+    }
+}
+
 fn sheet_layout(screen: Rect, side: SheetSide) -> (Vec2, egui::Pos2, Vec2) {
     let max_width = DEFAULT_MAX_WIDTH.min(screen.width());
     let max_height = DEFAULT_MAX_HEIGHT.min(screen.height());
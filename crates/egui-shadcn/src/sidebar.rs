@@ -1,14 +1,23 @@
 //! Sidebar component - collapsible navigation rail.
 
+use crate::breakpoint::{Breakpoint, breakpoints};
+use crate::collapsible::{CollapsibleProps, collapsible};
+use crate::sheet::{SheetProps, SheetSide, sheet, sheet_content};
 use crate::theme::Theme;
+use crate::tooltip::{TooltipProps, TooltipSide, tooltip};
 use egui::RichText;
+use egui::epaint::Shadow;
 use egui::{
-    Align, Align2, Color32, CornerRadius, FontId, Frame, Id, Layout, Margin, Response, Sense,
-    Stroke, StrokeKind, Ui, Vec2, WidgetText, pos2, vec2,
+    Align, Align2, Color32, CornerRadius, CursorIcon, FontId, Frame, Id, InnerResponse, Layout,
+    Margin, Painter, Pos2, Rect, Response, Sense, Stroke, StrokeKind, Ui, Vec2, WidgetText, pos2,
+    vec2,
 };
+use lucide_icons::Icon;
 
 const DEFAULT_EXPANDED_WIDTH: f32 = 240.0;
 const DEFAULT_COLLAPSED_WIDTH: f32 = 64.0;
+/// Dragging the rail this far toggles the sidebar.
+const RAIL_DRAG_THRESHOLD: f32 = 24.0;
 
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 pub enum SidebarSide {
@@ -17,6 +26,17 @@ pub enum SidebarSide {
     Right,
 }
 
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SidebarVariant {
+    /// Flush with the window edge, separated from the page by a border.
+    #[default]
+    Sidebar,
+    /// A rounded, shadowed panel inset from the window edges.
+    Floating,
+    /// Borderless; pair it with [`sidebar_inset`] for the main content.
+    Inset,
+}
+
 pub struct SidebarProviderProps<'a> {
     pub id_source: Id,
     pub open: &'a mut bool,
@@ -24,6 +44,10 @@ pub struct SidebarProviderProps<'a> {
     pub expanded_width: f32,
     pub collapsed_width: f32,
     pub animate: bool,
+
+    /// Below this breakpoint of the provider's available width the sidebar becomes an
+    /// off-canvas sheet with its own open state. `None` keeps the docked sidebar at any width.
+    pub mobile_breakpoint: Option<Breakpoint>,
     pub on_open_change: Option<&'a mut dyn FnMut(bool)>,
 }
 
@@ -36,10 +60,16 @@ impl<'a> SidebarProviderProps<'a> {
             expanded_width: DEFAULT_EXPANDED_WIDTH,
             collapsed_width: DEFAULT_COLLAPSED_WIDTH,
             animate: true,
+            mobile_breakpoint: Some(Breakpoint::Md),
             on_open_change: None,
         }
     }
 
+    pub fn mobile_breakpoint(mut self, breakpoint: Option<Breakpoint>) -> Self {
+        self.mobile_breakpoint = breakpoint;
+        self
+    }
+
     pub fn default_open(mut self, default_open: bool) -> Self {
         self.default_open = default_open;
         self
@@ -68,16 +98,38 @@ impl<'a> SidebarProviderProps<'a> {
 
 pub struct SidebarContext<'a> {
     pub id_source: Id,
+
+    /// Open state of the docked sidebar; the mobile sheet keeps its own, see
+    /// [`SidebarContext::is_open_mobile`].
     pub open: &'a mut bool,
     pub expanded_width: f32,
     pub collapsed_width: f32,
     pub animate: bool,
     on_open_change: Option<&'a mut dyn FnMut(bool)>,
+    side: SidebarSide,
+    rect: Rect,
+    mobile: bool,
+    open_mobile: bool,
 }
 
 impl<'a> SidebarContext<'a> {
+    /// The mobile sheet always shows the full sidebar, so it is never collapsed.
     pub fn is_collapsed(&self) -> bool {
-        !*self.open
+        !self.mobile && !*self.open
+    }
+
+    /// Whether the sidebar is shown as a sheet because the layout is below the mobile
+    /// breakpoint.
+    pub fn is_mobile(&self) -> bool {
+        self.mobile
+    }
+
+    pub fn is_open_mobile(&self) -> bool {
+        self.open_mobile
+    }
+
+    pub fn set_open_mobile(&mut self, open: bool) {
+        self.open_mobile = open;
     }
 
     pub fn set_open(&mut self, open: bool) {
@@ -90,15 +142,21 @@ impl<'a> SidebarContext<'a> {
         }
     }
 
+    /// Toggles the mobile sheet on mobile layouts and the docked sidebar otherwise.
     pub fn toggle(&mut self) {
-        let next = !*self.open;
-        self.set_open(next);
+        if self.mobile {
+            self.open_mobile = !self.open_mobile;
+        } else {
+            let next = !*self.open;
+            self.set_open(next);
+        }
     }
 }
 
 #[derive(Clone, Copy, Debug)]
 pub struct SidebarProps {
     pub side: SidebarSide,
+    pub variant: SidebarVariant,
     pub padding: Margin,
     pub border: bool,
 }
@@ -107,11 +165,17 @@ impl SidebarProps {
     pub fn new() -> Self {
         Self {
             side: SidebarSide::Left,
+            variant: SidebarVariant::Sidebar,
             padding: Margin::same(0),
             border: true,
         }
     }
 
+    pub fn variant(mut self, variant: SidebarVariant) -> Self {
+        self.variant = variant;
+        self
+    }
+
     pub fn side(mut self, side: SidebarSide) -> Self {
         self.side = side;
         self
@@ -136,7 +200,11 @@ impl Default for SidebarProps {
 
 pub struct SidebarResponse<R> {
     pub response: Response,
-    pub inner: R,
+
+    /// `None` while the mobile sheet is closed.
+    pub inner: Option<R>,
+
+    /// Width taken from the layout; zero on mobile.
     pub width: f32,
 }
 
@@ -159,6 +227,15 @@ pub fn sidebar_provider<R>(
 ) -> R {
     apply_default_open(ui, &mut props);
 
+    let mobile = props
+        .mobile_breakpoint
+        .is_some_and(|mobile| breakpoints(ui.ctx()).resolve(ui.available_width()) < mobile);
+    let open_mobile_id = props.id_source.with("sidebar-open-mobile");
+    let open_mobile = ui
+        .ctx()
+        .data(|d| d.get_temp::<bool>(open_mobile_id))
+        .unwrap_or(false);
+
     let mut ctx = SidebarContext {
         id_source: props.id_source,
         open: props.open,
@@ -166,9 +243,17 @@ pub fn sidebar_provider<R>(
         collapsed_width: props.collapsed_width,
         animate: props.animate,
         on_open_change: props.on_open_change,
+        side: SidebarSide::Left,
+        rect: Rect::NOTHING,
+        mobile,
+        open_mobile,
     };
 
-    add_contents(ui, &mut ctx)
+    let inner = add_contents(ui, &mut ctx);
+    let open_mobile = ctx.open_mobile;
+    ui.ctx()
+        .data_mut(|d| d.insert_temp(open_mobile_id, open_mobile));
+    inner
 }
 
 pub fn sidebar<R>(
@@ -178,6 +263,11 @@ pub fn sidebar<R>(
     props: SidebarProps,
     add_contents: impl FnOnce(&mut Ui, &mut SidebarContext) -> R,
 ) -> SidebarResponse<R> {
+    ctx.side = props.side;
+    if ctx.mobile {
+        return mobile_sidebar(ui, theme, ctx, props, add_contents);
+    }
+
     let open = *ctx.open;
     let anim_t = if ctx.animate {
         ui.ctx()
@@ -192,8 +282,17 @@ pub fn sidebar<R>(
     let height = ui.available_height().max(1.0);
 
     let palette = &theme.palette;
-    let rounding = CornerRadius::same(theme.radius.r2.round() as u8);
-    let border = Stroke::new(1.0, palette.sidebar_border);
+    let border = if props.border {
+        Stroke::new(1.0, palette.sidebar_border)
+    } else {
+        Stroke::NONE
+    };
+    let (outer_margin, radius, border, shadow) = match props.variant {
+        SidebarVariant::Sidebar => (0, theme.radius.r2, border, Shadow::NONE),
+        SidebarVariant::Floating => (8, theme.radius.r3, border, panel_shadow()),
+        SidebarVariant::Inset => (8, 0.0, Stroke::NONE, Shadow::NONE),
+    };
+    let rounding = CornerRadius::same(radius.round() as u8);
 
     let inner = ui.allocate_ui_with_layout(
         Vec2::new(width, height),
@@ -205,8 +304,10 @@ pub fn sidebar<R>(
 
             let frame = Frame::default()
                 .fill(palette.sidebar)
-                .stroke(if props.border { border } else { Stroke::NONE })
+                .stroke(border)
                 .corner_radius(rounding)
+                .shadow(shadow)
+                .outer_margin(Margin::same(outer_margin))
                 .inner_margin(props.padding);
 
             frame
@@ -218,13 +319,143 @@ pub fn sidebar<R>(
         },
     );
 
+    ctx.rect = inner.response.rect;
+
     SidebarResponse {
         response: inner.response,
-        inner: inner.inner,
+        inner: Some(inner.inner),
         width,
     }
 }
 
+fn mobile_sidebar<R>(
+    ui: &mut Ui,
+    theme: &Theme,
+    ctx: &mut SidebarContext,
+    props: SidebarProps,
+    add_contents: impl FnOnce(&mut Ui, &mut SidebarContext) -> R,
+) -> SidebarResponse<R> {
+    ctx.rect = Rect::NOTHING;
+    let response = ui.allocate_response(Vec2::ZERO, Sense::hover());
+    let side = match props.side {
+        SidebarSide::Left => SheetSide::Left,
+        SidebarSide::Right => SheetSide::Right,
+    };
+
+    let was_open = ctx.open_mobile;
+    let mut open = was_open;
+    let inner = sheet(
+        ui,
+        SheetProps::new(ctx.id_source.with("mobile-sheet"), &mut open).side(side),
+        |ui, sheet_ctx| {
+            sheet_content(ui, theme, sheet_ctx, |content_ui| {
+                Frame::default()
+                    .fill(theme.palette.sidebar)
+                    .inner_margin(props.padding)
+                    .show(content_ui, |sidebar_ui| {
+                        sidebar_ui.set_min_size(sidebar_ui.available_size());
+                        sidebar_ui.spacing_mut().item_spacing = vec2(0.0, 0.0);
+                        sidebar_ui.visuals_mut().override_text_color =
+                            Some(theme.palette.sidebar_foreground);
+                        add_contents(sidebar_ui, ctx)
+                    })
+                    .inner
+            })
+        },
+    );
+    // The sheet closes itself on Escape, outside clicks and its close button.
+    if open != was_open {
+        ctx.open_mobile = open;
+    }
+
+    SidebarResponse {
+        response,
+        inner,
+        width: 0.0,
+    }
+}
+
+fn panel_shadow() -> Shadow {
+    Shadow {
+        offset: [0, 1],
+        blur: 4,
+        spread: 0,
+        color: Color32::from_black_alpha(18),
+    }
+}
+
+/// Main content area next to a [`SidebarVariant::Inset`] sidebar: a rounded page-colored panel
+/// filling the remaining space.
+pub fn sidebar_inset<R>(
+    ui: &mut Ui,
+    theme: &Theme,
+    add_contents: impl FnOnce(&mut Ui) -> R,
+) -> InnerResponse<R> {
+    let margin = Margin {
+        left: 0,
+        right: 8,
+        top: 8,
+        bottom: 8,
+    };
+    Frame::default()
+        .fill(theme.palette.background)
+        .corner_radius(CornerRadius::same(theme.radius.r4.round() as u8))
+        .shadow(panel_shadow())
+        .outer_margin(margin)
+        .inner_margin(Margin::same(16))
+        .show(ui, |inset_ui| {
+            let size = inset_ui.available_size();
+            inset_ui.set_min_size(size);
+            add_contents(inset_ui)
+        })
+}
+
+/// A thin hit area along the sidebar's inner edge. Clicking toggles the sidebar; dragging
+/// towards the page expands it and dragging back collapses it. Call it after [`sidebar`].
+pub fn sidebar_rail(ui: &mut Ui, theme: &Theme, ctx: &mut SidebarContext) -> Response {
+    if !ctx.rect.is_positive() {
+        return ui.allocate_response(Vec2::ZERO, Sense::hover());
+    }
+
+    let (edge, outward) = match ctx.side {
+        SidebarSide::Left => (ctx.rect.right(), 1.0),
+        SidebarSide::Right => (ctx.rect.left(), -1.0),
+    };
+    let rect = Rect::from_x_y_ranges(edge - 8.0..=edge + 8.0, ctx.rect.y_range());
+    let response = ui.interact(
+        rect,
+        ctx.id_source.with("sidebar-rail"),
+        Sense::click_and_drag(),
+    );
+
+    if response.clicked() {
+        ctx.toggle();
+    }
+    if let Some(delta) = response.total_drag_delta() {
+        let pulled = delta.x * outward;
+        if pulled > RAIL_DRAG_THRESHOLD {
+            ctx.set_open(true);
+        } else if pulled < -RAIL_DRAG_THRESHOLD {
+            ctx.set_open(false);
+        }
+    }
+
+    if response.hovered() || response.dragged() {
+        ui.painter().vline(
+            edge,
+            ctx.rect.y_range(),
+            Stroke::new(2.0, theme.palette.sidebar_border),
+        );
+    }
+
+    let collapses_west = (ctx.side == SidebarSide::Left) == *ctx.open;
+    response.on_hover_cursor(if collapses_west {
+        CursorIcon::ResizeWest
+    } else {
+        CursorIcon::ResizeEast
+    })
+}
+
 pub fn sidebar_trigger(
     ui: &mut Ui,
     theme: &Theme,
@@ -413,6 +644,7 @@ impl SidebarMenuButtonSize {
 #[derive(Clone, Debug)]
 pub struct SidebarMenuButtonProps {
     pub label: WidgetText,
+    pub icon: Option<Icon>,
     pub size: SidebarMenuButtonSize,
     pub active: bool,
     pub disabled: bool,
@@ -423,6 +655,7 @@ impl SidebarMenuButtonProps {
     pub fn new(label: impl Into<WidgetText>) -> Self {
         Self {
             label: label.into(),
+            icon: None,
             size: SidebarMenuButtonSize::Md,
             active: false,
             disabled: false,
@@ -430,6 +663,13 @@ impl SidebarMenuButtonProps {
         }
     }
 
+    /// Drawn before the label. A collapsed sidebar shows only the icon and moves the label into
+    /// a tooltip. Requires the Lucide font.
+    pub fn icon(mut self, icon: Icon) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+
     pub fn size(mut self, size: SidebarMenuButtonSize) -> Self {
         self.size = size;
         self
@@ -456,8 +696,20 @@ pub fn sidebar_menu_button(
     theme: &Theme,
     ctx: &SidebarContext,
     props: SidebarMenuButtonProps,
+) -> Response {
+    menu_button(ui, theme, ctx, props, None)
+}
+
+/// `chevron` is the openness of an attached sub-menu, drawn as a rotating chevron.
+fn menu_button(
+    ui: &mut Ui,
+    theme: &Theme,
+    ctx: &SidebarContext,
+    props: SidebarMenuButtonProps,
+    chevron: Option<f32>,
 ) -> Response {
     let collapsed = ctx.is_collapsed();
+    let icon_only = collapsed && (props.icon.is_some() || !props.show_label_when_collapsed);
     let height = props.size.height();
     let padding = props.size.padding();
     let desired = vec2(ui.available_width(), height);
@@ -486,33 +738,54 @@ pub fn sidebar_menu_button(
     } else {
         palette.sidebar_foreground
     };
-
-    let label_text = if collapsed && !props.show_label_when_collapsed {
-        let mut short = props.label.text().to_string();
-        short.truncate(1);
-        WidgetText::from(short)
-    } else {
-        props.label
-    };
-
-    let align = if collapsed && !props.show_label_when_collapsed {
-        Align2::CENTER_CENTER
-    } else {
-        Align2::LEFT_CENTER
-    };
-    let pos = if collapsed && !props.show_label_when_collapsed {
-        rect.center()
+    let font = FontId::proportional(props.size.text_size());
+    let icon_font = FontId::proportional(props.size.text_size() + 3.0);
+
+    if icon_only {
+        let glyph = match props.icon {
+            Some(icon) => icon.unicode().to_string(),
+            None => props.label.text().chars().take(1).collect(),
+        };
+        let font = if props.icon.is_some() {
+            icon_font
+        } else {
+            font
+        };
+        ui.painter().text(
+            rect.center(),
+            Align2::CENTER_CENTER,
+            glyph,
+            font,
+            text_color,
+        );
     } else {
-        pos2(rect.left() + padding.left as f32, rect.center().y)
-    };
-
-    ui.painter().text(
-        pos,
-        align,
-        label_text.text(),
-        FontId::proportional(props.size.text_size()),
-        text_color,
-    );
+        let mut x = rect.left() + padding.left as f32;
+        if let Some(icon) = props.icon {
+            let galley =
+                ui.painter()
+                    .layout_no_wrap(icon.unicode().to_string(), icon_font, text_color);
+            x += galley.size().x + 8.0;
+            ui.painter().galley(
+                pos2(
+                    rect.left() + padding.left as f32,
+                    rect.center().y - galley.size().y / 2.0,
+                ),
+                galley,
+                text_color,
+            );
+        }
+        ui.painter().text(
+            pos2(x, rect.center().y),
+            Align2::LEFT_CENTER,
+            props.label.text(),
+            font,
+            text_color,
+        );
+        if let Some(openness) = chevron {
+            let center = pos2(rect.right() - padding.right as f32 - 4.0, rect.center().y);
+            paint_chevron(ui.painter(), center, openness, text_color);
+        }
+    }
 
     if response.has_focus() && !props.disabled {
         let focus_color = palette.sidebar_ring;
@@ -524,9 +797,311 @@ pub fn sidebar_menu_button(
         );
     }
 
+    if icon_only {
+        tooltip(
+            &response,
+            ui,
+            theme,
+            TooltipProps::new(props.label.text()).side(ctx.tooltip_side()),
+        );
+    }
+
     if props.disabled {
         response
     } else {
         response.on_hover_cursor(egui::CursorIcon::PointingHand)
     }
 }
+
+/// A right-pointing chevron that turns to point down as `openness` goes from 0 to 1.
+pub(crate) fn paint_chevron(painter: &Painter, center: Pos2, openness: f32, color: Color32) {
+    let angle = openness * std::f32::consts::FRAC_PI_2;
+    let (sin, cos) = angle.sin_cos();
+    let rotate = |x: f32, y: f32| center + vec2(x * cos - y * sin, x * sin + y * cos);
+    painter.line(
+        vec![rotate(-2.0, -4.0), rotate(2.0, 0.0), rotate(-2.0, 4.0)],
+        Stroke::new(1.5, color),
+    );
+}
+
+impl SidebarContext<'_> {
+    fn tooltip_side(&self) -> TooltipSide {
+        match self.side {
+            SidebarSide::Left => TooltipSide::Right,
+            SidebarSide::Right => TooltipSide::Left,
+        }
+    }
+}
+
+/// Nested menu under a menu item, indented behind a guide line. Hidden while the sidebar is
+/// collapsed. Fill it with [`sidebar_menu_item`] and [`sidebar_menu_button`].
+pub fn sidebar_menu_sub<R>(
+    ui: &mut Ui,
+    theme: &Theme,
+    ctx: &SidebarContext,
+    add_contents: impl FnOnce(&mut Ui) -> R,
+) -> Option<R> {
+    if ctx.is_collapsed() {
+        return None;
+    }
+
+    let margin = Margin {
+        left: 24,
+        right: 0,
+        top: 2,
+        bottom: 2,
+    };
+    let inner = Frame::NONE.inner_margin(margin).show(ui, |sub_ui| {
+        sub_ui.spacing_mut().item_spacing = vec2(0.0, 2.0);
+        add_contents(sub_ui)
+    });
+    let rect = inner.response.rect;
+    ui.painter().vline(
+        rect.left() + 14.0,
+        rect.top() + 2.0..=rect.bottom() - 2.0,
+        Stroke::new(1.0, theme.palette.sidebar_border),
+    );
+    Some(inner.inner)
+}
+
+/// Paints a count or short label at the right end of a menu button. Hidden while the sidebar is
+/// collapsed.
+pub fn sidebar_menu_badge(
+    ui: &mut Ui,
+    theme: &Theme,
+    ctx: &SidebarContext,
+    button: &Response,
+    text: impl Into<String>,
+) {
+    if ctx.is_collapsed() {
+        return;
+    }
+    let color = if button.hovered() {
+        theme.palette.sidebar_accent_foreground
+    } else {
+        theme.palette.sidebar_foreground
+    };
+    ui.painter().text(
+        pos2(button.rect.right() - 8.0, button.rect.center().y),
+        Align2::RIGHT_CENTER,
+        text.into(),
+        FontId::proportional(11.0),
+        color,
+    );
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct SidebarMenuActionProps {
+    pub icon: Icon,
+
+    /// Only show the action while its menu button is hovered.
+    pub show_on_hover: bool,
+}
+
+impl SidebarMenuActionProps {
+    pub fn new(icon: Icon) -> Self {
+        Self {
+            icon,
+            show_on_hover: false,
+        }
+    }
+
+    pub fn show_on_hover(mut self, show: bool) -> Self {
+        self.show_on_hover = show;
+        self
+    }
+}
+
+/// A small icon button on the right end of a menu button, e.g. a "more" menu. Clicks land on
+/// the action instead of the menu button. Hidden while the sidebar is collapsed.
+pub fn sidebar_menu_action(
+    ui: &mut Ui,
+    theme: &Theme,
+    ctx: &SidebarContext,
+    button: &Response,
+    props: SidebarMenuActionProps,
+) -> Response {
+    if ctx.is_collapsed() {
+        return ui.allocate_response(Vec2::ZERO, Sense::hover());
+    }
+
+    let rect = Rect::from_center_size(
+        pos2(button.rect.right() - 14.0, button.rect.center().y),
+        Vec2::splat(20.0),
+    );
+    let response = ui.interact(rect, button.id.with("sidebar-menu-action"), Sense::click());
+    let visible =
+        !props.show_on_hover || ui.rect_contains_pointer(button.rect) || response.has_focus();
+    if !visible {
+        return response;
+    }
+
+    let palette = &theme.palette;
+    if response.hovered() || response.has_focus() {
+        ui.painter().rect_filled(
+            rect,
+            CornerRadius::same(theme.radius.r2.round() as u8),
+            palette.sidebar_accent,
+        );
+    }
+    ui.painter().text(
+        rect.center(),
+        Align2::CENTER_CENTER,
+        props.icon.unicode(),
+        FontId::proportional(14.0),
+        palette.sidebar_accent_foreground,
+    );
+    response.on_hover_cursor(CursorIcon::PointingHand)
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct SidebarCollapsibleProps {
+    pub id_source: Id,
+    pub default_open: bool,
+}
+
+impl SidebarCollapsibleProps {
+    pub fn new(id_source: Id) -> Self {
+        Self {
+            id_source,
+            default_open: true,
+        }
+    }
+
+    pub fn default_open(mut self, open: bool) -> Self {
+        self.default_open = open;
+        self
+    }
+}
+
+pub struct SidebarCollapsibleResponse<R> {
+    /// The trigger: the group label or the menu button.
+    pub response: Response,
+
+    /// `None` while the section is closed or hidden.
+    pub inner: Option<R>,
+    pub open: bool,
+}
+
+/// A group whose label opens and closes its contents. While the sidebar is collapsed the label
+/// is hidden and the contents always show.
+pub fn sidebar_group_collapsible<R>(
+    ui: &mut Ui,
+    theme: &Theme,
+    ctx: &SidebarContext,
+    props: SidebarCollapsibleProps,
+    label: SidebarGroupLabelProps,
+    add_contents: impl FnOnce(&mut Ui) -> R,
+) -> SidebarCollapsibleResponse<R> {
+    if ctx.is_collapsed() {
+        let response = ui.allocate_response(Vec2::ZERO, Sense::hover());
+        let inner = sidebar_group_content(ui, ctx, add_contents);
+        return SidebarCollapsibleResponse {
+            response,
+            inner: Some(inner),
+            open: true,
+        };
+    }
+
+    sidebar_collapsible(
+        ui,
+        theme,
+        props,
+        |ui, openness| {
+            let (rect, response) =
+                ui.allocate_exact_size(vec2(ui.available_width(), 28.0), Sense::click());
+            let palette = &theme.palette;
+            if response.hovered() || response.has_focus() {
+                ui.painter().rect_filled(
+                    rect,
+                    CornerRadius::same(theme.radius.r2.round() as u8),
+                    palette.sidebar_accent,
+                );
+            }
+            let color = palette.sidebar_foreground.gamma_multiply(0.6);
+            ui.painter().text(
+                pos2(rect.left() + 8.0, rect.center().y),
+                Align2::LEFT_CENTER,
+                label.text.text(),
+                FontId::proportional(11.0),
+                color,
+            );
+            paint_chevron(
+                ui.painter(),
+                pos2(rect.right() - 12.0, rect.center().y),
+                openness,
+                color,
+            );
+            response.on_hover_cursor(CursorIcon::PointingHand)
+        },
+        |ui| sidebar_group_content(ui, ctx, add_contents),
+    )
+}
+
+/// A menu button that opens a [`sidebar_menu_sub`] below it, with a chevron showing its state.
+pub fn sidebar_menu_collapsible<R>(
+    ui: &mut Ui,
+    theme: &Theme,
+    ctx: &SidebarContext,
+    props: SidebarCollapsibleProps,
+    button: SidebarMenuButtonProps,
+    add_sub: impl FnOnce(&mut Ui) -> R,
+) -> SidebarCollapsibleResponse<R> {
+    let mut result = sidebar_collapsible(
+        ui,
+        theme,
+        props,
+        |ui, openness| menu_button(ui, theme, ctx, button, Some(openness)),
+        |ui| sidebar_menu_sub(ui, theme, ctx, add_sub),
+    );
+    let inner = result.inner.take().flatten();
+    SidebarCollapsibleResponse {
+        response: result.response,
+        inner,
+        open: result.open,
+    }
+}
+
+fn sidebar_collapsible<R>(
+    ui: &mut Ui,
+    theme: &Theme,
+    props: SidebarCollapsibleProps,
+    add_trigger: impl FnOnce(&mut Ui, f32) -> Response,
+    add_contents: impl FnOnce(&mut Ui) -> R,
+) -> SidebarCollapsibleResponse<R> {
+    let open_id = props.id_source.with("sidebar-collapsible-open");
+    let mut open = ui
+        .ctx()
+        .data(|d| d.get_temp::<bool>(open_id))
+        .unwrap_or(props.default_open);
+    let openness = ui.ctx().animate_bool_with_time(
+        props.id_source.with("sidebar-collapsible-chevron"),
+        open,
+        theme.motion.fast_ms / 1000.0,
+    );
+
+    let (response, inner) = ui
+        .vertical(|ui| {
+            ui.spacing_mut().item_spacing = vec2(0.0, 4.0);
+            collapsible(
+                ui,
+                theme,
+                CollapsibleProps::new(props.id_source, &mut open).with_animation(true),
+                |ui, collapsible| {
+                    let response = collapsible.trigger(ui, |ui| add_trigger(ui, openness));
+                    let inner = collapsible
+                        .content(ui, add_contents)
+                        .map(|inner| inner.inner);
+                    (response, inner)
+                },
+            )
+        })
+        .inner;
+    ui.ctx().data_mut(|d| d.insert_temp(open_id, open));
+
+    SidebarCollapsibleResponse {
+        response,
+        inner,
+        open,
+    }
+}
@@ -0,0 +1,749 @@
+//! Drag-and-drop reordering. [`sortable`] lays out items along an axis; they can be dragged
+//! with the pointer, or picked up with Space, moved with the arrow keys and dropped with Space
+//! or Enter. Dropping reports a [`SortableMove`] that the caller applies to its own data.
+//!
+//! Lists sharing a [`SortableProps::group`] accept items from each other, which is how kanban
+//! boards are built. With the keyboard, the arrows across the axis move to the neighbouring list.
+//!
+//! # Example
+//! ```ignore
+//! let response = sortable(ui, &theme, SortableProps::new(Id::new("tasks"), tasks.len()), |ui, item| {
+//!     ui.label(&tasks[item.index]);
+//! });
+//! if let Some(moved) = response.moved {
+//!     reorder_items(&mut tasks, &moved);
+//! }
+//! ```
+
+use crate::card::{CardSize, CardTokens, CardVariant, card_tokens_with_options};
+use crate::theme::Theme;
+use egui::{
+    Align, Area, Context, CursorIcon, FocusDirection, Frame, Id, Key, Layout, Margin, Modifiers,
+    Order, Rect, Response, Sense, Stroke, StrokeKind, Ui, UiBuilder, Vec2, vec2,
+};
+
+// How far outside a list the pointer may be and still drop into it.
+const DROP_MARGIN: f32 = 8.0;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SortableAxis {
+    #[default]
+    Vertical,
+    Horizontal,
+}
+
+impl SortableAxis {
+    fn main(self, v: Vec2) -> f32 {
+        match self {
+            SortableAxis::Vertical => v.y,
+            SortableAxis::Horizontal => v.x,
+        }
+    }
+
+    fn cross(self, v: Vec2) -> f32 {
+        match self {
+            SortableAxis::Vertical => v.x,
+            SortableAxis::Horizontal => v.y,
+        }
+    }
+
+    fn vec(self, main: f32, cross: f32) -> Vec2 {
+        match self {
+            SortableAxis::Vertical => vec2(cross, main),
+            SortableAxis::Horizontal => vec2(main, cross),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct SortableProps {
+    pub id_source: Id,
+    pub len: usize,
+    pub axis: SortableAxis,
+
+    /// Lists in the same group exchange items. Defaults to the list's own id.
+    pub group: Option<Id>,
+
+    pub spacing: f32,
+
+    /// Paints every item as a card.
+    pub card: bool,
+
+    /// Lets items take keyboard focus. Turn it off when items contain their own focusable
+    /// widgets; Space then picks up the item that holds the focus.
+    pub focusable: bool,
+
+    /// Keeps an empty list large enough to drop onto.
+    pub min_size: Vec2,
+
+    pub animate: bool,
+    pub enabled: bool,
+}
+
+impl SortableProps {
+    pub fn new(id_source: Id, len: usize) -> Self {
+        Self {
+            id_source,
+            len,
+            axis: SortableAxis::Vertical,
+            group: None,
+            spacing: 8.0,
+            card: true,
+            focusable: true,
+            min_size: Vec2::ZERO,
+            animate: true,
+            enabled: true,
+        }
+    }
+
+    pub fn axis(mut self, axis: SortableAxis) -> Self {
+        self.axis = axis;
+        self
+    }
+
+    pub fn group(mut self, group: Id) -> Self {
+        self.group = Some(group);
+        self
+    }
+
+    pub fn spacing(mut self, spacing: f32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    pub fn card(mut self, card: bool) -> Self {
+        self.card = card;
+        self
+    }
+
+    pub fn focusable(mut self, focusable: bool) -> Self {
+        self.focusable = focusable;
+        self
+    }
+
+    pub fn min_size(mut self, min_size: Vec2) -> Self {
+        self.min_size = min_size;
+        self
+    }
+
+    pub fn animate(mut self, animate: bool) -> Self {
+        self.animate = animate;
+        self
+    }
+
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SortableLocation {
+    /// The `id_source` of the list.
+    pub container: Id,
+    pub index: usize,
+}
+
+/// An item dropped at a new place. `to.index` counts the items of the target list after the
+/// moved item has been taken out, so it can be inserted there directly.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SortableMove {
+    pub from: SortableLocation,
+    pub to: SortableLocation,
+}
+
+impl SortableMove {
+    /// Whether the item stayed in its list.
+    pub fn is_reorder(&self) -> bool {
+        self.from.container == self.to.container
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct SortableItem {
+    pub index: usize,
+
+    /// The item is being dragged or was picked up with the keyboard.
+    pub dragged: bool,
+}
+
+#[derive(Clone, Debug)]
+pub struct SortableResponse {
+    pub response: Response,
+    pub moved: Option<SortableMove>,
+
+    /// Index of this list's item being dragged.
+    pub dragging: Option<usize>,
+
+    /// Where the dragged item would land in this list.
+    pub drop_index: Option<usize>,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct DragSession {
+    source: SortableLocation,
+    target: Option<SortableLocation>,
+    size: Vec2,
+
+    /// Pointer position relative to the item's corner when the drag began.
+    grab: Vec2,
+    keyboard: bool,
+}
+
+/// Last frame's layout, used for hit testing before this frame's items exist.
+#[derive(Clone, Debug)]
+struct ListMemory {
+    rect: Rect,
+
+    /// Resting rect of each item, or `Rect::NOTHING` while it is dragged away.
+    slots: Vec<Rect>,
+}
+
+impl Default for ListMemory {
+    fn default() -> Self {
+        Self {
+            rect: Rect::NOTHING,
+            slots: Vec::new(),
+        }
+    }
+}
+
+/// The lists of a group in the order they were shown, for moving between them with the keyboard.
+#[derive(Clone, Debug, Default)]
+struct ListRegistry {
+    pass: u64,
+    current: Vec<(Id, usize)>,
+    previous: Vec<(Id, usize)>,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Slot {
+    Item(usize),
+    Gap,
+}
+
+pub fn sortable(
+    ui: &mut Ui,
+    theme: &Theme,
+    props: SortableProps,
+    mut add_item: impl FnMut(&mut Ui, SortableItem),
+) -> SortableResponse {
+    let id = props.id_source;
+    let group = props.group.unwrap_or(id);
+    let ctx = ui.ctx().clone();
+    let session_id = group.with("sortable-session");
+    let memory_id = id.with("sortable-memory");
+    let mut session = ctx.data(|d| d.get_temp::<DragSession>(session_id));
+    let memory = ctx
+        .data(|d| d.get_temp::<ListMemory>(memory_id))
+        .unwrap_or_default();
+    let lists = register_list(&ctx, group, id, props.len);
+    let (pointer, primary_down, released) = ui.input(|i| {
+        (
+            i.pointer.interact_pos(),
+            i.pointer.primary_down(),
+            i.pointer.primary_released(),
+        )
+    });
+    let mut moved = None;
+
+    // A pointer drag that ended outside every list is dropped on the next frame.
+    if session.is_some_and(|s| !s.keyboard && !primary_down && !released) {
+        session = None;
+    }
+
+    handle_keys(ui, &props, &memory, &lists, &mut session, &mut moved);
+
+    if let Some(mut s) = session.filter(|s| !s.keyboard)
+        && let Some(pointer) = pointer
+    {
+        if memory.rect.expand(DROP_MARGIN).contains(pointer) {
+            let center = props.axis.main((pointer - s.grab).to_vec2() + s.size * 0.5);
+            let index = memory
+                .slots
+                .iter()
+                .enumerate()
+                .filter(|(index, slot)| {
+                    slot.is_positive()
+                        && !(s.source.container == id && *index == s.source.index)
+                        && props.axis.main(slot.center().to_vec2()) < center
+                })
+                .count();
+            s.target = Some(SortableLocation {
+                container: id,
+                index,
+            });
+        } else if s.target.is_some_and(|target| target.container == id) {
+            s.target = None;
+        }
+        session = Some(s);
+        if released && let Some(target) = s.target.filter(|target| target.container == id) {
+            moved = (target != s.source).then_some(SortableMove {
+                from: s.source,
+                to: target,
+            });
+            session = None;
+        }
+    }
+
+    let source_here = session.filter(|s| s.source.container == id);
+    let target_here = session
+        .and_then(|s| s.target)
+        .filter(|target| target.container == id);
+    let keyboard = session.is_some_and(|s| s.keyboard);
+    let mut order: Vec<Slot> = (0..props.len)
+        .filter(|index| source_here.is_none_or(|s| s.source.index != *index))
+        .map(Slot::Item)
+        .collect();
+    match (source_here, target_here) {
+        (Some(s), Some(target)) => {
+            let slot = if keyboard {
+                Slot::Item(s.source.index)
+            } else {
+                Slot::Gap
+            };
+            order.insert(target.index.min(order.len()), slot);
+        }
+        // Out of every list, the item keeps its place.
+        (Some(s), None) if s.target.is_none() => {
+            order.insert(s.source.index.min(order.len()), Slot::Gap);
+        }
+        (None, Some(target)) => order.insert(target.index.min(order.len()), Slot::Gap),
+        _ => {}
+    }
+
+    let axis = props.axis;
+    let origin = ui.cursor().min;
+    let available = ui.available_size();
+    let duration = if session.is_some() && props.animate {
+        theme.motion.base_ms / 1000.0
+    } else {
+        0.0
+    };
+    let tokens = card_tokens_with_options(
+        &theme.palette,
+        CardVariant::Surface,
+        true,
+        CardSize::Size1,
+        false,
+    );
+    let mut slots = vec![Rect::NOTHING; props.len];
+    let mut offset = 0.0;
+    let mut cross = match axis {
+        SortableAxis::Vertical => available.x,
+        SortableAxis::Horizontal => 0.0,
+    };
+    let mut started = None;
+
+    for (position, slot) in order.iter().enumerate() {
+        if position > 0 {
+            offset += props.spacing;
+        }
+        match *slot {
+            Slot::Gap => {
+                let size = session.map_or(Vec2::ZERO, |s| s.size);
+                let rect = Rect::from_min_size(origin + axis.vec(offset, 0.0), size);
+                paint_placeholder(ui, theme, rect);
+                offset += axis.main(size);
+                cross = cross.max(axis.cross(size));
+            }
+            Slot::Item(index) => {
+                let shown = ctx.animate_value_with_time(
+                    id.with(("sortable-offset", index)),
+                    offset,
+                    duration,
+                );
+                let max_rect = item_max_rect(axis, origin + axis.vec(shown, 0.0), available);
+                let item = SortableItem {
+                    index,
+                    dragged: keyboard && source_here.is_some_and(|s| s.source.index == index),
+                };
+                let (rect, response) =
+                    show_item(ui, theme, &props, &tokens, max_rect, item, &mut add_item);
+                if response.drag_started()
+                    && session.is_none()
+                    && let Some(press) = ui.input(|i| i.pointer.press_origin())
+                {
+                    started = Some(DragSession {
+                        source: SortableLocation {
+                            container: id,
+                            index,
+                        },
+                        target: None,
+                        size: rect.size(),
+                        grab: press - rect.min,
+                        keyboard: false,
+                    });
+                }
+                slots[index] = Rect::from_min_size(origin + axis.vec(offset, 0.0), rect.size());
+                offset += axis.main(rect.size());
+                cross = cross.max(axis.cross(rect.size()));
+            }
+        }
+    }
+    // The item follows the pointer in a layer of its own.
+    if let Some(s) = session.filter(|s| !s.keyboard && s.source.container == id)
+        && let Some(pointer) = pointer
+    {
+        let item = SortableItem {
+            index: s.source.index,
+            dragged: true,
+        };
+        Area::new(group.with("sortable-preview"))
+            .order(Order::Tooltip)
+            .fixed_pos(pointer - s.grab)
+            .interactable(false)
+            .show(&ctx, |area_ui| {
+                let max_rect = item_max_rect(axis, area_ui.max_rect().min, s.size);
+                show_item(
+                    area_ui,
+                    theme,
+                    &props,
+                    &tokens,
+                    max_rect,
+                    item,
+                    &mut add_item,
+                );
+            });
+        ctx.set_cursor_icon(CursorIcon::Grabbing);
+    }
+
+    // The preview starts on the next frame, once the item has left the list.
+    if let Some(mut s) = started {
+        s.target = Some(s.source);
+        session = Some(s);
+    }
+
+    let rect = Rect::from_min_size(origin, axis.vec(offset, cross).max(props.min_size));
+    let mut response = ui.allocate_rect(rect, Sense::hover());
+    if moved.is_some() {
+        response.mark_changed();
+    }
+    if session.is_some() || moved.is_some() {
+        ctx.request_repaint();
+    }
+
+    ctx.data_mut(|d| {
+        d.insert_temp(memory_id, ListMemory { rect, slots });
+        match session {
+            Some(s) => d.insert_temp(session_id, s),
+            None => d.remove::<DragSession>(session_id),
+        }
+    });
+
+    SortableResponse {
+        response,
+        moved,
+        dragging: source_here.map(|s| s.source.index),
+        drop_index: target_here.map(|target| target.index),
+    }
+}
+
+/// Records the list for this pass and returns the group's lists from the last complete pass.
+fn register_list(ctx: &Context, group: Id, id: Id, len: usize) -> Vec<(Id, usize)> {
+    let registry_id = group.with("sortable-lists");
+    let pass = ctx.cumulative_pass_nr();
+    ctx.data_mut(|d| {
+        let registry = d.get_temp_mut_or_default::<ListRegistry>(registry_id);
+        if registry.pass != pass {
+            registry.previous = std::mem::take(&mut registry.current);
+            registry.pass = pass;
+        }
+        if !registry.current.iter().any(|(list, _)| *list == id) {
+            registry.current.push((id, len));
+        }
+        if registry.previous.is_empty() {
+            registry.current.clone()
+        } else {
+            registry.previous.clone()
+        }
+    })
+}
+
+/// Space picks up the focused item; while it is held the arrows move it, Space or Enter drop
+/// it and Escape puts it back.
+fn handle_keys(
+    ui: &Ui,
+    props: &SortableProps,
+    memory: &ListMemory,
+    lists: &[(Id, usize)],
+    session: &mut Option<DragSession>,
+    moved: &mut Option<SortableMove>,
+) {
+    let id = props.id_source;
+    let ctx = ui.ctx();
+    let Some(mut s) = *session else {
+        if !props.enabled {
+            return;
+        }
+        // Focusable items are picked up themselves, leaving Space to the widgets inside them.
+        let focused = ctx.memory(|m| m.focused());
+        let index = if props.focusable {
+            focused.and_then(|focused| (0..props.len).find(|index| item_id(id, *index) == focused))
+        } else {
+            focused
+                .and_then(|focused| ctx.read_response(focused))
+                .and_then(|focused| {
+                    memory
+                        .slots
+                        .iter()
+                        .position(|slot| slot.contains(focused.rect.center()))
+                })
+        };
+        let index = index.filter(|index| *index < memory.slots.len());
+        if let Some(index) = index
+            && ui.input_mut(|i| i.consume_key(Modifiers::NONE, Key::Space))
+        {
+            let source = SortableLocation {
+                container: id,
+                index,
+            };
+            *session = Some(DragSession {
+                source,
+                target: Some(source),
+                size: memory.slots[index].size(),
+                grab: Vec2::ZERO,
+                keyboard: true,
+            });
+        }
+        return;
+    };
+    if s.source.container != id {
+        return;
+    }
+    if ui.input_mut(|i| i.consume_key(Modifiers::NONE, Key::Escape)) {
+        *session = None;
+        return;
+    }
+    if !s.keyboard {
+        return;
+    }
+
+    let (prev, next, cross_prev, cross_next) = match props.axis {
+        SortableAxis::Vertical => (
+            Key::ArrowUp,
+            Key::ArrowDown,
+            Key::ArrowLeft,
+            Key::ArrowRight,
+        ),
+        SortableAxis::Horizontal => (
+            Key::ArrowLeft,
+            Key::ArrowRight,
+            Key::ArrowUp,
+            Key::ArrowDown,
+        ),
+    };
+    let (prev, next, cross_prev, cross_next, drop) = ui.input_mut(|i| {
+        (
+            i.consume_key(Modifiers::NONE, prev),
+            i.consume_key(Modifiers::NONE, next),
+            i.consume_key(Modifiers::NONE, cross_prev),
+            i.consume_key(Modifiers::NONE, cross_next),
+            i.consume_key(Modifiers::NONE, Key::Space) | i.consume_key(Modifiers::NONE, Key::Enter),
+        )
+    });
+    if prev || next || cross_prev || cross_next {
+        // The arrows move the item, not the focus.
+        ctx.memory_mut(|m| m.move_focus(FocusDirection::None));
+    }
+
+    // The last index the item can take in a list.
+    let last_index = |container: Id| {
+        let len = lists
+            .iter()
+            .find(|(list, _)| *list == container)
+            .map_or(props.len, |(_, len)| *len);
+        if container == s.source.container {
+            len.saturating_sub(1)
+        } else {
+            len
+        }
+    };
+    let mut target = s.target.unwrap_or(s.source);
+    if prev {
+        target.index = target.index.saturating_sub(1);
+    }
+    if next {
+        target.index = (target.index + 1).min(last_index(target.container));
+    }
+    if cross_prev || cross_next {
+        let position = lists.iter().position(|(list, _)| *list == target.container);
+        let neighbour = position.and_then(|position| {
+            if cross_next {
+                lists.get(position + 1)
+            } else {
+                position
+                    .checked_sub(1)
+                    .and_then(|position| lists.get(position))
+            }
+        });
+        if let Some((container, _)) = neighbour {
+            target = SortableLocation {
+                container: *container,
+                index: target.index.min(last_index(*container)),
+            };
+        }
+    }
+    s.target = Some(target);
+    *session = Some(s);
+
+    if drop {
+        *moved = (target != s.source).then_some(SortableMove {
+            from: s.source,
+            to: target,
+        });
+        *session = None;
+        if props.focusable {
+            let item_id = item_id(target.container, target.index);
+            ctx.memory_mut(|m| m.request_focus(item_id));
+        }
+    }
+}
+
+/// The id an item's `Ui` gets, and with it the focus, wherever the item is shown.
+fn item_id(list: Id, index: usize) -> Id {
+    Id::new(item_salt(list, index))
+}
+
+fn item_salt(list: Id, index: usize) -> Id {
+    list.with(("sortable-item", index))
+}
+
+fn item_max_rect(axis: SortableAxis, min: egui::Pos2, available: Vec2) -> Rect {
+    let size = match axis {
+        SortableAxis::Vertical => vec2(available.x, f32::INFINITY),
+        SortableAxis::Horizontal => vec2(f32::INFINITY, available.y),
+    };
+    Rect::from_min_size(min, size)
+}
+
+fn show_item(
+    ui: &mut Ui,
+    theme: &Theme,
+    props: &SortableProps,
+    tokens: &CardTokens,
+    max_rect: Rect,
+    item: SortableItem,
+    add_item: &mut impl FnMut(&mut Ui, SortableItem),
+) -> (Rect, Response) {
+    let sense = match (props.enabled, props.focusable) {
+        (false, _) => Sense::hover(),
+        (true, true) => Sense::drag(),
+        (true, false) => Sense::DRAG,
+    };
+    let layout = match props.axis {
+        SortableAxis::Vertical => Layout::top_down(Align::Min),
+        SortableAxis::Horizontal => Layout::left_to_right(Align::Min),
+    };
+    let mut child = ui.new_child(
+        UiBuilder::new()
+            .id_salt(item_salt(props.id_source, item.index))
+            .global_scope(true)
+            .max_rect(max_rect)
+            .layout(layout)
+            .sense(sense),
+    );
+    // Selectable labels would take the drag for text selection.
+    child.style_mut().interaction.selectable_labels = false;
+    let response = child.response();
+    let hovered = response.hovered() && props.enabled;
+
+    if props.card {
+        let (fill, stroke, shadow) = if item.dragged {
+            (
+                tokens.background_active,
+                tokens.stroke_active,
+                tokens.shadow_hover,
+            )
+        } else if hovered {
+            (
+                tokens.background_hover,
+                tokens.stroke_hover,
+                tokens.shadow_idle,
+            )
+        } else {
+            (tokens.background, tokens.stroke, tokens.shadow_idle)
+        };
+        let padding = CardSize::Size1.padding();
+        Frame::new()
+            .fill(fill)
+            .stroke(stroke)
+            .shadow(shadow)
+            .corner_radius(CardSize::Size1.rounding_with_scale(&theme.radius))
+            .inner_margin(Margin::symmetric(padding.x as i8, padding.y as i8))
+            .show(&mut child, |ui| {
+                if props.axis == SortableAxis::Vertical {
+                    ui.set_min_width(ui.available_width());
+                }
+                add_item(ui, item);
+            });
+    } else {
+        add_item(&mut child, item);
+    }
+
+    let rect = child.min_rect();
+    if response.has_focus() || (item.dragged && !ui.is_sizing_pass()) {
+        let radius = if props.card {
+            CardSize::Size1.rounding_with_scale(&theme.radius)
+        } else {
+            theme.radius.r2.round().into()
+        };
+        child.painter().rect_stroke(
+            rect,
+            radius,
+            theme.focus.stroke(theme.palette.ring),
+            StrokeKind::Outside,
+        );
+    }
+    drop(child);
+
+    let response = if hovered {
+        response.on_hover_cursor(CursorIcon::Grab)
+    } else {
+        response
+    };
+    (rect, response)
+}
+
+/// Marks where the dragged item will land.
+fn paint_placeholder(ui: &Ui, theme: &Theme, rect: Rect) {
+    let palette = &theme.palette;
+    ui.painter().rect(
+        rect,
+        CardSize::Size1.rounding_with_scale(&theme.radius),
+        palette.muted.gamma_multiply(0.5),
+        Stroke::new(1.0, palette.ring),
+        StrokeKind::Inside,
+    );
+}
+
+/// Applies a move within one list. Returns `false` for moves between lists.
+pub fn reorder_items<T>(items: &mut Vec<T>, moved: &SortableMove) -> bool {
+    if !moved.is_reorder() || moved.from.index >= items.len() {
+        return false;
+    }
+    let item = items.remove(moved.from.index);
+    let index = moved.to.index.min(items.len());
+    items.insert(index, item);
+    true
+}
+
+/// Applies a move between `lists`, where `containers[i]` is the `id_source` of `lists[i]`.
+pub fn apply_sortable_move<T>(
+    lists: &mut [Vec<T>],
+    containers: &[Id],
+    moved: &SortableMove,
+) -> bool {
+    let position = |container: Id| containers.iter().position(|id| *id == container);
+    let (Some(from), Some(to)) = (position(moved.from.container), position(moved.to.container))
+    else {
+        return false;
+    };
+    if from >= lists.len() || to >= lists.len() || moved.from.index >= lists[from].len() {
+        return false;
+    }
+    let item = lists[from].remove(moved.from.index);
+    let index = moved.to.index.min(lists[to].len());
+    lists[to].insert(index, item);
+    true
+}
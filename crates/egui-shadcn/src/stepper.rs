@@ -0,0 +1,843 @@
+//! Stepper for multi-step flows such as onboarding wizards. Each [`StepItem`] names the
+//! [`FormState`] fields it owns; moving forward validates those fields first and marks the
+//! step as errored when they fail.
+//!
+//! # Example
+//! ```ignore
+//! let steps = [
+//!     StepItem::new("Account").fields(["email", "password"]),
+//!     StepItem::new("Profile").fields(["name"]).optional(true),
+//!     StepItem::new("Confirm"),
+//! ];
+//! stepper(ui, &theme, StepperProps::new(Id::new("signup"), &steps, &mut state, &mut form), |ui, step, form| {
+//!     // Fields of `step`.
+//! });
+//! ```
+
+use crate::button::{Button, ButtonSize, ButtonVariant};
+use crate::form::FormState;
+use crate::progress::{ProgressProps, ProgressSize, progress};
+use crate::theme::Theme;
+use crate::tokens::mix;
+use egui::{
+    Align, Align2, Color32, FontId, Galley, Id, Layout, Painter, Pos2, Rect, Response, Sense,
+    Stroke, TextStyle, TextWrapMode, Ui, WidgetText, pos2, vec2,
+};
+use lucide_icons::Icon;
+use std::collections::BTreeSet;
+use std::sync::Arc;
+
+const INDICATOR_SIZE: f32 = 28.0;
+const CONNECTOR_GAP: f32 = 6.0;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum StepperOrientation {
+    #[default]
+    Horizontal,
+    Vertical,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StepStatus {
+    Upcoming,
+    Current,
+    Completed,
+    Error,
+    Skipped,
+}
+
+#[derive(Clone, Debug)]
+pub struct StepItem {
+    pub title: WidgetText,
+    pub description: Option<WidgetText>,
+
+    /// Shown in the indicator instead of the step number.
+    pub icon: Option<Icon>,
+
+    /// The form fields validated before leaving this step forward.
+    pub fields: Vec<String>,
+
+    /// Optional steps can be skipped without validating.
+    pub optional: bool,
+}
+
+impl StepItem {
+    pub fn new(title: impl Into<WidgetText>) -> Self {
+        Self {
+            title: title.into(),
+            description: None,
+            icon: None,
+            fields: Vec::new(),
+            optional: false,
+        }
+    }
+
+    pub fn description(mut self, description: impl Into<WidgetText>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn icon(mut self, icon: Icon) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+
+    pub fn fields<S: Into<String>>(mut self, fields: impl IntoIterator<Item = S>) -> Self {
+        self.fields = fields.into_iter().map(Into::into).collect();
+        self
+    }
+
+    pub fn optional(mut self, optional: bool) -> Self {
+        self.optional = optional;
+        self
+    }
+}
+
+/// Where the user is in the flow. The methods can also be called directly, e.g. from
+/// custom navigation buttons when the built-in controls are hidden.
+#[derive(Clone, Debug, Default)]
+pub struct StepperState {
+    pub current: usize,
+    pub completed: BTreeSet<usize>,
+    pub skipped: BTreeSet<usize>,
+
+    /// Steps whose fields failed validation and still have errors.
+    pub errors: BTreeSet<usize>,
+
+    /// The last step has been completed.
+    pub finished: bool,
+}
+
+impl StepperState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn status(&self, index: usize) -> StepStatus {
+        if self.errors.contains(&index) {
+            StepStatus::Error
+        } else if index == self.current && !self.finished {
+            StepStatus::Current
+        } else if self.completed.contains(&index) {
+            StepStatus::Completed
+        } else if self.skipped.contains(&index) {
+            StepStatus::Skipped
+        } else {
+            StepStatus::Upcoming
+        }
+    }
+
+    /// Validates the current step and moves past it. Completing the last step finishes the
+    /// flow. Returns `false` when validation fails.
+    pub fn next(&mut self, steps: &[StepItem], form: &mut FormState) -> bool {
+        let Some(step) = steps.get(self.current) else {
+            return false;
+        };
+        if self.finished {
+            return false;
+        }
+        if !form.validate_fields(&step.fields) {
+            self.errors.insert(self.current);
+            return false;
+        }
+        self.errors.remove(&self.current);
+        self.skipped.remove(&self.current);
+        self.completed.insert(self.current);
+        if self.current + 1 < steps.len() {
+            self.current += 1;
+        } else {
+            self.finished = true;
+        }
+        true
+    }
+
+    /// Moves past an optional step without validating it.
+    pub fn skip(&mut self, steps: &[StepItem]) -> bool {
+        if self.finished || !steps.get(self.current).is_some_and(|step| step.optional) {
+            return false;
+        }
+        self.errors.remove(&self.current);
+        self.completed.remove(&self.current);
+        self.skipped.insert(self.current);
+        if self.current + 1 < steps.len() {
+            self.current += 1;
+        } else {
+            self.finished = true;
+        }
+        true
+    }
+
+    pub fn back(&mut self) -> bool {
+        if self.finished {
+            self.finished = false;
+            return true;
+        }
+        if self.current == 0 {
+            return false;
+        }
+        self.current -= 1;
+        true
+    }
+
+    /// Goes back freely, or forward through `next` so every step on the way is validated.
+    /// Stops at the first step that fails.
+    pub fn go_to(&mut self, index: usize, steps: &[StepItem], form: &mut FormState) -> bool {
+        if index >= steps.len() || (index == self.current && !self.finished) {
+            return false;
+        }
+        if index <= self.current {
+            self.current = index;
+            self.finished = false;
+            return true;
+        }
+        while self.current < index {
+            if !self.next(steps, form) {
+                return false;
+            }
+        }
+        true
+    }
+
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Whether the indicator of `index` can be clicked to jump there: visited steps and the one
+    /// right after the furthest of them.
+    fn reachable(&self, index: usize) -> bool {
+        let furthest = self
+            .completed
+            .iter()
+            .chain(&self.skipped)
+            .copied()
+            .chain(std::iter::once(self.current))
+            .max()
+            .unwrap_or(0);
+        index <= furthest + 1
+    }
+}
+
+#[derive(Debug)]
+pub struct StepperProps<'a> {
+    pub id_source: Id,
+    pub steps: &'a [StepItem],
+    pub state: &'a mut StepperState,
+    pub form: &'a mut FormState,
+    pub orientation: StepperOrientation,
+    pub show_progress: bool,
+    pub show_controls: bool,
+
+    /// Lets visited steps be revisited by clicking their indicator.
+    pub clickable: bool,
+
+    /// Also validates the current step before going back.
+    pub validate_on_back: bool,
+
+    pub back_label: WidgetText,
+    pub next_label: WidgetText,
+    pub finish_label: WidgetText,
+    pub skip_label: WidgetText,
+}
+
+impl<'a> StepperProps<'a> {
+    pub fn new(
+        id_source: Id,
+        steps: &'a [StepItem],
+        state: &'a mut StepperState,
+        form: &'a mut FormState,
+    ) -> Self {
+        Self {
+            id_source,
+            steps,
+            state,
+            form,
+            orientation: StepperOrientation::Horizontal,
+            show_progress: true,
+            show_controls: true,
+            clickable: true,
+            validate_on_back: false,
+            back_label: "Back".into(),
+            next_label: "Next".into(),
+            finish_label: "Finish".into(),
+            skip_label: "Skip".into(),
+        }
+    }
+
+    pub fn orientation(mut self, orientation: StepperOrientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    pub fn show_progress(mut self, show: bool) -> Self {
+        self.show_progress = show;
+        self
+    }
+
+    pub fn show_controls(mut self, show: bool) -> Self {
+        self.show_controls = show;
+        self
+    }
+
+    pub fn clickable(mut self, clickable: bool) -> Self {
+        self.clickable = clickable;
+        self
+    }
+
+    pub fn validate_on_back(mut self, validate: bool) -> Self {
+        self.validate_on_back = validate;
+        self
+    }
+
+    pub fn back_label(mut self, label: impl Into<WidgetText>) -> Self {
+        self.back_label = label.into();
+        self
+    }
+
+    pub fn next_label(mut self, label: impl Into<WidgetText>) -> Self {
+        self.next_label = label.into();
+        self
+    }
+
+    pub fn finish_label(mut self, label: impl Into<WidgetText>) -> Self {
+        self.finish_label = label.into();
+        self
+    }
+
+    pub fn skip_label(mut self, label: impl Into<WidgetText>) -> Self {
+        self.skip_label = label.into();
+        self
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct StepperResponse<R> {
+    pub content: R,
+
+    /// The current step changed, or the flow finished.
+    pub changed: bool,
+
+    /// Moving on was refused because the step's fields are invalid.
+    pub blocked: bool,
+
+    pub finished: bool,
+}
+
+/// Navigation requested this frame, applied after the step's content has run.
+#[derive(Clone, Copy, Debug)]
+enum Nav {
+    Back,
+    Next,
+    Skip,
+    GoTo(usize),
+}
+
+/// Title and secondary lines of one step, laid out once per frame.
+struct StepText {
+    title: Arc<Galley>,
+    lines: Vec<Arc<Galley>>,
+}
+
+impl StepText {
+    fn height(&self) -> f32 {
+        self.title.size().y + self.lines.iter().map(|line| line.size().y).sum::<f32>()
+    }
+
+    fn width(&self) -> f32 {
+        self.lines
+            .iter()
+            .map(|line| line.size().x)
+            .fold(self.title.size().x, f32::max)
+    }
+}
+
+pub fn stepper<R>(
+    ui: &mut Ui,
+    theme: &Theme,
+    props: StepperProps<'_>,
+    add_step: impl FnOnce(&mut Ui, usize, &mut FormState) -> R,
+) -> StepperResponse<R> {
+    let StepperProps {
+        id_source,
+        steps,
+        state,
+        form,
+        orientation,
+        show_progress,
+        show_controls,
+        clickable,
+        validate_on_back,
+        back_label,
+        next_label,
+        finish_label,
+        skip_label,
+    } = props;
+    let labels = Labels {
+        back: back_label,
+        next: next_label,
+        finish: finish_label,
+        skip: skip_label,
+    };
+    let count = steps.len();
+    if count > 0 {
+        state.current = state.current.min(count - 1);
+    }
+
+    // Errors clear as soon as the fields are fixed.
+    state.errors.retain(|index| {
+        steps
+            .get(*index)
+            .is_some_and(|step| form.has_errors(&step.fields))
+    });
+
+    let texts: Vec<StepText> = steps
+        .iter()
+        .map(|step| step_text(ui, theme, step))
+        .collect();
+    let mut nav = None;
+
+    if show_progress && count > 0 {
+        let done = state.completed.len() + state.skipped.len();
+        let label = if state.finished {
+            format!("All {count} steps complete")
+        } else {
+            format!("Step {} of {count}", state.current + 1)
+        };
+        ui.label(
+            egui::RichText::new(&label)
+                .size(12.0)
+                .color(theme.palette.muted_foreground),
+        );
+        ui.add_space(4.0);
+        progress(
+            ui,
+            theme,
+            ProgressProps::new(Some(done as f32))
+                .max(count as f32)
+                .size(ProgressSize::Size1)
+                .label(label),
+        );
+        ui.add_space(16.0);
+    }
+
+    let content = match orientation {
+        StepperOrientation::Horizontal => {
+            if let Some(index) =
+                horizontal_header(ui, theme, id_source, steps, state, &texts, clickable)
+            {
+                nav = Some(Nav::GoTo(index));
+            }
+            ui.add_space(16.0);
+            let content = add_step(ui, state.current, form);
+            if show_controls {
+                ui.add_space(16.0);
+                nav = nav.or(controls(ui, theme, &labels, steps, state));
+            }
+            content
+        }
+        StepperOrientation::Vertical => {
+            let mut content = None;
+            let mut add_step = Some(add_step);
+            let mut previous: Option<Rect> = None;
+            for (index, text) in texts.iter().enumerate() {
+                let (indicator, clicked) = step_row(
+                    ui,
+                    theme,
+                    id_source,
+                    index,
+                    &steps[index],
+                    state,
+                    text,
+                    clickable,
+                );
+                if let Some(previous) = previous {
+                    paint_connector(
+                        ui.painter(),
+                        theme,
+                        state,
+                        index - 1,
+                        previous.center_bottom() + vec2(0.0, CONNECTOR_GAP),
+                        indicator.center_top() - vec2(0.0, CONNECTOR_GAP),
+                    );
+                }
+                if clicked {
+                    nav = Some(Nav::GoTo(index));
+                }
+
+                let mut bottom = indicator;
+                if index == state.current
+                    && let Some(add_step) = add_step.take()
+                {
+                    let indent = INDICATOR_SIZE + 12.0;
+                    let inner = ui.horizontal(|ui| {
+                        ui.add_space(indent);
+                        ui.vertical(|ui| {
+                            ui.add_space(8.0);
+                            content = Some(add_step(ui, index, form));
+                            if show_controls {
+                                ui.add_space(12.0);
+                                nav = nav.or(controls(ui, theme, &labels, steps, state));
+                            }
+                        });
+                    });
+                    bottom = bottom.union(Rect::from_min_max(
+                        pos2(indicator.left(), inner.response.rect.top()),
+                        pos2(indicator.right(), inner.response.rect.bottom()),
+                    ));
+                }
+                if index + 1 < count {
+                    ui.add_space(12.0);
+                }
+                previous = Some(bottom);
+            }
+            // Without steps the loop never reaches the current one.
+            match (content, add_step) {
+                (Some(content), _) => content,
+                (None, Some(add_step)) => add_step(ui, state.current, form),
+                (None, None) => unreachable!("the step content runs once"),
+            }
+        }
+    };
+
+    let before = (state.current, state.finished);
+    let mut blocked = false;
+    match nav {
+        Some(Nav::Next) => blocked = !state.next(steps, form),
+        Some(Nav::Skip) => {
+            state.skip(steps);
+        }
+        Some(Nav::Back) => {
+            let fields = steps
+                .get(state.current)
+                .map_or(&[][..], |step| &step.fields[..]);
+            if validate_on_back && !form.validate_fields(fields) {
+                state.errors.insert(state.current);
+                blocked = true;
+            } else {
+                state.back();
+            }
+        }
+        Some(Nav::GoTo(index)) => blocked = !state.go_to(index, steps, form),
+        None => {}
+    }
+    let changed = before != (state.current, state.finished);
+    if changed || blocked {
+        ui.ctx().request_repaint();
+    }
+
+    StepperResponse {
+        content,
+        changed,
+        blocked,
+        finished: state.finished,
+    }
+}
+
+struct Labels {
+    back: WidgetText,
+    next: WidgetText,
+    finish: WidgetText,
+    skip: WidgetText,
+}
+
+fn controls(
+    ui: &mut Ui,
+    theme: &Theme,
+    labels: &Labels,
+    steps: &[StepItem],
+    state: &StepperState,
+) -> Option<Nav> {
+    let mut nav = None;
+    let last = state.current + 1 >= steps.len();
+    ui.horizontal(|ui| {
+        let back = Button::new(labels.back.clone())
+            .variant(ButtonVariant::Outline)
+            .size(ButtonSize::Sm)
+            .enabled(state.current > 0 || state.finished)
+            .show(ui, theme);
+        if back.clicked() {
+            nav = Some(Nav::Back);
+        }
+        ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
+            let next_label = if last {
+                labels.finish.clone()
+            } else {
+                labels.next.clone()
+            };
+            let next = Button::new(next_label)
+                .size(ButtonSize::Sm)
+                .enabled(!state.finished)
+                .show(ui, theme);
+            if next.clicked() {
+                nav = Some(Nav::Next);
+            }
+            if steps.get(state.current).is_some_and(|step| step.optional) && !state.finished {
+                let skip = Button::new(labels.skip.clone())
+                    .variant(ButtonVariant::Ghost)
+                    .size(ButtonSize::Sm)
+                    .show(ui, theme);
+                if skip.clicked() {
+                    nav = Some(Nav::Skip);
+                }
+            }
+        });
+    });
+    nav
+}
+
+fn step_text(ui: &Ui, theme: &Theme, step: &StepItem) -> StepText {
+    let galley = |text: WidgetText, style: TextStyle| {
+        text.into_galley(ui, Some(TextWrapMode::Extend), f32::INFINITY, style)
+    };
+    let mut lines = Vec::new();
+    if let Some(description) = &step.description {
+        lines.push(galley(description.clone(), TextStyle::Small));
+    }
+    if step.optional {
+        let optional = egui::RichText::new("Optional").color(theme.palette.muted_foreground);
+        lines.push(galley(optional.into(), TextStyle::Small));
+    }
+    StepText {
+        title: galley(step.title.clone(), TextStyle::Body),
+        lines,
+    }
+}
+
+/// Indicators spread evenly across the width with the text centered under them. Returns the
+/// step whose indicator was clicked.
+fn horizontal_header(
+    ui: &mut Ui,
+    theme: &Theme,
+    id_source: Id,
+    steps: &[StepItem],
+    state: &StepperState,
+    texts: &[StepText],
+    clickable: bool,
+) -> Option<usize> {
+    let count = steps.len().max(1);
+    let text_height = texts.iter().map(StepText::height).fold(0.0, f32::max);
+    let height = INDICATOR_SIZE + 6.0 + text_height;
+    let (rect, _) = ui.allocate_exact_size(vec2(ui.available_width(), height), Sense::hover());
+    let segment = rect.width() / count as f32;
+    let mut clicked = None;
+    let mut previous: Option<Rect> = None;
+
+    for (index, (step, text)) in steps.iter().zip(texts).enumerate() {
+        let center_x = rect.left() + segment * (index as f32 + 0.5);
+        let indicator = Rect::from_center_size(
+            pos2(center_x, rect.top() + INDICATOR_SIZE / 2.0),
+            egui::Vec2::splat(INDICATOR_SIZE),
+        );
+        if let Some(previous) = previous {
+            paint_connector(
+                ui.painter(),
+                theme,
+                state,
+                index - 1,
+                previous.right_center() + vec2(CONNECTOR_GAP, 0.0),
+                indicator.left_center() - vec2(CONNECTOR_GAP, 0.0),
+            );
+        }
+        previous = Some(indicator);
+
+        let hit = Rect::from_center_size(
+            pos2(center_x, rect.center().y),
+            vec2(text.width().max(INDICATOR_SIZE), rect.height()),
+        );
+        let response = step_interact(ui, id_source, index, state, hit, clickable);
+        paint_indicator(
+            ui.painter(),
+            theme,
+            indicator,
+            index,
+            step,
+            state,
+            &response,
+        );
+
+        let status = state.status(index);
+        let mut y = indicator.bottom() + 6.0;
+        let title_pos = pos2(center_x - text.title.size().x / 2.0, y);
+        ui.painter()
+            .galley(title_pos, text.title.clone(), title_color(theme, status));
+        y += text.title.size().y;
+        for line in &text.lines {
+            let pos = pos2(center_x - line.size().x / 2.0, y);
+            ui.painter()
+                .galley(pos, line.clone(), theme.palette.muted_foreground);
+            y += line.size().y;
+        }
+        if response.clicked() {
+            clicked = Some(index);
+        }
+    }
+    clicked
+}
+
+/// One step of the vertical layout: the indicator with the text beside it. Returns the
+/// indicator rect and whether it was clicked.
+#[allow(clippy::too_many_arguments)]
+fn step_row(
+    ui: &mut Ui,
+    theme: &Theme,
+    id_source: Id,
+    index: usize,
+    step: &StepItem,
+    state: &StepperState,
+    text: &StepText,
+    clickable: bool,
+) -> (Rect, bool) {
+    let height = INDICATOR_SIZE.max(text.height());
+    let width = INDICATOR_SIZE + 12.0 + text.width();
+    let (rect, _) = ui.allocate_exact_size(vec2(width, height), Sense::hover());
+    let indicator = Rect::from_min_size(rect.min, egui::Vec2::splat(INDICATOR_SIZE));
+    let response = step_interact(ui, id_source, index, state, rect, clickable);
+    paint_indicator(
+        ui.painter(),
+        theme,
+        indicator,
+        index,
+        step,
+        state,
+        &response,
+    );
+
+    let status = state.status(index);
+    let x = indicator.right() + 12.0;
+    let mut y = if text.lines.is_empty() {
+        indicator.center().y - text.title.size().y / 2.0
+    } else {
+        rect.top()
+    };
+    ui.painter()
+        .galley(pos2(x, y), text.title.clone(), title_color(theme, status));
+    y += text.title.size().y;
+    for line in &text.lines {
+        ui.painter()
+            .galley(pos2(x, y), line.clone(), theme.palette.muted_foreground);
+        y += line.size().y;
+    }
+    (indicator, response.clicked())
+}
+
+fn step_interact(
+    ui: &mut Ui,
+    id_source: Id,
+    index: usize,
+    state: &StepperState,
+    rect: Rect,
+    clickable: bool,
+) -> Response {
+    let current = index == state.current && !state.finished;
+    let sense = if clickable && !current && state.reachable(index) {
+        Sense::click()
+    } else {
+        Sense::hover()
+    };
+    let response = ui.interact(rect, id_source.with(("step", index)), sense);
+    if sense.senses_click() {
+        response.on_hover_cursor(egui::CursorIcon::PointingHand)
+    } else {
+        response
+    }
+}
+
+fn title_color(theme: &Theme, status: StepStatus) -> Color32 {
+    match status {
+        StepStatus::Error => theme.palette.destructive,
+        StepStatus::Current | StepStatus::Completed => theme.palette.foreground,
+        StepStatus::Upcoming | StepStatus::Skipped => theme.palette.muted_foreground,
+    }
+}
+
+fn paint_indicator(
+    painter: &Painter,
+    theme: &Theme,
+    rect: Rect,
+    index: usize,
+    step: &StepItem,
+    state: &StepperState,
+    response: &Response,
+) {
+    let palette = &theme.palette;
+    let status = state.status(index);
+    let (fill, stroke, text_color) = match status {
+        StepStatus::Completed => (palette.primary, Stroke::NONE, palette.primary_foreground),
+        StepStatus::Error => (
+            palette.destructive,
+            Stroke::NONE,
+            palette.destructive_foreground,
+        ),
+        StepStatus::Current => (
+            palette.background,
+            Stroke::new(2.0, palette.primary),
+            palette.primary,
+        ),
+        StepStatus::Skipped => (
+            palette.muted,
+            Stroke::new(1.0, palette.border),
+            palette.muted_foreground,
+        ),
+        StepStatus::Upcoming => (
+            palette.background,
+            Stroke::new(1.0, palette.border),
+            palette.muted_foreground,
+        ),
+    };
+    let fill = if response.hovered() && response.sense.senses_click() {
+        mix(fill, palette.accent, 0.3)
+    } else {
+        fill
+    };
+    let center = rect.center();
+    let radius = rect.width() / 2.0;
+    painter.circle(center, radius, fill, stroke);
+    if response.has_focus() {
+        painter.circle_stroke(center, radius + 2.0, theme.focus.stroke(palette.ring));
+    }
+
+    let glyph = match (status, step.icon) {
+        (StepStatus::Completed, _) => Some(Icon::Check),
+        (StepStatus::Error, _) => Some(Icon::CircleAlert),
+        (_, icon) => icon,
+    };
+    match glyph {
+        Some(icon) => {
+            painter.text(
+                center,
+                Align2::CENTER_CENTER,
+                icon.unicode(),
+                FontId::proportional(14.0),
+                text_color,
+            );
+        }
+        None => {
+            painter.text(
+                center,
+                Align2::CENTER_CENTER,
+                (index + 1).to_string(),
+                FontId::proportional(13.0),
+                text_color,
+            );
+        }
+    }
+}
+
+/// The line between step `index` and the next, filled once `index` is done.
+fn paint_connector(
+    painter: &Painter,
+    theme: &Theme,
+    state: &StepperState,
+    index: usize,
+    from: Pos2,
+    to: Pos2,
+) {
+    let done = state.completed.contains(&index) || state.skipped.contains(&index);
+    let color = if done {
+        theme.palette.primary
+    } else {
+        theme.palette.border
+    };
+    if from.distance(to) > 0.0 {
+        painter.line_segment([from, to], Stroke::new(2.0, color));
+    }
+}
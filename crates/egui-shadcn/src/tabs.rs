@@ -1,4 +1,5 @@
 use crate::scroll_area::{ScrollAreaProps, ScrollDirection, scroll_area};
+use crate::sortable::{SortableAxis, SortableMove, SortableProps, sortable};
 use crate::theme::Theme;
 use crate::tokens::{ColorPalette, DEFAULT_FOCUS, ease_out_cubic, mix};
 use egui::scroll_area::ScrollBarVisibility;
@@ -142,6 +143,10 @@ pub struct TabsProps<'a> {
 
     pub animate: bool,
     pub compact: bool,
+
+    /// Lets triggers be dragged, or picked up with Space, to reorder them. Wrapping tab lists
+    /// are not reorderable.
+    pub reorderable: bool,
 }
 
 pub struct OnValueChange<'a>(pub Box<dyn FnMut(&str) + 'a>);
@@ -179,6 +184,7 @@ impl<'a> TabsProps<'a> {
             high_contrast: false,
             animate: true,
             compact: false,
+            reorderable: false,
         }
     }
 
@@ -286,11 +292,24 @@ impl<'a> TabsProps<'a> {
         self.compact = compact;
         self
     }
+
+    pub fn reorderable(mut self, reorderable: bool) -> Self {
+        self.reorderable = reorderable;
+        self
+    }
 }
 
 pub struct TabsResult<R> {
     pub bar_response: Response,
     pub content: R,
+
+    /// The trigger clicked or activated from the keyboard this frame, even if it was already
+    /// active.
+    pub activated: Option<String>,
+
+    /// A trigger dropped at a new position; apply it to the items with
+    /// [`reorder_items`](crate::reorder_items).
+    pub moved: Option<SortableMove>,
 }
 
 fn apply_default_value(ui: &Ui, props: &mut TabsProps<'_>) {
@@ -334,6 +353,8 @@ pub fn tabs<'a, R>(
         return TabsResult {
             bar_response: dummy,
             content: render_content(ui, &TabItem::new("empty", WidgetText::from("Empty"))),
+            activated: None,
+            moved: None,
         };
     }
 
@@ -357,6 +378,8 @@ pub fn tabs<'a, R>(
     let anim_duration = theme.motion.base_ms / 1000.0;
     let mut current_active = props.active.clone();
     let mut pending_active: Option<String> = None;
+    let mut activated: Option<String> = None;
+    let mut moved: Option<SortableMove> = None;
 
     let mut bar_rect: Rect = Rect::NOTHING;
     let bar_response = Frame::default()
@@ -580,15 +603,17 @@ pub fn tabs<'a, R>(
                     let text_pos = rect.center() - 0.5 * galley.size();
                     trigger_ui.painter().galley(text_pos, galley, text_color);
 
-                    if (response.clicked()
+                    if response.clicked()
                         || (has_focus
                             && trigger_ui.input(|i| {
                                 i.key_pressed(egui::Key::Enter) || i.key_pressed(egui::Key::Space)
-                            })))
-                        && current_active != tab.id
+                            }))
                     {
-                        current_active = tab.id.clone();
-                        pending_active = Some(tab.id.clone());
+                        activated = Some(tab.id.clone());
+                        if current_active != tab.id {
+                            current_active = tab.id.clone();
+                            pending_active = Some(tab.id.clone());
+                        }
                     }
 
                     let mut response = response;
@@ -598,30 +623,53 @@ pub fn tabs<'a, R>(
                     response
                 };
 
-                match props.orientation {
-                    TabsOrientation::Horizontal => {
-                        if props.wrap == TabsWrap::NoWrap {
-                            triggers_ui.horizontal(|row_ui| {
-                                for (index, tab) in props.items.iter().enumerate() {
-                                    render_trigger(row_ui, index, tab);
-                                }
-                            });
-                        } else {
-                            triggers_ui.horizontal_wrapped(|row_ui| {
+                let sortable_axis = match props.orientation {
+                    TabsOrientation::Horizontal if props.wrap == TabsWrap::NoWrap => {
+                        Some(SortableAxis::Horizontal)
+                    }
+                    TabsOrientation::Horizontal => None,
+                    TabsOrientation::Vertical => Some(SortableAxis::Vertical),
+                };
+                if props.reorderable
+                    && let Some(axis) = sortable_axis
+                {
+                    let sortable_props =
+                        SortableProps::new(props.id_source.with("sortable"), props.items.len())
+                            .axis(axis)
+                            .spacing(tokens.gap)
+                            .card(false)
+                            .focusable(false)
+                            .animate(props.animate);
+                    moved = sortable(triggers_ui, theme, sortable_props, |item_ui, item| {
+                        render_trigger(item_ui, item.index, &props.items[item.index]);
+                    })
+                    .moved;
+                } else {
+                    match props.orientation {
+                        TabsOrientation::Horizontal => {
+                            if props.wrap == TabsWrap::NoWrap {
+                                triggers_ui.horizontal(|row_ui| {
+                                    for (index, tab) in props.items.iter().enumerate() {
+                                        render_trigger(row_ui, index, tab);
+                                    }
+                                });
+                            } else {
+                                triggers_ui.horizontal_wrapped(|row_ui| {
+                                    for (index, tab) in props.items.iter().enumerate() {
+                                        render_trigger(row_ui, index, tab);
+                                    }
+                                });
+                            }
+                        }
+                        TabsOrientation::Vertical => {
+                            triggers_ui.vertical(|col_ui| {
                                 for (index, tab) in props.items.iter().enumerate() {
-                                    render_trigger(row_ui, index, tab);
+                                    render_trigger(col_ui, index, tab);
                                 }
                             });
                         }
                     }
-                    TabsOrientation::Vertical => {
-                        triggers_ui.vertical(|col_ui| {
-                            for (index, tab) in props.items.iter().enumerate() {
-                                render_trigger(col_ui, index, tab);
-                            }
-                        });
-                    }
-                };
+                }
 
                 if let Some(current) = focused_index {
                     let (next_key, prev_key) = match props.orientation {
@@ -654,6 +702,7 @@ pub fn tabs<'a, R>(
                         {
                             current_active = next_tab.id.clone();
                             pending_active = Some(next_tab.id.clone());
+                            activated = Some(next_tab.id.clone());
                         }
                         let next_id = trigger_ids[next_index];
                         triggers_ui.memory_mut(|m| m.request_focus(next_id));
@@ -720,6 +769,8 @@ pub fn tabs<'a, R>(
     TabsResult {
         bar_response,
         content,
+        activated,
+        moved,
     }
 }
 
@@ -0,0 +1,538 @@
+//! Tags input - a text field that turns entries into removable chips.
+//!
+//! Enter or a delimiter (comma by default) commits the typed text, Backspace on an empty field
+//! removes the last tag and every chip has a × button. Tags are trimmed, checked against the
+//! optional [`Validator`], duplicates and the max count, and rejected text stays in the field.
+//!
+//! # Example
+//! ```ignore
+//! tags_input(
+//!     ui,
+//!     &theme,
+//!     TagsInputProps::new("labels", &mut labels).validator(max_length(20, "Too long")),
+//! );
+//! ```
+
+use crate::badge::{BadgeProps, BadgeSize, BadgeVariant, removable_badge};
+use crate::combobox::{ComboboxList, ComboboxSize, combobox_list, filter_items};
+use crate::form::{FieldValue, Validator};
+use crate::input::{InputSize, InputStyle, InputVariant};
+use crate::popover::{PopoverAlign, PopoverAutoFocusEvent, PopoverProps, PopoverSide, popover};
+use crate::select::SelectItem;
+use crate::theme::Theme;
+use egui::epaint::RectShape;
+use egui::{
+    Color32, Key, Margin, Modifiers, Rect, Response, Sense, Shape, Stroke, StrokeKind, TextEdit,
+    TextStyle, Ui, WidgetText, vec2,
+};
+use log::trace;
+use std::fmt::Debug;
+use std::hash::Hash;
+
+pub struct TagsInputProps<'a, Id>
+where
+    Id: Hash + Debug,
+{
+    pub id_source: Id,
+
+    pub tags: &'a mut Vec<String>,
+
+    pub placeholder: &'a str,
+
+    pub variant: InputVariant,
+
+    pub size: InputSize,
+
+    pub badge_variant: BadgeVariant,
+
+    pub enabled: bool,
+
+    pub is_invalid: bool,
+
+    pub width: Option<f32>,
+
+    pub max_tags: Option<usize>,
+
+    pub allow_duplicates: bool,
+
+    /// Characters that commit the typed text, in addition to Enter.
+    pub delimiters: Vec<char>,
+
+    /// Runs on each tag as [`FieldValue::Text`]; an error rejects the tag.
+    pub validator: Option<Validator>,
+
+    /// Options offered in a dropdown while typing. The option value becomes the tag.
+    pub suggestions: Option<&'a [SelectItem]>,
+
+    pub empty_text: &'a str,
+
+    pub duplicate_message: &'a str,
+
+    pub max_tags_message: &'a str,
+}
+
+impl<'a, Id: Hash + Debug> TagsInputProps<'a, Id> {
+    pub fn new(id_source: Id, tags: &'a mut Vec<String>) -> Self {
+        Self {
+            id_source,
+            tags,
+            placeholder: "",
+            variant: InputVariant::Surface,
+            size: InputSize::Size2,
+            badge_variant: BadgeVariant::Surface,
+            enabled: true,
+            is_invalid: false,
+            width: None,
+            max_tags: None,
+            allow_duplicates: false,
+            delimiters: vec![','],
+            validator: None,
+            suggestions: None,
+            empty_text: "No suggestions.",
+            duplicate_message: "Already added.",
+            max_tags_message: "Tag limit reached.",
+        }
+    }
+
+    pub fn placeholder(mut self, placeholder: &'a str) -> Self {
+        self.placeholder = placeholder;
+        self
+    }
+
+    pub fn variant(mut self, variant: InputVariant) -> Self {
+        self.variant = variant;
+        self
+    }
+
+    pub fn size(mut self, size: InputSize) -> Self {
+        self.size = size;
+        self
+    }
+
+    pub fn badge_variant(mut self, variant: BadgeVariant) -> Self {
+        self.badge_variant = variant;
+        self
+    }
+
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    pub fn invalid(mut self, is_invalid: bool) -> Self {
+        self.is_invalid = is_invalid;
+        self
+    }
+
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    pub fn max_tags(mut self, max_tags: usize) -> Self {
+        self.max_tags = Some(max_tags);
+        self
+    }
+
+    pub fn allow_duplicates(mut self, allow: bool) -> Self {
+        self.allow_duplicates = allow;
+        self
+    }
+
+    pub fn delimiters(mut self, delimiters: impl Into<Vec<char>>) -> Self {
+        self.delimiters = delimiters.into();
+        self
+    }
+
+    pub fn validator(mut self, validator: Validator) -> Self {
+        self.validator = Some(validator);
+        self
+    }
+
+    pub fn suggestions(mut self, suggestions: &'a [SelectItem]) -> Self {
+        self.suggestions = Some(suggestions);
+        self
+    }
+
+    pub fn empty_text(mut self, text: &'a str) -> Self {
+        self.empty_text = text;
+        self
+    }
+
+    pub fn duplicate_message(mut self, message: &'a str) -> Self {
+        self.duplicate_message = message;
+        self
+    }
+
+    pub fn max_tags_message(mut self, message: &'a str) -> Self {
+        self.max_tags_message = message;
+        self
+    }
+
+    /// Why `tag` would be rejected, if it would.
+    pub fn check_tag(&self, tag: &str) -> Option<String> {
+        if self.max_tags.is_some_and(|max| self.tags.len() >= max) {
+            return Some(self.max_tags_message.to_string());
+        }
+        if !self.allow_duplicates && self.tags.iter().any(|existing| existing == tag) {
+            return Some(self.duplicate_message.to_string());
+        }
+        self.validator
+            .as_ref()
+            .and_then(|validator| validator(&FieldValue::Text(tag.to_string())))
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct TagsInputResponse {
+    pub response: Response,
+    pub added: Vec<String>,
+    pub removed: Vec<String>,
+    /// The reason the last commit was rejected; cleared once the text changes.
+    pub error: Option<String>,
+}
+
+impl TagsInputResponse {
+    pub fn changed(&self) -> bool {
+        !self.added.is_empty() || !self.removed.is_empty()
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+struct TagsInputState {
+    text: String,
+    error: Option<String>,
+    /// Escape closed the suggestions; they stay closed until the text changes.
+    dismissed: bool,
+    popup_hovered: bool,
+    height: f32,
+}
+
+fn count_options(items: &[SelectItem]) -> usize {
+    items
+        .iter()
+        .map(|item| match item {
+            SelectItem::Option { disabled, .. } => usize::from(!*disabled),
+            SelectItem::Group { items, .. } => count_options(items),
+            SelectItem::Separator | SelectItem::Label(_) => 0,
+        })
+        .sum()
+}
+
+fn without_tags(items: &[SelectItem], tags: &[String]) -> Vec<SelectItem> {
+    items
+        .iter()
+        .filter_map(|item| match item {
+            SelectItem::Option { value, .. } if tags.contains(value) => None,
+            SelectItem::Group { label, items } => Some(SelectItem::Group {
+                label: label.clone(),
+                items: without_tags(items, tags),
+            }),
+            other => Some(other.clone()),
+        })
+        .collect()
+}
+
+pub fn tags_input<Id>(
+    ui: &mut Ui,
+    theme: &Theme,
+    props: TagsInputProps<'_, Id>,
+) -> TagsInputResponse
+where
+    Id: Hash + Debug,
+{
+    trace!(
+        "Rendering tags input tags={} max={:?}",
+        props.tags.len(),
+        props.max_tags
+    );
+
+    let id = ui.make_persistent_id(&props.id_source);
+    let edit_id = id.with("edit");
+    let state_id = id.with("tags-state");
+    let mut state = ui
+        .ctx()
+        .data(|d| d.get_temp::<TagsInputState>(state_id))
+        .unwrap_or_default();
+
+    let width = props.width.unwrap_or(280.0);
+    let size = props.size;
+    let focused = ui.memory(|m| m.has_focus(edit_id));
+    let mut added = Vec::new();
+    let mut removed = Vec::new();
+
+    // Enter and Backspace are handled before the text edit sees them.
+    let (confirm, backspace) = if focused && props.enabled {
+        ui.input_mut(|i| {
+            let confirm = i.consume_key(Modifiers::NONE, Key::Enter);
+            let backspace = state.text.is_empty() && i.consume_key(Modifiers::NONE, Key::Backspace);
+            (confirm, backspace)
+        })
+    } else {
+        (false, false)
+    };
+    if backspace && let Some(tag) = props.tags.pop() {
+        removed.push(tag);
+        state.error = None;
+    }
+
+    let suggestions = props
+        .suggestions
+        .map(|items| filter_items(&without_tags(items, props.tags), state.text.trim()))
+        .unwrap_or_default();
+    let mut open = props.enabled
+        && (focused || state.popup_hovered)
+        && !state.dismissed
+        && count_options(&suggestions) > 0;
+    let was_open = open;
+
+    let mut style = InputStyle::from_palette(&theme.palette, props.variant);
+    style.rounding = size.rounding();
+    let invalid = props.is_invalid || state.error.is_some();
+    let mut text = std::mem::take(&mut state.text);
+    let previous_text = text.clone();
+    let last_height = state.height;
+    let mut field_height = last_height;
+    let mut remove_index = None;
+    let tags_snapshot = props.tags.clone();
+
+    let mut prevent_focus_restore = |event: &mut PopoverAutoFocusEvent| {
+        event.preventable.prevent_default();
+    };
+    let (field_response, chosen) = popover(
+        ui,
+        theme,
+        PopoverProps::new(id.with("suggestions"), &mut open)
+            .side(PopoverSide::Bottom)
+            .align(PopoverAlign::Start)
+            .with_width(width)
+            .with_max_height(280.0)
+            .with_content_padding(Margin::same(4))
+            .with_animation(false)
+            .on_close_auto_focus(&mut prevent_focus_restore),
+        |ui| {
+            let padding = size.padding();
+            let chip_size = match size {
+                InputSize::Size1 => BadgeSize::Size1,
+                InputSize::Size2 => BadgeSize::Size2,
+                InputSize::Size3 => BadgeSize::Size3,
+            };
+            // The click area is registered first so chips and the text edit stay on top of it;
+            // its height is the one measured last frame.
+            let start = ui.cursor().min;
+            let last_rect = Rect::from_min_size(start, vec2(width, last_height.max(size.height())));
+            let response = ui.interact(last_rect, id, Sense::click());
+            if response.clicked() && props.enabled {
+                ui.memory_mut(|m| m.request_focus(edit_id));
+            }
+            let background = ui.painter().add(Shape::Noop);
+
+            let frame = egui::Frame::NONE
+                .inner_margin(Margin::symmetric(padding.x as i8, (padding.y * 0.5) as i8))
+                .show(ui, |ui| {
+                    let inner_width = width - padding.x * 2.0;
+                    ui.set_width(inner_width);
+                    ui.set_min_height(size.height() - padding.y);
+                    ui.horizontal_wrapped(|ui| {
+                        ui.spacing_mut().item_spacing = vec2(4.0, 4.0);
+                        for (idx, tag) in tags_snapshot.iter().enumerate() {
+                            let (_, remove) = removable_badge(
+                                ui,
+                                theme,
+                                BadgeProps::new(tag)
+                                    .size(chip_size)
+                                    .variant(props.badge_variant)
+                                    .color(theme.palette.foreground),
+                                props.enabled,
+                            );
+                            if remove {
+                                remove_index = Some(idx);
+                            }
+                        }
+
+                        // Wrapping layouts report the full row as available width.
+                        let remaining = ui.available_size_before_wrap().x;
+                        let edit_width = if remaining < 80.0 {
+                            inner_width
+                        } else {
+                            remaining
+                        };
+                        let placeholder: WidgetText = if tags_snapshot.is_empty() {
+                            props.placeholder.into()
+                        } else {
+                            "".into()
+                        };
+                        let mut inner_style = ui.style().as_ref().clone();
+                        inner_style.text_styles.insert(TextStyle::Body, size.font());
+                        inner_style.visuals.selection.bg_fill = style.selection_bg;
+                        inner_style.visuals.selection.stroke = Stroke::new(1.0, style.selection_fg);
+                        ui.set_style(inner_style);
+                        let edit = TextEdit::singleline(&mut text)
+                            .id(edit_id)
+                            .hint_text(placeholder.color(style.placeholder_color))
+                            .text_color(style.text_color)
+                            .frame(false)
+                            .margin(vec2(0.0, 2.0))
+                            .desired_width(edit_width);
+                        ui.add_enabled(props.enabled, edit)
+                    })
+                    .inner
+                });
+            let rect = Rect::from_min_size(
+                start,
+                vec2(width, frame.response.rect.height().max(size.height())),
+            );
+            field_height = rect.height();
+
+            let has_focus = ui.memory(|m| m.has_focus(edit_id));
+            let border = match (invalid, has_focus) {
+                (true, _) => style.invalid_border,
+                (false, true) => style.border_focus,
+                (false, false) => style.border,
+            };
+            let bg = if !props.enabled {
+                style.bg.gamma_multiply(style.disabled_opacity)
+            } else if has_focus {
+                style.bg_focus
+            } else if response.hovered() {
+                style.bg_hover
+            } else {
+                style.bg
+            };
+            let painter = ui.painter();
+            painter.set(background, RectShape::filled(rect, style.rounding, bg));
+            if border != Color32::TRANSPARENT {
+                painter.rect_stroke(
+                    rect,
+                    style.rounding,
+                    Stroke::new(1.0, border),
+                    StrokeKind::Inside,
+                );
+            }
+            if has_focus && props.enabled {
+                let ring = if invalid {
+                    style.invalid_ring
+                } else {
+                    style.focus_ring
+                };
+                painter.rect_stroke(
+                    rect,
+                    style.rounding,
+                    Stroke::new(style.focus_ring_width, ring),
+                    StrokeKind::Outside,
+                );
+            }
+            // Hover-only, so the popover does not toggle when the field is clicked.
+            ui.interact(rect, id.with("anchor"), Sense::hover())
+        },
+        |ui| {
+            let chosen = combobox_list(
+                ui,
+                theme,
+                ComboboxList {
+                    items: &suggestions,
+                    search: "",
+                    selected: None,
+                    width: width - 8.0,
+                    size: match size {
+                        InputSize::Size1 => ComboboxSize::Size1,
+                        InputSize::Size2 => ComboboxSize::Size2,
+                        InputSize::Size3 => ComboboxSize::Size3,
+                    },
+                    empty_text: props.empty_text,
+                    active_index_id: id.with("active-suggestion"),
+                    focus_selected: false,
+                    confirm,
+                },
+            );
+            (chosen, ui.ui_contains_pointer())
+        },
+    );
+
+    state.popup_hovered = chosen.as_ref().is_some_and(|(_, hovered)| *hovered);
+    if was_open && !open {
+        state.dismissed = true;
+    }
+
+    if let Some(idx) = remove_index
+        && idx < props.tags.len()
+    {
+        removed.push(props.tags.remove(idx));
+        state.error = None;
+    }
+
+    state.height = field_height;
+    let text_changed = text != previous_text;
+    let split = |value: &str| -> Vec<String> {
+        value
+            .split(|c| props.delimiters.contains(&c))
+            .map(str::to_string)
+            .collect()
+    };
+    let mut pending: Vec<String> = Vec::new();
+    if let Some((Some(value), _)) = chosen {
+        pending.push(value);
+        text.clear();
+        ui.memory_mut(|m| m.request_focus(edit_id));
+    } else if confirm {
+        pending = split(&std::mem::take(&mut text));
+    } else if text_changed && props.delimiters.iter().any(|d| text.contains(*d)) {
+        // Only edits are split, so restored rejections wait for the user to change them.
+        pending = split(&text);
+        text = pending.pop().unwrap_or_default();
+    }
+
+    let mut rejected = Vec::new();
+    for candidate in pending {
+        let tag = candidate.trim().to_string();
+        if tag.is_empty() {
+            continue;
+        }
+        match props.check_tag(&tag) {
+            Some(error) => {
+                state.error = Some(error);
+                rejected.push(tag);
+            }
+            None => {
+                props.tags.push(tag.clone());
+                added.push(tag);
+            }
+        }
+    }
+    if !added.is_empty() && rejected.is_empty() {
+        state.error = None;
+    }
+    if !rejected.is_empty() {
+        // Keep rejected text in the field so it can be corrected.
+        let separator = match props.delimiters.first() {
+            Some(d) if d.is_whitespace() => d.to_string(),
+            Some(d) => format!("{d} "),
+            None => " ".to_string(),
+        };
+        let remainder = text.trim_start();
+        if !remainder.is_empty() {
+            rejected.push(remainder.to_string());
+        }
+        text = rejected.join(&separator);
+    } else if text_changed && added.is_empty() && removed.is_empty() {
+        state.error = None;
+    }
+    if text_changed {
+        state.dismissed = false;
+    }
+
+    state.text = text;
+    ui.ctx()
+        .data_mut(|d| d.insert_temp(state_id, state.clone()));
+
+    let mut response = field_response;
+    if !added.is_empty() || !removed.is_empty() {
+        response.mark_changed();
+    }
+    TagsInputResponse {
+        response,
+        added,
+        removed,
+        error: state.error,
+    }
+}
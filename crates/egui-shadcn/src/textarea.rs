@@ -1,14 +1,26 @@
+use crate::command::{
+    CommandContext, CommandGroupProps, CommandItemProps, CommandListProps, CommandNavigation,
+    CommandProps, command, command_empty, command_group, command_item, command_list,
+    command_matches,
+};
+use crate::highlight::{HighlightStyle, TextHighlighter};
+use crate::mention::{MentionItem, TextareaMentions, insert_mention, mention_query, sync_mentions};
+use crate::popover::{PopoverAlign, PopoverAutoFocusEvent, PopoverProps, PopoverSide, popover};
 use crate::theme::Theme;
 use crate::tokens::{
     ColorPalette, ControlSize, InputVariant as TokenInputVariant, ease_out_cubic, input_tokens, mix,
 };
+use egui::text::{CCursor, CCursorRange, LayoutJob};
 use egui::{
-    Color32, CornerRadius, FontId, Rect, Response, Sense, Stroke, StrokeKind, TextEdit, TextStyle,
-    Ui, UiBuilder, Vec2, Vec2b, WidgetText, pos2, vec2,
+    Align2, Color32, CornerRadius, EventFilter, FontId, Galley, Key, Margin, Modifiers, Rect,
+    Response, ScrollArea, Sense, Stroke, StrokeKind, TextBuffer, TextEdit, TextStyle, Ui,
+    UiBuilder, Vec2, Vec2b, WidgetText, pos2, vec2,
 };
 use log::trace;
-use std::fmt::Debug;
+use std::cell::Cell;
+use std::fmt::{self, Debug};
 use std::hash::Hash;
+use std::sync::Arc;
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
 pub enum TextareaVariant {
@@ -337,6 +349,19 @@ where
 
     pub rows: Option<usize>,
 
+    /// Autosize: the textarea grows with its content between `min_rows` and `max_rows`, and
+    /// scrolls beyond `max_rows`.
+    pub min_rows: Option<usize>,
+
+    pub max_rows: Option<usize>,
+
+    pub line_numbers: bool,
+
+    pub highlighter: Option<TextareaHighlighter<'a>>,
+
+    /// `@mention` and `/command` suggestions, see [`crate::mention`].
+    pub mentions: Option<TextareaMentions<'a>>,
+
     pub width: Option<f32>,
 
     pub style: Option<TextareaStyle>,
@@ -346,6 +371,14 @@ where
     pub high_contrast: bool,
 }
 
+pub struct TextareaHighlighter<'a>(pub Box<dyn TextHighlighter + 'a>);
+
+impl Debug for TextareaHighlighter<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TextareaHighlighter").finish()
+    }
+}
+
 impl<'a, Id: Hash + Debug> TextareaProps<'a, Id> {
     pub fn new(id_source: Id, value: &'a mut String) -> Self {
         Self {
@@ -362,6 +395,11 @@ impl<'a, Id: Hash + Debug> TextareaProps<'a, Id> {
             show_counter: false,
             max_len: None,
             rows: None,
+            min_rows: None,
+            max_rows: None,
+            line_numbers: false,
+            highlighter: None,
+            mentions: None,
             width: None,
             style: None,
             accent_color: None,
@@ -424,6 +462,37 @@ impl<'a, Id: Hash + Debug> TextareaProps<'a, Id> {
         self
     }
 
+    pub fn min_rows(mut self, min_rows: usize) -> Self {
+        self.min_rows = Some(min_rows);
+        self
+    }
+
+    pub fn max_rows(mut self, max_rows: usize) -> Self {
+        self.max_rows = Some(max_rows);
+        self
+    }
+
+    pub fn autosize(mut self, min_rows: usize, max_rows: usize) -> Self {
+        self.min_rows = Some(min_rows);
+        self.max_rows = Some(max_rows);
+        self
+    }
+
+    pub fn line_numbers(mut self, line_numbers: bool) -> Self {
+        self.line_numbers = line_numbers;
+        self
+    }
+
+    pub fn highlighter(mut self, highlighter: impl TextHighlighter + 'a) -> Self {
+        self.highlighter = Some(TextareaHighlighter(Box::new(highlighter)));
+        self
+    }
+
+    pub fn mentions(mut self, mentions: TextareaMentions<'a>) -> Self {
+        self.mentions = Some(mentions);
+        self
+    }
+
     pub fn width(mut self, width: f32) -> Self {
         self.width = Some(width);
         self
@@ -469,6 +538,11 @@ impl<'a, Id: Hash + Debug> TextareaBuilder<'a, Id> {
             show_counter: false,
             max_len: None,
             rows: None,
+            min_rows: None,
+            max_rows: None,
+            line_numbers: false,
+            highlighter: None,
+            mentions: None,
             width: None,
             style: None,
             accent_color: None,
@@ -493,6 +567,11 @@ where
     pub show_counter: bool,
     pub max_len: Option<usize>,
     pub rows: Option<usize>,
+    pub min_rows: Option<usize>,
+    pub max_rows: Option<usize>,
+    pub line_numbers: bool,
+    pub highlighter: Option<TextareaHighlighter<'a>>,
+    pub mentions: Option<TextareaMentions<'a>>,
     pub width: Option<f32>,
     pub style: Option<TextareaStyle>,
     pub accent_color: Option<Color32>,
@@ -555,6 +634,37 @@ impl<'a, Id: Hash + Debug> TextareaBuilder<'a, Id> {
         self
     }
 
+    pub fn min_rows(mut self, min_rows: usize) -> Self {
+        self.min_rows = Some(min_rows);
+        self
+    }
+
+    pub fn max_rows(mut self, max_rows: usize) -> Self {
+        self.max_rows = Some(max_rows);
+        self
+    }
+
+    pub fn autosize(mut self, min_rows: usize, max_rows: usize) -> Self {
+        self.min_rows = Some(min_rows);
+        self.max_rows = Some(max_rows);
+        self
+    }
+
+    pub fn line_numbers(mut self, line_numbers: bool) -> Self {
+        self.line_numbers = line_numbers;
+        self
+    }
+
+    pub fn highlighter(mut self, highlighter: impl TextHighlighter + 'a) -> Self {
+        self.highlighter = Some(TextareaHighlighter(Box::new(highlighter)));
+        self
+    }
+
+    pub fn mentions(mut self, mentions: TextareaMentions<'a>) -> Self {
+        self.mentions = Some(mentions);
+        self
+    }
+
     pub fn width(mut self, width: f32) -> Self {
         self.width = Some(width);
         self
@@ -599,6 +709,11 @@ impl<'a, Id: Hash + Debug> TextareaBuilder<'a, Id> {
             show_counter: self.show_counter,
             max_len: self.max_len,
             rows: self.rows,
+            min_rows: self.min_rows,
+            max_rows: self.max_rows,
+            line_numbers: self.line_numbers,
+            highlighter: self.highlighter,
+            mentions: self.mentions,
             width: self.width,
             style: self.style,
             accent_color: self.accent_color,
@@ -608,7 +723,11 @@ impl<'a, Id: Hash + Debug> TextareaBuilder<'a, Id> {
     }
 }
 
-pub fn textarea_with_props<Id>(ui: &mut Ui, theme: &Theme, props: TextareaProps<'_, Id>) -> Response
+pub fn textarea_with_props<Id>(
+    ui: &mut Ui,
+    theme: &Theme,
+    mut props: TextareaProps<'_, Id>,
+) -> Response
 where
     Id: Hash + Debug,
 {
@@ -643,19 +762,70 @@ where
 
     let effectively_disabled = !props.enabled || props.read_only;
 
-    let min_height = if let Some(rows) = props.rows {
+    let id = ui.make_persistent_id(&props.id_source);
+    let width = props.width.unwrap_or(200.0);
+    let padding = props.size.padding();
+    let font = props.size.font();
+
+    let text_color = if effectively_disabled {
+        apply_opacity(style.text_color, 0.6)
+    } else {
+        style.text_color
+    };
+    let mut highlight_style =
+        HighlightStyle::from_palette(&theme.palette, font.clone(), text_color);
+    if effectively_disabled {
+        for color in [
+            &mut highlight_style.keyword,
+            &mut highlight_style.key,
+            &mut highlight_style.string,
+            &mut highlight_style.number,
+            &mut highlight_style.comment,
+            &mut highlight_style.punctuation,
+        ] {
+            *color = apply_opacity(*color, 0.6);
+        }
+    }
+    let highlighter = props.highlighter.as_ref().map(|h| h.0.as_ref());
+
+    let gutter_width = if props.line_numbers {
+        let lines = props.value.split('\n').count();
+        let digits = lines.to_string().len().max(2);
+        let digit_width = ui.fonts_mut(|f| f.glyph_width(&gutter_font(props.size), '0'));
+        digit_width * digits as f32 + GUTTER_GAP * 2.0
+    } else {
+        0.0
+    };
+
+    let autosize = props.min_rows.is_some() || props.max_rows.is_some();
+    let min_height = if autosize {
+        // Measured at the width the text had last frame, which may differ from `width` once
+        // the user resizes horizontally.
+        let width_id = id.with("autosize-width");
+        let last_width = ui
+            .ctx()
+            .data(|d| d.get_temp::<f32>(width_id))
+            .unwrap_or(width);
+        let wrap_width = (last_width - padding.x * 2.0 - gutter_width).max(1.0);
+        let galley = layout_text(ui, props.value, highlighter, &highlight_style, wrap_width);
+        let min_rows = props.min_rows.unwrap_or(1).max(1);
+        let max_rows = props.max_rows.unwrap_or(usize::MAX).max(min_rows);
+        let rows = galley.rows.len().clamp(min_rows, max_rows);
+        let row_height = ui.fonts_mut(|f| f.row_height(&font));
+        row_height * rows as f32 + padding.y * 2.0
+    } else if let Some(rows) = props.rows {
         let line_height = props.size.line_height();
-        line_height * rows as f32 + props.size.padding().y * 2.0
+        line_height * rows as f32 + padding.y * 2.0
     } else {
         props.size.min_height()
     };
 
-    let width = props.width.unwrap_or(200.0);
-
-    let id = ui.make_persistent_id(&props.id_source);
     let desired_size = vec2(width, min_height);
 
-    let resize_axes = props.resize.resizable_axes();
+    let mut resize_axes = props.resize.resizable_axes();
+    if autosize {
+        resize_axes.y = false;
+    }
     let (rect, response) = if resize_axes.any() {
         let resize_id = id.with("resize");
         let mut resize = egui::Resize::default()
@@ -703,6 +873,11 @@ where
     } else {
         ui.allocate_exact_size(desired_size, Sense::click())
     };
+
+    if autosize {
+        ui.ctx()
+            .data_mut(|d| d.insert_temp(id.with("autosize-width"), rect.width()));
+    }
 
     let has_focus = response.has_focus() || ui.memory(|m| m.has_focus(id.with("edit")));
 
@@ -767,19 +942,36 @@ where
         }
     }
 
-    let inner_rect = rect.shrink2(props.size.padding());
-
-    let text_color = if effectively_disabled {
-        apply_opacity(style.text_color, 0.6)
-    } else {
-        style.text_color
-    };
+    let inner_rect = rect.shrink2(padding);
 
     let placeholder_colored: WidgetText = props.placeholder.into();
     let placeholder_colored = placeholder_colored.color(style.placeholder_color);
 
     let token_variant = TokenInputVariant::from(props.variant);
     let tokens = input_tokens(&theme.palette, token_variant);
+
+    // While suggestions are open the list owns the arrow keys, Enter, Tab and Escape.
+    let edit_id = id.with("edit");
+    let mention_state_id = id.with("mention-state");
+    let mut mention_state = ui
+        .ctx()
+        .data(|d| d.get_temp::<MentionState>(mention_state_id))
+        .unwrap_or_default();
+    let mut navigation = CommandNavigation::default();
+    let text_before = props.mentions.as_ref().map(|_| props.value.clone());
+    if props.mentions.is_some() && mention_state.open && ui.memory(|m| m.has_focus(edit_id)) {
+        ui.input_mut(|i| {
+            navigation.up = i.consume_key(Modifiers::NONE, Key::ArrowUp);
+            navigation.down = i.consume_key(Modifiers::NONE, Key::ArrowDown);
+            if mention_state.has_items {
+                navigation.enter = i.consume_key(Modifiers::NONE, Key::Enter)
+                    || i.consume_key(Modifiers::NONE, Key::Tab);
+            }
+            if i.consume_key(Modifiers::NONE, Key::Escape) {
+                mention_state.dismissed = mention_state.query_start;
+            }
+        });
+    }
 
     let response = ui.scope_builder(UiBuilder::new().max_rect(inner_rect), |inner_ui| {
         inner_ui.set_clip_rect(inner_rect);
@@ -804,14 +996,17 @@ where
 
         inner_ui.set_style(inner_style);
 
+        let mut layouter = |ui: &Ui, text: &dyn TextBuffer, wrap_width: f32| {
+            layout_text(ui, text.as_str(), highlighter, &highlight_style, wrap_width)
+        };
         let mut edit = TextEdit::multiline(props.value)
-            .id(id.with("edit"))
+            .id(edit_id)
             .hint_text(placeholder_colored)
             .text_color(text_color)
             .frame(false)
             .margin(vec2(0.0, 0.0))
-            .desired_width(inner_rect.width())
-            .desired_rows(props.rows.unwrap_or(3));
+            .desired_width(inner_rect.width() - gutter_width)
+            .desired_rows(props.min_rows.or(props.rows).unwrap_or(3));
 
         if let Some(limit) = props.max_len {
             edit = edit.char_limit(limit);
@@ -821,9 +1016,188 @@ where
             edit = edit.interactive(false);
         }
 
-        inner_ui.add_enabled(props.enabled, edit)
+        if highlighter.is_some() {
+            edit = edit.layouter(&mut layouter);
+        }
+
+        let show = |ui: &mut Ui| {
+            ui.horizontal_top(|ui| {
+                ui.spacing_mut().item_spacing.x = 0.0;
+                let gutter = (gutter_width > 0.0).then(|| {
+                    ui.allocate_exact_size(vec2(gutter_width, 0.0), Sense::hover())
+                        .0
+                });
+                let output = ui.add_enabled_ui(props.enabled, |ui| edit.show(ui)).inner;
+                if let Some(gutter) = gutter {
+                    paint_line_numbers(
+                        ui,
+                        gutter.left(),
+                        gutter_width,
+                        &output.galley,
+                        output.galley_pos,
+                        gutter_font(props.size),
+                        style.placeholder_color,
+                    );
+                }
+                let caret = output.cursor_range.map(|range| {
+                    let rect = output.galley.pos_from_cursor(range.primary);
+                    (
+                        rect.translate(output.galley_pos.to_vec2()),
+                        range.primary.index,
+                    )
+                });
+                (output.response, caret)
+            })
+            .inner
+        };
+
+        if props.max_rows.is_some() {
+            ScrollArea::vertical()
+                .id_salt(id.with("scroll"))
+                .max_height(inner_rect.height())
+                .auto_shrink([false, true])
+                .show(inner_ui, show)
+                .inner
+        } else {
+            show(inner_ui)
+        }
     });
 
+    let (mut response, caret) = response.inner;
+
+    if let (Some(mentions), Some(text_before)) = (props.mentions.as_mut(), text_before) {
+        sync_mentions(&text_before, props.value, mentions.mentions);
+
+        let focused = ui.memory(|m| m.has_focus(edit_id));
+        let query = caret.and_then(|(rect, index)| {
+            let cursor = props
+                .value
+                .char_indices()
+                .nth(index)
+                .map_or(props.value.len(), |(byte, _)| byte);
+            mention_query(props.value, cursor, &mentions.triggers).map(|query| (rect, query))
+        });
+        let query_start = query.as_ref().map(|(_, query)| query.range.start);
+        if mention_state.query_start != query_start {
+            mention_state.dismissed = None;
+        }
+        mention_state.query_start = query_start;
+
+        let mut open = false;
+        let mut popup_hovered = false;
+        let mut has_items = false;
+        let chosen: Cell<Option<&MentionItem>> = Cell::new(None);
+        if let Some((caret_rect, query)) = &query
+            && let Some(trigger) = mentions
+                .triggers
+                .iter()
+                .find(|t| t.trigger == query.trigger)
+        {
+            has_items = trigger
+                .items
+                .iter()
+                .any(|item| command_matches(query.query.trim(), &item.label, &item.keywords));
+            open = (focused || mention_state.popup_hovered)
+                && props.enabled
+                && !props.read_only
+                && mention_state.dismissed != query_start;
+            let was_open = open;
+
+            let mut prevent_focus_restore = |event: &mut PopoverAutoFocusEvent| {
+                event.preventable.prevent_default();
+            };
+            let empty_text = mentions.empty_text;
+            let width = mentions.width;
+            // Only rendered while a trigger is typed, so the first render must not reset `open`.
+            let (_, hovered) = popover(
+                ui,
+                theme,
+                PopoverProps::new(id.with("mentions"), &mut open)
+                    .default_open(was_open)
+                    .anchor_rect(*caret_rect)
+                    .side(PopoverSide::Bottom)
+                    .align(PopoverAlign::Start)
+                    .with_width(width)
+                    .with_content_padding(Margin::same(4))
+                    .with_animation(false)
+                    .on_close_auto_focus(&mut prevent_focus_restore),
+                |ui| ui.interact(rect, id.with("mention-anchor"), Sense::hover()),
+                |ui| {
+                    command(
+                        ui,
+                        theme,
+                        CommandProps::new(id.with("mention-list"))
+                            .query(query.query.trim())
+                            .navigation(navigation)
+                            .show_border(false)
+                            .show_shadow(false)
+                            .min_width(width - 8.0),
+                        |ui, ctx| {
+                            command_list(
+                                ui,
+                                ctx,
+                                CommandListProps { max_height: 240.0 },
+                                |ui, ctx| {
+                                    command_empty(ui, ctx, empty_text);
+                                    match &trigger.heading {
+                                        Some(heading) => command_group(
+                                            ui,
+                                            ctx,
+                                            CommandGroupProps::new(heading.as_str()),
+                                            |ui, ctx| {
+                                                mention_items(ui, ctx, trigger.items, &chosen)
+                                            },
+                                        ),
+                                        None => mention_items(ui, ctx, trigger.items, &chosen),
+                                    }
+                                },
+                            );
+                        },
+                    );
+                    ui.ui_contains_pointer()
+                },
+            );
+            popup_hovered = hovered.unwrap_or(false);
+            if was_open && !open && chosen.get().is_none() {
+                mention_state.dismissed = query_start;
+            }
+        }
+
+        if let (Some(item), Some((_, query))) = (chosen.get(), &query) {
+            let cursor = insert_mention(props.value, query, item, mentions.mentions);
+            let mut edit_state = TextEdit::load_state(ui.ctx(), edit_id).unwrap_or_default();
+            let index = props.value[..cursor.min(props.value.len())].chars().count();
+            edit_state
+                .cursor
+                .set_char_range(Some(CCursorRange::one(CCursor::new(index))));
+            edit_state.store(ui.ctx(), edit_id);
+            ui.memory_mut(|m| m.request_focus(edit_id));
+            response.mark_changed();
+            open = false;
+        }
+
+        if open {
+            // Keeps focus on Escape and Tab next frame, so they reach the list instead.
+            ui.memory_mut(|m| {
+                m.set_focus_lock_filter(
+                    edit_id,
+                    EventFilter {
+                        tab: true,
+                        horizontal_arrows: true,
+                        vertical_arrows: true,
+                        escape: true,
+                    },
+                )
+            });
+        }
+
+        mention_state.open = open;
+        mention_state.popup_hovered = popup_hovered;
+        mention_state.has_items = has_items;
+        ui.ctx()
+            .data_mut(|d| d.insert_temp(mention_state_id, mention_state));
+    }
+
     if props.show_counter {
         let count = props.value.chars().count();
         let counter_text = if let Some(limit) = props.max_len {
@@ -872,5 +1246,86 @@ where
         );
     }
 
-    response.inner
+    response
+}
+
+const GUTTER_GAP: f32 = 6.0;
+
+fn mention_items<'i>(
+    ui: &mut Ui,
+    ctx: &mut CommandContext<'_>,
+    items: &'i [MentionItem],
+    chosen: &Cell<Option<&'i MentionItem>>,
+) {
+    for item in items {
+        let keywords: Vec<&str> = item.keywords.iter().map(String::as_str).collect();
+        let mut props = CommandItemProps::new(&item.id, item.label.as_str())
+            .keywords(&keywords)
+            .on_select(|| chosen.set(Some(item)));
+        if let Some(icon) = item.icon {
+            props = props.icon(icon);
+        }
+        command_item(ui, ctx, props);
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+struct MentionState {
+    /// The suggestion list was shown last frame.
+    open: bool,
+    has_items: bool,
+    popup_hovered: bool,
+    /// Byte index of the trigger being typed.
+    query_start: Option<usize>,
+    /// Escape closed the list for the trigger at this index.
+    dismissed: Option<usize>,
+}
+
+fn gutter_font(size: TextareaSize) -> FontId {
+    FontId::monospace(size.font_size() * 0.85)
+}
+
+fn layout_text(
+    ui: &Ui,
+    text: &str,
+    highlighter: Option<&dyn TextHighlighter>,
+    style: &HighlightStyle,
+    wrap_width: f32,
+) -> Arc<Galley> {
+    let mut job = match highlighter {
+        Some(highlighter) => highlighter.highlight(text, style),
+        None => LayoutJob::simple(text.to_owned(), style.font.clone(), style.text, wrap_width),
+    };
+    job.wrap.max_width = wrap_width;
+    ui.fonts_mut(|f| f.layout_job(job))
+}
+
+/// Numbers each logical line at its first wrapped row.
+fn paint_line_numbers(
+    ui: &Ui,
+    left: f32,
+    width: f32,
+    galley: &Galley,
+    galley_pos: egui::Pos2,
+    font: FontId,
+    color: Color32,
+) {
+    let painter = ui.painter();
+    let right = left + width - GUTTER_GAP;
+    let mut line = 1;
+    let mut line_start = true;
+    for row in &galley.rows {
+        if line_start {
+            let rect = row.rect().translate(galley_pos.to_vec2());
+            painter.text(
+                pos2(right, rect.center().y),
+                Align2::RIGHT_CENTER,
+                line.to_string(),
+                font.clone(),
+                color,
+            );
+            line += 1;
+        }
+        line_start = row.ends_with_newline;
+    }
 }
@@ -1,9 +1,11 @@
+use crate::overlay::{OverlayKind, OverlayLayer, is_topmost_overlay, register_overlay};
+use crate::popover::{PopoverAnchor, is_anchor_detached};
 use crate::theme::Theme;
 use crate::tokens::{ColorPalette, DEFAULT_MOTION, ease_out_cubic, mix};
 use egui::epaint::Shadow;
 use egui::{
-    Color32, CornerRadius, Frame, Id, Order, Pos2, Rect, Response, Stroke, Ui, Vec2, WidgetText,
-    vec2,
+    Color32, CornerRadius, Frame, Id, LayerId, Order, Pos2, Rect, Response, Stroke, Ui, Vec2,
+    WidgetText, vec2,
 };
 use log::trace;
 use std::time::Duration;
@@ -340,11 +342,17 @@ pub struct TooltipProps<'a> {
 
     pub avoid_collisions: bool,
 
+    pub anchor: PopoverAnchor,
+
     pub on_open_change: Option<&'a mut dyn FnMut(bool)>,
 
     pub on_escape_key_down: Option<&'a mut dyn FnMut(&mut TooltipEscapeKeyDownEvent)>,
 
     pub on_pointer_down_outside: Option<&'a mut dyn FnMut(&mut TooltipPointerDownOutsideEvent)>,
+
+    // Set by the delay builders so the tooltip keeps its own timing inside a provider.
+    delay_overridden: bool,
+    skip_delay_overridden: bool,
 }
 
 impl std::fmt::Debug for TooltipProps<'_> {
@@ -381,6 +389,7 @@ impl std::fmt::Debug for TooltipProps<'_> {
             .field("open", &self.open)
             .field("default_open", &self.default_open)
             .field("avoid_collisions", &self.avoid_collisions)
+            .field("anchor", &self.anchor)
             .field("on_open_change", &self.on_open_change.is_some())
             .field("on_escape_key_down", &self.on_escape_key_down.is_some())
             .field(
@@ -425,14 +434,18 @@ impl<'a> TooltipProps<'a> {
             open: None,
             default_open: false,
             avoid_collisions: true,
+            anchor: PopoverAnchor::Trigger,
             on_open_change: None,
             on_escape_key_down: None,
             on_pointer_down_outside: None,
+            delay_overridden: false,
+            skip_delay_overridden: false,
         }
     }
 
     pub fn delay_ms(mut self, delay_ms: u64) -> Self {
         self.delay_ms = delay_ms;
+        self.delay_overridden = true;
         self
     }
 
@@ -534,6 +547,7 @@ impl<'a> TooltipProps<'a> {
 
     pub fn skip_delay_ms(mut self, skip_delay: u64) -> Self {
         self.skip_delay_ms = skip_delay;
+        self.skip_delay_overridden = true;
         self
     }
 
@@ -618,6 +632,20 @@ impl<'a> TooltipProps<'a> {
         self.avoid_collisions = avoid;
         self
     }
+
+    /// Places the tooltip against a virtual anchor while hover still follows the anchor widget.
+    pub fn anchor(mut self, anchor: PopoverAnchor) -> Self {
+        self.anchor = anchor;
+        self
+    }
+
+    pub fn follow_cursor(self, follow: bool) -> Self {
+        self.anchor(if follow {
+            PopoverAnchor::Cursor
+        } else {
+            PopoverAnchor::Trigger
+        })
+    }
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -830,19 +858,141 @@ fn draw_arrow(
     painter.add(shape);
 }
 
-fn get_global_last_close_time(ctx: &egui::Context) -> Option<f64> {
-    ctx.data(|d| d.get_temp::<f64>(Id::new("__tooltip_global_last_close__")))
+/// Shared timing for a group of tooltips, like Radix's `TooltipProvider`. Once any tooltip in
+/// the group has been visible, the others open without delay for `skip_delay_ms`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TooltipProviderProps {
+    pub id: Id,
+    pub delay_ms: u64,
+    pub skip_delay_ms: u64,
+    pub disable_hoverable_content: bool,
+    /// Stops hover and focus from opening tooltips in this scope.
+    pub disabled: bool,
+}
+
+impl TooltipProviderProps {
+    pub fn new(id: Id) -> Self {
+        Self {
+            id,
+            delay_ms: 700,
+            skip_delay_ms: 300,
+            disable_hoverable_content: false,
+            disabled: false,
+        }
+    }
+
+    pub fn delay_ms(mut self, delay_ms: u64) -> Self {
+        self.delay_ms = delay_ms;
+        self
+    }
+
+    pub fn skip_delay_ms(mut self, skip_delay_ms: u64) -> Self {
+        self.skip_delay_ms = skip_delay_ms;
+        self
+    }
+
+    pub fn disable_hoverable_content(mut self, disable: bool) -> Self {
+        self.disable_hoverable_content = disable;
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+}
+
+const TOOLTIP_PROVIDER_STACK_KEY: &str = "__tooltip_provider_stack__";
+const TOOLTIPS_ENABLED_KEY: &str = "__tooltip_global_enabled__";
+
+struct TooltipProviderGuard {
+    ctx: egui::Context,
+}
+
+impl Drop for TooltipProviderGuard {
+    fn drop(&mut self) {
+        self.ctx.data_mut(|d| {
+            let id = Id::new(TOOLTIP_PROVIDER_STACK_KEY);
+            let mut stack = d
+                .get_temp::<Vec<TooltipProviderProps>>(id)
+                .unwrap_or_default();
+            stack.pop();
+            d.insert_temp(id, stack);
+        });
+    }
+}
+
+/// Runs `add_contents` with `props` applied to every [`tooltip`] shown inside it.
+pub fn tooltip_provider<R>(
+    ui: &mut Ui,
+    props: TooltipProviderProps,
+    add_contents: impl FnOnce(&mut Ui) -> R,
+) -> R {
+    ui.ctx().data_mut(|d| {
+        let id = Id::new(TOOLTIP_PROVIDER_STACK_KEY);
+        let mut stack = d
+            .get_temp::<Vec<TooltipProviderProps>>(id)
+            .unwrap_or_default();
+        stack.push(props);
+        d.insert_temp(id, stack);
+    });
+    let _guard = TooltipProviderGuard {
+        ctx: ui.ctx().clone(),
+    };
+    add_contents(ui)
+}
+
+/// The innermost provider around the widget being laid out.
+pub fn current_tooltip_provider(ctx: &egui::Context) -> Option<TooltipProviderProps> {
+    ctx.data(|d| {
+        d.get_temp::<Vec<TooltipProviderProps>>(Id::new(TOOLTIP_PROVIDER_STACK_KEY))
+            .and_then(|stack| stack.last().copied())
+    })
+}
+
+/// Turns every tooltip on or off, e.g. while the app is driven by touch input.
+pub fn set_tooltips_enabled(ctx: &egui::Context, enabled: bool) {
+    ctx.data_mut(|d| d.insert_temp(Id::new(TOOLTIPS_ENABLED_KEY), enabled));
+}
+
+pub fn tooltips_enabled(ctx: &egui::Context) -> bool {
+    ctx.data(|d| d.get_temp::<bool>(Id::new(TOOLTIPS_ENABLED_KEY)))
+        .unwrap_or(true)
+}
+
+fn last_close_key(group: Option<Id>) -> Id {
+    group.map_or_else(
+        || Id::new("__tooltip_global_last_close__"),
+        |group| group.with("tooltip-last-close"),
+    )
+}
+
+fn get_global_last_close_time(ctx: &egui::Context, group: Option<Id>) -> Option<f64> {
+    ctx.data(|d| d.get_temp::<f64>(last_close_key(group)))
 }
 
-fn set_global_last_close_time(ctx: &egui::Context, time: f64) {
-    ctx.data_mut(|d| d.insert_temp(Id::new("__tooltip_global_last_close__"), time));
+fn set_global_last_close_time(ctx: &egui::Context, group: Option<Id>, time: f64) {
+    ctx.data_mut(|d| d.insert_temp(last_close_key(group), time));
 }
 
 pub fn tooltip(anchor: &Response, ui: &mut Ui, theme: &Theme, mut props: TooltipProps<'_>) -> bool {
     let ctx = ui.ctx();
     let now = ctx.input(|i| i.time);
 
-    let anchor_hovered = anchor.hovered() || anchor.has_focus();
+    let provider = current_tooltip_provider(ctx);
+    let group = provider.map(|p| p.id);
+    if let Some(provider) = provider {
+        if !props.delay_overridden {
+            props.delay_ms = provider.delay_ms;
+        }
+        if !props.skip_delay_overridden {
+            props.skip_delay_ms = provider.skip_delay_ms;
+        }
+        props.disable_hoverable_content |= provider.disable_hoverable_content;
+    }
+    let hover_enabled = tooltips_enabled(ctx) && !provider.is_some_and(|p| p.disabled);
+
+    let anchor_hovered = hover_enabled && (anchor.hovered() || anchor.has_focus());
     let disabled = !anchor.enabled();
 
     if disabled && !props.show_when_disabled && !props.force_mount {
@@ -853,11 +1003,13 @@ pub fn tooltip(anchor: &Response, ui: &mut Ui, theme: &Theme, mut props: Tooltip
         .persistent_id
         .unwrap_or_else(|| anchor.id.with("tooltip"));
 
-    let anchor_rect = anchor.rect;
+    let anchor_rect = props.anchor.resolve(ctx, id, anchor.rect);
     let collision_boundary = props
         .collision_boundary
         .unwrap_or_else(|| ctx.viewport_rect());
-    if props.hide_when_detached && !collision_boundary.intersects(anchor_rect) && !props.force_mount
+    if props.hide_when_detached
+        && is_anchor_detached(collision_boundary, anchor_rect)
+        && !props.force_mount
     {
         return false;
     }
@@ -865,14 +1017,14 @@ pub fn tooltip(anchor: &Response, ui: &mut Ui, theme: &Theme, mut props: Tooltip
     let delay_secs = props.delay_ms as f64 / 1000.0;
     let animation_duration = (props.animation_duration_ms as f32).max(1.0) / 1000.0;
 
-    let global_last_close = get_global_last_close_time(ctx);
+    let global_last_close = get_global_last_close_time(ctx, group);
     let should_skip_delay = global_last_close.is_some_and(|close_time| {
         let elapsed = now - close_time;
         elapsed < (props.skip_delay_ms as f64 / 1000.0)
     });
 
     let tooltip_area_id = id.with("area");
-    let tooltip_hovered = if !props.disable_hoverable_content {
+    let tooltip_hovered = if hover_enabled && !props.disable_hoverable_content {
         ctx.data(|d| d.get_temp::<bool>(tooltip_area_id))
             .unwrap_or(false)
     } else {
@@ -973,14 +1125,16 @@ pub fn tooltip(anchor: &Response, ui: &mut Ui, theme: &Theme, mut props: Tooltip
     }
 
     if internal_open_before && !internal_open_after && !is_controlled {
-        set_global_last_close_time(ctx, now);
+        set_global_last_close_time(ctx, group, now);
     }
 
     let last_visible = ctx
         .data(|d| d.get_temp::<bool>(last_visible_key))
         .unwrap_or(false);
-    if last_visible && !render_open {
-        set_global_last_close_time(ctx, now);
+    // Refreshed while open too, so a neighbour hovered in the same frame the pointer leaves
+    // this tooltip already sees the group as warm.
+    if render_open || last_visible {
+        set_global_last_close_time(ctx, group, now);
     }
     ctx.data_mut(|d| d.insert_temp(last_visible_key, render_open));
 
@@ -1064,6 +1218,13 @@ pub fn tooltip(anchor: &Response, ui: &mut Ui, theme: &Theme, mut props: Tooltip
         .unwrap_or(TooltipPortalContainer::Tooltip)
         .order();
 
+    if render_open {
+        register_overlay(
+            ctx,
+            OverlayLayer::new(id, OverlayKind::Tooltip, LayerId::new(order, id)),
+        );
+    }
+
     let area_response = egui::Area::new(id)
         .order(order)
         .interactable(render_open)
@@ -1111,7 +1272,8 @@ pub fn tooltip(anchor: &Response, ui: &mut Ui, theme: &Theme, mut props: Tooltip
 
     let mut should_close = false;
 
-    if render_open && ctx.input(|i| i.key_pressed(egui::Key::Escape)) {
+    if render_open && ctx.input(|i| i.key_pressed(egui::Key::Escape)) && is_topmost_overlay(ctx, id)
+    {
         let mut evt = TooltipEscapeKeyDownEvent {
             key: egui::Key::Escape,
             preventable: TooltipPreventable::default(),
@@ -1129,7 +1291,7 @@ pub fn tooltip(anchor: &Response, ui: &mut Ui, theme: &Theme, mut props: Tooltip
         ctx.input(|i| (i.pointer.any_click(), i.pointer.interact_pos()));
     if render_open
         && any_click
-        && interact_pos.is_some_and(|pos| !tooltip_rect.contains(pos) && !anchor_rect.contains(pos))
+        && interact_pos.is_some_and(|pos| !tooltip_rect.contains(pos) && !anchor.rect.contains(pos))
     {
         let mut evt = TooltipPointerDownOutsideEvent {
             pointer_pos: interact_pos,
@@ -1157,7 +1319,7 @@ pub fn tooltip(anchor: &Response, ui: &mut Ui, theme: &Theme, mut props: Tooltip
                 if let Some(cb) = props.on_open_change.as_mut() {
                     cb(false);
                 }
-                set_global_last_close_time(ctx, now);
+                set_global_last_close_time(ctx, group, now);
             }
         }
     }
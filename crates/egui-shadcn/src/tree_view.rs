@@ -0,0 +1,1024 @@
+//! A hierarchical list for file explorers and outlines.
+//!
+//! The caller owns the [`TreeNode`]s and a [`TreeViewState`] holding the expanded, selected,
+//! checked and focused ids. Rows are only laid out while they intersect the clip rect, so
+//! large trees can be put in a fixed-height view with [`TreeViewProps::max_height`].
+//!
+//! # Example
+//! ```ignore
+//! let response = tree_view(ui, &theme, TreeViewProps::new(Id::new("files"), &nodes, &mut state));
+//! if let Some(id) = response.load_requested {
+//!     // Fetch the children, then call `set_children` on the node.
+//! }
+//! if let Some(moved) = response.moved {
+//!     apply_tree_move(&mut nodes, &moved);
+//! }
+//! ```
+
+use crate::checkbox::{CheckboxProps, CheckboxSize, CheckboxState, checkbox_with_props};
+use crate::scroll_area::{ScrollAreaProps, ScrollDirection, scroll_area};
+use crate::sidebar::paint_chevron;
+use crate::theme::Theme;
+use egui::{
+    Align2, Color32, CornerRadius, CursorIcon, Event, EventFilter, FontId, Id, Key, Modifiers,
+    Rect, Response, Sense, Stroke, StrokeKind, Ui, UiBuilder, pos2, vec2,
+};
+use lucide_icons::Icon;
+use std::collections::HashSet;
+use std::hash::Hash;
+
+const CHEVRON_WIDTH: f32 = 16.0;
+const CHECKBOX_WIDTH: f32 = 24.0;
+const ICON_WIDTH: f32 = 20.0;
+
+#[derive(Clone, Debug)]
+pub struct TreeNode<K> {
+    pub id: K,
+    pub label: String,
+    pub icon: Option<Icon>,
+
+    /// `None` for leaves. A branch may have no children, like an empty folder.
+    pub children: Option<Vec<TreeNode<K>>>,
+
+    /// The children haven't been loaded yet; expanding the node requests them.
+    pub lazy: bool,
+
+    pub disabled: bool,
+}
+
+impl<K> TreeNode<K> {
+    pub fn new(id: K, label: impl Into<String>) -> Self {
+        Self {
+            id,
+            label: label.into(),
+            icon: None,
+            children: None,
+            lazy: false,
+            disabled: false,
+        }
+    }
+
+    pub fn icon(mut self, icon: Icon) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+
+    pub fn children(mut self, children: Vec<TreeNode<K>>) -> Self {
+        self.children = Some(children);
+        self
+    }
+
+    /// A branch without children.
+    pub fn branch(mut self) -> Self {
+        self.children.get_or_insert_with(Vec::new);
+        self
+    }
+
+    /// A branch whose children are loaded on first expansion.
+    pub fn lazy(mut self) -> Self {
+        self.lazy = true;
+        self.branch()
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Fills in the children of a lazy node.
+    pub fn set_children(&mut self, children: Vec<TreeNode<K>>) {
+        self.children = Some(children);
+        self.lazy = false;
+    }
+
+    pub fn is_branch(&self) -> bool {
+        self.children.is_some()
+    }
+}
+
+impl<K: Clone + Eq + Hash> TreeNode<K> {
+    /// Nodes with loaded children mirror their descendants: checked when all of them are,
+    /// indeterminate when only some are. Other nodes are checked when their id is in `checked`.
+    pub fn check_state(&self, checked: &HashSet<K>) -> CheckboxState {
+        match &self.children {
+            Some(children) if !children.is_empty() => {
+                let mut states = children.iter().map(|child| child.check_state(checked));
+                let first = states.next().unwrap_or(CheckboxState::Unchecked);
+                if first != CheckboxState::Indeterminate && states.all(|state| state == first) {
+                    first
+                } else {
+                    CheckboxState::Indeterminate
+                }
+            }
+            _ => checked.contains(&self.id).into(),
+        }
+    }
+
+    /// Checks or unchecks the node and all of its descendants.
+    pub fn set_checked(&self, checked: &mut HashSet<K>, value: bool) {
+        if value {
+            checked.insert(self.id.clone());
+        } else {
+            checked.remove(&self.id);
+        }
+        for child in self.children.iter().flatten() {
+            child.set_checked(checked, value);
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct TreeViewState<K> {
+    pub expanded: HashSet<K>,
+    pub selected: HashSet<K>,
+    pub checked: HashSet<K>,
+
+    /// The row keyboard navigation starts from.
+    pub focused: Option<K>,
+
+    /// Where shift-selection ranges start.
+    anchor: Option<K>,
+    dragging: Option<K>,
+}
+
+impl<K> Default for TreeViewState<K> {
+    fn default() -> Self {
+        Self {
+            expanded: HashSet::new(),
+            selected: HashSet::new(),
+            checked: HashSet::new(),
+            focused: None,
+            anchor: None,
+            dragging: None,
+        }
+    }
+}
+
+impl<K: Clone + Eq + Hash> TreeViewState<K> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn is_expanded(&self, id: &K) -> bool {
+        self.expanded.contains(id)
+    }
+
+    pub fn set_expanded(&mut self, id: K, expanded: bool) {
+        if expanded {
+            self.expanded.insert(id);
+        } else {
+            self.expanded.remove(&id);
+        }
+    }
+
+    pub fn is_selected(&self, id: &K) -> bool {
+        self.selected.contains(id)
+    }
+
+    /// The node being dragged, if any.
+    pub fn dragging(&self) -> Option<&K> {
+        self.dragging.as_ref()
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TreeSelectionMode {
+    None,
+    #[default]
+    Single,
+
+    /// Ctrl/Cmd-click toggles a row and Shift-click selects a range.
+    Multi,
+}
+
+#[derive(Debug)]
+pub struct TreeViewProps<'a, K> {
+    pub id_source: Id,
+    pub nodes: &'a [TreeNode<K>],
+    pub state: &'a mut TreeViewState<K>,
+    pub selection: TreeSelectionMode,
+
+    /// Shows a tri-state checkbox on every row.
+    pub checkboxes: bool,
+
+    /// Lets rows be dragged onto, before or after other rows.
+    pub draggable: bool,
+
+    pub row_height: f32,
+    pub indent: f32,
+
+    /// Puts the tree in a scroll area of at most this height.
+    pub max_height: Option<f32>,
+}
+
+impl<'a, K> TreeViewProps<'a, K> {
+    pub fn new(id_source: Id, nodes: &'a [TreeNode<K>], state: &'a mut TreeViewState<K>) -> Self {
+        Self {
+            id_source,
+            nodes,
+            state,
+            selection: TreeSelectionMode::Single,
+            checkboxes: false,
+            draggable: false,
+            row_height: 28.0,
+            indent: 16.0,
+            max_height: None,
+        }
+    }
+
+    pub fn selection(mut self, selection: TreeSelectionMode) -> Self {
+        self.selection = selection;
+        self
+    }
+
+    pub fn checkboxes(mut self, checkboxes: bool) -> Self {
+        self.checkboxes = checkboxes;
+        self
+    }
+
+    pub fn draggable(mut self, draggable: bool) -> Self {
+        self.draggable = draggable;
+        self
+    }
+
+    pub fn row_height(mut self, row_height: f32) -> Self {
+        self.row_height = row_height;
+        self
+    }
+
+    pub fn indent(mut self, indent: f32) -> Self {
+        self.indent = indent;
+        self
+    }
+
+    pub fn max_height(mut self, max_height: f32) -> Self {
+        self.max_height = Some(max_height);
+        self
+    }
+}
+
+/// Moving `node` so that it ends up at `index` among the children of `parent`, or among the
+/// roots when `parent` is `None`. The index counts siblings after `node` has been removed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TreeMove<K> {
+    pub node: K,
+    pub parent: Option<K>,
+    pub index: usize,
+}
+
+#[derive(Clone, Debug)]
+pub struct TreeViewResponse<K> {
+    pub response: Response,
+
+    /// Double-clicked, or focused when Enter was pressed.
+    pub activated: Option<K>,
+
+    /// A node that was expanded (`true`) or collapsed (`false`).
+    pub toggled: Option<(K, bool)>,
+
+    /// A lazy node was expanded and needs its children.
+    pub load_requested: Option<K>,
+
+    /// A drag ended over a valid drop target; see [`apply_tree_move`].
+    pub moved: Option<TreeMove<K>>,
+
+    pub selection_changed: bool,
+    pub checked_changed: bool,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct FlatRow<'n, K> {
+    node: &'n TreeNode<K>,
+    depth: usize,
+    parent: Option<usize>,
+    index: usize,
+
+    /// Placeholder shown under a lazy node while its children load.
+    loading: bool,
+}
+
+/// What happened during the frame, before it becomes a [`TreeViewResponse`].
+struct TreeEvents<K> {
+    activated: Option<K>,
+    toggled: Option<(K, bool)>,
+    load_requested: Option<K>,
+    selection_changed: bool,
+    checked_changed: bool,
+}
+
+enum DropIndicator {
+    Line { y: f32, depth: usize },
+    Row(usize),
+}
+
+pub fn tree_view<K: Clone + Eq + Hash>(
+    ui: &mut Ui,
+    theme: &Theme,
+    props: TreeViewProps<'_, K>,
+) -> TreeViewResponse<K> {
+    match props.max_height {
+        Some(max_height) => {
+            let scroll = ScrollAreaProps::default()
+                .with_id(props.id_source.with("scroll"))
+                .with_direction(ScrollDirection::Vertical)
+                .with_auto_shrink([false, true])
+                .with_max_size(vec2(ui.available_width(), max_height));
+            scroll_area(ui, theme, scroll, |ui| tree_body(ui, theme, props))
+        }
+        None => tree_body(ui, theme, props),
+    }
+}
+
+fn tree_body<K: Clone + Eq + Hash>(
+    ui: &mut Ui,
+    theme: &Theme,
+    props: TreeViewProps<'_, K>,
+) -> TreeViewResponse<K> {
+    let TreeViewProps {
+        id_source: tree_id,
+        nodes,
+        state,
+        selection,
+        checkboxes,
+        draggable,
+        row_height,
+        indent,
+        ..
+    } = props;
+    let mut result = TreeEvents {
+        activated: None,
+        toggled: None,
+        load_requested: None,
+        selection_changed: false,
+        checked_changed: false,
+    };
+
+    let has_focus = ui.memory(|m| m.has_focus(tree_id));
+    let mut scroll_to = None;
+    if has_focus {
+        let rows = flatten(nodes, &state.expanded);
+        scroll_to = handle_keys(
+            ui,
+            tree_id,
+            &rows,
+            state,
+            selection,
+            checkboxes,
+            &mut result,
+        );
+    }
+
+    let rows = flatten(nodes, &state.expanded);
+    let width = ui.available_width();
+    let (rect, _) =
+        ui.allocate_exact_size(vec2(width, rows.len() as f32 * row_height), Sense::hover());
+    let response = ui.interact(rect, tree_id, Sense::click());
+    ui.memory_mut(|m| {
+        m.set_focus_lock_filter(
+            tree_id,
+            EventFilter {
+                horizontal_arrows: true,
+                vertical_arrows: true,
+                ..Default::default()
+            },
+        )
+    });
+    let row_rect = |index: usize| {
+        Rect::from_min_size(
+            pos2(rect.left(), rect.top() + index as f32 * row_height),
+            vec2(rect.width(), row_height),
+        )
+    };
+    if let Some(index) = scroll_to {
+        ui.scroll_to_rect(row_rect(index), None);
+    }
+
+    // Only rows inside the clip rect are laid out.
+    let clip = ui.clip_rect();
+    let first = ((clip.top() - rect.top()) / row_height).floor().max(0.0) as usize;
+    let last =
+        (((clip.bottom() - rect.top()) / row_height).ceil().max(0.0) as usize).min(rows.len());
+
+    let palette = &theme.palette;
+    let painter = ui.painter_at(rect);
+    let font = FontId::proportional(14.0);
+    let radius = CornerRadius::same(theme.radius.r2.round() as u8);
+    let modifiers = ui.input(|i| i.modifiers);
+    let mut clicked_row = None;
+
+    for (index, row) in rows.iter().enumerate().take(last).skip(first) {
+        let row_rect = row_rect(index);
+        let mut x = row_rect.left() + 8.0 + row.depth as f32 * indent;
+
+        if row.loading {
+            painter.text(
+                pos2(x + CHEVRON_WIDTH, row_rect.center().y),
+                Align2::LEFT_CENTER,
+                "Loading…",
+                FontId::proportional(13.0),
+                palette.muted_foreground,
+            );
+            continue;
+        }
+
+        let node = row.node;
+        let row_id = tree_id.with(("row", &node.id));
+        let sense = match (node.disabled, draggable) {
+            (true, _) => Sense::hover(),
+            (false, true) => Sense::CLICK | Sense::DRAG,
+            (false, false) => Sense::CLICK,
+        };
+        let row_response = ui.interact(row_rect, row_id, sense);
+        let selected = state.selected.contains(&node.id);
+        let hovered = row_response.hovered() && state.dragging.is_none();
+
+        let bg = if selected {
+            palette.accent
+        } else if hovered {
+            palette.accent.gamma_multiply(0.5)
+        } else {
+            Color32::TRANSPARENT
+        };
+        if bg != Color32::TRANSPARENT {
+            painter.rect_filled(row_rect, radius, bg);
+        }
+        if response.has_focus() && state.focused.as_ref() == Some(&node.id) {
+            painter.rect_stroke(
+                row_rect.shrink(1.0),
+                radius,
+                Stroke::new(1.0, palette.ring),
+                StrokeKind::Inside,
+            );
+        }
+        let text_color = if node.disabled {
+            palette.muted_foreground
+        } else if selected || hovered {
+            palette.accent_foreground
+        } else {
+            palette.foreground
+        };
+
+        let mut chevron_clicked = false;
+        if node.is_branch() {
+            let expanded = state.expanded.contains(&node.id);
+            let chevron_rect =
+                Rect::from_min_size(pos2(x, row_rect.top()), vec2(CHEVRON_WIDTH, row_height));
+            let openness = ui.ctx().animate_bool_with_time(
+                row_id.with("chevron"),
+                expanded,
+                theme.motion.base_ms / 1000.0,
+            );
+            paint_chevron(&painter, chevron_rect.center(), openness, text_color);
+            chevron_clicked = row_response.clicked()
+                && row_response
+                    .interact_pointer_pos()
+                    .is_some_and(|pos| chevron_rect.contains(pos));
+        }
+        x += CHEVRON_WIDTH;
+
+        if checkboxes {
+            let box_rect = Rect::from_center_size(
+                pos2(x + 8.0, row_rect.center().y),
+                vec2(CHECKBOX_WIDTH, 16.0),
+            );
+            let mut check = node.check_state(&state.checked);
+            let before = check;
+            ui.scope_builder(
+                UiBuilder::new()
+                    .max_rect(box_rect)
+                    .id_salt(row_id.with("check")),
+                |ui| {
+                    checkbox_with_props(
+                        ui,
+                        theme,
+                        &mut check,
+                        "",
+                        CheckboxProps::default()
+                            .with_size(CheckboxSize::Size1)
+                            .with_enabled(!node.disabled),
+                    )
+                },
+            );
+            if check != before {
+                node.set_checked(&mut state.checked, before != CheckboxState::Checked);
+                result.checked_changed = true;
+            }
+            x += CHECKBOX_WIDTH;
+        }
+
+        if let Some(icon) = node.icon {
+            painter.text(
+                pos2(x, row_rect.center().y),
+                Align2::LEFT_CENTER,
+                icon.unicode(),
+                FontId::proportional(15.0),
+                text_color,
+            );
+            x += ICON_WIDTH;
+        }
+        painter.text(
+            pos2(x, row_rect.center().y),
+            Align2::LEFT_CENTER,
+            &node.label,
+            font.clone(),
+            text_color,
+        );
+
+        if chevron_clicked {
+            toggle_expanded(state, node, &mut result);
+        } else if row_response.clicked() {
+            clicked_row = Some(index);
+            if row_response.double_clicked() {
+                if node.is_branch() {
+                    toggle_expanded(state, node, &mut result);
+                }
+                result.activated = Some(node.id.clone());
+            }
+        }
+        if row_response.drag_started() {
+            state.dragging = Some(node.id.clone());
+        }
+        if !node.disabled {
+            row_response.on_hover_cursor(CursorIcon::PointingHand);
+        }
+    }
+
+    if let Some(index) = clicked_row {
+        response.request_focus();
+        state.focused = Some(rows[index].node.id.clone());
+        result.selection_changed |= select_on_click(state, &rows, index, selection, modifiers);
+    }
+
+    let mut moved = None;
+    if let Some(dragged) = state.dragging.clone() {
+        let pointer = ui.input(|i| i.pointer.interact_pos());
+        let target = pointer.filter(|pos| rect.contains(*pos)).and_then(|pos| {
+            let offset = pos.y - rect.top();
+            let index = ((offset / row_height) as usize).min(rows.len().saturating_sub(1));
+            let fraction = offset / row_height - index as f32;
+            drop_target(
+                nodes,
+                &rows,
+                &state.expanded,
+                &dragged,
+                index,
+                fraction,
+                row_height,
+            )
+        });
+        if let Some((_, indicator)) = &target {
+            match *indicator {
+                DropIndicator::Line { y, depth } => {
+                    let left = rect.left() + 8.0 + depth as f32 * indent + CHEVRON_WIDTH;
+                    let y = rect.top() + y;
+                    painter.circle_stroke(pos2(left, y), 3.0, Stroke::new(2.0, palette.primary));
+                    painter.hline(
+                        left + 3.0..=rect.right(),
+                        y,
+                        Stroke::new(2.0, palette.primary),
+                    );
+                }
+                DropIndicator::Row(index) => {
+                    painter.rect_stroke(
+                        row_rect(index).shrink(1.0),
+                        radius,
+                        Stroke::new(2.0, palette.primary),
+                        StrokeKind::Inside,
+                    );
+                }
+            }
+        }
+        ui.ctx().set_cursor_icon(CursorIcon::Grabbing);
+        if !ui.input(|i| i.pointer.primary_down()) {
+            moved = target.map(|(moved, _)| moved);
+            state.dragging = None;
+        }
+    }
+
+    let mut response = response;
+    if result.selection_changed || result.checked_changed {
+        response.mark_changed();
+    }
+    if response.changed() || moved.is_some() || result.toggled.is_some() {
+        ui.ctx().request_repaint();
+    }
+    TreeViewResponse {
+        response,
+        activated: result.activated,
+        toggled: result.toggled,
+        load_requested: result.load_requested,
+        moved,
+        selection_changed: result.selection_changed,
+        checked_changed: result.checked_changed,
+    }
+}
+
+fn flatten<'n, K: Eq + Hash>(
+    nodes: &'n [TreeNode<K>],
+    expanded: &HashSet<K>,
+) -> Vec<FlatRow<'n, K>> {
+    fn walk<'n, K: Eq + Hash>(
+        nodes: &'n [TreeNode<K>],
+        expanded: &HashSet<K>,
+        depth: usize,
+        parent: Option<usize>,
+        rows: &mut Vec<FlatRow<'n, K>>,
+    ) {
+        for (index, node) in nodes.iter().enumerate() {
+            let row = rows.len();
+            rows.push(FlatRow {
+                node,
+                depth,
+                parent,
+                index,
+                loading: false,
+            });
+            if !expanded.contains(&node.id) {
+                continue;
+            }
+            if node.lazy {
+                rows.push(FlatRow {
+                    node,
+                    depth: depth + 1,
+                    parent: Some(row),
+                    index: 0,
+                    loading: true,
+                });
+            } else if let Some(children) = &node.children {
+                walk(children, expanded, depth + 1, Some(row), rows);
+            }
+        }
+    }
+
+    let mut rows = Vec::new();
+    walk(nodes, expanded, 0, None, &mut rows);
+    rows
+}
+
+fn toggle_expanded<K: Clone + Eq + Hash>(
+    state: &mut TreeViewState<K>,
+    node: &TreeNode<K>,
+    result: &mut TreeEvents<K>,
+) {
+    let expanded = !state.expanded.contains(&node.id);
+    state.set_expanded(node.id.clone(), expanded);
+    if expanded && node.lazy {
+        result.load_requested = Some(node.id.clone());
+    }
+    result.toggled = Some((node.id.clone(), expanded));
+}
+
+fn select_on_click<K: Clone + Eq + Hash>(
+    state: &mut TreeViewState<K>,
+    rows: &[FlatRow<'_, K>],
+    index: usize,
+    selection: TreeSelectionMode,
+    modifiers: Modifiers,
+) -> bool {
+    let id = &rows[index].node.id;
+    let before = state.selected.clone();
+    match selection {
+        TreeSelectionMode::None => return false,
+        TreeSelectionMode::Single => {
+            state.selected = HashSet::from([id.clone()]);
+        }
+        TreeSelectionMode::Multi if modifiers.shift => {
+            select_range(state, rows, index);
+            return state.selected != before;
+        }
+        TreeSelectionMode::Multi if modifiers.command => {
+            if !state.selected.remove(id) {
+                state.selected.insert(id.clone());
+            }
+        }
+        TreeSelectionMode::Multi => {
+            state.selected = HashSet::from([id.clone()]);
+        }
+    }
+    state.anchor = Some(id.clone());
+    state.selected != before
+}
+
+/// Selects the visible rows between the anchor and `index`.
+fn select_range<K: Clone + Eq + Hash>(
+    state: &mut TreeViewState<K>,
+    rows: &[FlatRow<'_, K>],
+    index: usize,
+) {
+    let anchor = state
+        .anchor
+        .as_ref()
+        .and_then(|anchor| {
+            rows.iter()
+                .position(|row| !row.loading && row.node.id == *anchor)
+        })
+        .unwrap_or(index);
+    state.selected = rows[anchor.min(index)..=anchor.max(index)]
+        .iter()
+        .filter(|row| !row.loading && !row.node.disabled)
+        .map(|row| row.node.id.clone())
+        .collect();
+}
+
+/// Applies a key press to `state` and returns the row that gained focus, if any.
+fn handle_keys<K: Clone + Eq + Hash>(
+    ui: &Ui,
+    tree_id: Id,
+    rows: &[FlatRow<'_, K>],
+    state: &mut TreeViewState<K>,
+    selection: TreeSelectionMode,
+    checkboxes: bool,
+    result: &mut TreeEvents<K>,
+) -> Option<usize> {
+    let visible: Vec<usize> = (0..rows.len()).filter(|i| !rows[*i].loading).collect();
+    if visible.is_empty() {
+        return None;
+    }
+    let current = state
+        .focused
+        .as_ref()
+        .and_then(|focused| visible.iter().position(|i| rows[*i].node.id == *focused));
+
+    // `consume_key` ignores extra Shift, so the Shift combinations are consumed first.
+    let (shift_down, shift_up, down, up, right, left, home, end, space, enter) =
+        ui.input_mut(|i| {
+            (
+                i.consume_key(Modifiers::SHIFT, Key::ArrowDown),
+                i.consume_key(Modifiers::SHIFT, Key::ArrowUp),
+                i.consume_key(Modifiers::NONE, Key::ArrowDown),
+                i.consume_key(Modifiers::NONE, Key::ArrowUp),
+                i.consume_key(Modifiers::NONE, Key::ArrowRight),
+                i.consume_key(Modifiers::NONE, Key::ArrowLeft),
+                i.consume_key(Modifiers::NONE, Key::Home),
+                i.consume_key(Modifiers::NONE, Key::End),
+                i.consume_key(Modifiers::NONE, Key::Space),
+                i.consume_key(Modifiers::NONE, Key::Enter),
+            )
+        });
+    let typed = typeahead_buffer(ui, tree_id);
+
+    let last = visible.len() - 1;
+    let mut target = None;
+    if down || shift_down {
+        target = Some(current.map_or(0, |c| (c + 1).min(last)));
+    } else if up || shift_up {
+        target = Some(current.map_or(last, |c| c.saturating_sub(1)));
+    } else if home {
+        target = Some(0);
+    } else if end {
+        target = Some(last);
+    }
+
+    if let Some(c) = current {
+        let row_index = visible[c];
+        let node = rows[row_index].node;
+        let expanded = state.expanded.contains(&node.id);
+        if right && node.is_branch() {
+            if !expanded {
+                toggle_expanded(state, node, result);
+            } else if rows
+                .get(row_index + 1)
+                .is_some_and(|next| !next.loading && next.parent == Some(row_index))
+            {
+                target = Some(c + 1);
+            }
+        } else if left {
+            if expanded {
+                toggle_expanded(state, node, result);
+            } else if let Some(parent) = rows[row_index].parent {
+                target = visible.iter().position(|i| *i == parent);
+            }
+        } else if space && !node.disabled {
+            if checkboxes {
+                let value = node.check_state(&state.checked) != CheckboxState::Checked;
+                node.set_checked(&mut state.checked, value);
+                result.checked_changed = true;
+            } else {
+                // Space toggles a row in multi-selection, like Ctrl/Cmd-click.
+                result.selection_changed |=
+                    select_on_click(state, rows, row_index, selection, Modifiers::COMMAND);
+            }
+        } else if enter && !node.disabled {
+            result.selection_changed |=
+                select_on_click(state, rows, row_index, selection, Modifiers::NONE);
+            result.activated = Some(node.id.clone());
+        }
+    }
+
+    if let Some(typed) = typed {
+        let start = match (current, typed.chars().count()) {
+            (Some(c), 1) => c + 1,
+            (Some(c), _) => c,
+            (None, _) => 0,
+        };
+        let typed = typed.to_lowercase();
+        target = (0..visible.len())
+            .map(|offset| (start + offset) % visible.len())
+            .find(|c| {
+                rows[visible[*c]]
+                    .node
+                    .label
+                    .to_lowercase()
+                    .starts_with(&typed)
+            })
+            .or(target);
+    }
+
+    let target = target?;
+    let row_index = visible[target];
+    state.focused = Some(rows[row_index].node.id.clone());
+    if (shift_down || shift_up) && selection == TreeSelectionMode::Multi {
+        if state.anchor.is_none() {
+            state.anchor = current
+                .map(|c| rows[visible[c]].node.id.clone())
+                .or_else(|| state.focused.clone());
+        }
+        let before = state.selected.clone();
+        select_range(state, rows, row_index);
+        result.selection_changed |= state.selected != before;
+    }
+    ui.ctx().request_repaint();
+    Some(row_index)
+}
+
+/// Collects typed characters into a buffer that resets after a short pause.
+fn typeahead_buffer(ui: &Ui, tree_id: Id) -> Option<String> {
+    let text: String = ui.input(|i| {
+        i.events
+            .iter()
+            .filter_map(|event| match event {
+                Event::Text(text) => Some(text.as_str()),
+                _ => None,
+            })
+            .filter(|text| !text.chars().any(char::is_control) && text.trim() != "")
+            .collect()
+    });
+    if text.is_empty() {
+        return None;
+    }
+    let now = ui.input(|i| i.time);
+    let buffer_id = tree_id.with("typeahead");
+    let (mut buffer, last) = ui
+        .data(|d| d.get_temp::<(String, f64)>(buffer_id))
+        .unwrap_or_default();
+    if now - last > 0.8 {
+        buffer.clear();
+    }
+    buffer.push_str(&text);
+    ui.data_mut(|d| d.insert_temp(buffer_id, (buffer.clone(), now)));
+    Some(buffer)
+}
+
+/// Where the dragged node would land with the pointer `fraction` of the way down row `index`.
+fn drop_target<K: Clone + Eq + Hash>(
+    nodes: &[TreeNode<K>],
+    rows: &[FlatRow<'_, K>],
+    expanded: &HashSet<K>,
+    dragged: &K,
+    index: usize,
+    fraction: f32,
+    row_height: f32,
+) -> Option<(TreeMove<K>, DropIndicator)> {
+    let row = rows.get(index).filter(|row| !row.loading)?;
+
+    // A node can't be dropped into itself or its own subtree.
+    let mut ancestor = Some(index);
+    while let Some(i) = ancestor {
+        if rows[i].node.id == *dragged {
+            return None;
+        }
+        ancestor = rows[i].parent;
+    }
+
+    let node = row.node;
+    let top = index as f32 * row_height;
+    let parent_id = row.parent.map(|parent| rows[parent].node.id.clone());
+    // `set_children` replaces a lazy node's placeholder list, so anything dropped into it
+    // before it loads would be lost; unloaded branches only take drops before or after them.
+    let accepts_children = node.is_branch() && !node.lazy;
+    let (parent, target_index, indicator) =
+        if fraction < 0.25 || (!accepts_children && fraction < 0.5) {
+            let line = DropIndicator::Line {
+                y: top,
+                depth: row.depth,
+            };
+            (parent_id, row.index, line)
+        } else if !accepts_children || (fraction > 0.75 && !expanded.contains(&node.id)) {
+            let line = DropIndicator::Line {
+                y: top + row_height,
+                depth: row.depth,
+            };
+            (parent_id, row.index + 1, line)
+        } else if fraction > 0.75 {
+            let line = DropIndicator::Line {
+                y: top + row_height,
+                depth: row.depth + 1,
+            };
+            (Some(node.id.clone()), 0, line)
+        } else {
+            let len = node.children.as_ref().map_or(0, Vec::len);
+            (Some(node.id.clone()), len, DropIndicator::Row(index))
+        };
+
+    let (current_parent, current_index) = locate(nodes, dragged, None)?;
+    let mut target_index = target_index;
+    if current_parent == parent.as_ref() {
+        if current_index < target_index {
+            target_index -= 1;
+        }
+        if current_index == target_index {
+            return None;
+        }
+    }
+    let moved = TreeMove {
+        node: dragged.clone(),
+        parent,
+        index: target_index,
+    };
+    Some((moved, indicator))
+}
+
+/// The parent id and sibling index of `id`.
+fn locate<'n, K: PartialEq>(
+    nodes: &'n [TreeNode<K>],
+    id: &K,
+    parent: Option<&'n K>,
+) -> Option<(Option<&'n K>, usize)> {
+    for (index, node) in nodes.iter().enumerate() {
+        if node.id == *id {
+            return Some((parent, index));
+        }
+        if let Some(children) = &node.children
+            && let Some(found) = locate(children, id, Some(&node.id))
+        {
+            return Some(found);
+        }
+    }
+    None
+}
+
+fn find<'n, K: PartialEq>(nodes: &'n [TreeNode<K>], id: &K) -> Option<&'n TreeNode<K>> {
+    nodes.iter().find_map(|node| {
+        if node.id == *id {
+            Some(node)
+        } else {
+            node.children
+                .as_deref()
+                .and_then(|children| find(children, id))
+        }
+    })
+}
+
+fn find_mut<'n, K: PartialEq>(nodes: &'n mut [TreeNode<K>], id: &K) -> Option<&'n mut TreeNode<K>> {
+    for node in nodes {
+        if node.id == *id {
+            return Some(node);
+        }
+        if let Some(found) = node
+            .children
+            .as_mut()
+            .and_then(|children| find_mut(children, id))
+        {
+            return Some(found);
+        }
+    }
+    None
+}
+
+fn remove<K: PartialEq>(nodes: &mut Vec<TreeNode<K>>, id: &K) -> Option<TreeNode<K>> {
+    if let Some(index) = nodes.iter().position(|node| node.id == *id) {
+        return Some(nodes.remove(index));
+    }
+    nodes.iter_mut().find_map(|node| {
+        node.children
+            .as_mut()
+            .and_then(|children| remove(children, id))
+    })
+}
+
+/// Applies a [`TreeMove`] reported by [`tree_view`]. Returns `false`, leaving the tree
+/// untouched, when a node is missing or the parent lies inside the moved node.
+pub fn apply_tree_move<K: PartialEq>(nodes: &mut Vec<TreeNode<K>>, moved: &TreeMove<K>) -> bool {
+    let Some(node) = find(nodes, &moved.node) else {
+        return false;
+    };
+    if let Some(parent) = &moved.parent
+        && (find(std::slice::from_ref(node), parent).is_some() || find(nodes, parent).is_none())
+    {
+        return false;
+    }
+
+    let Some(node) = remove(nodes, &moved.node) else {
+        return false;
+    };
+    let siblings = match &moved.parent {
+        Some(parent) => match find_mut(nodes, parent) {
+            Some(parent) => parent.children.get_or_insert_with(Vec::new),
+            None => return false,
+        },
+        None => nodes,
+    };
+    let index = moved.index.min(siblings.len());
+    siblings.insert(index, node);
+    true
+}
@@ -185,6 +185,7 @@ fn accordion_renders_without_panic() {
 }
 
 #[test]
+#[allow(clippy::let_and_return)]
 fn accordion_with_items_renders() {
     init_logger();
     use egui_shadcn::{AccordionItemProps, AccordionProps, accordion, accordion_item};
@@ -0,0 +1,131 @@
+use egui::load::{ImageLoadResult, ImageLoader, ImagePoll, LoadError, SizeHint, SizedTexture};
+use egui::{Pos2, Rect, Shape, TextureId, vec2};
+use egui_shadcn::{
+    AvatarGroupProps, AvatarImageStatus, AvatarProps, AvatarSize, Theme, avatar, avatar_group,
+};
+use std::sync::Arc;
+
+/// Keeps `slow://` images pending forever and rejects everything else.
+struct SlowLoader;
+
+impl ImageLoader for SlowLoader {
+    fn id(&self) -> &str {
+        "slow"
+    }
+
+    fn load(&self, _ctx: &egui::Context, uri: &str, _size_hint: SizeHint) -> ImageLoadResult {
+        if uri.starts_with("slow://") {
+            Ok(ImagePoll::Pending { size: None })
+        } else {
+            Err(LoadError::NotSupported)
+        }
+    }
+
+    fn forget(&self, _uri: &str) {}
+
+    fn forget_all(&self) {}
+
+    fn byte_size(&self) -> usize {
+        0
+    }
+}
+
+/// Runs one frame at `time` and returns the statuses and whether any text was painted.
+fn frame(
+    ctx: &egui::Context,
+    time: f64,
+    avatars: &[AvatarProps<'static>],
+) -> (Vec<AvatarImageStatus>, bool) {
+    let theme = Theme::default();
+    let input = egui::RawInput {
+        screen_rect: Some(Rect::from_min_size(Pos2::ZERO, vec2(400.0, 400.0))),
+        time: Some(time),
+        ..Default::default()
+    };
+    let mut statuses = Vec::new();
+    let output = ctx.run(input, |ctx| {
+        egui::CentralPanel::default().show(ctx, |ui| {
+            for props in avatars {
+                statuses.push(avatar(ui, &theme, props.clone()).status);
+            }
+        });
+    });
+    let painted_text = output
+        .shapes
+        .iter()
+        .any(|clipped| matches!(clipped.shape, Shape::Text(_)));
+    (statuses, painted_text)
+}
+
+#[test]
+fn reports_the_image_status() {
+    let ctx = egui::Context::default();
+    ctx.add_image_loader(Arc::new(SlowLoader));
+    let texture = SizedTexture::new(TextureId::Managed(0), vec2(40.0, 20.0));
+    let avatars = [
+        AvatarProps::new("JD"),
+        AvatarProps::new("JD").image(texture),
+        AvatarProps::new("JD").image("slow://jd.png"),
+        AvatarProps::new("JD").image("missing://jd.png"),
+    ];
+
+    let (statuses, _) = frame(&ctx, 0.0, &avatars);
+    assert_eq!(
+        statuses,
+        [
+            AvatarImageStatus::Idle,
+            AvatarImageStatus::Loaded,
+            AvatarImageStatus::Loading,
+            AvatarImageStatus::Error,
+        ]
+    );
+}
+
+#[test]
+fn fallback_waits_for_the_delay_while_loading() {
+    let ctx = egui::Context::default();
+    ctx.add_image_loader(Arc::new(SlowLoader));
+    let avatars = [AvatarProps::new("JD")
+        .image("slow://jd.png")
+        .fallback_delay(0.6)
+        .id_source("jd")];
+
+    assert!(
+        !frame(&ctx, 0.0, &avatars).1,
+        "no initials before the delay"
+    );
+    assert!(!frame(&ctx, 0.5, &avatars).1);
+    assert!(
+        frame(&ctx, 1.0, &avatars).1,
+        "initials once the delay passed"
+    );
+
+    let immediate = [AvatarProps::new("JD").image("slow://jd.png")];
+    assert!(frame(&ctx, 1.5, &immediate).1);
+}
+
+#[test]
+fn group_overlaps_and_counts_the_overflow() {
+    let ctx = egui::Context::default();
+    let theme = Theme::default();
+    let mut result = None;
+    let _ = ctx.run(egui::RawInput::default(), |ctx| {
+        egui::CentralPanel::default().show(ctx, |ui| {
+            let avatars = ["AB", "CD", "EF", "GH", "IJ"]
+                .map(AvatarProps::new)
+                .to_vec();
+            result = Some(avatar_group(
+                ui,
+                &theme,
+                AvatarGroupProps::new(avatars)
+                    .max(3)
+                    .size(AvatarSize::Size5)
+                    .overlap(0.25),
+            ));
+        });
+    });
+    let result = result.expect("rendered");
+    assert_eq!(result.overflow, 2);
+    // Three avatars and the chip, each 40px, advancing 30px.
+    assert_eq!(result.response.rect.size(), vec2(130.0, 40.0));
+}
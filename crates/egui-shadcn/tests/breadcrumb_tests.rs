@@ -0,0 +1,130 @@
+use egui::{Event, Id, Modifiers, PointerButton, Pos2, Rect, Shape, vec2};
+use egui_shadcn::{BreadcrumbPathProps, BreadcrumbProps, Theme, breadcrumb_path};
+use std::ops::Range;
+
+const SEGMENTS: [&str; 6] = [
+    "Home",
+    "Workspace",
+    "Projects",
+    "Components",
+    "Navigation",
+    "Breadcrumb",
+];
+
+struct Frame {
+    clicked: Option<usize>,
+    collapsed: Range<usize>,
+    texts: Vec<(String, Rect)>,
+}
+
+fn frame(
+    ctx: &egui::Context,
+    time: f64,
+    width: f32,
+    events: Vec<Event>,
+    max_items: Option<usize>,
+) -> Frame {
+    let theme = Theme::default();
+    let input = egui::RawInput {
+        screen_rect: Some(Rect::from_min_size(Pos2::ZERO, vec2(width, 300.0))),
+        time: Some(time),
+        events,
+        ..Default::default()
+    };
+    let mut result = None;
+    let output = ctx.run(input, |ctx| {
+        egui::CentralPanel::default().show(ctx, |ui| {
+            let response = breadcrumb_path(
+                ui,
+                &theme,
+                BreadcrumbProps::new(),
+                BreadcrumbPathProps {
+                    max_items,
+                    ..BreadcrumbPathProps::new(Id::new("path"), &SEGMENTS)
+                },
+            );
+            result = Some((response.clicked, response.collapsed));
+        });
+    });
+    let (clicked, collapsed) = result.unwrap();
+    let texts = output
+        .shapes
+        .iter()
+        .filter_map(|clipped| match &clipped.shape {
+            Shape::Text(text) => Some((
+                text.galley.text().to_string(),
+                text.galley.rect.translate(text.pos.to_vec2()),
+            )),
+            _ => None,
+        })
+        .collect();
+    Frame {
+        clicked,
+        collapsed,
+        texts,
+    }
+}
+
+fn text_rect(frame: &Frame, label: &str) -> Option<Rect> {
+    frame
+        .texts
+        .iter()
+        .find(|(text, _)| text == label)
+        .map(|(_, rect)| *rect)
+}
+
+fn pointer(pos: Pos2, pressed: bool) -> Vec<Event> {
+    vec![
+        Event::PointerMoved(pos),
+        Event::PointerButton {
+            pos,
+            button: PointerButton::Primary,
+            pressed,
+            modifiers: Modifiers::NONE,
+        },
+    ]
+}
+
+#[test]
+fn segments_collapse_only_when_they_overflow() {
+    let ctx = egui::Context::default();
+    let wide = frame(&ctx, 0.0, 800.0, Vec::new(), None);
+    assert_eq!(wide.collapsed, 0..0);
+    assert!(text_rect(&wide, "Projects").is_some());
+
+    let narrow = frame(&ctx, 0.1, 300.0, Vec::new(), None);
+    assert_eq!(narrow.collapsed.start, 1);
+    assert!(narrow.collapsed.end > 1 && narrow.collapsed.end < SEGMENTS.len());
+    assert!(text_rect(&narrow, "Workspace").is_none());
+    assert!(text_rect(&narrow, "Breadcrumb").is_some());
+
+    let limited = frame(&ctx, 0.2, 800.0, Vec::new(), Some(3));
+    assert_eq!(limited.collapsed, 1..4);
+}
+
+#[test]
+fn collapsed_segments_are_picked_from_the_ellipsis_menu() {
+    let ctx = egui::Context::default();
+    let path = Some(3);
+    let first = frame(&ctx, 0.0, 800.0, Vec::new(), path);
+    // The ellipsis follows "Home", a separator and the spacing around it.
+    let home = text_rect(&first, "Home").unwrap();
+    let ellipsis = Pos2::new(home.right() + 6.0 + 12.0 + 6.0 + 10.0, home.center().y);
+
+    let mut time = 0.0;
+    for pressed in [true, false] {
+        time += 0.1;
+        frame(&ctx, time, 800.0, pointer(ellipsis, pressed), path);
+    }
+    time += 0.1;
+    let open = frame(&ctx, time, 800.0, Vec::new(), path);
+    let projects = text_rect(&open, "Projects").expect("collapsed segment in the menu");
+
+    let mut clicked = None;
+    for pressed in [true, false] {
+        time += 0.1;
+        clicked =
+            clicked.or(frame(&ctx, time, 800.0, pointer(projects.center(), pressed), path).clicked);
+    }
+    assert_eq!(clicked, Some(2));
+}
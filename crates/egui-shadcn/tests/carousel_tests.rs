@@ -0,0 +1,380 @@
+use egui::{Event, Modifiers, MouseWheelUnit, PointerButton, Pos2, Rect, pos2, vec2};
+use egui_shadcn::{
+    CarouselAlign, CarouselContentProps, CarouselItemProps, CarouselOptions, CarouselProps,
+    CarouselThumbnailsProps, Theme, carousel, carousel_content, carousel_dots, carousel_item,
+    carousel_thumbnails,
+};
+use std::time::Duration;
+
+const SLIDES: usize = 5;
+
+struct Frame {
+    index: usize,
+    changed: bool,
+    repaint_delay: Duration,
+    viewport: Rect,
+    slides: Vec<Rect>,
+    dots: Rect,
+    thumbnails: Vec<Rect>,
+    snap_count: usize,
+}
+
+impl Default for Frame {
+    fn default() -> Self {
+        Self {
+            index: 0,
+            changed: false,
+            repaint_delay: Duration::MAX,
+            viewport: Rect::NOTHING,
+            slides: Vec::new(),
+            dots: Rect::NOTHING,
+            thumbnails: Vec::new(),
+            snap_count: 0,
+        }
+    }
+}
+
+fn frame(ctx: &egui::Context, time: f64, events: Vec<Event>, opts: CarouselOptions) -> Frame {
+    let theme = Theme::default();
+    let input = egui::RawInput {
+        screen_rect: Some(Rect::from_min_size(Pos2::ZERO, vec2(400.0, 400.0))),
+        time: Some(time),
+        events,
+        ..Default::default()
+    };
+    let mut frame = Frame::default();
+    let output = ctx.run(input, |ctx| {
+        egui::CentralPanel::default().show(ctx, |ui| {
+            let response = carousel(
+                ui,
+                &theme,
+                CarouselProps::new(egui::Id::new("carousel")).opts(opts),
+                |ui, context| {
+                    let content = carousel_content(
+                        ui,
+                        &theme,
+                        context,
+                        CarouselContentProps::new().size(vec2(300.0, 120.0)),
+                        |ui, context| {
+                            (0..SLIDES)
+                                .map(|index| {
+                                    carousel_item(
+                                        ui,
+                                        context,
+                                        CarouselItemProps::new(index),
+                                        |_| {},
+                                    )
+                                    .response
+                                    .rect
+                                })
+                                .collect()
+                        },
+                    );
+                    frame.viewport = content.response.rect;
+                    frame.slides = content.inner;
+                    frame.snap_count = context.snap_count();
+                    frame.dots = carousel_dots(ui, &theme, context).rect;
+                    let _ = carousel_thumbnails(
+                        ui,
+                        &theme,
+                        context,
+                        CarouselThumbnailsProps::new(),
+                        |ui, _| frame.thumbnails.push(ui.max_rect()),
+                    );
+                },
+            );
+            frame.index = response.index;
+            frame.changed = response.changed;
+        });
+    });
+    frame.repaint_delay = output.viewport_output[&egui::ViewportId::ROOT].repaint_delay;
+    frame
+}
+
+/// Offset of the first slide from the viewport's left edge once the carousel is at rest.
+fn settle(ctx: &egui::Context, time: &mut f64, opts: CarouselOptions) -> (usize, f32) {
+    let mut last = Frame::default();
+    for _ in 0..40 {
+        *time += 0.1;
+        last = frame(ctx, *time, Vec::new(), opts);
+    }
+    (last.index, last.slides[0].left() - last.viewport.left())
+}
+
+fn drag(ctx: &egui::Context, time: &mut f64, opts: CarouselOptions, from: Pos2, steps: &[f32]) {
+    frame(
+        ctx,
+        *time,
+        vec![
+            Event::PointerMoved(from),
+            Event::PointerButton {
+                pos: from,
+                button: PointerButton::Primary,
+                pressed: true,
+                modifiers: Modifiers::NONE,
+            },
+        ],
+        opts,
+    );
+    let mut x = from.x;
+    for step in steps {
+        *time += 0.02;
+        x += step;
+        frame(ctx, *time, vec![Event::PointerMoved(pos2(x, from.y))], opts);
+    }
+    *time += 0.02;
+    frame(
+        ctx,
+        *time,
+        vec![Event::PointerButton {
+            pos: pos2(x, from.y),
+            button: PointerButton::Primary,
+            pressed: false,
+            modifiers: Modifiers::NONE,
+        }],
+        opts,
+    );
+}
+
+fn click(ctx: &egui::Context, time: &mut f64, opts: CarouselOptions, pos: Pos2) {
+    for pressed in [true, false] {
+        *time += 0.1;
+        frame(
+            ctx,
+            *time,
+            vec![
+                Event::PointerMoved(pos),
+                Event::PointerButton {
+                    pos,
+                    button: PointerButton::Primary,
+                    pressed,
+                    modifiers: Modifiers::NONE,
+                },
+            ],
+            opts,
+        );
+    }
+}
+
+#[test]
+fn slides_to_scroll_groups_snap_points() {
+    let ctx = egui::Context::default();
+    let opts = CarouselOptions::default().slides_to_scroll(2);
+    let mut time = 0.0;
+    let (index, _) = settle(&ctx, &mut time, opts);
+    assert_eq!(index, 0);
+
+    let first = frame(&ctx, time, Vec::new(), opts);
+    assert_eq!(first.snap_count, 3);
+
+    // Dots are 8pt wide with a 6pt gap; the selected one is a 20pt pill.
+    for (x, expected) in [(30.0, 2), (44.0, 4)] {
+        let dots = frame(&ctx, time, Vec::new(), opts).dots;
+        click(
+            &ctx,
+            &mut time,
+            opts,
+            pos2(dots.left() + x, dots.center().y),
+        );
+        let (index, offset) = settle(&ctx, &mut time, opts);
+        assert_eq!(index, expected);
+        assert!((offset + expected as f32 * 316.0).abs() < 1.0, "{offset}");
+    }
+}
+
+#[test]
+fn align_positions_the_selected_slide() {
+    // Slides take 80% of the 300pt viewport so the alignment shows.
+    for (align, expected) in [
+        (CarouselAlign::Start, 0.0),
+        (CarouselAlign::Center, 30.0),
+        (CarouselAlign::End, 60.0),
+    ] {
+        let ctx = egui::Context::default();
+        let opts = CarouselOptions::default().align(align);
+        let mut last = Frame::default();
+        for step in 0..3 {
+            last = frame_with_basis(&ctx, step as f64 * 0.1, opts, 0.8);
+        }
+        let offset = last.slides[0].left() - last.viewport.left();
+        assert!((offset - expected).abs() < 1.0, "{align:?}: {offset}");
+    }
+}
+
+fn frame_with_basis(ctx: &egui::Context, time: f64, opts: CarouselOptions, basis: f32) -> Frame {
+    let theme = Theme::default();
+    let input = egui::RawInput {
+        screen_rect: Some(Rect::from_min_size(Pos2::ZERO, vec2(400.0, 400.0))),
+        time: Some(time),
+        ..Default::default()
+    };
+    let mut frame = Frame::default();
+    let _ = ctx.run(input, |ctx| {
+        egui::CentralPanel::default().show(ctx, |ui| {
+            carousel(
+                ui,
+                &theme,
+                CarouselProps::new(egui::Id::new("carousel-basis")).opts(opts),
+                |ui, context| {
+                    let content = carousel_content(
+                        ui,
+                        &theme,
+                        context,
+                        CarouselContentProps::new()
+                            .size(vec2(300.0, 120.0))
+                            .item_basis(basis),
+                        |ui, context| {
+                            carousel_item(ui, context, CarouselItemProps::new(0), |_| {})
+                                .response
+                                .rect
+                        },
+                    );
+                    frame.viewport = content.response.rect;
+                    frame.slides = vec![content.inner];
+                },
+            );
+        });
+    });
+    frame
+}
+
+#[test]
+fn dragging_snaps_to_a_slide_unless_drag_free() {
+    let ctx = egui::Context::default();
+    let opts = CarouselOptions::default();
+    let mut time = 0.0;
+    let viewport = frame(&ctx, time, Vec::new(), opts).viewport;
+    let start = viewport.center();
+
+    // A short drag springs back.
+    drag(&ctx, &mut time, opts, start, &[-10.0, -10.0, -10.0]);
+    time += 1.0;
+    assert_eq!(settle(&ctx, &mut time, opts), (0, 0.0));
+
+    // A long, slow drag moves to the next slide.
+    let slow = [-20.0; 6];
+    drag(&ctx, &mut time, opts, start, &slow);
+    time += 1.0;
+    let (index, offset) = settle(&ctx, &mut time, opts);
+    assert_eq!(index, 1);
+    assert!((offset + 316.0).abs() < 1.0, "{offset}");
+
+    // In drag-free mode a fast flick glides past the pointer and stops between slides.
+    let ctx = egui::Context::default();
+    let opts = CarouselOptions::default().drag_free(true);
+    let mut time = 0.0;
+    frame(&ctx, time, Vec::new(), opts);
+    drag(&ctx, &mut time, opts, start, &[-30.0; 4]);
+    let (_, offset) = settle(&ctx, &mut time, opts);
+    assert!(
+        offset < -121.0,
+        "momentum should carry past the pointer: {offset}"
+    );
+    let snapped = (0..SLIDES).any(|index| (offset + index as f32 * 316.0).abs() < 1.0);
+    assert!(!snapped, "drag-free should not snap: {offset}");
+}
+
+#[test]
+fn drag_free_glide_reports_changes_and_comes_to_rest() {
+    let ctx = egui::Context::default();
+    let opts = CarouselOptions::default().drag_free(true);
+    let mut time = 0.0;
+    let start = frame(&ctx, time, Vec::new(), opts).viewport.center();
+    drag(&ctx, &mut time, opts, start, &[-60.0; 4]);
+
+    let mut changes = Vec::new();
+    let mut last = Frame::default();
+    for _ in 0..40 {
+        time += 0.1;
+        last = frame(&ctx, time, Vec::new(), opts);
+        if last.changed {
+            changes.push(last.index);
+        }
+    }
+    // Snap points passed during the glide are reported as changes.
+    assert!(last.index > 0);
+    assert_eq!(changes.last(), Some(&last.index));
+    assert!(
+        changes.windows(2).all(|pair| pair[0] < pair[1]),
+        "{changes:?}"
+    );
+    assert_eq!(
+        last.repaint_delay,
+        Duration::MAX,
+        "a resting drag-free carousel stops repainting"
+    );
+}
+
+#[test]
+fn horizontal_scroll_moves_and_snaps() {
+    let ctx = egui::Context::default();
+    let opts = CarouselOptions::default();
+    let mut time = 0.0;
+    let viewport = frame(&ctx, time, Vec::new(), opts).viewport;
+    time += 0.1;
+    frame(
+        &ctx,
+        time,
+        vec![
+            Event::PointerMoved(viewport.center()),
+            Event::MouseWheel {
+                unit: MouseWheelUnit::Point,
+                delta: vec2(-250.0, 0.0),
+                modifiers: Modifiers::NONE,
+            },
+        ],
+        opts,
+    );
+    let (index, offset) = settle(&ctx, &mut time, opts);
+    assert_eq!(index, 1);
+    assert!((offset + 316.0).abs() < 1.0, "{offset}");
+}
+
+#[test]
+fn autoplay_pauses_while_hovered() {
+    let ctx = egui::Context::default();
+    let opts = CarouselOptions::default()
+        .autoplay(true)
+        .autoplay_delay_ms(1000.0);
+    let mut time = 0.0;
+    let viewport = frame(&ctx, time, Vec::new(), opts).viewport;
+
+    time += 0.1;
+    frame(
+        &ctx,
+        time,
+        vec![Event::PointerMoved(viewport.center())],
+        opts,
+    );
+    let (index, _) = settle(&ctx, &mut time, opts);
+    assert_eq!(index, 0, "hovering holds autoplay");
+
+    time += 0.1;
+    frame(
+        &ctx,
+        time,
+        vec![Event::PointerMoved(pos2(390.0, 390.0))],
+        opts,
+    );
+    for _ in 0..12 {
+        time += 0.1;
+        frame(&ctx, time, Vec::new(), opts);
+    }
+    assert_eq!(frame(&ctx, time, Vec::new(), opts).index, 1);
+}
+
+#[test]
+fn thumbnails_follow_and_select_slides() {
+    let ctx = egui::Context::default();
+    let opts = CarouselOptions::default();
+    let mut time = 0.0;
+    frame(&ctx, time, Vec::new(), opts);
+    time += 0.1;
+    let thumbnails = frame(&ctx, time, Vec::new(), opts).thumbnails;
+    assert_eq!(thumbnails.len(), SLIDES);
+
+    click(&ctx, &mut time, opts, thumbnails[2].center());
+    let (index, offset) = settle(&ctx, &mut time, opts);
+    assert_eq!(index, 2);
+    assert!((offset + 2.0 * 316.0).abs() < 1.0, "{offset}");
+}
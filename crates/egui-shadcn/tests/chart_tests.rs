@@ -0,0 +1,316 @@
+#![cfg(feature = "plot")]
+
+use egui_plot::PlotPoint;
+use egui_shadcn::{
+    AreaChart, ChartConfig, ChartConfigItem, ChartProps, ChartSeries, ChartSlice, ChartStacking,
+    MultiBarChart, PieChart, RadarChart, RadialBarChart, RingSeries, Theme, TimeAxis, chart,
+    chart_color, format_chart_value, format_time_tick, lttb, stack_series, time_x,
+};
+
+fn series() -> Vec<ChartSeries> {
+    vec![
+        ChartSeries::new("Desktop", vec![1.0, 3.0]),
+        ChartSeries::new("Mobile", vec![3.0, 0.0]),
+    ]
+}
+
+#[test]
+fn stacks_and_normalizes_series() {
+    let series = series();
+    assert_eq!(
+        stack_series(&series, ChartStacking::None),
+        [vec![(0.0, 1.0), (0.0, 3.0)], vec![(0.0, 3.0), (0.0, 0.0)]]
+    );
+    assert_eq!(
+        stack_series(&series, ChartStacking::Stacked),
+        [vec![(0.0, 1.0), (0.0, 3.0)], vec![(1.0, 4.0), (3.0, 3.0)]]
+    );
+    assert_eq!(
+        stack_series(&series, ChartStacking::Percent),
+        [
+            vec![(0.0, 25.0), (0.0, 100.0)],
+            vec![(25.0, 100.0), (100.0, 100.0)]
+        ]
+    );
+}
+
+#[test]
+fn negative_values_stack_below_zero() {
+    let series = vec![
+        ChartSeries::new("Income", vec![4.0, 2.0]),
+        ChartSeries::new("Refunds", vec![-1.0, -2.0]),
+        ChartSeries::new("Fees", vec![-3.0, 4.0]),
+    ];
+    assert_eq!(
+        stack_series(&series, ChartStacking::Stacked),
+        [
+            vec![(0.0, 4.0), (0.0, 2.0)],
+            vec![(-1.0, 0.0), (-2.0, 0.0)],
+            vec![(-4.0, -1.0), (2.0, 6.0)]
+        ]
+    );
+    let percent = stack_series(&series, ChartStacking::Percent);
+    assert_eq!(
+        percent,
+        [
+            vec![(0.0, 50.0), (0.0, 25.0)],
+            vec![(-12.5, 0.0), (-25.0, 0.0)],
+            vec![(-50.0, -12.5), (25.0, 75.0)]
+        ]
+    );
+    for (lower, upper) in percent.iter().flatten() {
+        assert!(lower <= upper);
+        assert!((-100.0..=100.0).contains(lower) && (-100.0..=100.0).contains(upper));
+    }
+}
+
+#[test]
+fn series_default_to_chart_colors() {
+    let theme = Theme::default();
+    let custom = egui::Color32::RED;
+    let mut series = series();
+    series.push(ChartSeries::new("Tablet", vec![2.0, 2.0]).color(custom));
+    let chart = MultiBarChart::new(series).stacking(ChartStacking::Percent);
+
+    let legend = chart.legend_items(&theme);
+    assert_eq!(legend[0].color, theme.palette.chart_1);
+    assert_eq!(legend[1].color, theme.palette.chart_2);
+    assert_eq!(legend[2].color, custom);
+    assert_eq!(chart_color(&theme, 5), theme.palette.chart_1);
+
+    let rows = chart.tooltip_items(&theme, 0);
+    let values: Vec<&str> = rows.iter().map(|row| row.value.as_str()).collect();
+    assert_eq!(values, ["17%", "50%", "33%"]);
+    assert_eq!(format_chart_value(2.5), "2.50");
+}
+
+#[test]
+fn polar_charts_hit_test_plot_points() {
+    let slices = vec![
+        ChartSlice::new("Chrome", 1.0),
+        ChartSlice::new("Safari", 1.0),
+        ChartSlice::new("Firefox", 2.0),
+    ];
+    // Slices start at 12 o'clock and run clockwise.
+    let pie = PieChart::new(slices.clone());
+    assert_eq!(pie.slice_at(PlotPoint::new(0.5, 0.5)), Some(0));
+    assert_eq!(pie.slice_at(PlotPoint::new(0.5, -0.5)), Some(1));
+    assert_eq!(pie.slice_at(PlotPoint::new(-0.5, 0.0)), Some(2));
+    assert_eq!(pie.slice_at(PlotPoint::new(1.0, 1.0)), None);
+
+    let donut = PieChart::new(slices.clone()).donut(0.6);
+    assert_eq!(
+        donut.slice_at(PlotPoint::new(0.1, 0.1)),
+        None,
+        "inside the hole"
+    );
+
+    let radial = RadialBarChart::new(slices).inner_radius(0.4);
+    assert_eq!(radial.slice_at(PlotPoint::new(0.0, 0.5)), Some(0));
+    assert_eq!(radial.slice_at(PlotPoint::new(0.0, -0.9)), Some(2));
+    assert_eq!(radial.slice_at(PlotPoint::new(0.0, 0.1)), None);
+
+    let radar = RadarChart::new(
+        ["A", "B", "C", "D"].map(String::from).to_vec(),
+        vec![ChartSeries::new("S", vec![1.0, 2.0, 3.0, 4.0])],
+    );
+    assert_eq!(radar.axis_at(PlotPoint::new(0.0, 0.8)), Some(0));
+    assert_eq!(radar.axis_at(PlotPoint::new(0.8, 0.1)), Some(1));
+    assert_eq!(radar.axis_at(PlotPoint::new(-0.8, 0.0)), Some(3));
+}
+
+#[test]
+fn renders_every_chart_type() {
+    let ctx = egui::Context::default();
+    let theme = Theme::default();
+    let slices = vec![ChartSlice::new("A", 2.0), ChartSlice::new("B", 1.0)];
+    let axes = ["A", "B", "C"].map(String::from).to_vec();
+    let series = vec![
+        ChartSeries::new("S1", vec![1.0, 2.0, 3.0]),
+        ChartSeries::new("S2", vec![3.0, 1.0, 2.0]),
+    ];
+    let _ = ctx.run(egui::RawInput::default(), |ctx| {
+        egui::CentralPanel::default().show(ctx, |ui| {
+            chart(
+                ui,
+                &theme,
+                ChartProps::new(egui::Id::new("area")),
+                |plot_ui| {
+                    AreaChart::new(series.clone())
+                        .stacking(ChartStacking::Stacked)
+                        .show(plot_ui, &theme);
+                    MultiBarChart::new(series.clone())
+                        .horizontal(true)
+                        .show(plot_ui, &theme);
+                },
+            );
+            chart(
+                ui,
+                &theme,
+                ChartProps::new(egui::Id::new("polar")).polar(),
+                |plot_ui| {
+                    PieChart::new(slices.clone())
+                        .donut(0.5)
+                        .center_label("3", "Total")
+                        .show(plot_ui, &theme);
+                    RadarChart::new(axes.clone(), series.clone()).show(plot_ui, &theme);
+                    RadialBarChart::new(slices.clone()).show(plot_ui, &theme);
+                },
+            );
+            let mut live = RingSeries::new(50);
+            live.extend((0..200).map(|i| [1_700_000_000.0 + i as f64, (i % 7) as f64]));
+            chart(
+                ui,
+                &theme,
+                ChartProps::new(egui::Id::new("live")).time_x_axis(TimeAxis::Utc),
+                |plot_ui| {
+                    live.line(20).show(plot_ui);
+                    assert!(live.follow_latest(plot_ui, 30.0));
+                },
+            );
+        });
+    });
+}
+
+fn config() -> ChartConfig {
+    ChartConfig::new()
+        .item("desktop", ChartConfigItem::new("Desktop"))
+        .item(
+            "mobile",
+            ChartConfigItem::new("Mobile").color(egui::Color32::RED),
+        )
+        .item("tablet", ChartConfigItem::new("Tablet"))
+}
+
+#[test]
+fn config_drives_labels_colors_and_rows() {
+    let theme = Theme::default();
+    let mut config = config();
+    assert_eq!(config.label("mobile"), "Mobile");
+    assert_eq!(config.label("watch"), "watch");
+    assert_eq!(config.color(&theme, "desktop"), theme.palette.chart_1);
+    assert_eq!(config.color(&theme, "mobile"), egui::Color32::RED);
+    assert_eq!(config.color(&theme, "tablet"), theme.palette.chart_3);
+
+    let data = [
+        ("tablet", vec![5.0, 6.0]),
+        ("desktop", vec![1.0, 2.0]),
+        ("mobile", vec![3.0, 4.0]),
+    ];
+    config.toggle("mobile");
+    let labels: Vec<String> = config
+        .visible_series(&theme, data.clone())
+        .into_iter()
+        .map(|series| series.label)
+        .collect();
+    assert_eq!(
+        labels,
+        ["Desktop", "Tablet"],
+        "config order, hidden skipped"
+    );
+    assert!(config.line(&theme, "mobile", Vec::new()).is_none());
+
+    let rows = config.tooltip_items(&theme, 1, data);
+    let rows: Vec<(&str, &str)> = rows
+        .iter()
+        .map(|row| (row.label.as_str(), row.value.as_str()))
+        .collect();
+    assert_eq!(rows, [("Desktop", "2"), ("Tablet", "6")]);
+}
+
+#[test]
+fn clicking_a_legend_entry_toggles_its_series() {
+    let ctx = egui::Context::default();
+    let theme = Theme::default();
+    let mut config = config();
+    let mut time = 0.0;
+    let mut frame = |events: Vec<egui::Event>, config: &mut ChartConfig| {
+        let input = egui::RawInput {
+            screen_rect: Some(egui::Rect::from_min_size(
+                egui::Pos2::ZERO,
+                egui::vec2(400.0, 400.0),
+            )),
+            events,
+            time: Some(time),
+            ..Default::default()
+        };
+        time += 0.5;
+        let mut toggled = None;
+        let _ = ctx.run(input, |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                toggled = config.show_legend(ui, &theme).inner;
+            });
+        });
+        toggled
+    };
+
+    // The legend row is centered in the available height.
+    let pos = egui::pos2(20.0, 200.0);
+    let button = |pressed| egui::Event::PointerButton {
+        pos,
+        button: egui::PointerButton::Primary,
+        pressed,
+        modifiers: egui::Modifiers::default(),
+    };
+    frame(vec![egui::Event::PointerMoved(pos)], &mut config);
+    frame(vec![button(true)], &mut config);
+    assert_eq!(
+        frame(vec![button(false)], &mut config).as_deref(),
+        Some("desktop")
+    );
+    assert!(config.is_hidden("desktop"));
+
+    frame(vec![button(true)], &mut config);
+    frame(vec![button(false)], &mut config);
+    assert!(!config.is_hidden("desktop"));
+}
+
+#[test]
+fn ring_series_evicts_the_oldest_points() {
+    let mut series = RingSeries::new(3);
+    assert_eq!(series.push(0.0, 1.0), None);
+    series.extend([[1.0, 2.0], [2.0, 3.0]]);
+    assert_eq!(series.push(3.0, 4.0), Some([0.0, 1.0]));
+    assert_eq!(series.len(), 3);
+    assert_eq!(series.to_vec(), [[1.0, 2.0], [2.0, 3.0], [3.0, 4.0]]);
+    assert_eq!(series.latest(), Some([3.0, 4.0]));
+
+    let time = chrono::DateTime::from_timestamp(1_700_000_000, 500_000_000).expect("valid");
+    series.push_time(&time, 5.0);
+    assert_eq!(series.latest(), Some([1_700_000_000.5, 5.0]));
+    assert_eq!(time_x(&time), 1_700_000_000.5);
+}
+
+#[test]
+fn lttb_keeps_the_ends_and_the_peaks() {
+    let points: Vec<[f64; 2]> = (0..100)
+        .map(|x| [x as f64, if x == 42 { 50.0 } else { 0.0 }])
+        .collect();
+    let sampled = lttb(&points, 10);
+    assert_eq!(sampled.len(), 10);
+    assert_eq!(sampled.first(), points.first());
+    assert_eq!(sampled.last(), points.last());
+    assert!(sampled.contains(&[42.0, 50.0]), "the spike survives");
+    assert!(sampled.windows(2).all(|pair| pair[0][0] < pair[1][0]));
+
+    assert_eq!(lttb(&points[..5], 10), &points[..5]);
+
+    let mut series = RingSeries::new(1000);
+    series.extend(points);
+    assert_eq!(series.downsampled(20).len(), 20);
+    assert_eq!(series.downsampled(200).len(), 100);
+}
+
+#[test]
+fn time_ticks_follow_the_interval() {
+    // 2023-11-14 22:13:20 UTC
+    let seconds = 1_700_000_000.0;
+    assert_eq!(format_time_tick(seconds, 5.0, TimeAxis::Utc), "22:13:20");
+    assert_eq!(format_time_tick(seconds, 600.0, TimeAxis::Utc), "22:13");
+    assert_eq!(format_time_tick(seconds, 86400.0, TimeAxis::Utc), "Nov 14");
+    assert_eq!(
+        format_time_tick(seconds, 2592000.0, TimeAxis::Utc),
+        "Nov 2023"
+    );
+    assert_eq!(format_time_tick(seconds, 31536000.0, TimeAxis::Utc), "2023");
+}
@@ -26,6 +26,7 @@ fn dialog_defaults_match_radix_content_api() {
         "default max_width should be 600px"
     );
     assert!(!props.as_child, "as_child must be opt-in");
+    assert!(props.trap_focus, "dialogs trap focus by default");
 }
 
 #[test]
@@ -45,3 +46,200 @@ fn dialog_tokens_match_shadcn_reference() {
     assert_eq!(tokens.background, theme.palette.background);
     assert_eq!(tokens.border.color, theme.palette.border);
 }
+
+fn run_dialog_frame(
+    ctx: &egui::Context,
+    events: Vec<egui::Event>,
+    open: &mut bool,
+    behind: &mut String,
+    inside: &mut String,
+) {
+    let theme = Theme::default();
+    let input = egui::RawInput {
+        events,
+        ..Default::default()
+    };
+    let _ = ctx.run(input, |ctx| {
+        egui::CentralPanel::default().show(ctx, |ui| {
+            ui.add(egui::TextEdit::singleline(behind).id(egui::Id::new("behind")));
+            let props = DialogProps::new(egui::Id::new("focus-dialog"), open)
+                .with_animation(false)
+                .scrollable(false);
+            egui_shadcn::dialog(ui, &theme, props, |ui| {
+                ui.add(egui::TextEdit::singleline(inside).id(egui::Id::new("inside")));
+            });
+        });
+    });
+}
+
+fn tab_event() -> egui::Event {
+    egui::Event::Key {
+        key: egui::Key::Tab,
+        physical_key: None,
+        pressed: true,
+        repeat: false,
+        modifiers: egui::Modifiers::default(),
+    }
+}
+
+#[test]
+fn dialog_traps_focus_and_restores_it_on_close() {
+    init_logger();
+    let ctx = egui::Context::default();
+    let mut open = false;
+    let mut behind = String::new();
+    let mut inside = String::new();
+    let behind_id = egui::Id::new("behind");
+
+    ctx.memory_mut(|m| m.request_focus(behind_id));
+    run_dialog_frame(&ctx, Vec::new(), &mut open, &mut behind, &mut inside);
+    assert_eq!(ctx.memory(|m| m.focused()), Some(behind_id));
+
+    open = true;
+    // The first frame is egui's invisible sizing pass for the new area.
+    run_dialog_frame(&ctx, Vec::new(), &mut open, &mut behind, &mut inside);
+    run_dialog_frame(&ctx, Vec::new(), &mut open, &mut behind, &mut inside);
+    assert_eq!(
+        ctx.memory(|m| m.focused()),
+        Some(egui::Id::new("inside")),
+        "first focusable inside the dialog should receive focus on open"
+    );
+
+    for _ in 0..4 {
+        run_dialog_frame(&ctx, vec![tab_event()], &mut open, &mut behind, &mut inside);
+        assert_ne!(
+            ctx.memory(|m| m.focused()),
+            Some(behind_id),
+            "Tab must not reach widgets behind the dialog"
+        );
+    }
+
+    open = false;
+    run_dialog_frame(&ctx, Vec::new(), &mut open, &mut behind, &mut inside);
+    assert_eq!(
+        ctx.memory(|m| m.focused()),
+        Some(behind_id),
+        "focus should return to the previously focused widget"
+    );
+}
+
+#[test]
+fn dialog_auto_focus_can_be_prevented() {
+    init_logger();
+    let ctx = egui::Context::default();
+    let theme = Theme::default();
+    let mut open = true;
+    let mut calls = 0;
+    let mut on_open = |evt: &mut egui_shadcn::DialogAutoFocusEvent| {
+        calls += 1;
+        evt.preventable.prevent_default();
+    };
+    let mut inside = String::new();
+    for _ in 0..2 {
+        let _ = ctx.run(egui::RawInput::default(), |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                let props = DialogProps::new(egui::Id::new("prevent-dialog"), &mut open)
+                    .with_animation(false)
+                    .on_open_auto_focus(&mut on_open);
+                egui_shadcn::dialog(ui, &theme, props, |ui| {
+                    ui.add(egui::TextEdit::singleline(&mut inside).id(egui::Id::new("inside")));
+                });
+            });
+        });
+    }
+    assert_eq!(calls, 1);
+    assert_eq!(ctx.memory(|m| m.focused()), None);
+}
+
+fn run_geometry_frame(ctx: &egui::Context, events: Vec<egui::Event>, open: &mut bool) {
+    let theme = Theme::default();
+    let input = egui::RawInput {
+        screen_rect: Some(egui::Rect::from_min_size(
+            egui::Pos2::ZERO,
+            egui::vec2(800.0, 600.0),
+        )),
+        events,
+        ..Default::default()
+    };
+    let _ = ctx.run(input, |ctx| {
+        egui::CentralPanel::default().show(ctx, |ui| {
+            let props = DialogProps::new(egui::Id::new("drag-dialog"), open)
+                .with_title("Move me")
+                .with_width(400.0)
+                .with_height(200.0)
+                .with_animation(false)
+                .scrollable(false)
+                .draggable(true)
+                .resizable(true);
+            egui_shadcn::dialog(ui, &theme, props, |ui| {
+                ui.label("body");
+            });
+        });
+    });
+}
+
+fn pointer_button(pos: egui::Pos2, pressed: bool) -> egui::Event {
+    egui::Event::PointerButton {
+        pos,
+        button: egui::PointerButton::Primary,
+        pressed,
+        modifiers: egui::Modifiers::default(),
+    }
+}
+
+fn drag_dialog(ctx: &egui::Context, open: &mut bool, from: egui::Pos2, to: egui::Pos2) {
+    run_geometry_frame(ctx, vec![egui::Event::PointerMoved(from)], open);
+    run_geometry_frame(ctx, vec![pointer_button(from, true)], open);
+    run_geometry_frame(ctx, vec![egui::Event::PointerMoved(to)], open);
+    run_geometry_frame(ctx, vec![pointer_button(to, false)], open);
+    run_geometry_frame(ctx, Vec::new(), open);
+}
+
+#[test]
+fn draggable_dialog_moves_with_its_header() {
+    init_logger();
+    let ctx = egui::Context::default();
+    let mut open = true;
+    for _ in 0..2 {
+        run_geometry_frame(&ctx, Vec::new(), &mut open);
+    }
+    // 400x200 content centered on an 800x600 screen starts at (200, 200).
+    drag_dialog(
+        &ctx,
+        &mut open,
+        egui::pos2(300.0, 210.0),
+        egui::pos2(360.0, 260.0),
+    );
+
+    let geometry = egui_shadcn::dialog_geometry(&ctx, egui::Id::new("drag-dialog"));
+    assert_eq!(geometry.offset, egui::vec2(60.0, 50.0));
+    assert!(open, "dragging the header must not dismiss the dialog");
+}
+
+#[test]
+fn resizable_dialog_grows_from_its_edge() {
+    init_logger();
+    let ctx = egui::Context::default();
+    let mut open = true;
+    for _ in 0..2 {
+        run_geometry_frame(&ctx, Vec::new(), &mut open);
+    }
+    let frame_right = 200.0 + 400.0 + 48.0;
+    drag_dialog(
+        &ctx,
+        &mut open,
+        egui::pos2(frame_right, 240.0),
+        egui::pos2(frame_right + 40.0, 240.0),
+    );
+
+    let geometry = egui_shadcn::dialog_geometry(&ctx, egui::Id::new("drag-dialog"));
+    let size = geometry.size.expect("resizing stores a size");
+    assert_eq!(size.x, 440.0);
+    assert!(open, "resizing must not dismiss the dialog");
+
+    egui_shadcn::reset_dialog_geometry(&ctx, egui::Id::new("drag-dialog"));
+    assert_eq!(
+        egui_shadcn::dialog_geometry(&ctx, egui::Id::new("drag-dialog")),
+        egui_shadcn::DialogGeometry::default()
+    );
+}
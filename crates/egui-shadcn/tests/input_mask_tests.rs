@@ -0,0 +1,158 @@
+use egui::{Event, Key, Modifiers, PointerButton, Pos2, Rect, pos2, vec2};
+use egui_shadcn::{
+    InputMask, InputOTPProps, InputProps, MaskToken, Theme, input_otp, remask_edit,
+    text_input_with_props,
+};
+
+fn init_logger() {
+    let _ = env_logger::builder().is_test(true).try_init();
+}
+
+#[test]
+fn parses_pattern_tokens() {
+    let mask = InputMask::new("A9-\\9*");
+    assert_eq!(
+        mask.tokens(),
+        &[
+            MaskToken::UpperLetter,
+            MaskToken::Digit,
+            MaskToken::Literal('-'),
+            MaskToken::Literal('9'),
+            MaskToken::Alphanumeric,
+        ]
+    );
+    assert_eq!(mask.slot_count(), 3);
+}
+
+#[test]
+fn applies_literals_as_slots_fill() {
+    let mask = InputMask::phone_us();
+    assert_eq!(mask.apply(""), "");
+    assert_eq!(mask.apply("5"), "(5");
+    assert_eq!(mask.apply("555"), "(555");
+    assert_eq!(mask.apply("5551"), "(555) 1");
+    assert_eq!(mask.apply("5551234567"), "(555) 123-4567");
+
+    let value = mask.value("555.123.45");
+    assert_eq!(value.raw, "55512345");
+    assert_eq!(value.masked, "(555) 123-45");
+    assert!(!value.complete);
+    assert!(mask.is_complete("(555) 123-4567"));
+}
+
+#[test]
+fn rejects_and_transforms_characters() {
+    let mask = InputMask::new("AAAA-9999");
+    assert_eq!(mask.value("ab1cd-12x34").masked, "ABCD-1234");
+
+    let iban = InputMask::iban();
+    assert_eq!(
+        iban.value("de89370400440532013000").masked,
+        "DE89 3704 0044 0532 0130 00"
+    );
+}
+
+#[test]
+fn caret_follows_inserted_literals() {
+    let mask = InputMask::phone_us();
+    // Typing the fourth digit after "(555" inserts ") " before it.
+    let (masked, cursor) = remask_edit(&mask, "(555", 4, "(5551", 5);
+    assert_eq!(masked, "(555) 1");
+    assert_eq!(cursor, 7);
+
+    // Inserting in the middle shifts later digits; the caret skips the separator that now
+    // follows the typed digit.
+    let (masked, cursor) = remask_edit(&mask, "(555) 123", 3, "(5595) 123", 4);
+    assert_eq!(masked, "(559) 512-3");
+    assert_eq!(cursor, 6);
+}
+
+#[test]
+fn deleting_a_literal_removes_the_adjacent_character() {
+    let mask = InputMask::phone_us();
+    // Backspace right after ") " removes the digit before the separator.
+    let (masked, cursor) = remask_edit(&mask, "(555) 123", 6, "(555)123", 5);
+    assert_eq!(masked, "(551) 23");
+    assert_eq!(cursor, 3);
+
+    // Delete before ")" removes the digit after the separator.
+    let (masked, cursor) = remask_edit(&mask, "(555) 123", 4, "(555 123", 4);
+    assert_eq!(masked, "(555) 23");
+    assert_eq!(cursor, 6);
+}
+
+#[test]
+fn masked_input_formats_typed_and_pasted_text() {
+    init_logger();
+    let ctx = egui::Context::default();
+    let theme = Theme::default();
+    let mask = InputMask::phone_us();
+    let mut value = String::new();
+    let mut time = 0.0;
+
+    let mut frame = |events: Vec<Event>, value: &mut String| {
+        let input = egui::RawInput {
+            screen_rect: Some(Rect::from_min_size(Pos2::ZERO, vec2(400.0, 200.0))),
+            events,
+            time: Some(time),
+            ..Default::default()
+        };
+        time += 0.5;
+        let _ = ctx.run(input, |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                text_input_with_props(ui, &theme, InputProps::new("phone", value).mask(&mask));
+            });
+        });
+    };
+
+    let pos = pos2(60.0, 24.0);
+    let button = |pressed| Event::PointerButton {
+        pos,
+        button: PointerButton::Primary,
+        pressed,
+        modifiers: Modifiers::default(),
+    };
+    frame(vec![Event::PointerMoved(pos)], &mut value);
+    frame(vec![button(true)], &mut value);
+    frame(vec![button(false)], &mut value);
+
+    for digit in ["5", "5", "5", "1"] {
+        frame(vec![Event::Text(digit.into())], &mut value);
+    }
+    assert_eq!(value, "(555) 1");
+
+    frame(vec![Event::Paste("23-45x67".into())], &mut value);
+    assert_eq!(value, "(555) 123-4567");
+
+    frame(
+        vec![Event::Key {
+            key: Key::Backspace,
+            physical_key: None,
+            pressed: true,
+            repeat: false,
+            modifiers: Modifiers::NONE,
+        }],
+        &mut value,
+    );
+    assert_eq!(value, "(555) 123-456");
+    assert_eq!(mask.unmask(&value), "555123456");
+}
+
+#[test]
+fn input_otp_uses_mask_slots() {
+    init_logger();
+    let ctx = egui::Context::default();
+    let theme = Theme::default();
+    let mask = InputMask::new("AA-99");
+    let mut value = "x1y23".to_string();
+
+    let _ = ctx.run(egui::RawInput::default(), |ctx| {
+        egui::CentralPanel::default().show(ctx, |ui| {
+            let props = InputOTPProps::new(6).mask(&mask);
+            assert_eq!(props.max_length, 4);
+            input_otp(ui, &theme, &mut value, props, |_, _| {});
+        });
+    });
+
+    assert_eq!(value, "XY23");
+}
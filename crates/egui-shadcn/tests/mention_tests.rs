@@ -0,0 +1,169 @@
+use egui::{Event, Key, Modifiers, PointerButton, Pos2, Rect, pos2, vec2};
+use egui_shadcn::{
+    Mention, MentionItem, MentionQuery, MentionTrigger, TextareaMentions, TextareaProps, Theme,
+    insert_mention, mention_query, sync_mentions, textarea_with_props,
+};
+
+fn init_logger() {
+    let _ = env_logger::builder().is_test(true).try_init();
+}
+
+fn key(key: Key) -> Event {
+    Event::Key {
+        key,
+        physical_key: None,
+        pressed: true,
+        repeat: false,
+        modifiers: Modifiers::NONE,
+    }
+}
+
+fn people() -> Vec<MentionItem> {
+    vec![
+        MentionItem::new("u1", "alice"),
+        MentionItem::new("u2", "bob").keywords(&["robert"]),
+    ]
+}
+
+fn mention(id: &str, label: &str, start: usize) -> Mention {
+    Mention {
+        trigger: '@',
+        id: id.to_string(),
+        label: label.to_string(),
+        range: start..start + label.len() + 1,
+    }
+}
+
+#[test]
+fn finds_the_trigger_before_the_caret() {
+    let items = people();
+    let triggers = [
+        MentionTrigger::new('@', &items),
+        MentionTrigger::new('/', &items).line_start(true),
+    ];
+
+    assert_eq!(
+        mention_query("hi @al", 6, &triggers),
+        Some(MentionQuery {
+            trigger: '@',
+            range: 3..6,
+            query: "al".to_string(),
+        })
+    );
+    assert_eq!(
+        mention_query("hi @", 4, &triggers).map(|q| q.query),
+        Some(String::new())
+    );
+    assert_eq!(
+        mention_query("mail@al", 7, &triggers),
+        None,
+        "must start a word"
+    );
+    assert_eq!(
+        mention_query("@al ice", 7, &triggers),
+        None,
+        "no whitespace in the query"
+    );
+    assert_eq!(
+        mention_query("note\n/todo", 10, &triggers).map(|q| q.trigger),
+        Some('/')
+    );
+    assert_eq!(
+        mention_query("a /todo", 7, &triggers),
+        None,
+        "slash only at line start"
+    );
+}
+
+#[test]
+fn sync_shifts_and_drops_mentions() {
+    let mut mentions = vec![mention("u1", "alice", 3), mention("u2", "bob", 14)];
+    let previous = "hi @alice and @bob";
+
+    sync_mentions(previous, ">> hi @alice and @bob", &mut mentions);
+    assert_eq!(mentions[0].range, 6..12);
+    assert_eq!(mentions[1].range, 17..21);
+
+    sync_mentions(
+        ">> hi @alice and @bob",
+        ">> hi @alce and @bob",
+        &mut mentions,
+    );
+    assert_eq!(mentions.len(), 1, "editing inside a mention drops it");
+    assert_eq!(mentions[0].id, "u2");
+    assert_eq!(mentions[0].range, 16..20);
+}
+
+#[test]
+fn insert_replaces_the_query() {
+    let items = people();
+    let triggers = [MentionTrigger::new('@', &items)];
+    let mut text = "hi @al".to_string();
+    let mut mentions = Vec::new();
+    let query = mention_query(&text, text.len(), &triggers).expect("query");
+
+    let cursor = insert_mention(&mut text, &query, &items[0], &mut mentions);
+    assert_eq!(text, "hi @alice ");
+    assert_eq!(cursor, text.len());
+    assert_eq!(mentions, [mention("u1", "alice", 3)]);
+}
+
+#[test]
+fn textarea_inserts_the_selected_suggestion() {
+    init_logger();
+    let ctx = egui::Context::default();
+    let theme = Theme::default();
+    let items = people();
+    let mut text = String::new();
+    let mut mentions = Vec::new();
+    let mut time = 0.0;
+
+    let mut frame = |events: Vec<Event>, text: &mut String, mentions: &mut Vec<Mention>| {
+        let input = egui::RawInput {
+            screen_rect: Some(Rect::from_min_size(Pos2::ZERO, vec2(500.0, 400.0))),
+            events,
+            time: Some(time),
+            ..Default::default()
+        };
+        time += 0.5;
+        let _ = ctx.run(input, |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                textarea_with_props(
+                    ui,
+                    &theme,
+                    TextareaProps::new("message", text).width(300.0).mentions(
+                        TextareaMentions::new(mentions).trigger(MentionTrigger::new('@', &items)),
+                    ),
+                );
+            });
+        });
+    };
+
+    let pos = pos2(60.0, 24.0);
+    let button = |pressed| Event::PointerButton {
+        pos,
+        button: PointerButton::Primary,
+        pressed,
+        modifiers: Modifiers::default(),
+    };
+    frame(vec![Event::PointerMoved(pos)], &mut text, &mut mentions);
+    frame(vec![button(true)], &mut text, &mut mentions);
+    frame(vec![button(false)], &mut text, &mut mentions);
+
+    frame(vec![Event::Text("hey @".into())], &mut text, &mut mentions);
+    frame(Vec::new(), &mut text, &mut mentions);
+    frame(vec![key(Key::ArrowDown)], &mut text, &mut mentions);
+    frame(vec![key(Key::Enter)], &mut text, &mut mentions);
+    assert_eq!(text, "hey @bob ");
+    assert_eq!(mentions, [mention("u2", "bob", 4)]);
+
+    frame(
+        vec![Event::Text("and @rob".into())],
+        &mut text,
+        &mut mentions,
+    );
+    frame(vec![key(Key::Escape)], &mut text, &mut mentions);
+    frame(vec![key(Key::Enter)], &mut text, &mut mentions);
+    assert_eq!(text, "hey @bob and @rob\n", "Escape closes the list");
+    assert_eq!(mentions.len(), 1);
+}
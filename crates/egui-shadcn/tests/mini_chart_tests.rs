@@ -0,0 +1,69 @@
+use egui::{Event, Id, Pos2, Rect, pos2, vec2};
+use egui_shadcn::{MiniChartProps, MiniChartResponse, Theme, mini_area, mini_bar, sparkline};
+
+type Widget = fn(&mut egui::Ui, &Theme, MiniChartProps<'_>) -> MiniChartResponse;
+
+/// Renders `widget` at the top left of the panel and returns its response after hovering `pos`.
+fn hover(widget: Widget, values: &[f64], pos: Option<Pos2>) -> MiniChartResponse {
+    let ctx = egui::Context::default();
+    let theme = Theme::default();
+    let mut result = None;
+    for time in [0.0, 0.5] {
+        let input = egui::RawInput {
+            screen_rect: Some(Rect::from_min_size(Pos2::ZERO, vec2(400.0, 400.0))),
+            events: pos.map(Event::PointerMoved).into_iter().collect(),
+            time: Some(time),
+            ..Default::default()
+        };
+        let _ = ctx.run(input, |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                result = Some(widget(
+                    ui,
+                    &theme,
+                    MiniChartProps::new(Id::new("mini"), values).size(vec2(100.0, 40.0)),
+                ));
+            });
+        });
+    }
+    result.expect("rendered")
+}
+
+#[test]
+fn allocates_the_requested_size() {
+    let values = [1.0, 3.0, 2.0];
+    for widget in [sparkline as Widget, mini_bar, mini_area] {
+        let response = hover(widget, &values, None);
+        assert_eq!(response.response.rect.size(), vec2(100.0, 40.0));
+        assert_eq!(response.hovered_index, None);
+    }
+}
+
+#[test]
+fn hovering_picks_the_nearest_value() {
+    let values = [1.0, 3.0, 2.0, 5.0];
+    // The panel's content starts at (8, 8); the widget is 100 wide.
+    let left = |x: f32| Some(pos2(8.0 + x, 20.0));
+
+    assert_eq!(hover(sparkline, &values, left(2.0)).hovered_index, Some(0));
+    assert_eq!(hover(sparkline, &values, left(60.0)).hovered_index, Some(2));
+    assert_eq!(hover(mini_area, &values, left(98.0)).hovered_index, Some(3));
+
+    // Bars split the width into equal slots.
+    assert_eq!(hover(mini_bar, &values, left(24.0)).hovered_index, Some(0));
+    assert_eq!(hover(mini_bar, &values, left(26.0)).hovered_index, Some(1));
+    assert_eq!(hover(mini_bar, &values, left(140.0)).hovered_index, None);
+}
+
+#[test]
+fn empty_and_flat_series_render() {
+    for widget in [sparkline as Widget, mini_bar, mini_area] {
+        assert_eq!(
+            hover(widget, &[], Some(pos2(50.0, 20.0))).hovered_index,
+            None
+        );
+        assert_eq!(
+            hover(widget, &[2.0, 2.0], Some(pos2(10.0, 20.0))).hovered_index,
+            Some(0)
+        );
+    }
+}
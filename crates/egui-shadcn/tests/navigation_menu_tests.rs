@@ -0,0 +1,194 @@
+use egui::{Event, Id, Key, Modifiers, Pos2, Rect, Ui, vec2};
+use egui_shadcn::{
+    NavigationMenuContentProps, NavigationMenuLinkProps, NavigationMenuProps, Theme,
+    navigation_menu, navigation_menu_content, navigation_menu_item, navigation_menu_link,
+    navigation_menu_list, navigation_menu_trigger,
+};
+use std::cell::Cell;
+
+/// What one frame of the test menu reported.
+#[derive(Clone, Copy, Default)]
+struct Frame {
+    open: Option<&'static str>,
+    triggers: [Option<(Id, Rect)>; 2],
+    docs: Option<Id>,
+    first_link: Option<Id>,
+    viewport: Option<Rect>,
+}
+
+fn link(ui: &mut Ui, theme: &Theme) -> Id {
+    navigation_menu_link(
+        ui,
+        theme,
+        NavigationMenuLinkProps::new()
+            .min_width(200.0)
+            .min_height(40.0),
+        |ui, _| ui.label("Link"),
+    )
+    .response
+    .id
+}
+
+/// Runs one frame of a menu with items "a" (one link) and "b" (three links) followed by a
+/// top-level link.
+fn frame(ctx: &egui::Context, time: f64, events: Vec<Event>, props: NavigationMenuProps) -> Frame {
+    let theme = Theme::default();
+    let input = egui::RawInput {
+        screen_rect: Some(Rect::from_min_size(Pos2::ZERO, vec2(800.0, 600.0))),
+        time: Some(time),
+        events,
+        ..Default::default()
+    };
+    let result = Cell::new(Frame::default());
+    let _ = ctx.run(input, |ctx| {
+        egui::CentralPanel::default().show(ctx, |ui| {
+            navigation_menu(ui, &theme, props, |ui, nav_ctx| {
+                navigation_menu_list(ui, nav_ctx, |ui, nav_ctx| {
+                    for (index, (name, links)) in [("a", 1), ("b", 3)].into_iter().enumerate() {
+                        navigation_menu_item(ui, nav_ctx, name, |ui, item_ctx| {
+                            let trigger =
+                                navigation_menu_trigger(ui, &theme, nav_ctx, item_ctx, name);
+                            let mut report = result.get();
+                            report.triggers[index] = Some((trigger.id, trigger.rect));
+                            let _ = navigation_menu_content(
+                                ui,
+                                &theme,
+                                nav_ctx,
+                                item_ctx,
+                                NavigationMenuContentProps::new(),
+                                |content_ui| {
+                                    report.open = Some(name);
+                                    report.viewport = Some(content_ui.clip_rect());
+                                    report.first_link = Some(link(content_ui, &theme));
+                                    for _ in 1..links {
+                                        link(content_ui, &theme);
+                                    }
+                                },
+                            );
+                            result.set(report);
+                        });
+                    }
+                    let mut report = result.get();
+                    report.docs = Some(link(ui, &theme));
+                    result.set(report);
+                });
+            });
+        });
+    });
+    result.get()
+}
+
+fn props() -> NavigationMenuProps {
+    NavigationMenuProps::new(Id::new("nav"))
+}
+
+fn key(key: Key) -> Vec<Event> {
+    vec![Event::Key {
+        key,
+        physical_key: None,
+        pressed: true,
+        repeat: false,
+        modifiers: Modifiers::NONE,
+    }]
+}
+
+fn hover(pos: Pos2) -> Vec<Event> {
+    vec![Event::PointerMoved(pos)]
+}
+
+fn focused(ctx: &egui::Context) -> Option<Id> {
+    ctx.memory(|m| m.focused())
+}
+
+#[test]
+fn hover_opens_after_the_delay_and_skips_it_right_after_closing() {
+    let ctx = egui::Context::default();
+    let layout = frame(&ctx, 0.0, Vec::new(), props());
+    let a = layout.triggers[0].unwrap().1.center();
+    let b = layout.triggers[1].unwrap().1.center();
+
+    assert_eq!(frame(&ctx, 0.1, hover(a), props()).open, None);
+    assert_eq!(frame(&ctx, 0.2, hover(a), props()).open, None);
+    frame(&ctx, 0.35, hover(a), props());
+    assert_eq!(frame(&ctx, 0.4, hover(a), props()).open, Some("a"));
+
+    // Leaving closes the content; another trigger hovered within the skip delay opens at once.
+    frame(&ctx, 0.5, hover(Pos2::new(700.0, 500.0)), props());
+    assert_eq!(frame(&ctx, 0.55, hover(b), props()).open, None);
+    assert_eq!(frame(&ctx, 0.6, hover(b), props()).open, Some("b"));
+
+    // Once the skip delay has passed the delay applies again.
+    frame(&ctx, 0.7, hover(Pos2::new(700.0, 500.0)), props());
+    frame(&ctx, 1.5, hover(Pos2::new(700.0, 500.0)), props());
+    frame(&ctx, 1.6, hover(a), props());
+    assert_eq!(frame(&ctx, 1.65, hover(a), props()).open, None);
+}
+
+#[test]
+fn arrow_keys_move_between_triggers_and_into_content() {
+    let ctx = egui::Context::default();
+    let layout = frame(&ctx, 0.0, Vec::new(), props());
+    let trigger_a = layout.triggers[0].unwrap().0;
+    let trigger_b = layout.triggers[1].unwrap().0;
+    let docs = layout.docs.unwrap();
+    ctx.memory_mut(|m| m.request_focus(trigger_a));
+
+    let time = Cell::new(0.0);
+    let tick = || {
+        time.set(time.get() + 0.1);
+        time.get()
+    };
+    let press = |k: Key| frame(&ctx, tick(), key(k), props());
+
+    assert_eq!(press(Key::ArrowRight).open, None);
+    assert_eq!(focused(&ctx), Some(trigger_b));
+    press(Key::ArrowRight);
+    assert_eq!(focused(&ctx), Some(docs));
+    press(Key::ArrowRight);
+    assert_eq!(focused(&ctx), Some(trigger_a));
+    press(Key::End);
+    assert_eq!(focused(&ctx), Some(docs));
+    press(Key::Home);
+    assert_eq!(focused(&ctx), Some(trigger_a));
+
+    // ArrowDown opens the content and focuses its first link on the next frame.
+    press(Key::ArrowDown);
+    let open = frame(&ctx, tick(), Vec::new(), props());
+    assert_eq!(open.open, Some("a"));
+    assert_eq!(focused(&ctx), open.first_link);
+
+    // Escape closes it and returns focus to the trigger.
+    press(Key::Escape);
+    assert_eq!(frame(&ctx, tick(), Vec::new(), props()).open, None);
+    assert_eq!(focused(&ctx), Some(trigger_a));
+}
+
+#[test]
+fn viewport_resizes_smoothly_between_contents() {
+    let ctx = egui::Context::default();
+    let props = props().delay_ms(0);
+    let layout = frame(&ctx, 0.0, Vec::new(), props);
+    let a = layout.triggers[0].unwrap().1.center();
+    let b = layout.triggers[1].unwrap().1.center();
+
+    let mut time = 0.0;
+    let mut run = |pos: Pos2, frames: usize| {
+        let mut last = Frame::default();
+        for _ in 0..frames {
+            time += 1.0 / 60.0;
+            last = frame(&ctx, time, hover(pos), props);
+        }
+        last
+    };
+
+    let small = run(a, 60).viewport.unwrap().height();
+    // The first frame of "b" measures it; the viewport then eases towards its height.
+    run(b, 2);
+    let between = run(b, 1).viewport.unwrap().height();
+    let large = run(b, 60).viewport.unwrap().height();
+    assert!(large > small + 40.0, "{small} -> {large}");
+    assert!(
+        between > small && between < large,
+        "{small} < {between} < {large}"
+    );
+}
@@ -0,0 +1,202 @@
+use egui::{Event, Key, Modifiers, PointerButton, Pos2, Rect, pos2, vec2};
+use egui_shadcn::{
+    NumberFormat, NumberInputProps, Theme, format_number, number_input, parse_number,
+};
+
+fn init_logger() {
+    let _ = env_logger::builder().is_test(true).try_init();
+}
+
+fn key(key: Key, modifiers: Modifiers) -> Event {
+    Event::Key {
+        key,
+        physical_key: None,
+        pressed: true,
+        repeat: false,
+        modifiers,
+    }
+}
+
+fn click(pos: Pos2) -> Vec<Vec<Event>> {
+    let button = |pressed| Event::PointerButton {
+        pos,
+        button: PointerButton::Primary,
+        pressed,
+        modifiers: Modifiers::default(),
+    };
+    vec![
+        vec![Event::PointerMoved(pos)],
+        vec![button(true)],
+        vec![button(false)],
+    ]
+}
+
+type Props<'v, T> = NumberInputProps<'v, &'static str, T>;
+
+struct Harness {
+    ctx: egui::Context,
+    theme: Theme,
+    invalid: bool,
+    time: f64,
+}
+
+impl Harness {
+    fn new() -> Self {
+        Self {
+            ctx: egui::Context::default(),
+            theme: Theme::default(),
+            invalid: false,
+            time: 0.0,
+        }
+    }
+
+    fn frame<T: egui_shadcn::NumberValue>(
+        &mut self,
+        value: &mut T,
+        events: Vec<Event>,
+        configure: for<'v> fn(Props<'v, T>) -> Props<'v, T>,
+    ) {
+        let input = egui::RawInput {
+            screen_rect: Some(Rect::from_min_size(Pos2::ZERO, vec2(400.0, 300.0))),
+            events,
+            time: Some(self.time),
+            ..Default::default()
+        };
+        let theme = &self.theme;
+        let mut invalid = false;
+        let _ = self.ctx.run(input, |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                invalid =
+                    number_input(ui, theme, configure(NumberInputProps::new("qty", value))).invalid;
+            });
+        });
+        self.invalid = invalid;
+        // Far enough apart that separate clicks never count as a double-click.
+        self.time += 0.5;
+    }
+
+    fn invalid(&self) -> bool {
+        self.invalid
+    }
+}
+
+#[test]
+fn formats_with_separators_decimals_and_units() {
+    let format = NumberFormat::locale("en-US").decimals(2).currency("$");
+    assert_eq!(format_number(1234567.891, &format), "$1,234,567.89");
+    assert_eq!(format_number(-1234.5, &format), "-$1,234.50");
+
+    let format = NumberFormat::locale("de-DE").decimals(1).suffix(" kg");
+    assert_eq!(format_number(98765.43, &format), "98.765,4 kg");
+
+    let format = NumberFormat::new().percent();
+    assert_eq!(format_number(12.5, &format), "12.5%");
+    assert_eq!(format_number(0.1 + 0.2, &NumberFormat::new()), "0.3");
+}
+
+#[test]
+fn parses_formatted_and_plain_text() {
+    let format = NumberFormat::locale("de-DE").currency("€");
+    assert_eq!(parse_number("€1.234,5", &format), Some(1234.5));
+    assert_eq!(parse_number("1234,5", &format), Some(1234.5));
+    assert_eq!(parse_number("-7", &format), Some(-7.0));
+    assert_eq!(parse_number("12a", &format), None);
+    assert_eq!(parse_number("", &format), None);
+
+    let format = NumberFormat::new().percent();
+    assert_eq!(parse_number("45%", &format), Some(45.0));
+}
+
+#[test]
+fn arrow_keys_step_within_range() {
+    init_logger();
+    let mut harness = Harness::new();
+    let mut value = 8_i32;
+    fn configure(props: Props<'_, i32>) -> Props<'_, i32> {
+        props.range(0.0, 10.0).step(1.0)
+    }
+
+    harness.frame(&mut value, Vec::new(), configure);
+    for events in click(pos2(60.0, 24.0)) {
+        harness.frame(&mut value, events, configure);
+    }
+    harness.frame(
+        &mut value,
+        vec![key(Key::ArrowUp, Modifiers::NONE)],
+        configure,
+    );
+    assert_eq!(value, 9);
+
+    harness.frame(
+        &mut value,
+        vec![key(Key::ArrowUp, Modifiers::SHIFT)],
+        configure,
+    );
+    assert_eq!(value, 10, "stepping clamps to max");
+
+    harness.frame(
+        &mut value,
+        vec![key(Key::ArrowDown, Modifiers::NONE)],
+        configure,
+    );
+    assert_eq!(value, 9);
+}
+
+#[test]
+fn blur_parses_and_clamps_typed_text() {
+    init_logger();
+    let mut harness = Harness::new();
+    let mut value = 1.0_f64;
+    fn configure(props: Props<'_, f64>) -> Props<'_, f64> {
+        props.max(50.0)
+    }
+
+    harness.frame(&mut value, Vec::new(), configure);
+    for events in click(pos2(150.0, 24.0)) {
+        harness.frame(&mut value, events, configure);
+    }
+    harness.frame(&mut value, vec![Event::Text("00".into())], configure);
+    assert_eq!(value, 1.0, "typing does not commit before blur");
+
+    for events in click(pos2(300.0, 250.0)) {
+        harness.frame(&mut value, events, configure);
+    }
+    assert_eq!(value, 50.0);
+    assert!(!harness.invalid());
+}
+
+#[test]
+fn unparsable_or_out_of_range_text_is_flagged() {
+    init_logger();
+    let mut harness = Harness::new();
+    let mut value = 4.0_f64;
+    fn configure(props: Props<'_, f64>) -> Props<'_, f64> {
+        props.range(0.0, 10.0).clamp(false)
+    }
+
+    harness.frame(&mut value, Vec::new(), configure);
+    for events in click(pos2(150.0, 24.0)) {
+        harness.frame(&mut value, events, configure);
+    }
+    harness.frame(&mut value, vec![Event::Text("x".into())], configure);
+    harness.frame(
+        &mut value,
+        vec![key(Key::Enter, Modifiers::NONE)],
+        configure,
+    );
+    assert_eq!(value, 4.0);
+    assert!(harness.invalid());
+
+    for events in click(pos2(150.0, 24.0)) {
+        harness.frame(&mut value, events, configure);
+    }
+    // Refocusing replaces the rejected text with the current value.
+    harness.frame(&mut value, vec![Event::Text("2".into())], configure);
+    harness.frame(
+        &mut value,
+        vec![key(Key::Enter, Modifiers::NONE)],
+        configure,
+    );
+    assert_eq!(value, 42.0, "without clamping the value is kept");
+    assert!(harness.invalid());
+}
@@ -0,0 +1,277 @@
+use egui::{Id, LayerId, Order};
+use egui_shadcn::{
+    DialogProps, OverlayKind, OverlayLayer, Theme, is_topmost_overlay,
+    overlay_accepts_outside_click, overlay_stack, register_overlay, topmost_overlay,
+};
+
+fn init_logger() {
+    let _ = env_logger::builder().is_test(true).try_init();
+}
+
+fn layer(name: &str, kind: OverlayKind) -> OverlayLayer {
+    let id = Id::new(name);
+    OverlayLayer::new(id, kind, LayerId::new(Order::Foreground, id))
+}
+
+fn run_frame(ctx: &egui::Context, events: Vec<egui::Event>, add: impl FnMut(&egui::Context)) {
+    let input = egui::RawInput {
+        events,
+        ..Default::default()
+    };
+    let _ = ctx.run(input, add);
+}
+
+fn escape_event() -> egui::Event {
+    egui::Event::Key {
+        key: egui::Key::Escape,
+        physical_key: None,
+        pressed: true,
+        repeat: false,
+        modifiers: egui::Modifiers::default(),
+    }
+}
+
+#[test]
+fn overlays_stack_in_open_order() {
+    init_logger();
+    let ctx = egui::Context::default();
+    let outer = layer("outer", OverlayKind::Dialog);
+    let inner = layer("inner", OverlayKind::Popover);
+
+    run_frame(&ctx, Vec::new(), |ctx| register_overlay(ctx, outer));
+    run_frame(&ctx, Vec::new(), |ctx| {
+        register_overlay(ctx, outer);
+        register_overlay(ctx, inner);
+    });
+    // The inner overlay keeps its place even if it registers first.
+    run_frame(&ctx, Vec::new(), |ctx| {
+        register_overlay(ctx, inner);
+        register_overlay(ctx, outer);
+    });
+    run_frame(&ctx, Vec::new(), |ctx| {
+        let ids: Vec<Id> = overlay_stack(ctx).iter().map(|l| l.id).collect();
+        assert_eq!(ids, vec![outer.id, inner.id]);
+        assert_eq!(topmost_overlay(ctx).map(|l| l.id), Some(inner.id));
+        assert!(is_topmost_overlay(ctx, inner.id));
+        assert!(!is_topmost_overlay(ctx, outer.id));
+        assert!(
+            is_topmost_overlay(ctx, Id::new("unregistered")),
+            "overlays opened this frame are treated as topmost"
+        );
+    });
+    run_frame(&ctx, Vec::new(), |ctx| {
+        assert!(
+            overlay_stack(ctx).is_empty(),
+            "overlays that stop registering leave the stack"
+        );
+    });
+}
+
+#[test]
+fn transient_overlays_do_not_block_outside_click() {
+    init_logger();
+    let ctx = egui::Context::default();
+    let popover = layer("popover", OverlayKind::Popover);
+    let tooltip = layer("tooltip", OverlayKind::Tooltip);
+
+    run_frame(&ctx, Vec::new(), |ctx| {
+        register_overlay(ctx, popover);
+        register_overlay(ctx, tooltip);
+    });
+    run_frame(&ctx, Vec::new(), |ctx| {
+        assert!(overlay_accepts_outside_click(ctx, popover.id));
+        assert!(overlay_accepts_outside_click(ctx, tooltip.id));
+        assert!(!is_topmost_overlay(ctx, popover.id));
+    });
+}
+
+#[test]
+fn dialog_backdrop_locks_scrolling_underneath() {
+    init_logger();
+    let ctx = egui::Context::default();
+    let theme = Theme::default();
+
+    // Returns the scroll offset of the page behind the dialog.
+    let frame = |ctx: &egui::Context, events: Vec<egui::Event>, open: &mut bool| {
+        let input = egui::RawInput {
+            screen_rect: Some(egui::Rect::from_min_size(
+                egui::Pos2::ZERO,
+                egui::vec2(800.0, 600.0),
+            )),
+            events,
+            ..Default::default()
+        };
+        let mut offset = 0.0;
+        let _ = ctx.run(input, |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                offset = egui::ScrollArea::vertical()
+                    .show(ui, |ui| {
+                        for row in 0..100 {
+                            ui.label(format!("Row {row}"));
+                        }
+                    })
+                    .state
+                    .offset
+                    .y;
+                let props = DialogProps::new(Id::new("locking-dialog"), open)
+                    .with_animation(false)
+                    .scrollable(false);
+                egui_shadcn::dialog(ui, &theme, props, |ui| {
+                    ui.label("dialog");
+                });
+            });
+        });
+        offset
+    };
+    let wheel = || {
+        vec![
+            egui::Event::PointerMoved(egui::pos2(20.0, 300.0)),
+            egui::Event::MouseWheel {
+                unit: egui::MouseWheelUnit::Point,
+                delta: egui::vec2(0.0, -200.0),
+                modifiers: egui::Modifiers::NONE,
+            },
+        ]
+    };
+    let settle = |open: &mut bool| {
+        let mut offset = 0.0;
+        for _ in 0..20 {
+            offset = frame(&ctx, Vec::new(), open);
+        }
+        offset
+    };
+
+    let mut open = true;
+    frame(&ctx, Vec::new(), &mut open);
+    frame(&ctx, wheel(), &mut open);
+    assert_eq!(settle(&mut open), 0.0, "the backdrop swallows the wheel");
+
+    let mut open = false;
+    frame(&ctx, wheel(), &mut open);
+    assert!(
+        settle(&mut open) > 0.0,
+        "the page scrolls once the dialog closes"
+    );
+}
+
+#[test]
+fn escape_closes_only_the_nested_dialog() {
+    init_logger();
+    let ctx = egui::Context::default();
+    let theme = Theme::default();
+    let mut outer_open = true;
+    let mut inner_open = false;
+
+    let frame = |ctx: &egui::Context, outer_open: &mut bool, inner_open: &mut bool| {
+        egui::CentralPanel::default().show(ctx, |ui| {
+            let props = DialogProps::new(Id::new("outer-dialog"), outer_open)
+                .with_animation(false)
+                .scrollable(false);
+            egui_shadcn::dialog(ui, &theme, props, |ui| {
+                ui.label("outer");
+                let props = DialogProps::new(Id::new("inner-dialog"), inner_open)
+                    .with_animation(false)
+                    .scrollable(false);
+                egui_shadcn::dialog(ui, &theme, props, |ui| {
+                    ui.label("inner");
+                });
+            });
+        });
+    };
+
+    for _ in 0..2 {
+        run_frame(&ctx, Vec::new(), |ctx| {
+            frame(ctx, &mut outer_open, &mut inner_open)
+        });
+    }
+    // The nested dialog is not committed to the stack until the next frame.
+    inner_open = true;
+    run_frame(&ctx, vec![escape_event()], |ctx| {
+        frame(ctx, &mut outer_open, &mut inner_open)
+    });
+    assert!(!inner_open, "escape closes a dialog in the frame it opens");
+    assert!(
+        outer_open,
+        "the parent is no longer topmost once its child registers"
+    );
+
+    inner_open = true;
+    for _ in 0..2 {
+        run_frame(&ctx, Vec::new(), |ctx| {
+            frame(ctx, &mut outer_open, &mut inner_open)
+        });
+    }
+    run_frame(&ctx, vec![escape_event()], |ctx| {
+        frame(ctx, &mut outer_open, &mut inner_open)
+    });
+
+    assert!(!inner_open, "escape closes the topmost dialog");
+    assert!(outer_open, "escape must not close the dialog underneath");
+
+    run_frame(&ctx, Vec::new(), |ctx| {
+        frame(ctx, &mut outer_open, &mut inner_open)
+    });
+    run_frame(&ctx, vec![escape_event()], |ctx| {
+        frame(ctx, &mut outer_open, &mut inner_open)
+    });
+    assert!(!outer_open, "a second escape closes the outer dialog");
+}
+
+#[test]
+fn backdrop_click_keeps_dialog_content_on_top() {
+    init_logger();
+    let ctx = egui::Context::default();
+    let theme = Theme::default();
+    let mut open = true;
+
+    // Returns the rect of the button inside the dialog and whether it was clicked.
+    let frame = |ctx: &egui::Context, events: Vec<egui::Event>, open: &mut bool| {
+        let input = egui::RawInput {
+            screen_rect: Some(egui::Rect::from_min_size(
+                egui::Pos2::ZERO,
+                egui::vec2(800.0, 600.0),
+            )),
+            events,
+            ..Default::default()
+        };
+        let mut button = (egui::Rect::NOTHING, false);
+        let _ = ctx.run(input, |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                let props = DialogProps::new(Id::new("sticky-dialog"), open)
+                    .with_close_on_background(false)
+                    .with_animation(false)
+                    .scrollable(false);
+                egui_shadcn::dialog(ui, &theme, props, |ui| {
+                    let response = ui.button("Confirm");
+                    button = (response.rect, response.clicked());
+                });
+            });
+        });
+        button
+    };
+    let click = |pos: egui::Pos2| {
+        let button = |pressed| egui::Event::PointerButton {
+            pos,
+            button: egui::PointerButton::Primary,
+            pressed,
+            modifiers: egui::Modifiers::default(),
+        };
+        vec![egui::Event::PointerMoved(pos), button(true), button(false)]
+    };
+
+    frame(&ctx, Vec::new(), &mut open);
+    frame(&ctx, click(egui::pos2(5.0, 5.0)), &mut open);
+    let (button_rect, _) = frame(&ctx, Vec::new(), &mut open);
+    assert!(open, "the backdrop click is ignored");
+    assert_eq!(
+        ctx.layer_id_at(button_rect.center()),
+        Some(LayerId::new(
+            Order::Foreground,
+            Id::new("sticky-dialog").with("content")
+        )),
+        "the backdrop must not be raised above the content"
+    );
+
+    let (_, clicked) = frame(&ctx, click(button_rect.center()), &mut open);
+    assert!(clicked, "the dialog stays interactive");
+}
@@ -0,0 +1,177 @@
+use egui::{Event, Id, Key, Modifiers, PointerButton, Pos2, Rect, Shape, vec2};
+use egui_shadcn::{
+    PageItem, PaginationControlsProps, Theme, pagination_controls, pagination_items,
+};
+use std::cell::Cell;
+
+use PageItem::{Ellipsis, Page};
+
+#[test]
+fn pages_keep_boundaries_and_siblings() {
+    assert_eq!(
+        pagination_items(1, 5, 1, 1),
+        (1..=5).map(Page).collect::<Vec<_>>()
+    );
+    assert_eq!(
+        pagination_items(1, 20, 1, 1),
+        [Page(1), Page(2), Page(3), Page(4), Ellipsis, Page(20)]
+    );
+    assert_eq!(
+        pagination_items(10, 20, 1, 1),
+        [
+            Page(1),
+            Ellipsis,
+            Page(9),
+            Page(10),
+            Page(11),
+            Ellipsis,
+            Page(20)
+        ]
+    );
+    assert_eq!(
+        pagination_items(20, 20, 1, 1),
+        [Page(1), Ellipsis, Page(17), Page(18), Page(19), Page(20)]
+    );
+    assert_eq!(
+        pagination_items(10, 20, 2, 2),
+        [
+            Page(1),
+            Page(2),
+            Ellipsis,
+            Page(8),
+            Page(9),
+            Page(10),
+            Page(11),
+            Page(12),
+            Ellipsis,
+            Page(19),
+            Page(20)
+        ]
+    );
+    assert_eq!(
+        pagination_items(4, 20, 0, 1),
+        [Page(1), Ellipsis, Page(4), Ellipsis, Page(20)]
+    );
+}
+
+/// Runs one frame of the controls and returns whether they reported a change, along with the
+/// painted texts and their rects.
+fn frame(
+    ctx: &egui::Context,
+    time: f64,
+    events: Vec<Event>,
+    page: &mut usize,
+) -> (bool, Vec<(String, Rect)>) {
+    let theme = Theme::default();
+    let input = egui::RawInput {
+        screen_rect: Some(Rect::from_min_size(Pos2::ZERO, vec2(900.0, 200.0))),
+        time: Some(time),
+        events,
+        ..Default::default()
+    };
+    let changed = Cell::new(false);
+    let output = ctx.run(input, |ctx| {
+        egui::CentralPanel::default().show(ctx, |ui| {
+            let response = pagination_controls(
+                ui,
+                &theme,
+                PaginationControlsProps::new(Id::new("pages"), 20, page).show_jump(true),
+            );
+            changed.set(response.changed());
+        });
+    });
+    let texts = output
+        .shapes
+        .iter()
+        .filter_map(|clipped| match &clipped.shape {
+            Shape::Text(text) => Some((
+                text.galley.text().to_string(),
+                text.galley.rect.translate(text.pos.to_vec2()),
+            )),
+            _ => None,
+        })
+        .collect();
+    (changed.get(), texts)
+}
+
+fn text_rect(texts: &[(String, Rect)], label: &str) -> Rect {
+    texts
+        .iter()
+        .find(|(text, _)| text == label)
+        .map(|(_, rect)| *rect)
+        .unwrap_or_else(|| panic!("{label} not painted"))
+}
+
+fn click(pos: Pos2) -> Vec<Vec<Event>> {
+    [true, false]
+        .into_iter()
+        .map(|pressed| {
+            vec![
+                Event::PointerMoved(pos),
+                Event::PointerButton {
+                    pos,
+                    button: PointerButton::Primary,
+                    pressed,
+                    modifiers: Modifiers::NONE,
+                },
+            ]
+        })
+        .collect()
+}
+
+#[test]
+fn controls_step_and_jump_between_pages() {
+    let ctx = egui::Context::default();
+    let mut page = 1;
+    let mut time = 0.0;
+    let (_, texts) = frame(&ctx, time, Vec::new(), &mut page);
+
+    let mut changed = false;
+    for events in click(text_rect(&texts, "Next").center()) {
+        time += 0.1;
+        changed |= frame(&ctx, time, events, &mut page).0;
+    }
+    assert_eq!(page, 2);
+    assert!(changed);
+
+    for events in click(text_rect(&texts, "Page").center()) {
+        time += 0.1;
+        frame(&ctx, time, events, &mut page);
+    }
+    for events in [
+        vec![Event::Text("17".to_string())],
+        vec![Event::Key {
+            key: Key::Enter,
+            physical_key: None,
+            pressed: true,
+            repeat: false,
+            modifiers: Modifiers::NONE,
+        }],
+    ] {
+        time += 0.1;
+        frame(&ctx, time, events, &mut page);
+    }
+    assert_eq!(page, 17);
+
+    // Out-of-range pages are clamped.
+    for events in click(text_rect(&texts, "Page").center()) {
+        time += 0.1;
+        frame(&ctx, time, events, &mut page);
+    }
+    time += 0.1;
+    frame(&ctx, time, vec![Event::Text("99".to_string())], &mut page);
+    time += 0.1;
+    frame(
+        &ctx,
+        time,
+        vec![Event::Key {
+            key: Key::Enter,
+            physical_key: None,
+            pressed: true,
+            repeat: false,
+            modifiers: Modifiers::NONE,
+        }],
+        &mut page,
+    );
+    assert_eq!(page, 20);
+}
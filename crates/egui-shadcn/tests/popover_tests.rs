@@ -0,0 +1,104 @@
+use egui::{Id, Pos2, Rect, pos2, vec2};
+use egui_shadcn::{PopoverAnchor, PopoverProps, PopoverSide, Theme, is_anchor_detached, popover};
+
+fn trigger_rect() -> Rect {
+    Rect::from_min_size(pos2(10.0, 10.0), vec2(80.0, 24.0))
+}
+
+#[test]
+fn trigger_anchor_uses_trigger_rect() {
+    let ctx = egui::Context::default();
+    let rect = PopoverAnchor::Trigger.resolve(&ctx, Id::new("anchor"), trigger_rect());
+    assert_eq!(rect, trigger_rect());
+    assert!(!PopoverAnchor::Trigger.is_virtual());
+}
+
+#[test]
+fn virtual_anchors_resolve_to_their_geometry() {
+    let ctx = egui::Context::default();
+    let custom = Rect::from_min_size(pos2(300.0, 200.0), vec2(4.0, 4.0));
+    assert_eq!(
+        PopoverAnchor::Rect(custom).resolve(&ctx, Id::new("anchor"), trigger_rect()),
+        custom
+    );
+    let point = pos2(120.0, 80.0);
+    assert_eq!(
+        PopoverAnchor::Point(point).resolve(&ctx, Id::new("anchor"), trigger_rect()),
+        Rect::from_min_size(point, egui::Vec2::ZERO)
+    );
+}
+
+#[test]
+fn cursor_anchor_keeps_last_pointer_position() {
+    let ctx = egui::Context::default();
+    let id = Id::new("cursor-anchor");
+    let mut resolved = Rect::NOTHING;
+    let input = egui::RawInput {
+        events: vec![egui::Event::PointerMoved(pos2(50.0, 60.0))],
+        ..Default::default()
+    };
+    let _ = ctx.run(input, |ctx| {
+        resolved = PopoverAnchor::Cursor.resolve(ctx, id, trigger_rect());
+    });
+    assert_eq!(resolved.min, pos2(50.0, 60.0));
+
+    let input = egui::RawInput {
+        events: vec![egui::Event::PointerGone],
+        ..Default::default()
+    };
+    let _ = ctx.run(input, |ctx| {
+        resolved = PopoverAnchor::Cursor.resolve(ctx, id, trigger_rect());
+    });
+    assert_eq!(resolved.min, pos2(50.0, 60.0));
+}
+
+#[test]
+fn point_anchor_detaches_outside_boundary() {
+    let boundary = Rect::from_min_size(Pos2::ZERO, vec2(100.0, 100.0));
+    let inside = Rect::from_min_size(pos2(50.0, 50.0), egui::Vec2::ZERO);
+    let outside = Rect::from_min_size(pos2(150.0, 50.0), egui::Vec2::ZERO);
+    assert!(!is_anchor_detached(boundary, inside));
+    assert!(is_anchor_detached(boundary, outside));
+}
+
+#[test]
+fn popover_is_placed_against_virtual_point() {
+    let ctx = egui::Context::default();
+    let theme = Theme::default();
+    let mut open = true;
+    let point = pos2(400.0, 300.0);
+    let input = || egui::RawInput {
+        screen_rect: Some(Rect::from_min_size(Pos2::ZERO, vec2(800.0, 600.0))),
+        ..Default::default()
+    };
+    for _ in 0..2 {
+        let _ = ctx.run(input(), |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                let props = PopoverProps::new(Id::new("virtual-popover"), &mut open)
+                    .default_open(true)
+                    .anchor_point(point)
+                    .side(PopoverSide::Bottom)
+                    .with_width(200.0)
+                    .with_max_height(120.0)
+                    .with_animation(false);
+                let _ = popover(
+                    ui,
+                    &theme,
+                    props,
+                    |ui| ui.label("trigger"),
+                    |ui| {
+                        ui.label("content");
+                    },
+                );
+            });
+        });
+    }
+    let rect = ctx
+        .memory(|m| m.area_rect(Id::new("virtual-popover").with("content")))
+        .expect("popover content is shown");
+    assert!(rect.top() >= point.y, "content sits below the point, got {rect:?}");
+    assert!(
+        (rect.center().x - point.x).abs() < 2.0,
+        "content is centered on the point, got {rect:?}"
+    );
+}
@@ -202,3 +202,228 @@ fn resizable_sizes_respect_min_max() {
     let clamped = props.clamp_size(50.0);
     assert_eq!(clamped, 50.0);
 }
+
+// =============================================================================
+// Layout constraint tests
+// =============================================================================
+
+fn run_group(
+    ctx: &egui::Context,
+    sizes: &mut Vec<f32>,
+    panels: &[egui_shadcn::ResizablePanelProps],
+    events: Vec<egui::Event>,
+    mut with_ctx: impl FnMut(&mut egui_shadcn::ResizableContext<'_>),
+) {
+    use egui_shadcn::{
+        ResizableHandleProps, ResizablePanelGroupProps, resizable_handle, resizable_panel,
+        resizable_panel_group,
+    };
+
+    let theme = Theme::default();
+    let input = egui::RawInput {
+        screen_rect: Some(egui::Rect::from_min_size(
+            egui::Pos2::ZERO,
+            egui::vec2(800.0, 400.0),
+        )),
+        events,
+        ..Default::default()
+    };
+    let _ = ctx.run(input, |ctx| {
+        egui::CentralPanel::default().show(ctx, |ui| {
+            resizable_panel_group(
+                ui,
+                &theme,
+                ResizablePanelGroupProps::new("constraints").auto_save_id("constraints-layout"),
+                sizes,
+                |ui, group| {
+                    for (idx, props) in panels.iter().enumerate() {
+                        if idx > 0 {
+                            resizable_handle(
+                                ui,
+                                &theme,
+                                group,
+                                ResizableHandleProps::new(),
+                                idx - 1,
+                            );
+                        }
+                        resizable_panel(ui, group, props.clone(), idx, |ui| {
+                            let _ = ui.button(format!("Panel {idx}"));
+                        });
+                    }
+                    with_ctx(group);
+                },
+            );
+        });
+    });
+}
+
+#[test]
+fn adjust_layout_respects_min_and_max() {
+    use egui_shadcn::{ResizablePanelProps, adjust_layout};
+
+    let constraints = vec![
+        ResizablePanelProps::new(50.0).max_size(60.0),
+        ResizablePanelProps::new(50.0).min_size(30.0),
+    ];
+    let layout = adjust_layout(&[50.0, 50.0], &constraints, 0, 30.0);
+    assert_eq!(layout, vec![60.0, 40.0]);
+
+    let layout = adjust_layout(&[50.0, 50.0], &constraints, 0, -100.0);
+    assert_eq!(layout, vec![0.0, 100.0]);
+}
+
+#[test]
+fn adjust_layout_cascades_into_further_panels() {
+    use egui_shadcn::{ResizablePanelProps, adjust_layout};
+
+    let constraints = vec![
+        ResizablePanelProps::new(30.0),
+        ResizablePanelProps::new(40.0).min_size(20.0),
+        ResizablePanelProps::new(30.0).min_size(10.0),
+    ];
+    let layout = adjust_layout(&[30.0, 40.0, 30.0], &constraints, 0, 35.0);
+    assert_eq!(layout, vec![65.0, 20.0, 15.0]);
+    assert_eq!(layout.iter().sum::<f32>(), 100.0);
+}
+
+#[test]
+fn adjust_layout_collapses_past_half_of_min() {
+    use egui_shadcn::{ResizablePanelProps, adjust_layout};
+
+    let constraints = vec![
+        ResizablePanelProps::new(30.0)
+            .min_size(20.0)
+            .collapsible(true)
+            .collapsed_size(4.0),
+        ResizablePanelProps::new(70.0),
+    ];
+    // Just below the minimum: the panel stays at its minimum.
+    let layout = adjust_layout(&[30.0, 70.0], &constraints, 0, -13.0);
+    assert_eq!(layout, vec![20.0, 80.0]);
+    // Past the midpoint between collapsed size and minimum: the panel snaps closed.
+    let layout = adjust_layout(&[30.0, 70.0], &constraints, 0, -19.0);
+    assert_eq!(layout, vec![4.0, 96.0]);
+}
+
+#[test]
+fn validate_layout_sums_to_hundred() {
+    use egui_shadcn::{ResizablePanelProps, validate_layout};
+
+    let constraints = vec![
+        ResizablePanelProps::new(50.0).max_size(40.0),
+        ResizablePanelProps::new(50.0),
+    ];
+    let layout = validate_layout(&[70.0, 20.0], &constraints);
+    assert_eq!(layout, vec![40.0, 60.0]);
+}
+
+#[test]
+fn context_collapse_and_expand_restore_size() {
+    init_logger();
+    use egui_shadcn::ResizablePanelProps;
+
+    let ctx = egui::Context::default();
+    let panels = vec![
+        ResizablePanelProps::new(30.0)
+            .min_size(15.0)
+            .collapsible(true),
+        ResizablePanelProps::new(70.0),
+    ];
+    let mut sizes = Vec::new();
+    run_group(&ctx, &mut sizes, &panels, Vec::new(), |_| {});
+    assert_eq!(sizes, vec![30.0, 70.0]);
+
+    run_group(&ctx, &mut sizes, &panels, Vec::new(), |group| {
+        group.collapse(0);
+        assert!(group.is_collapsed(0));
+    });
+    assert_eq!(sizes, vec![0.0, 100.0]);
+
+    run_group(&ctx, &mut sizes, &panels, Vec::new(), |group| {
+        group.expand(0);
+        assert!(!group.is_collapsed(0));
+    });
+    assert_eq!(sizes, vec![30.0, 70.0]);
+}
+
+#[test]
+fn focused_handle_resizes_with_arrow_keys() {
+    init_logger();
+    use egui_shadcn::ResizablePanelProps;
+
+    let ctx = egui::Context::default();
+    let panels = vec![
+        ResizablePanelProps::new(50.0).max_size(75.0),
+        ResizablePanelProps::new(50.0),
+    ];
+    let mut sizes = Vec::new();
+    run_group(&ctx, &mut sizes, &panels, Vec::new(), |_| {});
+
+    let key = |key: egui::Key| egui::Event::Key {
+        key,
+        physical_key: None,
+        pressed: true,
+        repeat: false,
+        modifiers: egui::Modifiers::default(),
+    };
+    // The first Tab focuses the button in the first panel, the second one the handle.
+    for _ in 0..2 {
+        run_group(&ctx, &mut sizes, &panels, vec![key(egui::Key::Tab)], |_| {});
+        run_group(&ctx, &mut sizes, &panels, Vec::new(), |_| {});
+    }
+    // Arrow keys keep resizing instead of moving focus to the neighbouring button.
+    for expected in [vec![60.0, 40.0], vec![70.0, 30.0]] {
+        run_group(
+            &ctx,
+            &mut sizes,
+            &panels,
+            vec![key(egui::Key::ArrowRight)],
+            |_| {},
+        );
+        run_group(&ctx, &mut sizes, &panels, Vec::new(), |_| {});
+        assert_eq!(sizes, expected);
+    }
+
+    run_group(&ctx, &mut sizes, &panels, vec![key(egui::Key::End)], |_| {});
+    assert_eq!(sizes, vec![75.0, 25.0], "End stops at the max size");
+
+    run_group(
+        &ctx,
+        &mut sizes,
+        &panels,
+        vec![key(egui::Key::Home)],
+        |_| {},
+    );
+    assert_eq!(sizes, vec![0.0, 100.0]);
+}
+
+#[test]
+fn auto_save_id_restores_layout() {
+    init_logger();
+    use egui_shadcn::ResizablePanelProps;
+
+    let panels = vec![
+        ResizablePanelProps::new(50.0),
+        ResizablePanelProps::new(50.0),
+    ];
+    let ctx = egui::Context::default();
+    let mut sizes = Vec::new();
+    run_group(&ctx, &mut sizes, &panels, Vec::new(), |group| {
+        group.set_layout(&[35.0, 65.0]);
+    });
+    run_group(&ctx, &mut sizes, &panels, Vec::new(), |_| {});
+
+    let key = egui::Id::new((
+        "egui_shadcn_resizable_layout",
+        "constraints-layout".to_string(),
+    ));
+    let saved = ctx.data_mut(|d| d.get_persisted::<Vec<f32>>(key));
+    assert_eq!(saved, Some(vec![35.0, 65.0]));
+
+    // A new session with the same persisted memory starts from the saved layout.
+    let restored = egui::Context::default();
+    restored.data_mut(|d| d.insert_persisted(key, vec![35.0_f32, 65.0]));
+    let mut sizes = Vec::new();
+    run_group(&restored, &mut sizes, &panels, Vec::new(), |_| {});
+    assert_eq!(sizes, vec![35.0, 65.0]);
+}
@@ -0,0 +1,289 @@
+use egui::{Event, Id, Modifiers, PointerButton, Pos2, Rect, Shape, vec2};
+use egui_shadcn::{BreadcrumbProps, Route, Router, Theme, router_breadcrumb, router_tabs};
+
+#[derive(Clone, Debug, PartialEq)]
+enum Screen {
+    Home,
+    Projects,
+    Project { id: u32 },
+    ProjectSettings { id: u32 },
+    Settings,
+}
+
+impl Route for Screen {
+    fn title(&self) -> String {
+        match self {
+            Screen::Home => "Home".into(),
+            Screen::Projects => "Projects".into(),
+            Screen::Project { id } => format!("Project {id}"),
+            Screen::ProjectSettings { .. } => "Project settings".into(),
+            Screen::Settings => "Settings".into(),
+        }
+    }
+
+    fn parent(&self) -> Option<Self> {
+        match self {
+            Screen::Home => None,
+            Screen::ProjectSettings { id } => Some(Screen::Project { id: *id }),
+            Screen::Projects | Screen::Settings => Some(Screen::Home),
+            Screen::Project { .. } => Some(Screen::Projects),
+        }
+    }
+}
+
+#[test]
+fn back_and_forward_walk_the_history() {
+    let mut router = Router::new(Screen::Home);
+    router.navigate(Screen::Projects);
+    router.navigate(Screen::Project { id: 7 });
+    router.navigate(Screen::Project { id: 7 });
+    assert_eq!(router.history().len(), 3);
+
+    assert!(router.back());
+    assert_eq!(router.current(), &Screen::Projects);
+    assert!(router.can_go_forward());
+    assert!(router.forward());
+    assert_eq!(router.current(), &Screen::Project { id: 7 });
+    assert!(!router.forward());
+
+    // Navigating after going back drops the forward entries.
+    router.back();
+    router.navigate(Screen::Settings);
+    assert_eq!(
+        router.history(),
+        [Screen::Home, Screen::Projects, Screen::Settings]
+    );
+    assert!(!router.can_go_forward());
+
+    router.replace(Screen::Project { id: 1 });
+    assert_eq!(router.stack().len(), 3);
+    assert_eq!(router.current(), &Screen::Project { id: 1 });
+}
+
+#[test]
+fn history_is_capped() {
+    let mut router = Router::new(Screen::Home).with_max_history(3);
+    for id in 0..5 {
+        router.navigate(Screen::Project { id });
+    }
+    assert_eq!(router.history().len(), 3);
+    assert_eq!(router.history()[0], Screen::Project { id: 2 });
+    assert_eq!(router.current(), &Screen::Project { id: 4 });
+}
+
+#[test]
+fn parents_stay_active_for_their_descendants() {
+    let router = Router::new(Screen::Project { id: 3 });
+    assert!(router.is_active(&Screen::Project { id: 3 }));
+    assert!(!router.is_active(&Screen::Projects));
+    assert!(router.is_within(&Screen::Projects));
+    assert!(router.is_within(&Screen::Home));
+    assert!(!router.is_within(&Screen::Settings));
+    assert!(!router.is_within(&Screen::ProjectSettings { id: 3 }));
+    assert_eq!(
+        router.trail(),
+        [Screen::Home, Screen::Projects, Screen::Project { id: 3 }]
+    );
+}
+
+fn run(ctx: &egui::Context, events: Vec<Event>, router: &mut Router<Screen>) {
+    let input = egui::RawInput {
+        screen_rect: Some(Rect::from_min_size(Pos2::ZERO, vec2(800.0, 400.0))),
+        events,
+        ..Default::default()
+    };
+    let _ = ctx.run(input, |ctx| {
+        router.handle_input(ctx);
+    });
+}
+
+#[test]
+fn mouse_buttons_and_alt_arrows_navigate() {
+    let ctx = egui::Context::default();
+    let mut router = Router::new(Screen::Home);
+    router.navigate(Screen::Settings);
+
+    let button = |button, pressed| Event::PointerButton {
+        pos: Pos2::new(10.0, 10.0),
+        button,
+        pressed,
+        modifiers: Modifiers::NONE,
+    };
+    run(
+        &ctx,
+        vec![
+            Event::PointerMoved(Pos2::new(10.0, 10.0)),
+            button(PointerButton::Extra1, true),
+        ],
+        &mut router,
+    );
+    run(
+        &ctx,
+        vec![button(PointerButton::Extra1, false)],
+        &mut router,
+    );
+    assert_eq!(router.current(), &Screen::Home);
+
+    run(
+        &ctx,
+        vec![Event::Key {
+            key: egui::Key::ArrowRight,
+            physical_key: None,
+            pressed: true,
+            repeat: false,
+            modifiers: Modifiers::ALT,
+        }],
+        &mut router,
+    );
+    assert_eq!(router.current(), &Screen::Settings);
+}
+
+/// Renders breadcrumb and tabs bound to the router and returns the painted texts.
+fn frame(
+    ctx: &egui::Context,
+    time: f64,
+    events: Vec<Event>,
+    router: &mut Router<Screen>,
+) -> Vec<(String, Rect)> {
+    let theme = Theme::default();
+    let input = egui::RawInput {
+        screen_rect: Some(Rect::from_min_size(Pos2::ZERO, vec2(800.0, 400.0))),
+        time: Some(time),
+        events,
+        ..Default::default()
+    };
+    let output = ctx.run(input, |ctx| {
+        egui::CentralPanel::default().show(ctx, |ui| {
+            router_breadcrumb(
+                ui,
+                &theme,
+                BreadcrumbProps::new(),
+                Id::new("crumbs"),
+                router,
+            );
+            ui.add_space(16.0);
+            let routes = [Screen::Projects, Screen::Settings];
+            router_tabs(ui, &theme, Id::new("tabs"), router, &routes, |ui, route| {
+                ui.label(format!("{} content", route.title()));
+            });
+        });
+    });
+    output
+        .shapes
+        .iter()
+        .filter_map(|clipped| match &clipped.shape {
+            Shape::Text(text) => Some((
+                text.galley.text().to_string(),
+                text.galley.rect.translate(text.pos.to_vec2()),
+            )),
+            _ => None,
+        })
+        .collect()
+}
+
+fn click(
+    ctx: &egui::Context,
+    time: &mut f64,
+    texts: &[(String, Rect)],
+    label: &str,
+    router: &mut Router<Screen>,
+) {
+    let pos = texts
+        .iter()
+        .rev()
+        .find(|(text, _)| text == label)
+        .map(|(_, rect)| rect.center())
+        .unwrap_or_else(|| panic!("{label} not painted"));
+    for pressed in [true, false] {
+        *time += 0.1;
+        let events = vec![
+            Event::PointerMoved(pos),
+            Event::PointerButton {
+                pos,
+                button: PointerButton::Primary,
+                pressed,
+                modifiers: Modifiers::NONE,
+            },
+        ];
+        frame(ctx, *time, events, router);
+    }
+}
+
+#[test]
+fn breadcrumb_and_tabs_follow_the_route() {
+    let ctx = egui::Context::default();
+    let mut router = Router::new(Screen::Project { id: 3 });
+    let mut time = 0.0;
+    let texts = frame(&ctx, time, Vec::new(), &mut router);
+    assert!(texts.iter().any(|(text, _)| text == "Projects content"));
+
+    // The tab for a route's ancestor is selected; picking another tab navigates.
+    click(&ctx, &mut time, &texts, "Settings", &mut router);
+    assert_eq!(router.current(), &Screen::Settings);
+
+    time += 0.1;
+    let texts = frame(&ctx, time, Vec::new(), &mut router);
+    assert!(texts.iter().any(|(text, _)| text == "Settings content"));
+    click(&ctx, &mut time, &texts, "Home", &mut router);
+    assert_eq!(router.current(), &Screen::Home);
+    assert_eq!(router.history().len(), 3);
+}
+
+#[test]
+fn tabs_select_the_closest_route() {
+    let ctx = egui::Context::default();
+    let theme = Theme::default();
+    let mut router = Router::new(Screen::ProjectSettings { id: 1 });
+    let mut shown = None;
+    let input = egui::RawInput {
+        screen_rect: Some(Rect::from_min_size(Pos2::ZERO, vec2(800.0, 400.0))),
+        ..Default::default()
+    };
+    let _ = ctx.run(input, |ctx| {
+        egui::CentralPanel::default().show(ctx, |ui| {
+            let routes = [Screen::Project { id: 1 }, Screen::ProjectSettings { id: 1 }];
+            router_tabs(
+                ui,
+                &theme,
+                Id::new("tabs"),
+                &mut router,
+                &routes,
+                |_, route| {
+                    shown = Some(route.clone());
+                },
+            );
+        });
+    });
+    assert_eq!(shown, Some(Screen::ProjectSettings { id: 1 }));
+    assert_eq!(router.history().len(), 1);
+}
+
+#[test]
+fn tabs_without_a_matching_route_navigate_on_any_click() {
+    let ctx = egui::Context::default();
+    let mut router = Router::new(Screen::Home);
+    let mut time = 0.0;
+    let texts = frame(&ctx, time, Vec::new(), &mut router);
+    assert!(texts.iter().any(|(text, _)| text == "Home content"));
+
+    // The first tab is the fallback selection, but clicking it still navigates.
+    click(&ctx, &mut time, &texts, "Projects", &mut router);
+    assert_eq!(router.current(), &Screen::Projects);
+    assert_eq!(router.history().len(), 2);
+}
+
+#[test]
+fn tabs_without_routes_render_the_current_route() {
+    let ctx = egui::Context::default();
+    let theme = Theme::default();
+    let mut router = Router::new(Screen::Settings);
+    let mut shown = None;
+    let _ = ctx.run(egui::RawInput::default(), |ctx| {
+        egui::CentralPanel::default().show(ctx, |ui| {
+            router_tabs(ui, &theme, Id::new("tabs"), &mut router, &[], |_, route| {
+                shown = Some(route.clone());
+            });
+        });
+    });
+    assert_eq!(shown, Some(Screen::Settings));
+}
@@ -0,0 +1,97 @@
+use egui_shadcn::{SheetProps, SheetSide, resolve_sheet_snap};
+
+#[test]
+fn snap_points_are_sorted_and_clamped() {
+    let mut open = true;
+    let props =
+        SheetProps::new(egui::Id::new("sheet-snap"), &mut open).snap_points([1.5, 0.5, 0.25, 0.0]);
+
+    assert_eq!(props.snap_points, vec![0.25, 0.5, 1.0]);
+    assert!(!props.drag_to_dismiss, "drag to dismiss is opt-in");
+}
+
+#[test]
+fn drawer_enables_handle_and_dismiss() {
+    let mut open = true;
+    let props = SheetProps::new(egui::Id::new("sheet-drawer"), &mut open)
+        .side(SheetSide::Bottom)
+        .drawer();
+
+    assert!(props.drag_to_dismiss);
+    assert!(props.show_drag_handle);
+}
+
+#[test]
+fn release_settles_on_nearest_snap_point() {
+    let snaps = [100.0, 200.0, 400.0];
+
+    assert_eq!(resolve_sheet_snap(210.0, &snaps, 0.0, true), Some(1));
+    assert_eq!(resolve_sheet_snap(350.0, &snaps, 0.0, true), Some(2));
+    // A fast fling towards closing skips past the nearest point.
+    assert_eq!(resolve_sheet_snap(350.0, &snaps, 1000.0, true), Some(0));
+}
+
+#[test]
+fn release_below_smallest_snap_dismisses_only_when_allowed() {
+    let snaps = [100.0, 400.0];
+
+    assert_eq!(resolve_sheet_snap(30.0, &snaps, 0.0, true), None);
+    assert_eq!(resolve_sheet_snap(30.0, &snaps, 0.0, false), Some(0));
+    assert_eq!(resolve_sheet_snap(120.0, &snaps, 800.0, true), None);
+}
+
+#[test]
+fn closing_direction_points_off_screen() {
+    assert_eq!(SheetSide::Bottom.closing_direction(), egui::vec2(0.0, 1.0));
+    assert_eq!(SheetSide::Top.closing_direction(), egui::vec2(0.0, -1.0));
+    assert_eq!(SheetSide::Left.closing_direction(), egui::vec2(-1.0, 0.0));
+    assert_eq!(SheetSide::Right.closing_direction(), egui::vec2(1.0, 0.0));
+}
+
+#[test]
+fn drag_handle_never_overlaps_the_content() {
+    let ctx = egui::Context::default();
+    let theme = egui_shadcn::Theme::default();
+    for side in [
+        SheetSide::Top,
+        SheetSide::Right,
+        SheetSide::Bottom,
+        SheetSide::Left,
+    ] {
+        let id = egui::Id::new(("sheet-handle", side as u8));
+        let mut open = true;
+        let mut content = egui::Rect::NOTHING;
+        for _ in 0..2 {
+            let input = egui::RawInput {
+                screen_rect: Some(egui::Rect::from_min_size(
+                    egui::Pos2::ZERO,
+                    egui::vec2(800.0, 600.0),
+                )),
+                ..Default::default()
+            };
+            let _ = ctx.run(input, |ctx| {
+                egui::CentralPanel::default().show(ctx, |ui| {
+                    let props = SheetProps::new(id, &mut open).side(side).drawer();
+                    egui_shadcn::sheet(ui, props, |ui, context| {
+                        egui_shadcn::sheet_content(ui, &theme, context, |ui| {
+                            content = ui.max_rect();
+                        });
+                    });
+                });
+            });
+        }
+        let panel = ctx
+            .memory(|m| m.area_rect(id.with("content")))
+            .expect("sheet content shown");
+        let handle = match side {
+            SheetSide::Top => panel.with_min_y(panel.max.y - 28.0),
+            SheetSide::Right => panel.with_max_x(panel.min.x + 28.0),
+            SheetSide::Bottom => panel.with_max_y(panel.min.y + 28.0),
+            SheetSide::Left => panel.with_min_x(panel.max.x - 28.0),
+        };
+        assert!(
+            !handle.intersects(content),
+            "{side:?}: {handle:?} overlaps {content:?}"
+        );
+    }
+}
@@ -0,0 +1,388 @@
+use egui::{Event, Id, Modifiers, PointerButton, Pos2, Rect, Shape, Ui, vec2};
+use egui_shadcn::{
+    Breakpoint, Breakpoints, SidebarCollapsibleProps, SidebarContext, SidebarGroupLabelProps,
+    SidebarMenuActionProps, SidebarMenuButtonProps, SidebarProps, SidebarProviderProps, Theme,
+    sidebar, sidebar_group_collapsible, sidebar_menu_action, sidebar_menu_badge,
+    sidebar_menu_button, sidebar_menu_collapsible, sidebar_provider, sidebar_rail,
+};
+use lucide_icons::Icon;
+use std::cell::Cell;
+
+/// Runs one frame with a 240pt sidebar (64pt collapsed) on a desktop-wide screen and returns
+/// every painted text.
+fn frame(
+    ctx: &egui::Context,
+    time: f64,
+    events: Vec<Event>,
+    open: &mut bool,
+    build: &mut dyn FnMut(&mut Ui, &Theme, &mut SidebarContext),
+) -> Vec<String> {
+    let theme = Theme::default();
+    let input = egui::RawInput {
+        screen_rect: Some(Rect::from_min_size(Pos2::ZERO, vec2(1024.0, 400.0))),
+        time: Some(time),
+        events,
+        ..Default::default()
+    };
+    let default_open = *open;
+    let output = ctx.run(input, |ctx| {
+        egui::CentralPanel::default().show(ctx, |ui| {
+            sidebar_provider(
+                ui,
+                SidebarProviderProps::new(Id::new("sidebar"), open)
+                    .default_open(default_open)
+                    .animate(false),
+                |ui, sidebar_ctx| {
+                    ui.horizontal(|ui| {
+                        sidebar(
+                            ui,
+                            &theme,
+                            sidebar_ctx,
+                            SidebarProps::new(),
+                            |ui, sidebar_ctx| build(ui, &theme, sidebar_ctx),
+                        );
+                        sidebar_rail(ui, &theme, sidebar_ctx);
+                    });
+                },
+            );
+        });
+    });
+    output
+        .shapes
+        .iter()
+        .filter_map(|clipped| match &clipped.shape {
+            Shape::Text(text) => Some(text.galley.text().to_string()),
+            _ => None,
+        })
+        .collect()
+}
+
+fn pointer(pos: Pos2, pressed: Option<bool>) -> Vec<Event> {
+    let mut events = vec![Event::PointerMoved(pos)];
+    if let Some(pressed) = pressed {
+        events.push(Event::PointerButton {
+            pos,
+            button: PointerButton::Primary,
+            pressed,
+            modifiers: Modifiers::NONE,
+        });
+    }
+    events
+}
+
+fn click(
+    ctx: &egui::Context,
+    time: &mut f64,
+    pos: Pos2,
+    open: &mut bool,
+    build: &mut dyn FnMut(&mut Ui, &Theme, &mut SidebarContext),
+) {
+    for pressed in [true, false] {
+        *time += 0.1;
+        frame(ctx, *time, pointer(pos, Some(pressed)), open, build);
+    }
+}
+
+#[test]
+fn collapsed_buttons_show_icons_with_tooltips() {
+    let ctx = egui::Context::default();
+    let mut open = false;
+    let rect = Cell::new(Rect::NOTHING);
+    let mut build = |ui: &mut Ui, theme: &Theme, sidebar_ctx: &mut SidebarContext| {
+        rect.set(
+            sidebar_menu_button(
+                ui,
+                theme,
+                sidebar_ctx,
+                SidebarMenuButtonProps::new("Inbox").icon(Icon::Inbox),
+            )
+            .rect,
+        );
+    };
+
+    let texts = frame(&ctx, 0.0, Vec::new(), &mut open, &mut build);
+    assert!(!open);
+    assert!(texts.contains(&Icon::Inbox.unicode().to_string()));
+    assert!(!texts.iter().any(|text| text == "Inbox"));
+
+    let mut time = 0.0;
+    let mut texts = Vec::new();
+    for _ in 0..4 {
+        time += 0.5;
+        texts = frame(
+            &ctx,
+            time,
+            pointer(rect.get().center(), None),
+            &mut open,
+            &mut build,
+        );
+    }
+    assert!(
+        texts.iter().any(|text| text == "Inbox"),
+        "tooltip: {texts:?}"
+    );
+
+    let mut open = true;
+    let ctx = egui::Context::default();
+    let texts = frame(&ctx, 0.0, Vec::new(), &mut open, &mut build);
+    assert!(texts.iter().any(|text| text == "Inbox"));
+}
+
+#[test]
+fn collapsible_group_hides_its_contents() {
+    let ctx = egui::Context::default();
+    let mut open = true;
+    let trigger = Cell::new(Rect::NOTHING);
+    let shown = Cell::new(false);
+    let mut build = |ui: &mut Ui, theme: &Theme, sidebar_ctx: &mut SidebarContext| {
+        let response = sidebar_group_collapsible(
+            ui,
+            theme,
+            sidebar_ctx,
+            SidebarCollapsibleProps::new(Id::new("projects")),
+            SidebarGroupLabelProps::new("Projects"),
+            |ui| {
+                sidebar_menu_button(ui, theme, sidebar_ctx, SidebarMenuButtonProps::new("Alpha"));
+            },
+        );
+        trigger.set(response.response.rect);
+        shown.set(response.inner.is_some());
+    };
+
+    let mut time = 0.0;
+    let texts = frame(&ctx, time, Vec::new(), &mut open, &mut build);
+    assert!(shown.get() && texts.contains(&"Alpha".to_string()));
+
+    click(
+        &ctx,
+        &mut time,
+        trigger.get().center(),
+        &mut open,
+        &mut build,
+    );
+    for _ in 0..5 {
+        time += 0.5;
+        frame(&ctx, time, Vec::new(), &mut open, &mut build);
+    }
+    assert!(!shown.get());
+
+    click(
+        &ctx,
+        &mut time,
+        trigger.get().center(),
+        &mut open,
+        &mut build,
+    );
+    for _ in 0..5 {
+        time += 0.5;
+        frame(&ctx, time, Vec::new(), &mut open, &mut build);
+    }
+    assert!(shown.get());
+}
+
+#[test]
+fn sub_menus_hide_while_collapsed() {
+    let mut build = |ui: &mut Ui, theme: &Theme, sidebar_ctx: &mut SidebarContext| {
+        sidebar_menu_collapsible(
+            ui,
+            theme,
+            sidebar_ctx,
+            SidebarCollapsibleProps::new(Id::new("docs")),
+            SidebarMenuButtonProps::new("Docs").icon(Icon::Book),
+            |ui| {
+                sidebar_menu_button(ui, theme, sidebar_ctx, SidebarMenuButtonProps::new("Intro"));
+            },
+        );
+    };
+
+    let mut open = true;
+    let texts = frame(
+        &egui::Context::default(),
+        0.0,
+        Vec::new(),
+        &mut open,
+        &mut build,
+    );
+    assert!(texts.contains(&"Intro".to_string()));
+
+    let mut open = false;
+    let texts = frame(
+        &egui::Context::default(),
+        0.0,
+        Vec::new(),
+        &mut open,
+        &mut build,
+    );
+    assert!(!texts.contains(&"Intro".to_string()));
+}
+
+#[test]
+fn actions_take_clicks_from_the_button() {
+    let ctx = egui::Context::default();
+    let mut open = true;
+    let action_rect = Cell::new(Rect::NOTHING);
+    let (button_clicked, action_clicked) = (Cell::new(false), Cell::new(false));
+    let mut build = |ui: &mut Ui, theme: &Theme, sidebar_ctx: &mut SidebarContext| {
+        let button =
+            sidebar_menu_button(ui, theme, sidebar_ctx, SidebarMenuButtonProps::new("Inbox"));
+        sidebar_menu_badge(ui, theme, sidebar_ctx, &button, "24");
+        let action = sidebar_menu_action(
+            ui,
+            theme,
+            sidebar_ctx,
+            &button,
+            SidebarMenuActionProps::new(Icon::Ellipsis),
+        );
+        action_rect.set(action.rect);
+        button_clicked.set(button_clicked.get() || button.clicked());
+        action_clicked.set(action_clicked.get() || action.clicked());
+    };
+
+    let mut time = 0.0;
+    let texts = frame(&ctx, time, Vec::new(), &mut open, &mut build);
+    assert!(texts.contains(&"24".to_string()));
+
+    click(
+        &ctx,
+        &mut time,
+        action_rect.get().center(),
+        &mut open,
+        &mut build,
+    );
+    assert!(action_clicked.get());
+    assert!(!button_clicked.get());
+}
+
+#[test]
+fn rail_toggles_on_click_and_drag() {
+    let ctx = egui::Context::default();
+    let mut open = true;
+    let mut build = |_: &mut Ui, _: &Theme, _: &mut SidebarContext| {};
+    let mut time = 0.0;
+    frame(&ctx, time, Vec::new(), &mut open, &mut build);
+
+    // The sidebar starts at the panel margin, is 240pt wide and as tall as its contents.
+    let edge = Pos2::new(248.0, 16.0);
+    click(&ctx, &mut time, edge, &mut open, &mut build);
+    assert!(!open);
+
+    // Collapsed to 64pt: drag the rail outwards to expand.
+    let edge = Pos2::new(72.0, 16.0);
+    time += 0.1;
+    frame(&ctx, time, Vec::new(), &mut open, &mut build);
+    time += 0.1;
+    frame(&ctx, time, pointer(edge, Some(true)), &mut open, &mut build);
+    for step in 1..=4 {
+        time += 0.1;
+        let pos = edge + vec2(step as f32 * 10.0, 0.0);
+        frame(&ctx, time, pointer(pos, None), &mut open, &mut build);
+    }
+    assert!(open);
+}
+
+#[test]
+fn breakpoints_resolve_from_width() {
+    let breakpoints = Breakpoints::default();
+    assert_eq!(breakpoints.resolve(0.0), Breakpoint::Xs);
+    assert_eq!(breakpoints.resolve(639.0), Breakpoint::Xs);
+    assert_eq!(breakpoints.resolve(640.0), Breakpoint::Sm);
+    assert_eq!(breakpoints.resolve(800.0), Breakpoint::Md);
+    assert_eq!(breakpoints.resolve(1024.0), Breakpoint::Lg);
+    assert_eq!(breakpoints.resolve(2000.0), Breakpoint::Xl);
+    assert!(Breakpoint::Sm < Breakpoint::Md);
+    assert_eq!(breakpoints.min_width(Breakpoint::Lg), 1024.0);
+}
+
+/// Runs one frame at the given screen width, optionally toggling the sidebar from outside it.
+/// Returns whether the layout was mobile and whether the sidebar contents ran.
+fn responsive_frame(
+    ctx: &egui::Context,
+    time: f64,
+    width: f32,
+    open: &mut bool,
+    toggle: bool,
+) -> (bool, bool) {
+    let theme = Theme::default();
+    let input = egui::RawInput {
+        screen_rect: Some(Rect::from_min_size(Pos2::ZERO, vec2(width, 400.0))),
+        time: Some(time),
+        ..Default::default()
+    };
+    let mut result = (false, false);
+    let _ = ctx.run(input, |ctx| {
+        egui::CentralPanel::default().show(ctx, |ui| {
+            sidebar_provider(
+                ui,
+                SidebarProviderProps::new(Id::new("sidebar"), open).animate(false),
+                |ui, sidebar_ctx| {
+                    let shown = sidebar(ui, &theme, sidebar_ctx, SidebarProps::new(), |ui, _| {
+                        ui.label("Item")
+                    });
+                    if toggle {
+                        sidebar_ctx.toggle();
+                    }
+                    result = (sidebar_ctx.is_mobile(), shown.inner.is_some());
+                },
+            );
+        });
+    });
+    result
+}
+
+#[test]
+fn narrow_layouts_show_the_sidebar_as_a_sheet() {
+    let ctx = egui::Context::default();
+    let mut open = true;
+
+    assert_eq!(
+        responsive_frame(&ctx, 0.0, 1024.0, &mut open, false),
+        (false, true)
+    );
+
+    // Below `md` the docked sidebar is replaced by a sheet that starts closed.
+    assert_eq!(
+        responsive_frame(&ctx, 0.1, 400.0, &mut open, true),
+        (true, false)
+    );
+    assert!(open, "toggling on mobile leaves the desktop state alone");
+    assert_eq!(
+        responsive_frame(&ctx, 0.2, 400.0, &mut open, false),
+        (true, true)
+    );
+
+    // Each layout remembers its own open state.
+    assert_eq!(
+        responsive_frame(&ctx, 0.3, 1024.0, &mut open, true),
+        (false, true)
+    );
+    assert!(!open);
+    assert_eq!(
+        responsive_frame(&ctx, 0.4, 400.0, &mut open, false),
+        (true, true)
+    );
+}
+
+#[test]
+fn mobile_breakpoint_can_be_disabled() {
+    let ctx = egui::Context::default();
+    let theme = Theme::default();
+    let mut open = true;
+    let mut mobile = true;
+    let input = egui::RawInput {
+        screen_rect: Some(Rect::from_min_size(Pos2::ZERO, vec2(300.0, 400.0))),
+        ..Default::default()
+    };
+    let _ = ctx.run(input, |ctx| {
+        egui::CentralPanel::default().show(ctx, |ui| {
+            sidebar_provider(
+                ui,
+                SidebarProviderProps::new(Id::new("sidebar"), &mut open).mobile_breakpoint(None),
+                |ui, sidebar_ctx| {
+                    sidebar(ui, &theme, sidebar_ctx, SidebarProps::new(), |_, _| {});
+                    mobile = sidebar_ctx.is_mobile();
+                },
+            );
+        });
+    });
+    assert!(!mobile);
+}
@@ -0,0 +1,313 @@
+use egui::{Event, Id, Key, Modifiers, PointerButton, Pos2, Rect, Shape, Ui, vec2};
+use egui_shadcn::{
+    DataTableColumn, DataTableProps, SortableLocation, SortableMove, SortableProps, TabItem,
+    TabsProps, Theme, apply_sortable_move, data_table, reorder_items, sortable, tabs,
+};
+
+fn location(container: &str, index: usize) -> SortableLocation {
+    SortableLocation {
+        container: Id::new(container),
+        index,
+    }
+}
+
+/// Runs one frame and returns the rect of every painted label.
+fn frame(ctx: &egui::Context, events: Vec<Event>, add: impl FnMut(&mut Ui)) -> Vec<(String, Rect)> {
+    let input = egui::RawInput {
+        screen_rect: Some(Rect::from_min_size(Pos2::ZERO, vec2(800.0, 600.0))),
+        events,
+        ..Default::default()
+    };
+    let mut add = add;
+    let output = ctx.run(input, |ctx| {
+        egui::CentralPanel::default().show(ctx, |ui| add(ui));
+    });
+    output
+        .shapes
+        .iter()
+        .filter_map(|clipped| match &clipped.shape {
+            Shape::Text(text) => Some((
+                text.galley.text().to_string(),
+                text.galley.rect.translate(text.pos.to_vec2()),
+            )),
+            _ => None,
+        })
+        .collect()
+}
+
+fn find(labels: &[(String, Rect)], label: &str) -> Rect {
+    labels
+        .iter()
+        .find(|(text, _)| text == label)
+        .map(|(_, rect)| *rect)
+        .unwrap_or_else(|| panic!("{label} was not painted"))
+}
+
+fn press(pos: Pos2, pressed: bool) -> Event {
+    Event::PointerButton {
+        pos,
+        button: PointerButton::Primary,
+        pressed,
+        modifiers: Modifiers::NONE,
+    }
+}
+
+fn key(key: Key) -> Event {
+    Event::Key {
+        key,
+        physical_key: None,
+        pressed: true,
+        repeat: false,
+        modifiers: Modifiers::NONE,
+    }
+}
+
+/// Presses at `from`, drags to `to` over a few frames and releases there.
+fn drag_steps(from: Pos2, to: Pos2) -> Vec<Vec<Event>> {
+    vec![
+        vec![Event::PointerMoved(from), press(from, true)],
+        vec![Event::PointerMoved(from + vec2(0.0, 12.0))],
+        vec![Event::PointerMoved(to)],
+        vec![Event::PointerMoved(to)],
+        vec![press(to, false)],
+    ]
+}
+
+fn list(ui: &mut Ui, theme: &Theme, items: &[&str]) -> Option<SortableMove> {
+    sortable(
+        ui,
+        theme,
+        SortableProps::new(Id::new("list"), items.len()),
+        |ui, item| {
+            ui.label(items[item.index]);
+        },
+    )
+    .moved
+}
+
+#[test]
+fn moves_are_applied_within_and_between_lists() {
+    let mut items = vec!["a", "b", "c", "d"];
+    let moved = SortableMove {
+        from: location("list", 0),
+        to: location("list", 2),
+    };
+    assert!(reorder_items(&mut items, &moved));
+    assert_eq!(items, ["b", "c", "a", "d"]);
+
+    let mut lists = vec![vec!["a", "b"], vec!["c"]];
+    let containers = [Id::new("todo"), Id::new("done")];
+    let across = SortableMove {
+        from: location("todo", 1),
+        to: location("done", 0),
+    };
+    assert!(!reorder_items(&mut lists[0], &across));
+    assert!(apply_sortable_move(&mut lists, &containers, &across));
+    assert_eq!(lists, [vec!["a"], vec!["b", "c"]]);
+}
+
+#[test]
+fn dragging_an_item_reorders_the_list() {
+    let ctx = egui::Context::default();
+    let theme = Theme::default();
+    let items = ["First", "Second", "Third", "Fourth"];
+
+    let labels = frame(&ctx, vec![], |ui| {
+        list(ui, &theme, &items);
+    });
+    let first = find(&labels, "First").center();
+    let third = find(&labels, "Third").center() + vec2(0.0, 4.0);
+
+    let mut moved = None;
+    for events in drag_steps(first, third) {
+        frame(&ctx, events, |ui| {
+            moved = moved.or(list(ui, &theme, &items));
+        });
+    }
+    assert_eq!(
+        moved,
+        Some(SortableMove {
+            from: location("list", 0),
+            to: location("list", 2),
+        })
+    );
+}
+
+#[test]
+fn space_and_arrows_reorder_with_the_keyboard() {
+    let ctx = egui::Context::default();
+    let theme = Theme::default();
+    let items = ["First", "Second", "Third"];
+
+    let mut moved = None;
+    let steps = [
+        vec![],
+        vec![key(Key::Tab)],
+        vec![],
+        vec![key(Key::Space)],
+        vec![key(Key::ArrowDown)],
+        vec![key(Key::ArrowDown)],
+        vec![key(Key::ArrowDown)],
+        vec![key(Key::Space)],
+    ];
+    for events in steps {
+        frame(&ctx, events, |ui| {
+            moved = moved.or(list(ui, &theme, &items));
+        });
+    }
+    // The item stops at the end of the list.
+    assert_eq!(
+        moved,
+        Some(SortableMove {
+            from: location("list", 0),
+            to: location("list", 2),
+        })
+    );
+}
+
+#[test]
+fn items_move_between_lists_in_a_group() {
+    let ctx = egui::Context::default();
+    let theme = Theme::default();
+    let todo = ["Write docs", "Fix bug"];
+    let done = ["Ship release"];
+    let board = |ui: &mut Ui| {
+        let mut moved = None;
+        ui.columns(2, |columns| {
+            for (column, (name, items)) in columns
+                .iter_mut()
+                .zip([("todo", &todo[..]), ("done", &done[..])])
+            {
+                let props = SortableProps::new(Id::new(name), items.len())
+                    .group(Id::new("board"))
+                    .min_size(vec2(0.0, 80.0));
+                let response = sortable(column, &theme, props, |ui, item| {
+                    ui.label(items[item.index]);
+                });
+                moved = moved.or(response.moved);
+            }
+        });
+        moved
+    };
+
+    let labels = frame(&ctx, vec![], |ui| {
+        board(ui);
+    });
+    let from = find(&labels, "Fix bug").center();
+    let to = find(&labels, "Ship release").center() + vec2(0.0, 8.0);
+
+    let mut moved = None;
+    for events in drag_steps(from, to) {
+        frame(&ctx, events, |ui| {
+            moved = moved.or(board(ui));
+        });
+    }
+    assert_eq!(
+        moved,
+        Some(SortableMove {
+            from: location("todo", 1),
+            to: location("done", 1),
+        })
+    );
+
+    // With the keyboard, the arrows across the list move to the neighbouring list.
+    let mut moved = None;
+    let steps = [
+        vec![key(Key::Tab)],
+        vec![],
+        vec![key(Key::Space)],
+        vec![key(Key::ArrowRight)],
+        vec![key(Key::Space)],
+    ];
+    for events in steps {
+        frame(&ctx, events, |ui| {
+            moved = moved.or(board(ui));
+        });
+    }
+    assert_eq!(
+        moved,
+        Some(SortableMove {
+            from: location("todo", 0),
+            to: location("done", 0),
+        })
+    );
+}
+
+#[test]
+fn reorderable_tabs_report_moves() {
+    let ctx = egui::Context::default();
+    let theme = Theme::default();
+    let items = vec![
+        TabItem::new("account", "Account"),
+        TabItem::new("password", "Password"),
+        TabItem::new("billing", "Billing"),
+    ];
+    let mut active = "account".to_string();
+
+    let mut moved = None;
+    let steps = [
+        vec![],
+        vec![key(Key::Tab)],
+        vec![],
+        vec![key(Key::Space)],
+        vec![key(Key::ArrowRight)],
+        vec![key(Key::Space)],
+    ];
+    for events in steps {
+        frame(&ctx, events, |ui| {
+            let props = TabsProps::new(Id::new("tabs"), &items, &mut active).reorderable(true);
+            let result = tabs(ui, &theme, props, |_, _| {});
+            moved = moved.or(result.moved);
+        });
+    }
+    let moved = moved.expect("tab moved");
+    assert_eq!((moved.from.index, moved.to.index), (0, 1));
+    assert_eq!(active, "account");
+}
+
+#[test]
+fn data_table_rows_can_be_dragged_and_keep_their_selection() {
+    let ctx = egui::Context::default();
+    let theme = Theme::default();
+    let rows: Vec<String> = (0..4).map(|index| format!("Row {index}")).collect();
+    let table = |ui: &mut Ui| {
+        let columns = vec![DataTableColumn::new("name", "Name", |ui, row: &String| {
+            ui.label(row);
+        })];
+        data_table(
+            ui,
+            &theme,
+            DataTableProps::new(Id::new("rows"), columns, &rows)
+                .show_column_toggle(false)
+                .reorderable(true),
+        )
+    };
+
+    let labels = frame(&ctx, vec![], |ui| {
+        table(ui);
+    });
+    let checkbox = find(&labels, "Row 0").left_center() - vec2(24.0, 0.0);
+    let from = find(&labels, "Row 0").center();
+    let to = find(&labels, "Row 2").center() + vec2(0.0, 4.0);
+
+    let mut selected = Vec::new();
+    for events in [
+        vec![Event::PointerMoved(checkbox), press(checkbox, true)],
+        vec![press(checkbox, false)],
+    ] {
+        frame(&ctx, events, |ui| selected = table(ui).selected);
+    }
+    assert_eq!(selected, [0]);
+
+    let mut moved = None;
+    for events in drag_steps(from, to) {
+        frame(&ctx, events, |ui| {
+            let response = table(ui);
+            moved = moved.or(response.moved);
+            selected = response.selected;
+        });
+    }
+    let moved = moved.expect("row moved");
+    assert_eq!((moved.from.index, moved.to.index), (0, 2));
+    assert_eq!(selected, [2]);
+}
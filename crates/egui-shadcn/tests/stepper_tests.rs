@@ -0,0 +1,206 @@
+use egui::{Event, Id, Modifiers, PointerButton, Pos2, Rect, Shape, vec2};
+use egui_shadcn::{
+    FormState, StepItem, StepStatus, StepperOrientation, StepperProps, StepperResponse,
+    StepperState, Theme, ValidationMode, required, stepper,
+};
+use std::cell::RefCell;
+
+fn steps() -> Vec<StepItem> {
+    vec![
+        StepItem::new("Account").fields(["email"]),
+        StepItem::new("Profile")
+            .description("Tell us about you")
+            .fields(["bio"])
+            .optional(true),
+        StepItem::new("Confirm"),
+    ]
+}
+
+fn form() -> FormState {
+    let mut form = FormState::new(ValidationMode::OnSubmit);
+    form.field("email", required("Email is required"));
+    form.field("bio", required("Bio is required"));
+    // Bound inputs report their initial values on the first frame.
+    form.set_text("email", "");
+    form.set_text("bio", "");
+    form
+}
+
+/// Runs one frame and returns the response with the rect of every painted label.
+fn frame(
+    ctx: &egui::Context,
+    steps: &[StepItem],
+    state: &mut StepperState,
+    form: &mut FormState,
+    orientation: StepperOrientation,
+    events: Vec<Event>,
+) -> (StepperResponse<usize>, Vec<(String, Rect)>) {
+    let theme = Theme::default();
+    let input = egui::RawInput {
+        screen_rect: Some(Rect::from_min_size(Pos2::ZERO, vec2(800.0, 600.0))),
+        events,
+        ..Default::default()
+    };
+    let result = RefCell::new(None);
+    let output = ctx.run(input, |ctx| {
+        egui::CentralPanel::default().show(ctx, |ui| {
+            let props =
+                StepperProps::new(Id::new("wizard"), steps, state, form).orientation(orientation);
+            *result.borrow_mut() = Some(stepper(ui, &theme, props, |ui, step, _| {
+                ui.label(format!("Content {step}"));
+                step
+            }));
+        });
+    });
+    let labels = output
+        .shapes
+        .iter()
+        .filter_map(|clipped| match &clipped.shape {
+            Shape::Text(text) => Some((
+                text.galley.text().to_string(),
+                text.galley.rect.translate(text.pos.to_vec2()),
+            )),
+            _ => None,
+        })
+        .collect();
+    (result.into_inner().expect("stepper rendered"), labels)
+}
+
+fn find(labels: &[(String, Rect)], label: &str) -> Option<Rect> {
+    labels
+        .iter()
+        .find(|(text, _)| text == label)
+        .map(|(_, rect)| *rect)
+}
+
+fn click(pos: Pos2) -> Vec<Event> {
+    let button = |pressed| Event::PointerButton {
+        pos,
+        button: PointerButton::Primary,
+        pressed,
+        modifiers: Modifiers::NONE,
+    };
+    vec![Event::PointerMoved(pos), button(true), button(false)]
+}
+
+#[test]
+fn next_is_gated_on_the_step_fields() {
+    let steps = steps();
+    let mut form = form();
+    let mut state = StepperState::new();
+
+    assert!(!state.next(&steps, &mut form));
+    assert_eq!(state.status(0), StepStatus::Error);
+    assert_eq!(form.error("email"), Some("Email is required"));
+    // Other steps' fields are left alone.
+    assert_eq!(form.error("bio"), None);
+
+    form.set_text("email", "ada@example.com");
+    assert_eq!(form.error("email"), None);
+    assert!(state.next(&steps, &mut form));
+    assert_eq!(state.current, 1);
+    assert_eq!(state.status(0), StepStatus::Completed);
+    assert_eq!(state.status(1), StepStatus::Current);
+}
+
+#[test]
+fn optional_steps_can_be_skipped_and_revisited() {
+    let steps = steps();
+    let mut form = form();
+    form.set_text("email", "ada@example.com");
+    let mut state = StepperState::new();
+
+    assert!(!state.skip(&steps), "the first step is required");
+    assert!(state.next(&steps, &mut form));
+    assert!(state.skip(&steps));
+    assert_eq!(state.status(1), StepStatus::Skipped);
+    assert!(state.next(&steps, &mut form));
+    assert!(state.finished);
+
+    assert!(state.back());
+    assert!(!state.finished);
+    assert!(state.go_to(0, &steps, &mut form));
+    assert_eq!(state.current, 0);
+
+    // Jumping forward validates every step on the way and stops at the first failure.
+    assert!(!state.go_to(2, &steps, &mut form));
+    assert_eq!(state.current, 1);
+    assert_eq!(state.status(1), StepStatus::Error);
+}
+
+#[test]
+fn buttons_navigate_and_report_blocked_steps() {
+    let ctx = egui::Context::default();
+    let steps = steps();
+    let mut form = form();
+    let mut state = StepperState::new();
+    let horizontal = StepperOrientation::Horizontal;
+
+    let (response, labels) = frame(&ctx, &steps, &mut state, &mut form, horizontal, vec![]);
+    assert_eq!(response.content, 0);
+    assert!(find(&labels, "Step 1 of 3").is_some());
+    assert!(find(&labels, "Optional").is_some());
+    let next = find(&labels, "Next").unwrap().center();
+
+    let (response, _) = frame(&ctx, &steps, &mut state, &mut form, horizontal, click(next));
+    assert!(response.blocked);
+    assert!(!response.changed);
+    assert_eq!(state.status(0), StepStatus::Error);
+
+    form.set_text("email", "ada@example.com");
+    let (_, labels) = frame(&ctx, &steps, &mut state, &mut form, horizontal, vec![]);
+    assert!(state.errors.is_empty(), "fixing the field clears the error");
+    assert!(find(&labels, "Skip").is_none());
+
+    let (response, _) = frame(&ctx, &steps, &mut state, &mut form, horizontal, click(next));
+    assert!(response.changed);
+    assert_eq!(state.current, 1);
+
+    let (_, labels) = frame(&ctx, &steps, &mut state, &mut form, horizontal, vec![]);
+    let skip = find(&labels, "Skip").unwrap().center();
+    frame(&ctx, &steps, &mut state, &mut form, horizontal, click(skip));
+    assert_eq!(state.current, 2);
+    assert_eq!(state.status(1), StepStatus::Skipped);
+
+    let (_, labels) = frame(&ctx, &steps, &mut state, &mut form, horizontal, vec![]);
+    let finish = find(&labels, "Finish").unwrap().center();
+    let (response, _) = frame(
+        &ctx,
+        &steps,
+        &mut state,
+        &mut form,
+        horizontal,
+        click(finish),
+    );
+    assert!(response.finished);
+}
+
+#[test]
+fn vertical_steps_show_content_inline_and_revisit_on_click() {
+    let ctx = egui::Context::default();
+    let steps = steps();
+    let mut form = form();
+    form.set_text("email", "ada@example.com");
+    let mut state = StepperState::new();
+    state.next(&steps, &mut form);
+    let vertical = StepperOrientation::Vertical;
+
+    let (response, labels) = frame(&ctx, &steps, &mut state, &mut form, vertical, vec![]);
+    assert_eq!(response.content, 1);
+    let account = find(&labels, "Account").unwrap();
+    let content = find(&labels, "Content 1").unwrap();
+    let confirm = find(&labels, "Confirm").unwrap();
+    assert!(account.bottom() < content.top() && content.bottom() < confirm.top());
+
+    // Clicking a completed step goes back to it.
+    let (response, _) = frame(
+        &ctx,
+        &steps,
+        &mut state,
+        &mut form,
+        vertical,
+        click(account.center()),
+    );
+    assert!(response.changed);
+    assert_eq!(state.current, 0);
+}
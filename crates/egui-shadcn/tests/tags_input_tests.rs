@@ -0,0 +1,254 @@
+use egui::{Event, Key, Modifiers, PointerButton, Pos2, Rect, pos2, vec2};
+use egui_shadcn::form::{min_length, pattern};
+use egui_shadcn::{SelectItem, TagsInputProps, Theme, tags_input};
+
+fn init_logger() {
+    let _ = env_logger::builder().is_test(true).try_init();
+}
+
+fn key(key: Key) -> Event {
+    Event::Key {
+        key,
+        physical_key: None,
+        pressed: true,
+        repeat: false,
+        modifiers: Modifiers::NONE,
+    }
+}
+
+fn click(pos: Pos2) -> Vec<Vec<Event>> {
+    let button = |pressed| Event::PointerButton {
+        pos,
+        button: PointerButton::Primary,
+        pressed,
+        modifiers: Modifiers::default(),
+    };
+    vec![
+        vec![Event::PointerMoved(pos)],
+        vec![button(true)],
+        vec![button(false)],
+    ]
+}
+
+type Props<'v> = TagsInputProps<'v, &'static str>;
+
+struct Harness {
+    ctx: egui::Context,
+    theme: Theme,
+    time: f64,
+    error: Option<String>,
+    /// Every string painted in the last frame.
+    texts: Vec<String>,
+}
+
+impl Harness {
+    fn new() -> Self {
+        Self {
+            ctx: egui::Context::default(),
+            theme: Theme::default(),
+            time: 0.0,
+            error: None,
+            texts: Vec::new(),
+        }
+    }
+
+    fn frame(
+        &mut self,
+        tags: &mut Vec<String>,
+        events: Vec<Event>,
+        configure: for<'v> fn(Props<'v>) -> Props<'v>,
+    ) {
+        let input = egui::RawInput {
+            screen_rect: Some(Rect::from_min_size(Pos2::ZERO, vec2(400.0, 400.0))),
+            events,
+            time: Some(self.time),
+            ..Default::default()
+        };
+        let theme = &self.theme;
+        let mut error = None;
+        let output = self.ctx.run(input, |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                let response = tags_input(ui, theme, configure(TagsInputProps::new("tags", tags)));
+                error = response.error;
+            });
+        });
+        self.error = error;
+        self.texts = output
+            .shapes
+            .iter()
+            .filter_map(|clipped| match &clipped.shape {
+                egui::Shape::Text(text) => Some(text.galley.text().to_string()),
+                _ => None,
+            })
+            .collect();
+        // Far enough apart that separate clicks never count as a double-click.
+        self.time += 0.5;
+    }
+
+    fn focus(&mut self, tags: &mut Vec<String>, configure: for<'v> fn(Props<'v>) -> Props<'v>) {
+        self.frame(tags, Vec::new(), configure);
+        for events in click(pos2(200.0, 24.0)) {
+            self.frame(tags, events, configure);
+        }
+    }
+}
+
+fn plain(props: Props<'_>) -> Props<'_> {
+    props
+}
+
+#[test]
+fn enter_and_delimiters_commit_trimmed_tags() {
+    init_logger();
+    let mut harness = Harness::new();
+    let mut tags = Vec::new();
+    harness.focus(&mut tags, plain);
+
+    harness.frame(&mut tags, vec![Event::Text(" rust ".into())], plain);
+    harness.frame(&mut tags, vec![key(Key::Enter)], plain);
+    assert_eq!(tags, ["rust"]);
+
+    harness.frame(
+        &mut tags,
+        vec![Event::Paste("egui, ui,shadcn".into())],
+        plain,
+    );
+    assert_eq!(
+        tags,
+        ["rust", "egui", "ui"],
+        "the text after the last comma stays"
+    );
+
+    harness.frame(&mut tags, vec![key(Key::Enter)], plain);
+    assert_eq!(tags, ["rust", "egui", "ui", "shadcn"]);
+}
+
+#[test]
+fn backspace_on_empty_text_removes_last_tag() {
+    init_logger();
+    let mut harness = Harness::new();
+    let mut tags = vec!["a".to_string(), "b".to_string()];
+    harness.focus(&mut tags, plain);
+
+    harness.frame(&mut tags, vec![Event::Text("c".into())], plain);
+    harness.frame(&mut tags, vec![key(Key::Backspace)], plain);
+    assert_eq!(tags, ["a", "b"], "backspace edits the text first");
+
+    harness.frame(&mut tags, vec![key(Key::Backspace)], plain);
+    assert_eq!(tags, ["a"]);
+}
+
+#[test]
+fn rejects_duplicates_limits_and_invalid_tags() {
+    init_logger();
+    let mut harness = Harness::new();
+    let mut tags = vec!["a@example.com".to_string()];
+    fn configure(props: Props<'_>) -> Props<'_> {
+        props
+            .max_tags(2)
+            .validator(pattern(r"^[^@\s]+@[^@\s]+\.[a-z]+$", "Invalid email"))
+            .duplicate_message("Duplicate")
+            .max_tags_message("Too many")
+    }
+    harness.focus(&mut tags, configure);
+
+    harness.frame(
+        &mut tags,
+        vec![Event::Text("a@example.com".into())],
+        configure,
+    );
+    harness.frame(&mut tags, vec![key(Key::Enter)], configure);
+    assert_eq!(tags.len(), 1);
+    assert_eq!(harness.error.as_deref(), Some("Duplicate"));
+
+    harness.frame(&mut tags, vec![key(Key::Backspace)], configure);
+    assert_eq!(harness.error, None, "editing clears the error");
+    harness.frame(&mut tags, vec![Event::Text("1".into())], configure);
+    harness.frame(&mut tags, vec![key(Key::Enter)], configure);
+    assert_eq!(harness.error.as_deref(), Some("Invalid email"));
+
+    // Clear "a@example.co1" without reaching the tag.
+    for _ in 0..13 {
+        harness.frame(&mut tags, vec![key(Key::Backspace)], configure);
+    }
+    harness.frame(
+        &mut tags,
+        vec![Event::Text("b@example.com,".into())],
+        configure,
+    );
+    assert_eq!(tags, ["a@example.com", "b@example.com"]);
+
+    harness.frame(
+        &mut tags,
+        vec![Event::Text("c@example.com,".into())],
+        configure,
+    );
+    assert_eq!(tags.len(), 2);
+    assert_eq!(harness.error.as_deref(), Some("Too many"));
+}
+
+#[test]
+fn rejected_tags_are_restored_once_with_the_configured_delimiter() {
+    init_logger();
+    let mut harness = Harness::new();
+    let mut tags = Vec::new();
+    fn configure(props: Props<'_>) -> Props<'_> {
+        props
+            .delimiters([';'])
+            .validator(min_length(3, "Too short"))
+    }
+    harness.focus(&mut tags, configure);
+
+    harness.frame(
+        &mut tags,
+        vec![Event::Paste("ok;rust; egui".into())],
+        configure,
+    );
+    assert_eq!(tags, ["rust"]);
+    assert_eq!(harness.error.as_deref(), Some("Too short"));
+    harness.frame(&mut tags, Vec::new(), configure);
+    assert!(harness.texts.iter().any(|text| text == "ok; egui"));
+
+    // The restored text is left alone until it is edited.
+    for _ in 0..3 {
+        harness.frame(&mut tags, Vec::new(), configure);
+    }
+    assert_eq!(tags, ["rust"]);
+    assert!(harness.texts.iter().any(|text| text == "ok; egui"));
+    assert_eq!(harness.error.as_deref(), Some("Too short"));
+}
+
+#[test]
+fn check_tag_uses_form_validators() {
+    let mut tags = vec!["one".to_string()];
+    let props = TagsInputProps::new("tags", &mut tags).validator(min_length(3, "Too short"));
+    assert_eq!(props.check_tag("ab").as_deref(), Some("Too short"));
+    assert_eq!(props.check_tag("one").as_deref(), Some("Already added."));
+    assert_eq!(props.check_tag("two"), None);
+
+    let props = props.allow_duplicates(true);
+    assert_eq!(props.check_tag("one"), None);
+}
+
+#[test]
+fn enter_picks_the_active_suggestion() {
+    init_logger();
+    let mut harness = Harness::new();
+    let mut tags = vec!["rust".to_string()];
+    fn configure(props: Props<'_>) -> Props<'_> {
+        static ITEMS: std::sync::LazyLock<Vec<SelectItem>> = std::sync::LazyLock::new(|| {
+            vec![
+                SelectItem::option("rust", "Rust"),
+                SelectItem::option("ruby", "Ruby"),
+                SelectItem::option("go", "Go"),
+            ]
+        });
+        props.suggestions(&ITEMS)
+    }
+    harness.focus(&mut tags, configure);
+
+    harness.frame(&mut tags, vec![Event::Text("ru".into())], configure);
+    harness.frame(&mut tags, Vec::new(), configure);
+    harness.frame(&mut tags, vec![key(Key::Enter)], configure);
+    assert_eq!(tags, ["rust", "ruby"], "existing tags are not suggested");
+}
@@ -2,8 +2,9 @@ use egui::Color32;
 use egui_shadcn::Theme;
 use egui_shadcn::tokens::ColorPalette;
 use egui_shadcn::{
-    Textarea, TextareaProps, TextareaRadius, TextareaResize, TextareaSize, TextareaStyle,
-    TextareaVariant, textarea_with_props,
+    HighlightStyle, JsonHighlighter, KeywordHighlighter, TextHighlighter, Textarea, TextareaProps,
+    TextareaRadius, TextareaResize, TextareaSize, TextareaStyle, TextareaVariant,
+    textarea_with_props,
 };
 
 fn init_logger() {
@@ -486,3 +487,121 @@ fn textarea_style_default() {
     let style = TextareaStyle::default();
     assert_ne!(style.text_color, Color32::TRANSPARENT);
 }
+
+fn highlight_style() -> HighlightStyle {
+    HighlightStyle::from_palette(
+        &ColorPalette::default(),
+        egui::FontId::monospace(12.0),
+        Color32::WHITE,
+    )
+}
+
+fn color_of(job: &egui::text::LayoutJob, token: &str) -> Color32 {
+    let start = job.text.find(token).expect("token in text");
+    job.sections
+        .iter()
+        .find(|section| section.byte_range.contains(&start))
+        .map(|section| section.format.color)
+        .expect("section for token")
+}
+
+#[test]
+fn json_highlighter_colors_tokens() {
+    let style = highlight_style();
+    let text = r#"{"name": "egui", "stars": 12.5, "ok": true}"#;
+    let job = JsonHighlighter.highlight(text, &style);
+    assert_eq!(job.text, text);
+    assert_eq!(color_of(&job, "\"name\""), style.key);
+    assert_eq!(color_of(&job, "\"egui\""), style.string);
+    assert_eq!(color_of(&job, "12.5"), style.number);
+    assert_eq!(color_of(&job, "true"), style.keyword);
+    assert_eq!(color_of(&job, "{"), style.punctuation);
+}
+
+#[test]
+fn keyword_highlighter_matches_sql_case_insensitively() {
+    let style = highlight_style();
+    let text = "SELECT name FROM users where id = 'x' -- only one\nlimit 1";
+    let job = KeywordHighlighter::sql().highlight(text, &style);
+    assert_eq!(job.text, text);
+    assert_eq!(color_of(&job, "SELECT"), style.keyword);
+    assert_eq!(color_of(&job, "where"), style.keyword);
+    assert_eq!(color_of(&job, "name"), style.text);
+    assert_eq!(color_of(&job, "'x'"), style.string);
+    assert_eq!(color_of(&job, "-- only"), style.comment);
+    assert_eq!(color_of(&job, "limit"), style.keyword);
+    assert_eq!(color_of(&job, "1"), style.number);
+
+    let log = KeywordHighlighter::log();
+    assert!(log.is_keyword("ERROR"));
+    assert!(!log.is_keyword("error"));
+}
+
+fn autosized_height(text: &str) -> f32 {
+    let ctx = egui::Context::default();
+    let theme = Theme::default();
+    let mut text = text.to_string();
+    let mut height = 0.0;
+    let input = egui::RawInput {
+        screen_rect: Some(egui::Rect::from_min_size(
+            egui::Pos2::ZERO,
+            egui::vec2(400.0, 400.0),
+        )),
+        ..Default::default()
+    };
+    let _ = ctx.run(input, |ctx| {
+        egui::CentralPanel::default().show(ctx, |ui| {
+            let top = ui.cursor().top();
+            textarea_with_props(
+                ui,
+                &theme,
+                TextareaProps::new("autosize", &mut text)
+                    .width(240.0)
+                    .min_rows(2)
+                    .max_rows(4)
+                    .line_numbers(true),
+            );
+            height = ui.cursor().top() - top;
+        });
+    });
+    height
+}
+
+#[test]
+fn textarea_autosize_grows_between_min_and_max_rows() {
+    init_logger();
+    let empty = autosized_height("");
+    assert_eq!(autosized_height("one\ntwo"), empty, "min_rows is the floor");
+
+    let three = autosized_height("one\ntwo\nthree");
+    assert!(three > empty);
+
+    let four = autosized_height("1\n2\n3\n4");
+    assert!(four > three);
+    let capped = autosized_height(&"line\n".repeat(20));
+    // The scroll area rounds its content to whole pixels.
+    assert!((capped - four).abs() < 1.0, "max_rows caps the height");
+}
+
+#[test]
+fn textarea_uses_custom_highlighter() {
+    init_logger();
+    let ctx = egui::Context::default();
+    let theme = Theme::default();
+    let mut text = "hello".to_string();
+    let calls = std::cell::Cell::new(0);
+    let highlighter = |text: &str, style: &HighlightStyle| {
+        calls.set(calls.get() + 1);
+        egui::text::LayoutJob::simple_singleline(text.to_owned(), style.font.clone(), style.string)
+    };
+    let _ = ctx.run(egui::RawInput::default(), |ctx| {
+        egui::CentralPanel::default().show(ctx, |ui| {
+            textarea_with_props(
+                ui,
+                &theme,
+                TextareaProps::new("highlighted", &mut text).highlighter(highlighter),
+            );
+        });
+    });
+    assert!(calls.get() > 0);
+}
@@ -458,3 +458,137 @@ fn tooltip_style_arrow_fill_matches_bg() {
 
     assert_eq!(style.bg, style.arrow_fill);
 }
+
+#[test]
+fn tooltip_anchor_defaults_to_trigger_and_can_follow_cursor() {
+    init_logger();
+    let props = TooltipProps::new("Hi");
+    assert_eq!(props.anchor, egui_shadcn::PopoverAnchor::Trigger);
+
+    let props = TooltipProps::new("Hi").follow_cursor(true);
+    assert_eq!(props.anchor, egui_shadcn::PopoverAnchor::Cursor);
+
+    let point = egui::pos2(40.0, 40.0);
+    let props = TooltipProps::new("Hi").anchor(egui_shadcn::PopoverAnchor::Point(point));
+    assert_eq!(props.anchor, egui_shadcn::PopoverAnchor::Point(point));
+}
+
+fn run_toolbar_frame(
+    ctx: &Context,
+    time: f64,
+    pointer: egui::Pos2,
+    provider: egui_shadcn::TooltipProviderProps,
+    first_delay: Option<u64>,
+) -> (bool, bool) {
+    let theme = Theme::default();
+    let input = RawInput {
+        time: Some(time),
+        events: vec![egui::Event::PointerMoved(pointer)],
+        ..Default::default()
+    };
+    let mut shown = (false, false);
+    let _ = ctx.run(input, |ctx| {
+        egui::CentralPanel::default().show(ctx, |ui| {
+            egui_shadcn::tooltip_provider(ui, provider, |ui| {
+                let a_rect =
+                    egui::Rect::from_min_size(egui::pos2(20.0, 100.0), egui::vec2(40.0, 30.0));
+                let b_rect =
+                    egui::Rect::from_min_size(egui::pos2(70.0, 100.0), egui::vec2(40.0, 30.0));
+                let a = ui.interact(a_rect, egui::Id::new("toolbar-a"), egui::Sense::hover());
+                let b = ui.interact(b_rect, egui::Id::new("toolbar-b"), egui::Sense::hover());
+                let mut a_props = TooltipProps::new("A").side(TooltipSide::Bottom);
+                if let Some(delay) = first_delay {
+                    a_props = a_props.delay_ms(delay);
+                }
+                shown.0 = tooltip(&a, ui, &theme, a_props);
+                shown.1 = tooltip(
+                    &b,
+                    ui,
+                    &theme,
+                    TooltipProps::new("B").side(TooltipSide::Bottom),
+                );
+            })
+        });
+    });
+    shown
+}
+
+#[test]
+fn tooltip_provider_scope_is_visible_only_inside() {
+    init_logger();
+    let ctx = Context::default();
+    let provider = egui_shadcn::TooltipProviderProps::new(egui::Id::new("scope")).delay_ms(100);
+    let _ = ctx.run(RawInput::default(), |ctx| {
+        egui::CentralPanel::default().show(ctx, |ui| {
+            assert_eq!(egui_shadcn::current_tooltip_provider(ui.ctx()), None);
+            egui_shadcn::tooltip_provider(ui, provider, |ui| {
+                assert_eq!(
+                    egui_shadcn::current_tooltip_provider(ui.ctx()),
+                    Some(provider)
+                );
+            });
+            assert_eq!(egui_shadcn::current_tooltip_provider(ui.ctx()), None);
+        });
+    });
+}
+
+#[test]
+fn tooltip_provider_skips_delay_for_neighbours() {
+    init_logger();
+    let ctx = Context::default();
+    let provider = egui_shadcn::TooltipProviderProps::new(egui::Id::new("toolbar"))
+        .delay_ms(700)
+        .skip_delay_ms(300);
+    let over_a = egui::pos2(40.0, 115.0);
+    let over_b = egui::pos2(90.0, 115.0);
+
+    for time in [0.0, 0.1, 0.3] {
+        let shown = run_toolbar_frame(&ctx, time, over_a, provider, None);
+        assert_eq!(shown, (false, false), "waits for the provider delay");
+    }
+    let shown = run_toolbar_frame(&ctx, 0.9, over_a, provider, None);
+    assert!(shown.0, "opens after the provider delay");
+
+    run_toolbar_frame(&ctx, 0.95, over_b, provider, None);
+    let shown = run_toolbar_frame(&ctx, 1.0, over_b, provider, None);
+    assert!(shown.1, "a neighbour opens without waiting the delay again");
+}
+
+#[test]
+fn tooltip_delay_overrides_provider() {
+    init_logger();
+    let ctx = Context::default();
+    let provider = egui_shadcn::TooltipProviderProps::new(egui::Id::new("override")).delay_ms(5000);
+    let over_a = egui::pos2(40.0, 115.0);
+
+    run_toolbar_frame(&ctx, 0.0, over_a, provider, Some(0));
+    let shown = run_toolbar_frame(&ctx, 0.05, over_a, provider, Some(0));
+    assert!(shown.0, "an explicit delay wins over the provider");
+}
+
+#[test]
+fn disabled_tooltips_never_open() {
+    init_logger();
+    let ctx = Context::default();
+    let over_a = egui::pos2(40.0, 115.0);
+    let provider = egui_shadcn::TooltipProviderProps::new(egui::Id::new("disabled"))
+        .delay_ms(0)
+        .disabled(true);
+    for time in [0.0, 0.5, 1.0] {
+        assert_eq!(
+            run_toolbar_frame(&ctx, time, over_a, provider, None),
+            (false, false)
+        );
+    }
+
+    let ctx = Context::default();
+    egui_shadcn::set_tooltips_enabled(&ctx, false);
+    assert!(!egui_shadcn::tooltips_enabled(&ctx));
+    let provider = egui_shadcn::TooltipProviderProps::new(egui::Id::new("global")).delay_ms(0);
+    for time in [0.0, 0.5, 1.0] {
+        assert_eq!(
+            run_toolbar_frame(&ctx, time, over_a, provider, None),
+            (false, false)
+        );
+    }
+}
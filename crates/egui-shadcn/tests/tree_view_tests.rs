@@ -0,0 +1,446 @@
+use egui::{Event, Id, Key, Modifiers, PointerButton, Pos2, Rect, Shape, vec2};
+use egui_shadcn::{
+    CheckboxState, Theme, TreeMove, TreeNode, TreeSelectionMode, TreeViewProps, TreeViewResponse,
+    TreeViewState, apply_tree_move, tree_view,
+};
+use std::cell::RefCell;
+
+fn files() -> Vec<TreeNode<u32>> {
+    vec![
+        TreeNode::new(1, "src").children(vec![
+            TreeNode::new(2, "main.rs"),
+            TreeNode::new(3, "lib.rs"),
+            TreeNode::new(4, "widgets").lazy(),
+        ]),
+        TreeNode::new(5, "Cargo.toml"),
+        TreeNode::new(6, "README.md"),
+    ]
+}
+
+/// Runs one frame and returns the response with the rect of every painted label.
+fn frame(
+    ctx: &egui::Context,
+    nodes: &[TreeNode<u32>],
+    state: &mut TreeViewState<u32>,
+    configure: fn(TreeViewProps<'_, u32>) -> TreeViewProps<'_, u32>,
+    events: Vec<Event>,
+) -> (TreeViewResponse<u32>, Vec<(String, Rect)>) {
+    let theme = Theme::default();
+    let modifiers = events
+        .iter()
+        .find_map(|event| match event {
+            Event::PointerButton { modifiers, .. } | Event::Key { modifiers, .. } => {
+                Some(*modifiers)
+            }
+            _ => None,
+        })
+        .unwrap_or_default();
+    let input = egui::RawInput {
+        screen_rect: Some(Rect::from_min_size(Pos2::ZERO, vec2(600.0, 600.0))),
+        modifiers,
+        events,
+        ..Default::default()
+    };
+    let result = RefCell::new(None);
+    let output = ctx.run(input, |ctx| {
+        egui::CentralPanel::default().show(ctx, |ui| {
+            let props = configure(TreeViewProps::new(Id::new("files"), nodes, state));
+            *result.borrow_mut() = Some(tree_view(ui, &theme, props));
+        });
+    });
+    let labels = output
+        .shapes
+        .iter()
+        .filter_map(|clipped| match &clipped.shape {
+            Shape::Text(text) => Some((
+                text.galley.text().to_string(),
+                text.galley.rect.translate(text.pos.to_vec2()),
+            )),
+            _ => None,
+        })
+        .collect();
+    (result.into_inner().expect("tree rendered"), labels)
+}
+
+fn find(labels: &[(String, Rect)], label: &str) -> Option<Rect> {
+    labels
+        .iter()
+        .find(|(text, _)| text == label)
+        .map(|(_, rect)| *rect)
+}
+
+fn click(pos: Pos2, modifiers: Modifiers) -> Vec<Event> {
+    let button = |pressed| Event::PointerButton {
+        pos,
+        button: PointerButton::Primary,
+        pressed,
+        modifiers,
+    };
+    vec![Event::PointerMoved(pos), button(true), button(false)]
+}
+
+fn key(key: Key) -> Event {
+    key_with(key, Modifiers::NONE)
+}
+
+fn key_with(key: Key, modifiers: Modifiers) -> Event {
+    Event::Key {
+        key,
+        physical_key: None,
+        pressed: true,
+        repeat: false,
+        modifiers,
+    }
+}
+
+fn multi(props: TreeViewProps<'_, u32>) -> TreeViewProps<'_, u32> {
+    props.selection(TreeSelectionMode::Multi)
+}
+
+fn checks(props: TreeViewProps<'_, u32>) -> TreeViewProps<'_, u32> {
+    props.checkboxes(true)
+}
+
+fn drag(props: TreeViewProps<'_, u32>) -> TreeViewProps<'_, u32> {
+    props.draggable(true)
+}
+
+fn scrolled(props: TreeViewProps<'_, u32>) -> TreeViewProps<'_, u32> {
+    props.max_height(280.0)
+}
+
+#[test]
+fn parents_mirror_their_children_check_state() {
+    let nodes = files();
+    let mut checked = Default::default();
+    assert_eq!(nodes[0].check_state(&checked), CheckboxState::Unchecked);
+
+    nodes[0].children.as_ref().unwrap()[0].set_checked(&mut checked, true);
+    assert_eq!(nodes[0].check_state(&checked), CheckboxState::Indeterminate);
+
+    nodes[0].set_checked(&mut checked, true);
+    assert_eq!(nodes[0].check_state(&checked), CheckboxState::Checked);
+    assert!(checked.contains(&4));
+
+    nodes[0].set_checked(&mut checked, false);
+    assert!(checked.is_empty());
+}
+
+#[test]
+fn moves_reparent_nodes_but_not_into_their_own_subtree() {
+    let mut nodes = files();
+    let moved = TreeMove {
+        node: 6,
+        parent: Some(1),
+        index: 0,
+    };
+    assert!(apply_tree_move(&mut nodes, &moved));
+    assert_eq!(nodes.len(), 2);
+    assert_eq!(nodes[0].children.as_ref().unwrap()[0].label, "README.md");
+
+    let into_self = TreeMove {
+        node: 1,
+        parent: Some(4),
+        index: 0,
+    };
+    assert!(!apply_tree_move(&mut nodes, &into_self));
+    assert_eq!(nodes.len(), 2);
+}
+
+#[test]
+fn chevrons_expand_and_lazy_nodes_request_children() {
+    let ctx = egui::Context::default();
+    let nodes = files();
+    let mut state = TreeViewState::new();
+
+    let (_, labels) = frame(&ctx, &nodes, &mut state, |p| p, vec![]);
+    assert!(find(&labels, "main.rs").is_none());
+    let src = find(&labels, "src").unwrap();
+
+    // The chevron sits just left of the label.
+    let chevron = src.left_center() - vec2(8.0, 0.0);
+    let (response, _) = frame(
+        &ctx,
+        &nodes,
+        &mut state,
+        |p| p,
+        click(chevron, Modifiers::NONE),
+    );
+    assert_eq!(response.toggled, Some((1, true)));
+    assert!(state.selected.is_empty());
+
+    let (_, labels) = frame(&ctx, &nodes, &mut state, |p| p, vec![]);
+    let widgets = find(&labels, "widgets").unwrap();
+    let chevron = widgets.left_center() - vec2(8.0, 0.0);
+    let (response, _) = frame(
+        &ctx,
+        &nodes,
+        &mut state,
+        |p| p,
+        click(chevron, Modifiers::NONE),
+    );
+    assert_eq!(response.load_requested, Some(4));
+
+    let (_, labels) = frame(&ctx, &nodes, &mut state, |p| p, vec![]);
+    assert!(find(&labels, "Loading…").is_some());
+}
+
+#[test]
+fn shift_click_selects_a_range_in_multi_mode() {
+    let ctx = egui::Context::default();
+    let nodes = files();
+    let mut state = TreeViewState::new();
+
+    let (_, labels) = frame(&ctx, &nodes, &mut state, multi, vec![]);
+    let src = find(&labels, "src").unwrap().center();
+    let readme = find(&labels, "README.md").unwrap().center();
+
+    let (response, _) = frame(&ctx, &nodes, &mut state, multi, click(src, Modifiers::NONE));
+    assert!(response.selection_changed);
+    frame(
+        &ctx,
+        &nodes,
+        &mut state,
+        multi,
+        click(readme, Modifiers::SHIFT),
+    );
+    assert_eq!(state.selected.len(), 3);
+
+    frame(
+        &ctx,
+        &nodes,
+        &mut state,
+        multi,
+        click(src, Modifiers::COMMAND),
+    );
+    assert!(!state.is_selected(&1));
+    assert!(state.is_selected(&6));
+}
+
+#[test]
+fn shift_arrow_extends_the_selection_in_multi_mode() {
+    let ctx = egui::Context::default();
+    let nodes = files();
+    let mut state = TreeViewState::new();
+
+    let (_, labels) = frame(&ctx, &nodes, &mut state, multi, vec![]);
+    let src = find(&labels, "src").unwrap().center();
+    frame(&ctx, &nodes, &mut state, multi, click(src, Modifiers::NONE));
+
+    let shift_down = vec![key_with(Key::ArrowDown, Modifiers::SHIFT)];
+    frame(&ctx, &nodes, &mut state, multi, shift_down.clone());
+    let (response, _) = frame(&ctx, &nodes, &mut state, multi, shift_down);
+    assert!(response.selection_changed);
+    assert_eq!(state.focused, Some(6));
+    assert!([1, 5, 6].iter().all(|id| state.is_selected(id)));
+
+    let shift_up = vec![key_with(Key::ArrowUp, Modifiers::SHIFT)];
+    frame(&ctx, &nodes, &mut state, multi, shift_up);
+    assert_eq!(state.selected.len(), 2);
+    assert!(!state.is_selected(&6));
+
+    // A plain arrow only moves focus.
+    frame(&ctx, &nodes, &mut state, multi, vec![key(Key::ArrowDown)]);
+    assert_eq!(state.focused, Some(6));
+    assert_eq!(state.selected.len(), 2);
+}
+
+#[test]
+fn arrow_keys_home_end_and_typeahead_move_focus() {
+    let ctx = egui::Context::default();
+    let nodes = files();
+    let mut state = TreeViewState::new();
+
+    let (_, labels) = frame(&ctx, &nodes, &mut state, |p| p, vec![]);
+    let src = find(&labels, "src").unwrap().center();
+    frame(&ctx, &nodes, &mut state, |p| p, click(src, Modifiers::NONE));
+    assert_eq!(state.focused, Some(1));
+
+    frame(&ctx, &nodes, &mut state, |p| p, vec![key(Key::ArrowRight)]);
+    assert!(state.is_expanded(&1));
+    frame(&ctx, &nodes, &mut state, |p| p, vec![key(Key::ArrowRight)]);
+    assert_eq!(state.focused, Some(2));
+    frame(&ctx, &nodes, &mut state, |p| p, vec![key(Key::ArrowDown)]);
+    assert_eq!(state.focused, Some(3));
+    frame(&ctx, &nodes, &mut state, |p| p, vec![key(Key::ArrowLeft)]);
+    assert_eq!(state.focused, Some(1));
+    frame(&ctx, &nodes, &mut state, |p| p, vec![key(Key::End)]);
+    assert_eq!(state.focused, Some(6));
+    frame(&ctx, &nodes, &mut state, |p| p, vec![key(Key::Home)]);
+    assert_eq!(state.focused, Some(1));
+
+    frame(
+        &ctx,
+        &nodes,
+        &mut state,
+        |p| p,
+        vec![Event::Text("c".into())],
+    );
+    assert_eq!(state.focused, Some(5));
+
+    let (response, _) = frame(&ctx, &nodes, &mut state, |p| p, vec![key(Key::Enter)]);
+    assert_eq!(response.activated, Some(5));
+    assert!(state.is_selected(&5));
+}
+
+#[test]
+fn space_toggles_checkboxes_on_the_focused_branch() {
+    let ctx = egui::Context::default();
+    let nodes = files();
+    let mut state = TreeViewState::new();
+
+    let (_, labels) = frame(&ctx, &nodes, &mut state, checks, vec![]);
+    let src = find(&labels, "src").unwrap().center();
+    frame(
+        &ctx,
+        &nodes,
+        &mut state,
+        checks,
+        click(src, Modifiers::NONE),
+    );
+    let (response, _) = frame(&ctx, &nodes, &mut state, checks, vec![key(Key::Space)]);
+    assert!(response.checked_changed);
+    assert_eq!(nodes[0].check_state(&state.checked), CheckboxState::Checked);
+    assert!(state.checked.contains(&2));
+}
+
+#[test]
+fn large_trees_only_lay_out_visible_rows() {
+    let ctx = egui::Context::default();
+    let nodes: Vec<TreeNode<u32>> = (0..10_000)
+        .map(|index| TreeNode::new(index, format!("Item {index}")))
+        .collect();
+    let mut state = TreeViewState::new();
+
+    let (_, labels) = frame(&ctx, &nodes, &mut state, scrolled, vec![]);
+    let rows = labels
+        .iter()
+        .filter(|(text, _)| text.starts_with("Item "))
+        .count();
+    assert!((10..=12).contains(&rows), "rendered {rows} rows");
+
+    // Jumping to the end scrolls the last row into view.
+    let first = find(&labels, "Item 0").unwrap().center();
+    frame(
+        &ctx,
+        &nodes,
+        &mut state,
+        scrolled,
+        click(first, Modifiers::NONE),
+    );
+    frame(&ctx, &nodes, &mut state, scrolled, vec![key(Key::End)]);
+    let mut labels = Vec::new();
+    for _ in 0..60 {
+        labels = frame(&ctx, &nodes, &mut state, scrolled, vec![]).1;
+    }
+    assert_eq!(state.focused, Some(9_999));
+    assert!(find(&labels, "Item 9999").is_some());
+    assert!(find(&labels, "Item 0").is_none());
+}
+
+#[test]
+fn dragging_a_row_onto_a_branch_reports_a_move() {
+    let ctx = egui::Context::default();
+    let nodes = files();
+    let mut state = TreeViewState::new();
+
+    let (_, labels) = frame(&ctx, &nodes, &mut state, drag, vec![]);
+    let readme = find(&labels, "README.md").unwrap().center();
+    let src = find(&labels, "src").unwrap().center();
+    let press = |pos, pressed| Event::PointerButton {
+        pos,
+        button: PointerButton::Primary,
+        pressed,
+        modifiers: Modifiers::NONE,
+    };
+
+    frame(
+        &ctx,
+        &nodes,
+        &mut state,
+        drag,
+        vec![Event::PointerMoved(readme), press(readme, true)],
+    );
+    frame(
+        &ctx,
+        &nodes,
+        &mut state,
+        drag,
+        vec![Event::PointerMoved(readme - vec2(0.0, 20.0))],
+    );
+    frame(
+        &ctx,
+        &nodes,
+        &mut state,
+        drag,
+        vec![Event::PointerMoved(src)],
+    );
+    assert_eq!(state.dragging(), Some(&6));
+
+    let (response, _) = frame(&ctx, &nodes, &mut state, drag, vec![press(src, false)]);
+    assert_eq!(
+        response.moved,
+        Some(TreeMove {
+            node: 6,
+            parent: Some(1),
+            index: 3,
+        })
+    );
+    assert!(state.dragging().is_none());
+}
+
+#[test]
+fn dropping_onto_an_unloaded_lazy_branch_lands_beside_it() {
+    let ctx = egui::Context::default();
+    let nodes = files();
+    let mut state = TreeViewState::new();
+    state.expanded.insert(1);
+
+    let (_, labels) = frame(&ctx, &nodes, &mut state, drag, vec![]);
+    let readme = find(&labels, "README.md").unwrap().center();
+    let widgets = find(&labels, "widgets").unwrap().center();
+    let press = |pos, pressed| Event::PointerButton {
+        pos,
+        button: PointerButton::Primary,
+        pressed,
+        modifiers: Modifiers::NONE,
+    };
+
+    frame(
+        &ctx,
+        &nodes,
+        &mut state,
+        drag,
+        vec![Event::PointerMoved(readme), press(readme, true)],
+    );
+    frame(
+        &ctx,
+        &nodes,
+        &mut state,
+        drag,
+        vec![Event::PointerMoved(readme - vec2(0.0, 20.0))],
+    );
+    frame(
+        &ctx,
+        &nodes,
+        &mut state,
+        drag,
+        vec![Event::PointerMoved(widgets)],
+    );
+    let (response, _) = frame(&ctx, &nodes, &mut state, drag, vec![press(widgets, false)]);
+    assert_eq!(
+        response.moved,
+        Some(TreeMove {
+            node: 6,
+            parent: Some(1),
+            index: 3,
+        })
+    );
+
+    // Loading the lazy node's children must not drop the moved node.
+    let mut nodes = nodes;
+    assert!(apply_tree_move(&mut nodes, &response.moved.unwrap()));
+    let src = nodes[0].children.as_mut().unwrap();
+    src[2].set_children(vec![TreeNode::new(7, "mod.rs")]);
+    assert_eq!(src[3].label, "README.md");
+}
@@ -409,6 +409,7 @@ where
         iced::advanced::widget::tree::State::new(SliderState::default())
     }
 
+    #[allow(clippy::collapsible_match)]
     fn update(
         &mut self,
         tree: &mut Tree,